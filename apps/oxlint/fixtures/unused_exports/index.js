@@ -0,0 +1,4 @@
+import { used } from './lib';
+
+// import/no-unused-modules
+export const entry = used;
@@ -0,0 +1,4 @@
+export const used = 1;
+
+// import/no-unused-modules
+export const unused = 2;
@@ -283,6 +283,22 @@ pub struct EnablePlugins {
     /// Enable the node plugin and detect node usage problems
     #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
     pub node_plugin: OverrideToggle,
+
+    /// Enable the regexp plugin and detect regular expression problems
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub regexp_plugin: OverrideToggle,
+
+    /// Enable the vue plugin and detect Vue.js component problems
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub vue_plugin: OverrideToggle,
+
+    /// Enable the testing-library plugin and detect Testing Library problems
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub testing_library_plugin: OverrideToggle,
+
+    /// Enable the security plugin and detect injection and unsafe API problems
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub security_plugin: OverrideToggle,
 }
 
 /// Enables or disables a boolean option, or leaves it unset.
@@ -358,6 +374,10 @@ impl EnablePlugins {
         self.react_perf_plugin.inspect(|yes| plugins.set(LintPlugins::REACT_PERF, yes));
         self.promise_plugin.inspect(|yes| plugins.set(LintPlugins::PROMISE, yes));
         self.node_plugin.inspect(|yes| plugins.set(LintPlugins::NODE, yes));
+        self.regexp_plugin.inspect(|yes| plugins.set(LintPlugins::REGEXP, yes));
+        self.vue_plugin.inspect(|yes| plugins.set(LintPlugins::VUE, yes));
+        self.testing_library_plugin.inspect(|yes| plugins.set(LintPlugins::TESTING_LIBRARY, yes));
+        self.security_plugin.inspect(|yes| plugins.set(LintPlugins::SECURITY, yes));
 
         // Without this, jest plugins adapted to vitest will not be enabled.
         if self.vitest_plugin.is_enabled() && self.jest_plugin.is_not_set() {
@@ -674,6 +674,15 @@ mod test {
         assert_eq!(result.number_of_errors, 1);
     }
 
+    #[test]
+    fn test_import_plugin_unused_exports() {
+        let args = &["-c", "fixtures/unused_exports/.oxlintrc.json", "fixtures/unused_exports"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 2);
+    }
+
     #[test]
     fn test_fix() {
         use std::fs;
@@ -17,8 +17,12 @@ fn main() -> io::Result<()> {
     let source_type = SourceType::from_path(path).unwrap();
 
     match Compiler::default().execute(&source_text, source_type, path) {
-        Ok(printed) => {
-            println!("{printed}");
+        Ok(ret) => {
+            for diagnostic in ret.diagnostics {
+                let diagnostic = diagnostic.with_source_code(source_text.to_string());
+                println!("{diagnostic:?}");
+            }
+            println!("{}", ret.code);
         }
         Err(errors) => {
             for error in errors {
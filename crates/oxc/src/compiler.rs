@@ -1,9 +1,14 @@
-use std::{mem, ops::ControlFlow, path::Path};
+use std::{
+    fmt, mem,
+    ops::ControlFlow,
+    path::Path,
+    time::{Duration, Instant},
+};
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
 use oxc_codegen::{CodeGenerator, CodegenOptions, CodegenReturn};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions};
 use oxc_mangler::{MangleOptions, Mangler};
 use oxc_minifier::{CompressOptions, Compressor};
@@ -11,14 +16,26 @@ use oxc_parser::{ParseOptions, Parser, ParserReturn};
 use oxc_semantic::{ScopeTree, SemanticBuilder, SemanticBuilderReturn, SymbolTable};
 use oxc_span::SourceType;
 use oxc_transformer::{
-    InjectGlobalVariables, InjectGlobalVariablesConfig, ReplaceGlobalDefines,
-    ReplaceGlobalDefinesConfig, TransformOptions, Transformer, TransformerReturn,
+    EnvOptions, InjectGlobalVariables, InjectGlobalVariablesConfig, JsxOptions,
+    ReplaceGlobalDefines, ReplaceGlobalDefinesConfig, TransformOptions, Transformer,
+    TransformerReturn,
 };
 
 #[derive(Default)]
 pub struct Compiler {
     printed: String,
     errors: Vec<OxcDiagnostic>,
+    define: Option<ReplaceGlobalDefinesConfig>,
+}
+
+/// The output of [`Compiler::execute`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct CompilerReturn {
+    /// The printed code.
+    pub code: String,
+    /// Warnings reported while compiling, e.g. for assignments to defined names.
+    pub diagnostics: Vec<OxcDiagnostic>,
 }
 
 impl CompilerInterface for Compiler {
@@ -26,26 +43,40 @@ impl CompilerInterface for Compiler {
         self.errors.extend(errors);
     }
 
+    fn define_options(&self) -> Option<ReplaceGlobalDefinesConfig> {
+        self.define.clone()
+    }
+
     fn after_codegen(&mut self, ret: CodegenReturn) {
         self.printed = ret.code;
     }
 }
 
 impl Compiler {
+    /// Replace global identifiers and member expressions, e.g. `process.env.NODE_ENV`.
+    #[must_use]
+    pub fn with_define(mut self, define: ReplaceGlobalDefinesConfig) -> Self {
+        self.define = Some(define);
+        self
+    }
+
     /// # Errors
     ///
-    /// * A list of [OxcDiagnostic].
+    /// * A list of [OxcDiagnostic], when any of them is an error. Warnings are returned in
+    ///   [`CompilerReturn::diagnostics`] otherwise.
     pub fn execute(
         &mut self,
         source_text: &str,
         source_type: SourceType,
         source_path: &Path,
-    ) -> Result<String, Vec<OxcDiagnostic>> {
+    ) -> Result<CompilerReturn, Vec<OxcDiagnostic>> {
         self.compile(source_text, source_type, source_path);
-        if self.errors.is_empty() {
-            Ok(mem::take(&mut self.printed))
+        let errors = mem::take(&mut self.errors);
+        let printed = mem::take(&mut self.printed);
+        if errors.iter().all(|error| error.severity != Severity::Error) {
+            Ok(CompilerReturn { code: printed, diagnostics: errors })
         } else {
-            Err(mem::take(&mut self.errors))
+            Err(errors)
         }
     }
 }
@@ -121,108 +152,56 @@ pub trait CompilerInterface {
 
     fn after_codegen(&mut self, _ret: CodegenReturn) {}
 
-    fn compile(&mut self, source_text: &str, source_type: SourceType, source_path: &Path) {
-        let allocator = Allocator::default();
-
-        /* Parse */
-
-        let mut parser_return = self.parse(&allocator, source_text, source_type);
-        if self.after_parse(&mut parser_return).is_break() {
-            return;
-        }
-        if !parser_return.errors.is_empty() {
-            self.handle_errors(parser_return.errors);
-        }
-
-        let mut program = parser_return.program;
-
-        /* Isolated Declarations */
-        if let Some(options) = self.isolated_declaration_options() {
-            self.isolated_declaration(options, &allocator, &program, source_path);
-        }
-
-        /* Semantic */
-
-        let mut semantic_return = self.semantic(&program);
-        if !semantic_return.errors.is_empty() {
-            self.handle_errors(semantic_return.errors);
-            return;
-        }
-        if self.after_semantic(&mut program, &mut semantic_return).is_break() {
-            return;
-        }
-
-        let stats = semantic_return.semantic.stats();
-        let (mut symbols, mut scopes) = semantic_return.semantic.into_symbol_table_and_scope_tree();
-
-        /* Transform */
-
-        if let Some(options) = self.transform_options() {
-            let mut transformer_return =
-                self.transform(options, &allocator, &mut program, source_path, symbols, scopes);
-
-            if !transformer_return.errors.is_empty() {
-                self.handle_errors(transformer_return.errors);
-                return;
-            }
-
-            if self.after_transform(&mut program, &mut transformer_return).is_break() {
-                return;
-            }
-
-            (symbols, scopes) = (transformer_return.symbols, transformer_return.scopes);
-        }
-
-        let inject_options = self.inject_options();
-        let define_options = self.define_options();
-
-        // Symbols and scopes are out of sync.
-        if inject_options.is_some() || define_options.is_some() {
-            (symbols, scopes) = SemanticBuilder::new()
-                .with_stats(stats)
-                .build(&program)
-                .semantic
-                .into_symbol_table_and_scope_tree();
-        }
-
-        if let Some(options) = inject_options {
-            let ret = InjectGlobalVariables::new(&allocator, options).build(
-                symbols,
-                scopes,
-                &mut program,
-            );
-            symbols = ret.symbols;
-            scopes = ret.scopes;
-        }
-
-        if let Some(options) = define_options {
-            let ret =
-                ReplaceGlobalDefines::new(&allocator, options).build(symbols, scopes, &mut program);
-            Compressor::new(&allocator, CompressOptions::default())
-                .dead_code_elimination_with_symbols_and_scopes(
-                    ret.symbols,
-                    ret.scopes,
-                    &mut program,
-                );
-            // symbols = ret.symbols;
-            // scopes = ret.scopes;
-        }
-
-        /* Compress */
+    /// Called before running a [`CompilerStage`], for every stage except
+    /// [`CompilerStage::Parse`] which has no program yet.
+    ///
+    /// Symbols and scopes are built on demand by [`StageContext::semantic`] before
+    /// [`CompilerStage::Semantic`] has run.
+    ///
+    /// Return [`ControlFlow::Break`] to stop compiling.
+    fn before_stage(
+        &mut self,
+        _stage: CompilerStage,
+        _ctx: &mut StageContext<'_, '_>,
+    ) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
 
-        if let Some(options) = self.compress_options() {
-            self.compress(&allocator, &mut program, options);
-        }
+    /// Called after running a [`CompilerStage`], for every stage.
+    ///
+    /// Not called when the stage stops compiling because of errors, or because of another hook
+    /// like [`CompilerInterface::after_parse`].
+    ///
+    /// Return [`ControlFlow::Break`] to stop compiling.
+    fn after_stage(
+        &mut self,
+        _stage: CompilerStage,
+        _ctx: &mut StageContext<'_, '_>,
+    ) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
 
-        /* Mangler */
+    /// Run [`CompilerStage::TypeScript`] as its own stage, before JSX and ES lowering in
+    /// [`CompilerStage::Transform`], so hooks can run passes in between.
+    ///
+    /// This costs an extra traversal of the program.
+    fn separate_typescript_stage(&self) -> bool {
+        false
+    }
 
-        let mangler = self.mangle_options().map(|options| self.mangle(&mut program, options));
+    /// Measure how long each stage takes. The measurements are passed to
+    /// [`CompilerInterface::handle_stats`].
+    fn enable_stats(&self) -> bool {
+        false
+    }
 
-        /* Codegen */
+    fn handle_stats(&mut self, _stats: CompilerStats) {}
 
-        if let Some(options) = self.codegen_options() {
-            let ret = self.codegen(&program, source_path, mangler, options);
-            self.after_codegen(ret);
+    fn compile(&mut self, source_text: &str, source_type: SourceType, source_path: &Path) {
+        let mut stats = self.enable_stats().then(CompilerStats::default);
+        run_stages(self, source_text, source_type, source_path, stats.as_mut());
+        if let Some(stats) = stats {
+            self.handle_stats(stats);
         }
     }
 
@@ -308,3 +287,377 @@ pub trait CompilerInterface {
         CodeGenerator::new().with_options(options).with_mangler(mangler).build(program)
     }
 }
+
+/// A named stage of [`CompilerInterface::compile`], in the order the stages run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompilerStage {
+    Parse,
+    IsolatedDeclarations,
+    Semantic,
+    /// Strip TypeScript syntax.
+    ///
+    /// Only runs on its own when [`CompilerInterface::separate_typescript_stage`] is set,
+    /// otherwise TypeScript is stripped in [`CompilerStage::Transform`].
+    TypeScript,
+    /// Transform JSX and lower ES syntax.
+    Transform,
+    /// Inject global variables and replace global defines.
+    Define,
+    Compress,
+    Mangle,
+    Codegen,
+}
+
+impl CompilerStage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::IsolatedDeclarations => "isolated-declarations",
+            Self::Semantic => "semantic",
+            Self::TypeScript => "typescript",
+            Self::Transform => "transform",
+            Self::Define => "define",
+            Self::Compress => "compress",
+            Self::Mangle => "mangle",
+            Self::Codegen => "codegen",
+        }
+    }
+}
+
+impl fmt::Display for CompilerStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The program being compiled, passed to [`CompilerInterface::before_stage`] and
+/// [`CompilerInterface::after_stage`].
+pub struct StageContext<'s, 'a> {
+    allocator: &'a Allocator,
+    program: &'s mut Program<'a>,
+    semantic: &'s mut Option<(SymbolTable, ScopeTree)>,
+}
+
+impl<'a> StageContext<'_, 'a> {
+    pub fn allocator(&self) -> &'a Allocator {
+        self.allocator
+    }
+
+    pub fn program(&mut self) -> &mut Program<'a> {
+        self.program
+    }
+
+    /// The program together with its symbols and scopes, e.g. for running a
+    /// `oxc_traverse::Traverse` pass with `traverse_mut`.
+    ///
+    /// Symbols and scopes are rebuilt if an earlier stage left them out of sync with the
+    /// program. Passes which modify the program must keep them in sync.
+    pub fn semantic(&mut self) -> (&mut Program<'a>, &mut SymbolTable, &mut ScopeTree) {
+        let program = &mut *self.program;
+        let (symbols, scopes) = self.semantic.get_or_insert_with(|| {
+            SemanticBuilder::new().build(program).semantic.into_symbol_table_and_scope_tree()
+        });
+        (program, symbols, scopes)
+    }
+
+    pub fn symbols(&mut self) -> &mut SymbolTable {
+        self.semantic().1
+    }
+
+    pub fn scopes(&mut self) -> &mut ScopeTree {
+        self.semantic().2
+    }
+}
+
+/// How long each stage of [`CompilerInterface::compile`] took.
+///
+/// Only collected when [`CompilerInterface::enable_stats`] is set.
+#[derive(Debug, Default, Clone)]
+pub struct CompilerStats {
+    stages: Vec<(CompilerStage, Duration)>,
+}
+
+impl CompilerStats {
+    /// The stages which ran, in order, with their duration.
+    pub fn stages(&self) -> &[(CompilerStage, Duration)] {
+        &self.stages
+    }
+
+    pub fn get(&self, stage: CompilerStage) -> Option<Duration> {
+        self.stages.iter().find(|(s, _)| *s == stage).map(|(_, duration)| *duration)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.stages.iter().map(|(_, duration)| *duration).sum()
+    }
+}
+
+impl fmt::Display for CompilerStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (stage, duration) in &self.stages {
+            writeln!(f, "{:<22}{:>10.3}ms", stage.as_str(), duration.as_secs_f64() * 1000.0)?;
+        }
+        write!(f, "{:<22}{:>10.3}ms", "total", self.total().as_secs_f64() * 1000.0)
+    }
+}
+
+fn time<R>(
+    stats: &mut Option<&mut CompilerStats>,
+    stage: CompilerStage,
+    f: impl FnOnce() -> R,
+) -> R {
+    let Some(stats) = stats else { return f() };
+    let start = Instant::now();
+    let ret = f();
+    stats.stages.push((stage, start.elapsed()));
+    ret
+}
+
+fn before_stage<'a, C: CompilerInterface + ?Sized>(
+    compiler: &mut C,
+    stage: CompilerStage,
+    allocator: &'a Allocator,
+    program: &mut Program<'a>,
+    semantic: &mut Option<(SymbolTable, ScopeTree)>,
+) -> ControlFlow<()> {
+    compiler.before_stage(stage, &mut StageContext { allocator, program, semantic })
+}
+
+fn after_stage<'a, C: CompilerInterface + ?Sized>(
+    compiler: &mut C,
+    stage: CompilerStage,
+    allocator: &'a Allocator,
+    program: &mut Program<'a>,
+    semantic: &mut Option<(SymbolTable, ScopeTree)>,
+) -> ControlFlow<()> {
+    compiler.after_stage(stage, &mut StageContext { allocator, program, semantic })
+}
+
+#[allow(clippy::too_many_lines)]
+fn run_stages<C: CompilerInterface + ?Sized>(
+    compiler: &mut C,
+    source_text: &str,
+    source_type: SourceType,
+    source_path: &Path,
+    mut stats: Option<&mut CompilerStats>,
+) {
+    let allocator = Allocator::default();
+
+    /* Parse */
+
+    let mut parser_return = time(&mut stats, CompilerStage::Parse, || {
+        compiler.parse(&allocator, source_text, source_type)
+    });
+    if compiler.after_parse(&mut parser_return).is_break() {
+        return;
+    }
+    if !parser_return.errors.is_empty() {
+        compiler.handle_errors(parser_return.errors);
+    }
+
+    let mut program = parser_return.program;
+    // Symbols and scopes of `program`, `None` when they are not built yet or are out of sync
+    let mut semantic = None;
+
+    macro_rules! before_stage {
+        ($stage:expr) => {
+            if before_stage(compiler, $stage, &allocator, &mut program, &mut semantic).is_break() {
+                return;
+            }
+        };
+    }
+    macro_rules! after_stage {
+        ($stage:expr) => {
+            if after_stage(compiler, $stage, &allocator, &mut program, &mut semantic).is_break() {
+                return;
+            }
+        };
+    }
+
+    after_stage!(CompilerStage::Parse);
+
+    /* Isolated Declarations */
+
+    if let Some(options) = compiler.isolated_declaration_options() {
+        before_stage!(CompilerStage::IsolatedDeclarations);
+        time(&mut stats, CompilerStage::IsolatedDeclarations, || {
+            compiler.isolated_declaration(options, &allocator, &program, source_path);
+        });
+        after_stage!(CompilerStage::IsolatedDeclarations);
+    }
+
+    /* Semantic */
+
+    before_stage!(CompilerStage::Semantic);
+    let mut semantic_return =
+        time(&mut stats, CompilerStage::Semantic, || compiler.semantic(&program));
+    if !semantic_return.errors.is_empty() {
+        compiler.handle_errors(semantic_return.errors);
+        return;
+    }
+    if compiler.after_semantic(&mut program, &mut semantic_return).is_break() {
+        return;
+    }
+
+    let semantic_stats = semantic_return.semantic.stats();
+    semantic = Some(semantic_return.semantic.into_symbol_table_and_scope_tree());
+    after_stage!(CompilerStage::Semantic);
+
+    /* TypeScript */
+
+    let transform_options = compiler.transform_options().cloned();
+
+    if let Some(options) = &transform_options {
+        if compiler.separate_typescript_stage() && program.source_type.is_typescript() {
+            // Strip TypeScript only, leaving JSX and ES syntax to `Transform`.
+            let typescript_options = TransformOptions {
+                jsx: JsxOptions {
+                    jsx_plugin: false,
+                    display_name_plugin: false,
+                    jsx_self_plugin: false,
+                    jsx_source_plugin: false,
+                    refresh: None,
+                    ..options.jsx.clone()
+                },
+                env: EnvOptions::default(),
+                ..options.clone()
+            };
+
+            before_stage!(CompilerStage::TypeScript);
+            let transformer_return = time(&mut stats, CompilerStage::TypeScript, || {
+                let (symbols, scopes) = take_semantic(&mut semantic, &program);
+                compiler.transform(
+                    &typescript_options,
+                    &allocator,
+                    &mut program,
+                    source_path,
+                    symbols,
+                    scopes,
+                )
+            });
+            if !transformer_return.errors.is_empty() {
+                compiler.handle_errors(transformer_return.errors);
+                return;
+            }
+            program.source_type = program.source_type.with_javascript(true);
+            semantic = Some((transformer_return.symbols, transformer_return.scopes));
+            after_stage!(CompilerStage::TypeScript);
+        }
+    }
+
+    /* Transform */
+
+    if let Some(options) = &transform_options {
+        before_stage!(CompilerStage::Transform);
+        let mut transformer_return = time(&mut stats, CompilerStage::Transform, || {
+            let (symbols, scopes) = take_semantic(&mut semantic, &program);
+            compiler.transform(options, &allocator, &mut program, source_path, symbols, scopes)
+        });
+
+        if !transformer_return.errors.is_empty() {
+            compiler.handle_errors(transformer_return.errors);
+            return;
+        }
+
+        if compiler.after_transform(&mut program, &mut transformer_return).is_break() {
+            return;
+        }
+
+        semantic = Some((transformer_return.symbols, transformer_return.scopes));
+        after_stage!(CompilerStage::Transform);
+    }
+
+    /* Define */
+
+    let inject_options = compiler.inject_options();
+    let define_options = compiler.define_options();
+
+    if inject_options.is_some() || define_options.is_some() {
+        before_stage!(CompilerStage::Define);
+        let mut define_errors = vec![];
+        time(&mut stats, CompilerStage::Define, || {
+            // Symbols and scopes are out of sync.
+            let (mut symbols, mut scopes) = SemanticBuilder::new()
+                .with_stats(semantic_stats)
+                .build(&program)
+                .semantic
+                .into_symbol_table_and_scope_tree();
+
+            if let Some(options) = inject_options {
+                let ret = InjectGlobalVariables::new(&allocator, options).build(
+                    symbols,
+                    scopes,
+                    &mut program,
+                );
+                symbols = ret.symbols;
+                scopes = ret.scopes;
+            }
+
+            if let Some(options) = define_options {
+                let ret = ReplaceGlobalDefines::new(&allocator, options).build(
+                    symbols,
+                    scopes,
+                    &mut program,
+                );
+                define_errors = ret.errors;
+                Compressor::new(&allocator, CompressOptions::default())
+                    .dead_code_elimination_with_symbols_and_scopes(
+                        ret.symbols,
+                        ret.scopes,
+                        &mut program,
+                    );
+                // Dead code elimination leaves symbols and scopes out of sync.
+                semantic = None;
+            } else {
+                semantic = Some((symbols, scopes));
+            }
+        });
+        if !define_errors.is_empty() {
+            compiler.handle_errors(define_errors);
+        }
+        after_stage!(CompilerStage::Define);
+    }
+
+    /* Compress */
+
+    if let Some(options) = compiler.compress_options() {
+        before_stage!(CompilerStage::Compress);
+        time(&mut stats, CompilerStage::Compress, || {
+            compiler.compress(&allocator, &mut program, options);
+        });
+        semantic = None;
+        after_stage!(CompilerStage::Compress);
+    }
+
+    /* Mangler */
+
+    let mut mangler = None;
+    if let Some(options) = compiler.mangle_options() {
+        before_stage!(CompilerStage::Mangle);
+        mangler = Some(time(&mut stats, CompilerStage::Mangle, || {
+            compiler.mangle(&mut program, options)
+        }));
+        after_stage!(CompilerStage::Mangle);
+    }
+
+    /* Codegen */
+
+    if let Some(options) = compiler.codegen_options() {
+        before_stage!(CompilerStage::Codegen);
+        let ret = time(&mut stats, CompilerStage::Codegen, || {
+            compiler.codegen(&program, source_path, mangler, options)
+        });
+        compiler.after_codegen(ret);
+        after_stage!(CompilerStage::Codegen);
+    }
+}
+
+/// Take the symbols and scopes of `program`, rebuilding them if they are out of sync.
+fn take_semantic(
+    semantic: &mut Option<(SymbolTable, ScopeTree)>,
+    program: &Program<'_>,
+) -> (SymbolTable, ScopeTree) {
+    semantic.take().unwrap_or_else(|| {
+        SemanticBuilder::new().build(program).semantic.into_symbol_table_and_scope_tree()
+    })
+}
@@ -4,7 +4,9 @@
 mod compiler;
 
 #[cfg(feature = "full")]
-pub use compiler::{Compiler, CompilerInterface};
+pub use compiler::{
+    Compiler, CompilerInterface, CompilerReturn, CompilerStage, CompilerStats, StageContext,
+};
 
 pub mod allocator {
     //! Memory arena allocator used by all other submodules.
@@ -0,0 +1,246 @@
+use std::{ops::ControlFlow, path::Path, time::Duration};
+
+use oxc::{
+    codegen::CodegenReturn,
+    diagnostics::{OxcDiagnostic, Severity},
+    minifier::{CompressOptions, MangleOptions},
+    span::SourceType,
+    transformer::{ReplaceGlobalDefinesConfig, TransformOptions},
+    Compiler, CompilerInterface, CompilerStage, CompilerStats, StageContext,
+};
+
+/// Records the hooks called while compiling.
+#[derive(Default)]
+struct Recorder {
+    transform: Option<TransformOptions>,
+    define: Option<ReplaceGlobalDefinesConfig>,
+    compress: bool,
+    mangle: bool,
+    /// Return [`ControlFlow::Break`] from [`CompilerInterface::before_stage`] of this stage.
+    break_before: Option<CompilerStage>,
+    /// Remove the first statement of the program after this stage.
+    remove_first_statement_after: Option<CompilerStage>,
+    stats: bool,
+
+    events: Vec<String>,
+    errors: Vec<OxcDiagnostic>,
+    printed: Option<String>,
+    collected_stats: Option<CompilerStats>,
+}
+
+impl CompilerInterface for Recorder {
+    fn handle_errors(&mut self, errors: Vec<OxcDiagnostic>) {
+        self.events.push("errors".to_string());
+        self.errors.extend(errors);
+    }
+
+    fn transform_options(&self) -> Option<&TransformOptions> {
+        self.transform.as_ref()
+    }
+
+    fn define_options(&self) -> Option<ReplaceGlobalDefinesConfig> {
+        self.define.clone()
+    }
+
+    fn compress_options(&self) -> Option<CompressOptions> {
+        self.compress.then(CompressOptions::default)
+    }
+
+    fn mangle_options(&self) -> Option<MangleOptions> {
+        self.mangle.then(MangleOptions::default)
+    }
+
+    fn before_stage(
+        &mut self,
+        stage: CompilerStage,
+        _ctx: &mut StageContext<'_, '_>,
+    ) -> ControlFlow<()> {
+        self.events.push(format!("before {stage}"));
+        if self.break_before == Some(stage) {
+            return ControlFlow::Break(());
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn after_stage(
+        &mut self,
+        stage: CompilerStage,
+        ctx: &mut StageContext<'_, '_>,
+    ) -> ControlFlow<()> {
+        self.events.push(format!("after {stage}"));
+        if self.remove_first_statement_after == Some(stage) {
+            ctx.program().body.remove(0);
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn after_codegen(&mut self, ret: CodegenReturn) {
+        self.printed = Some(ret.code);
+    }
+
+    fn enable_stats(&self) -> bool {
+        self.stats
+    }
+
+    fn handle_stats(&mut self, stats: CompilerStats) {
+        self.collected_stats = Some(stats);
+    }
+}
+
+impl Recorder {
+    fn run(mut self, source_text: &str) -> Self {
+        self.compile(source_text, SourceType::ts(), Path::new("test.ts"));
+        self
+    }
+}
+
+fn events(events: &[&str]) -> Vec<String> {
+    events.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn stage_order() {
+    let recorder = Recorder {
+        transform: Some(TransformOptions::default()),
+        define: Some(ReplaceGlobalDefinesConfig::new(&[("DEBUG", "false")]).unwrap()),
+        compress: true,
+        mangle: true,
+        ..Recorder::default()
+    }
+    .run("const a: number = 1;");
+    assert_eq!(
+        recorder.events,
+        events(&[
+            "after parse",
+            "before semantic",
+            "after semantic",
+            "before transform",
+            "after transform",
+            "before define",
+            "after define",
+            "before compress",
+            "after compress",
+            "before mangle",
+            "after mangle",
+            "before codegen",
+            "after codegen",
+        ])
+    );
+    assert!(recorder.errors.is_empty());
+}
+
+#[test]
+fn stage_order_default() {
+    let recorder = Recorder::default().run("const a = 1;");
+    assert_eq!(
+        recorder.events,
+        events(&[
+            "after parse",
+            "before semantic",
+            "after semantic",
+            "before codegen",
+            "after codegen",
+        ])
+    );
+    assert_eq!(recorder.printed.as_deref(), Some("const a = 1;\n"));
+}
+
+#[test]
+fn hooks_modify_program() {
+    for stage in [CompilerStage::Parse, CompilerStage::Semantic, CompilerStage::Transform] {
+        let recorder = Recorder {
+            transform: Some(TransformOptions::default()),
+            remove_first_statement_after: Some(stage),
+            ..Recorder::default()
+        }
+        .run("const a: number = 1;\nconst b = 2;");
+        assert_eq!(recorder.printed.as_deref(), Some("const b = 2;\n"), "{stage}");
+    }
+}
+
+#[test]
+fn break_before_stage() {
+    let recorder = Recorder {
+        transform: Some(TransformOptions::default()),
+        break_before: Some(CompilerStage::Transform),
+        ..Recorder::default()
+    }
+    .run("const a: number = 1;");
+    assert_eq!(
+        recorder.events,
+        events(&["after parse", "before semantic", "after semantic", "before transform"])
+    );
+    assert_eq!(recorder.printed, None);
+}
+
+#[test]
+fn stop_on_semantic_errors() {
+    let recorder = Recorder { transform: Some(TransformOptions::default()), ..Recorder::default() }
+        .run("let a; let a;");
+    assert_eq!(recorder.events, events(&["after parse", "before semantic", "errors"]));
+    assert_eq!(recorder.errors.len(), 1);
+    assert_eq!(recorder.printed, None);
+}
+
+#[test]
+fn stats() {
+    let recorder = Recorder { stats: false, ..Recorder::default() }.run("const a = 1;");
+    assert!(recorder.collected_stats.is_none());
+
+    let recorder = Recorder {
+        transform: Some(TransformOptions::default()),
+        compress: true,
+        stats: true,
+        ..Recorder::default()
+    }
+    .run("const a: number = 1;");
+    let stats = recorder.collected_stats.unwrap();
+    let stages = stats.stages().iter().map(|(stage, _)| *stage).collect::<Vec<_>>();
+    assert_eq!(
+        stages,
+        [
+            CompilerStage::Parse,
+            CompilerStage::Semantic,
+            CompilerStage::Transform,
+            CompilerStage::Compress,
+            CompilerStage::Codegen,
+        ]
+    );
+    assert!(stats.get(CompilerStage::Mangle).is_none());
+    assert_eq!(
+        stats.total(),
+        stats.stages().iter().map(|(_, duration)| *duration).sum::<Duration>()
+    );
+    assert!(stats.to_string().ends_with("ms"));
+}
+
+#[test]
+fn execute_twice() {
+    let mut compiler = Compiler::default();
+    let path = Path::new("test.js");
+    assert_eq!(compiler.execute("let a; let a;", SourceType::mjs(), path).unwrap_err().len(), 1);
+    assert_eq!(compiler.execute("let a;", SourceType::mjs(), path).unwrap().code, "let a;\n");
+
+    // Warnings are returned with the output and not carried over to the next call
+    compiler.handle_errors(vec![OxcDiagnostic::warn("warning")]);
+    let ret = compiler.execute("let b;", SourceType::mjs(), path).unwrap();
+    assert_eq!(ret.code, "let b;\n");
+    assert_eq!(ret.diagnostics.len(), 1);
+    assert_eq!(compiler.execute("let c; let c;", SourceType::mjs(), path).unwrap_err().len(), 1);
+}
+
+#[test]
+fn execute_define_warnings() {
+    let define = ReplaceGlobalDefinesConfig::new(&[("process.env.X", "1")]).unwrap();
+    let mut compiler = Compiler::default().with_define(define);
+    let path = Path::new("test.js");
+
+    let ret = compiler.execute("console.log(process.env.X)", SourceType::mjs(), path).unwrap();
+    assert_eq!(ret.code, "console.log(1);\n");
+    assert!(ret.diagnostics.is_empty());
+
+    let ret = compiler.execute("process.env.X = 1", SourceType::mjs(), path).unwrap();
+    assert_eq!(ret.diagnostics.len(), 1);
+    assert_eq!(ret.diagnostics[0].severity, Severity::Warning);
+    assert!(ret.diagnostics[0].to_string().contains("process.env.X"));
+}
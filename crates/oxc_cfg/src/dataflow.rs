@@ -0,0 +1,151 @@
+//! A generic data-flow analysis framework over [`ControlFlowGraph`].
+//!
+//! Implement [`Analysis`] to describe the state tracked at each point of the
+//! graph and how basic blocks change it, then call [`solve`] to compute the
+//! fixpoint state at the entry and exit of every basic block.
+//!
+//! Edges of type [`EdgeType::NewFunction`] and [`EdgeType::Unreachable`] are
+//! not followed by default, so every function is analyzed on its own and the
+//! states of its entry (or exit) blocks start from [`Analysis::bottom`].
+
+use std::collections::VecDeque;
+
+use petgraph::{visit::EdgeRef, Direction as EdgeDirection};
+
+use crate::{BasicBlock, BlockNodeId, ControlFlowGraph, EdgeType, Instruction};
+
+/// The direction states flow through the graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From the entry of a block to its exit, e.g. reaching definitions.
+    Forward,
+    /// From the exit of a block to its entry, e.g. liveness.
+    Backward,
+}
+
+/// A data-flow problem solved by [`solve`].
+///
+/// For the solver to terminate, [`Analysis::Domain`] must form a lattice of
+/// finite height and both [`Analysis::join`] and the transfer functions must
+/// be monotone.
+pub trait Analysis {
+    /// The state tracked at each point of the graph.
+    type Domain: Clone + PartialEq;
+
+    const DIRECTION: Direction;
+
+    /// The initial state of every block, which is also the state at the
+    /// boundary of the graph (entry blocks for forward analyses, exit blocks
+    /// for backward ones).
+    fn bottom(&self) -> Self::Domain;
+
+    /// Merge the state flowing in from another block into `state`.
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain);
+
+    /// Apply the effect of a single instruction to `state`.
+    fn apply_instruction(&self, state: &mut Self::Domain, instruction: &Instruction);
+
+    /// Apply the effect of a whole basic block to `state`.
+    ///
+    /// By default this applies [`Analysis::apply_instruction`] to each
+    /// instruction of the block, in reverse order for backward analyses.
+    /// Analyses that track effects at a finer grain than instructions can
+    /// override this instead.
+    fn apply_block(&self, state: &mut Self::Domain, _block_id: BlockNodeId, block: &BasicBlock) {
+        match Self::DIRECTION {
+            Direction::Forward => {
+                for instruction in block.instructions() {
+                    self.apply_instruction(state, instruction);
+                }
+            }
+            Direction::Backward => {
+                for instruction in block.instructions().iter().rev() {
+                    self.apply_instruction(state, instruction);
+                }
+            }
+        }
+    }
+
+    /// Whether states flow along edges of this type.
+    fn follows_edge(&self, edge: &EdgeType) -> bool {
+        !matches!(edge, EdgeType::NewFunction | EdgeType::Unreachable)
+    }
+}
+
+/// The fixpoint states computed by [`solve`].
+#[derive(Debug)]
+pub struct DataflowResults<D> {
+    entry_states: Vec<D>,
+    exit_states: Vec<D>,
+}
+
+impl<D> DataflowResults<D> {
+    /// The state before the first instruction of `block`.
+    pub fn entry_state(&self, block: BlockNodeId) -> &D {
+        &self.entry_states[block.index()]
+    }
+
+    /// The state after the last instruction of `block`.
+    pub fn exit_state(&self, block: BlockNodeId) -> &D {
+        &self.exit_states[block.index()]
+    }
+}
+
+/// Solve a data-flow problem over all blocks of `cfg` with a worklist algorithm.
+pub fn solve<A: Analysis>(cfg: &ControlFlowGraph, analysis: &A) -> DataflowResults<A::Domain> {
+    let graph = cfg.graph();
+    let block_count = graph.node_count();
+    // States flow from `inputs` (before the block's transfer function) to `outputs`.
+    let mut inputs = vec![analysis.bottom(); block_count];
+    let mut outputs = vec![analysis.bottom(); block_count];
+
+    let (incoming, outgoing) = match A::DIRECTION {
+        Direction::Forward => (EdgeDirection::Incoming, EdgeDirection::Outgoing),
+        Direction::Backward => (EdgeDirection::Outgoing, EdgeDirection::Incoming),
+    };
+
+    let mut worklist = graph.node_indices().collect::<VecDeque<_>>();
+    if A::DIRECTION == Direction::Backward {
+        worklist.make_contiguous().reverse();
+    }
+    let mut queued = vec![true; block_count];
+
+    while let Some(block_id) = worklist.pop_front() {
+        queued[block_id.index()] = false;
+
+        let mut input = analysis.bottom();
+        for edge in graph.edges_directed(block_id, incoming) {
+            if analysis.follows_edge(edge.weight()) {
+                let from =
+                    if incoming == EdgeDirection::Incoming { edge.source() } else { edge.target() };
+                analysis.join(&mut input, &outputs[from.index()]);
+            }
+        }
+
+        let mut output = input.clone();
+        analysis.apply_block(&mut output, block_id, cfg.basic_block(block_id));
+        inputs[block_id.index()] = input;
+
+        if output != outputs[block_id.index()] {
+            outputs[block_id.index()] = output;
+            for edge in graph.edges_directed(block_id, outgoing) {
+                if analysis.follows_edge(edge.weight()) {
+                    let to = if outgoing == EdgeDirection::Outgoing {
+                        edge.target()
+                    } else {
+                        edge.source()
+                    };
+                    if !queued[to.index()] {
+                        queued[to.index()] = true;
+                        worklist.push_back(to);
+                    }
+                }
+            }
+        }
+    }
+
+    match A::DIRECTION {
+        Direction::Forward => DataflowResults { entry_states: inputs, exit_states: outputs },
+        Direction::Backward => DataflowResults { entry_states: outputs, exit_states: inputs },
+    }
+}
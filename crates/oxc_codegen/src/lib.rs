@@ -27,8 +27,11 @@ use oxc_syntax::{
 };
 
 use crate::{
-    binary_expr_visitor::BinaryExpressionVisitor, code_buffer::CodeBuffer, comment::CommentsMap,
-    operator::Operator, sourcemap_builder::SourcemapBuilder,
+    binary_expr_visitor::BinaryExpressionVisitor,
+    code_buffer::CodeBuffer,
+    comment::CommentsMap,
+    operator::Operator,
+    sourcemap_builder::{compose_source_maps, SourcemapBuilder},
 };
 pub use crate::{
     context::Context,
@@ -48,6 +51,8 @@ pub struct CodegenReturn {
     /// The source map from the input source code to the generated source code.
     ///
     /// You must set [`CodegenOptions::source_map_path`] for this to be [`Some`].
+    /// If [`CodegenOptions::input_source_map`] is set, the map points to the
+    /// sources of that map instead of the printed program.
     pub map: Option<oxc_sourcemap::SourceMap>,
 
     /// All the legal comments returned from [LegalComment::Linked] or [LegalComment::External].
@@ -221,7 +226,13 @@ impl<'a> Codegen<'a> {
         program.print(&mut self, Context::default());
         self.try_print_eof_legal_comments();
         let code = self.code.into_string();
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        let map = self.sourcemap_builder.map(|builder| {
+            let map = builder.into_sourcemap();
+            match &self.options.input_source_map {
+                Some(input_map) => compose_source_maps(&map, input_map),
+                None => map,
+            }
+        });
         CodegenReturn { code, map, legal_comments: self.legal_comments }
     }
 
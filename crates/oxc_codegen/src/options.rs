@@ -1,5 +1,7 @@
 use std::path::PathBuf;
 
+use oxc_sourcemap::SourceMap;
+
 /// Legal comment
 ///
 /// <https://esbuild.github.io/api/#legal-comments>
@@ -75,6 +77,17 @@ pub struct CodegenOptions {
     /// By default, the source map path is the same as the input source code
     /// (with a `.map` extension).
     pub source_map_path: Option<PathBuf>,
+
+    /// The source map of the source text being printed, e.g. produced by an
+    /// earlier step of the pipeline.
+    ///
+    /// When set, the generated source map is composed with it, so it maps the
+    /// generated code back to the original sources, including their
+    /// `sourcesContent`. Sources keep their order, so the input map's
+    /// `x_google_ignoreList` applies to the generated map unchanged.
+    ///
+    /// Only takes effect when [`CodegenOptions::source_map_path`] is set.
+    pub input_source_map: Option<SourceMap>,
 }
 
 impl Default for CodegenOptions {
@@ -86,6 +99,7 @@ impl Default for CodegenOptions {
             annotation_comments: false,
             legal_comments: LegalComment::default(),
             source_map_path: None,
+            input_source_map: None,
         }
     }
 }
@@ -2,8 +2,10 @@ use std::{path::Path, sync::Arc};
 
 use nonmax::NonMaxU32;
 use oxc_index::{Idx, IndexVec};
+use oxc_sourcemap::{SourceMap, Token};
 use oxc_span::Span;
 use oxc_syntax::identifier::{LS, PS};
+use rustc_hash::FxHashMap;
 
 // Irregular line breaks - '\u{2028}' (LS) and '\u{2029}' (PS)
 const LS_OR_PS_FIRST: u8 = 0xE2;
@@ -332,6 +334,57 @@ impl SourcemapBuilder {
     }
 }
 
+/// Trace the mappings of `map` (generated code -> input code) through
+/// `input_map` (input code -> original sources).
+///
+/// Mappings to positions that `input_map` does not cover are dropped. The
+/// sources of `input_map` are kept in the same order, so its `x_google_ignoreList`
+/// stays valid for the composed map.
+pub fn compose_source_maps(map: &SourceMap, input_map: &SourceMap) -> SourceMap {
+    let lookup_table = input_map.generate_lookup_table();
+    let mut names: Vec<Arc<str>> = vec![];
+    let mut name_ids = FxHashMap::<&str, u32>::default();
+    let mut tokens = Vec::with_capacity(map.get_tokens().size_hint().0);
+
+    for token in map.get_tokens() {
+        let Some(original) =
+            input_map.lookup_token(&lookup_table, token.get_src_line(), token.get_src_col())
+        else {
+            continue;
+        };
+        // Prefer the name from the original source, which is not renamed by earlier steps.
+        let name = original
+            .get_name_id()
+            .and_then(|id| input_map.get_name(id))
+            .or_else(|| token.get_name_id().and_then(|id| map.get_name(id)));
+        #[allow(clippy::cast_possible_truncation)]
+        let name_id = name.map(|name| {
+            *name_ids.entry(name).or_insert_with(|| {
+                names.push(Arc::from(name));
+                (names.len() - 1) as u32
+            })
+        });
+        tokens.push(Token::new(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            original.get_src_line(),
+            original.get_src_col(),
+            original.get_source_id(),
+            name_id,
+        ));
+    }
+
+    SourceMap::new(
+        map.get_file().map(Arc::from),
+        names,
+        input_map.get_source_root().map(ToString::to_string),
+        input_map.get_sources().map(Arc::from).collect(),
+        input_map.get_source_contents().map(|contents| contents.map(Arc::from).collect()),
+        tokens,
+        None,
+    )
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -463,4 +516,53 @@ mod test {
         let sm = builder.into_sourcemap();
         assert_eq!(sm.get_tokens().count(), 2);
     }
+
+    #[test]
+    fn compose() {
+        // intermediate code -> original sources
+        let mut input = oxc_sourcemap::SourceMapBuilder::default();
+        let original = input.add_source_and_content("original.ts", "original content");
+        let vendor = input.add_source_and_content("vendor.ts", "vendor content");
+        let name = input.add_name("originalName");
+        input.add_token(0, 0, 5, 2, Some(original), Some(name));
+        input.add_token(1, 0, 0, 0, Some(vendor), None);
+        let input = input.into_sourcemap();
+
+        // generated code -> intermediate code
+        let mut map = oxc_sourcemap::SourceMapBuilder::default();
+        let intermediate = map.add_source_and_content("intermediate.js", "a;\nb;\n");
+        let name = map.add_name("a");
+        map.add_token(0, 0, 0, 0, Some(intermediate), Some(name));
+        map.add_token(0, 2, 1, 0, Some(intermediate), None);
+        // not covered by the input map
+        map.add_token(0, 4, 3, 0, Some(intermediate), None);
+        let map = map.into_sourcemap();
+
+        let composed = compose_source_maps(&map, &input);
+        assert_eq!(composed.get_sources().collect::<Vec<_>>(), ["original.ts", "vendor.ts"]);
+        assert_eq!(
+            composed.get_source_contents().unwrap().collect::<Vec<_>>(),
+            ["original content", "vendor content"]
+        );
+        let tokens = composed
+            .get_tokens()
+            .map(|token| {
+                (
+                    token.get_dst_line(),
+                    token.get_dst_col(),
+                    token.get_source_id().and_then(|id| composed.get_source(id)),
+                    token.get_src_line(),
+                    token.get_src_col(),
+                    token.get_name_id().and_then(|id| composed.get_name(id)),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (0, 0, Some("original.ts"), 5, 2, Some("originalName")),
+                (0, 2, Some("vendor.ts"), 0, 0, None),
+            ]
+        );
+    }
 }
@@ -14,6 +14,27 @@ pub fn get_line_column(rope: &Rope, offset: u32, source_text: &str) -> (u32, u32
     (line_index as u32, column_index as u32)
 }
 
+/// Get UTF8 offset from UTF16 line and column and source text.
+///
+/// Returns [`None`] if `line` is out of bounds. A `column` past the end of the line
+/// is clamped to the end of the line.
+#[expect(clippy::cast_possible_truncation)]
+pub fn get_offset(rope: &Rope, line: u32, column: u32, source_text: &str) -> Option<u32> {
+    let line_index = line as usize;
+    if line_index >= rope.len_lines() {
+        return None;
+    }
+    let line_offset = rope.line_to_byte(line_index);
+    let mut utf16_count = 0;
+    for (byte_index, c) in source_text[line_offset..].char_indices() {
+        if utf16_count >= column as usize || c == '\n' || c == '\r' {
+            return Some((line_offset + byte_index) as u32);
+        }
+        utf16_count += c.len_utf16();
+    }
+    Some(source_text.len() as u32)
+}
+
 #[cfg(test)]
 mod test {
     use ropey::Rope;
@@ -90,6 +111,27 @@ mod test {
         assert_eq!(test_line_column(6, "🍄\nabc"), (1, 1));
     }
 
+    fn test_offset(line: u32, column: u32, source_text: &str) -> Option<u32> {
+        let rope = Rope::from_str(source_text);
+        super::get_offset(&rope, line, column, source_text)
+    }
+
+    #[test]
+    fn offset_round_trip() {
+        let source_text = "foo\n£\n🍄abc\n";
+        for offset in [0, 3, 4, 6, 7, 11, 14] {
+            let (line, column) = test_line_column(offset, source_text);
+            assert_eq!(test_offset(line, column, source_text), Some(offset));
+        }
+    }
+
+    #[test]
+    fn offset_out_of_bounds() {
+        assert_eq!(test_offset(0, 10, "foo\nbar"), Some(3));
+        assert_eq!(test_offset(1, 10, "foo\nbar"), Some(7));
+        assert_eq!(test_offset(5, 0, "foo\nbar"), None);
+    }
+
     #[cfg(test)]
     fn utf16_len(s: &str) -> usize {
         s.encode_utf16().count()
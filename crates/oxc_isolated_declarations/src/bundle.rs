@@ -0,0 +1,1316 @@
+//! Declaration Bundler
+//!
+//! Rolls up the declaration programs emitted by [`IsolatedDeclarations`] for each module of a
+//! package into a single `.d.ts` file per entry, similar to `api-extractor` and
+//! `rollup-plugin-dts`.
+//!
+//! References:
+//! * <https://api-extractor.com/pages/overview/demo_rollup>
+//! * <https://github.com/Swatinem/rollup-plugin-dts>
+
+use std::{
+    cell::{Cell, RefCell},
+    mem,
+    path::{Component, Path, PathBuf},
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Allocator, CloneIn};
+use oxc_ast::{ast::*, visit::walk_mut::*, AstBuilder, VisitMut, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_span::{Atom, GetSpan, SourceType, SPAN};
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    module_record::{
+        ExportEntry, ExportExportName, ExportImportName, ExportLocalName, ImportEntry,
+        ImportImportName, ModuleRecord, NameSpan, RequestedModule,
+    },
+    scope::{ScopeFlags, ScopeId},
+};
+
+use crate::{
+    diagnostics::{bundle_entry_not_found, bundle_export_assignment, bundle_missing_export},
+    IsolatedDeclarations, IsolatedDeclarationsOptions,
+};
+
+/// Extensions tried, in order, when resolving a relative module specifier.
+const EXTENSIONS: [&str; 5] = ["ts", "tsx", "mts", "cts", "d.ts"];
+
+type ModuleId = usize;
+
+#[non_exhaustive]
+pub struct DeclarationBundleReturn<'a> {
+    pub program: Program<'a>,
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+/// Bundles the declaration programs of several modules into one program.
+///
+/// Add the program returned by [`IsolatedDeclarations::build`] for every module with
+/// [`DeclarationBundler::add_module`], then call [`DeclarationBundler::build`] with the path of
+/// the entry module. Starting from the exports of the entry, the bundler follows imports and
+/// re-exports between the added modules, keeps only the declarations reachable from the entry,
+/// renames colliding top level names and emits a single program exporting the same names as
+/// the entry.
+///
+/// Relative module specifiers are resolved against the paths given to
+/// [`DeclarationBundler::add_module`], other specifiers are kept as imports of external modules.
+/// The comments of the bundled declarations are preserved, with the source texts of the modules
+/// concatenated into the source text of the bundle.
+pub struct DeclarationBundler<'a> {
+    ast: AstBuilder<'a>,
+
+    // state
+    modules: Vec<Module<'a>>,
+    paths: FxHashMap<PathBuf, ModuleId>,
+    /// External bindings imported or re-exported as values by any module, the others are only
+    /// imported as types.
+    value_imports: RefCell<FxHashSet<Binding<'a>>>,
+    errors: RefCell<Vec<OxcDiagnostic>>,
+
+    // options
+    strip_internal: bool,
+}
+
+/// A declaration program added to the bundler.
+struct Module<'a> {
+    path: PathBuf,
+    program: Program<'a>,
+    record: ModuleRecord<'a>,
+    /// Top level bindings, mapped to the indexes of the statements declaring them.
+    /// Anonymous default exports are declared as `default`.
+    declarations: FxHashMap<Atom<'a>, Vec<usize>>,
+    /// Module ids of the requested modules which are part of the bundle.
+    resolved_requests: FxHashMap<Atom<'a>, ModuleId>,
+    /// Start position of `@internal` jsdoc annotations.
+    internal_annotations: FxHashSet<u32>,
+}
+
+/// A binding referenced from the bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Binding<'a> {
+    /// A top level declaration of a bundled module.
+    Local(ModuleId, Atom<'a>),
+    /// The namespace object of a bundled module, e.g. `import * as ns from "./mod"`.
+    Namespace(ModuleId),
+    /// A binding imported from a module outside of the bundle.
+    External(Atom<'a>, ExternalName<'a>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ExternalName<'a> {
+    Name(Atom<'a>),
+    Default,
+    Namespace,
+}
+
+/// The bindings exported by a module.
+#[derive(Default)]
+struct Exports<'a> {
+    /// Bindings keyed by export name, in order of appearance.
+    bindings: Vec<(Atom<'a>, Binding<'a>)>,
+    /// External modules re-exported with `export * from "mod"`.
+    external_stars: Vec<Atom<'a>>,
+}
+
+impl Exports<'_> {
+    fn contains(&self, name: &str) -> bool {
+        self.bindings.iter().any(|(export_name, _)| export_name == name)
+    }
+}
+
+/// State collected while linking the modules reachable from the entry.
+#[derive(Default)]
+struct Linker<'a> {
+    /// Included bindings, in order of inclusion.
+    bindings: Vec<Binding<'a>>,
+    included: FxHashSet<Binding<'a>>,
+    /// The name a binding is referenced by, used as its preferred name.
+    hints: FxHashMap<Binding<'a>, Atom<'a>>,
+    /// Included statements, keyed by module id and statement index.
+    statements: FxHashMap<(ModuleId, usize), Statement<'a>>,
+    /// Bindings of the top level names referenced by each module.
+    links: FxHashMap<(ModuleId, Atom<'a>), Option<Binding<'a>>>,
+    /// Referenced names which are not declared in the bundle, e.g. `Promise`.
+    globals: FxHashSet<Atom<'a>>,
+    /// Exports of the modules included as namespace objects.
+    namespaces: Vec<(Binding<'a>, Exports<'a>)>,
+}
+
+impl<'a> DeclarationBundler<'a> {
+    pub fn new(allocator: &'a Allocator, options: IsolatedDeclarationsOptions) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            modules: vec![],
+            paths: FxHashMap::default(),
+            value_imports: RefCell::new(FxHashSet::default()),
+            errors: RefCell::new(vec![]),
+            strip_internal: options.strip_internal,
+        }
+    }
+
+    /// Add the declaration program of the module at `path`.
+    ///
+    /// When `strip_internal` is set, declarations with an `@internal` annotation in their JSDoc
+    /// comment are not exported from the bundle, and re-exports of names which no longer exist
+    /// are silently dropped.
+    pub fn add_module<P: AsRef<Path>>(&mut self, path: P, program: Program<'a>) {
+        let path = normalize_path(path.as_ref());
+        let record = self.build_module_record(&program);
+        let mut declarations: FxHashMap<Atom<'a>, Vec<usize>> = FxHashMap::default();
+        for (index, stmt) in program.body.iter().enumerate() {
+            Self::statement_bound_names(stmt, &mut |name| {
+                declarations.entry(name.clone()).or_default().push(index);
+            });
+        }
+        let internal_annotations = if self.strip_internal {
+            IsolatedDeclarations::build_internal_annotations(&program)
+        } else {
+            FxHashSet::default()
+        };
+        self.paths.insert(path.clone(), self.modules.len());
+        self.modules.push(Module {
+            path,
+            program,
+            record,
+            declarations,
+            resolved_requests: FxHashMap::default(),
+            internal_annotations,
+        });
+    }
+
+    /// # Errors
+    ///
+    /// Returns `Vec<Error>` if the entry could not be found, or if an import or re-export
+    /// between the bundled modules refers to a missing export.
+    pub fn build<P: AsRef<Path>>(mut self, entry: P) -> DeclarationBundleReturn<'a> {
+        let entry = normalize_path(entry.as_ref());
+        let Some(&entry_id) = self.paths.get(&entry) else {
+            self.error(bundle_entry_not_found(&entry.to_string_lossy()));
+            return DeclarationBundleReturn {
+                program: self.program("", self.ast.vec(), self.ast.vec()),
+                errors: self.take_errors(),
+            };
+        };
+        self.resolve_requests();
+
+        let order = self.module_order(entry_id);
+        for &module_id in &order {
+            let module = &self.modules[module_id];
+            if module
+                .program
+                .body
+                .iter()
+                .any(|stmt| matches!(stmt, Statement::TSExportAssignment(_)))
+            {
+                self.error(bundle_export_assignment(&module.path.to_string_lossy()));
+            }
+        }
+
+        let mut linker = Linker::default();
+
+        // 1. Include the exports of the entry and everything they reference.
+        let mut exports = self.collect_exports(entry_id, false, &mut FxHashSet::default());
+        if self.strip_internal {
+            exports.bindings.retain(|(_, binding)| !self.is_internal(binding));
+        }
+        for (name, binding) in &exports.bindings {
+            self.include(binding.clone(), name, &mut linker);
+        }
+
+        // 2. Include global augmentations, i.e. `declare global { ... }` and `declare module "mod" { ... }`.
+        for &module_id in &order {
+            for (index, stmt) in self.modules[module_id].program.body.iter().enumerate() {
+                if Self::is_augmentation(stmt) {
+                    self.include_statement(module_id, index, &mut linker);
+                }
+            }
+        }
+
+        // 3. Name the included bindings, the exported ones first.
+        let names = self.assign_names(&exports, &linker);
+
+        // 4. Emit external imports, declarations, namespace objects and exports.
+        let mut body = self.ast.vec();
+        self.emit_external_imports(&linker, &names, &mut body);
+        let (source_text, comments) =
+            self.emit_declarations(&order, &mut linker, &names, &mut body);
+        for (binding, exports) in &linker.namespaces {
+            body.push(self.namespace_object(&names[binding], exports, &names));
+        }
+        self.emit_exports(&exports, &names, &mut body);
+
+        DeclarationBundleReturn {
+            program: self.program(source_text, comments, body),
+            errors: self.take_errors(),
+        }
+    }
+
+    fn program(
+        &self,
+        source_text: &'a str,
+        comments: oxc_allocator::Vec<'a, Comment>,
+        body: oxc_allocator::Vec<'a, Statement<'a>>,
+    ) -> Program<'a> {
+        self.ast.program(
+            SPAN,
+            SourceType::d_ts(),
+            source_text,
+            comments,
+            None,
+            self.ast.vec(),
+            body,
+        )
+    }
+
+    fn take_errors(&self) -> Vec<OxcDiagnostic> {
+        mem::take(&mut self.errors.borrow_mut())
+    }
+
+    /// Add an Error
+    fn error(&self, error: OxcDiagnostic) {
+        self.errors.borrow_mut().push(error);
+    }
+}
+
+// Module records and resolution
+impl<'a> DeclarationBundler<'a> {
+    /// Build the [`ModuleRecord`] of a declaration program.
+    fn build_module_record(&self, program: &Program<'a>) -> ModuleRecord<'a> {
+        let mut record = ModuleRecord::new(self.ast.allocator);
+        let add_request = |record: &mut ModuleRecord<'a>,
+                           source: &StringLiteral<'a>,
+                           statement_span: Span,
+                           is_type: bool,
+                           is_import: bool| {
+            record
+                .requested_modules
+                .entry(source.value.clone())
+                .or_insert_with(|| self.ast.vec())
+                .push(RequestedModule { statement_span, span: source.span, is_type, is_import });
+        };
+        for stmt in &program.body {
+            let Some(decl) = stmt.as_module_declaration() else { continue };
+            record.has_module_syntax = true;
+            match decl {
+                ModuleDeclaration::ImportDeclaration(decl) => {
+                    add_request(
+                        &mut record,
+                        &decl.source,
+                        decl.span,
+                        decl.import_kind.is_type(),
+                        true,
+                    );
+                    let module_request = NameSpan::new(decl.source.value.clone(), decl.source.span);
+                    for specifier in decl.specifiers.iter().flatten() {
+                        let (import_name, local, is_type) = match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => (
+                                ImportImportName::Name(NameSpan::new(
+                                    specifier.imported.name(),
+                                    specifier.imported.span(),
+                                )),
+                                &specifier.local,
+                                specifier.import_kind.is_type(),
+                            ),
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                                (ImportImportName::Default(specifier.span), &specifier.local, false)
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                                (ImportImportName::NamespaceObject, &specifier.local, false)
+                            }
+                        };
+                        record.import_entries.push(ImportEntry {
+                            statement_span: decl.span,
+                            module_request: module_request.clone(),
+                            import_name,
+                            local_name: NameSpan::new(local.name.clone(), local.span),
+                            is_type: is_type || decl.import_kind.is_type(),
+                        });
+                    }
+                }
+                ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                    if let Some(declaration) = &decl.declaration {
+                        Self::declaration_bound_names(declaration, &mut |name| {
+                            let name = NameSpan::new(name.clone(), declaration.span());
+                            record.local_export_entries.push(ExportEntry {
+                                statement_span: decl.span,
+                                span: declaration.span(),
+                                export_name: ExportExportName::Name(name.clone()),
+                                local_name: ExportLocalName::Name(name),
+                                is_type: decl.export_kind.is_type(),
+                                ..ExportEntry::default()
+                            });
+                        });
+                        continue;
+                    }
+                    if let Some(source) = &decl.source {
+                        add_request(
+                            &mut record,
+                            source,
+                            decl.span,
+                            decl.export_kind.is_type(),
+                            false,
+                        );
+                    }
+                    for specifier in &decl.specifiers {
+                        let local = NameSpan::new(specifier.local.name(), specifier.local.span());
+                        let export_name = ExportExportName::Name(NameSpan::new(
+                            specifier.exported.name(),
+                            specifier.exported.span(),
+                        ));
+                        let is_type = decl.export_kind.is_type() || specifier.export_kind.is_type();
+                        if let Some(source) = &decl.source {
+                            record.indirect_export_entries.push(ExportEntry {
+                                statement_span: decl.span,
+                                span: specifier.span,
+                                module_request: Some(NameSpan::new(
+                                    source.value.clone(),
+                                    source.span,
+                                )),
+                                import_name: ExportImportName::Name(local),
+                                export_name,
+                                local_name: ExportLocalName::Null,
+                                is_type,
+                            });
+                        } else {
+                            record.local_export_entries.push(ExportEntry {
+                                statement_span: decl.span,
+                                span: specifier.span,
+                                export_name,
+                                local_name: ExportLocalName::Name(local),
+                                is_type,
+                                ..ExportEntry::default()
+                            });
+                        }
+                    }
+                }
+                ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                    let local_name = match &decl.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                            func.id.as_ref().map(|id| id.name.clone())
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                            class.id.as_ref().map(|id| id.name.clone())
+                        }
+                        ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => {
+                            Some(decl.id.name.clone())
+                        }
+                        ExportDefaultDeclarationKind::Identifier(ident) => Some(ident.name.clone()),
+                        _ => None,
+                    };
+                    let local_name = match local_name {
+                        Some(name) => ExportLocalName::Name(NameSpan::new(name, decl.span)),
+                        None => ExportLocalName::Default(NameSpan::new(
+                            Atom::from("default"),
+                            decl.span,
+                        )),
+                    };
+                    record.local_export_entries.push(ExportEntry {
+                        statement_span: decl.span,
+                        span: decl.declaration.span(),
+                        export_name: ExportExportName::Default(decl.exported.span()),
+                        local_name,
+                        ..ExportEntry::default()
+                    });
+                }
+                ModuleDeclaration::ExportAllDeclaration(decl) => {
+                    add_request(
+                        &mut record,
+                        &decl.source,
+                        decl.span,
+                        decl.export_kind.is_type(),
+                        false,
+                    );
+                    let mut entry = ExportEntry {
+                        statement_span: decl.span,
+                        span: decl.span,
+                        module_request: Some(NameSpan::new(
+                            decl.source.value.clone(),
+                            decl.source.span,
+                        )),
+                        is_type: decl.export_kind.is_type(),
+                        ..ExportEntry::default()
+                    };
+                    if let Some(exported) = &decl.exported {
+                        entry.import_name = ExportImportName::All;
+                        entry.export_name =
+                            ExportExportName::Name(NameSpan::new(exported.name(), exported.span()));
+                        record.indirect_export_entries.push(entry);
+                    } else {
+                        entry.import_name = ExportImportName::AllButDefault;
+                        record.star_export_entries.push(entry);
+                    }
+                }
+                ModuleDeclaration::TSExportAssignment(_)
+                | ModuleDeclaration::TSNamespaceExportDeclaration(_) => {}
+            }
+        }
+        record
+    }
+
+    /// Resolve the requested modules of every module against the added modules.
+    fn resolve_requests(&mut self) {
+        for module_id in 0..self.modules.len() {
+            let module = &self.modules[module_id];
+            let resolved_requests = module
+                .record
+                .requested_modules
+                .keys()
+                .filter_map(|specifier| {
+                    self.resolve_specifier(&module.path, specifier)
+                        .map(|target| (specifier.clone(), target))
+                })
+                .collect();
+            self.modules[module_id].resolved_requests = resolved_requests;
+        }
+    }
+
+    /// Resolve a relative module specifier, e.g. `./foo.js` to the module added as `foo.ts`.
+    fn resolve_specifier(&self, importer: &Path, specifier: &str) -> Option<ModuleId> {
+        if !(specifier.starts_with("./") || specifier.starts_with("../")) {
+            return None;
+        }
+        let base = normalize_path(&importer.parent().unwrap_or(Path::new("")).join(specifier));
+        if let Some(&module_id) = self.paths.get(&base) {
+            return Some(module_id);
+        }
+        let base_str = base.to_string_lossy();
+        let stem = [".js", ".mjs", ".cjs", ".jsx"]
+            .iter()
+            .find_map(|ext| base_str.strip_suffix(ext))
+            .unwrap_or(&base_str);
+        EXTENSIONS
+            .iter()
+            .map(|ext| format!("{stem}.{ext}"))
+            .chain(EXTENSIONS.iter().map(|ext| format!("{base_str}/index.{ext}")))
+            .find_map(|candidate| self.paths.get(Path::new(&candidate)).copied())
+    }
+
+    /// Modules reachable from the entry, dependencies first.
+    fn module_order(&self, entry_id: ModuleId) -> Vec<ModuleId> {
+        fn visit(
+            modules: &[Module],
+            module_id: ModuleId,
+            visited: &mut FxHashSet<ModuleId>,
+            order: &mut Vec<ModuleId>,
+        ) {
+            if !visited.insert(module_id) {
+                return;
+            }
+            let module = &modules[module_id];
+            let mut requests = module.resolved_requests.iter().collect::<Vec<_>>();
+            // Visit requests in source order
+            requests.sort_unstable_by_key(|(specifier, _)| {
+                module.record.requested_modules[*specifier]
+                    .first()
+                    .map(|request| request.span.start)
+            });
+            for (_, &target) in requests {
+                visit(modules, target, visited, order);
+            }
+            order.push(module_id);
+        }
+
+        let mut order = vec![];
+        visit(&self.modules, entry_id, &mut FxHashSet::default(), &mut order);
+        order
+    }
+
+    /// Collect the bindings exported by a module.
+    ///
+    /// `is_type` is whether the module is only re-exported as types.
+    fn collect_exports(
+        &self,
+        module_id: ModuleId,
+        is_type: bool,
+        visited: &mut FxHashSet<ModuleId>,
+    ) -> Exports<'a> {
+        let mut exports = Exports::default();
+        if !visited.insert(module_id) {
+            return exports;
+        }
+        let record = &self.modules[module_id].record;
+        for entry in record.local_export_entries.iter().chain(&record.indirect_export_entries) {
+            let export_name = match &entry.export_name {
+                ExportExportName::Name(name) => name.name.clone(),
+                ExportExportName::Default(_) => Atom::from("default"),
+                ExportExportName::Null => continue,
+            };
+            if exports.contains(&export_name) {
+                continue;
+            }
+            if let Some(binding) =
+                self.resolve_export_entry(module_id, entry, is_type || entry.is_type)
+            {
+                exports.bindings.push((export_name, binding));
+            }
+        }
+        for entry in &record.star_export_entries {
+            let Some(request) = &entry.module_request else { continue };
+            let Some(&target) = self.modules[module_id].resolved_requests.get(&request.name) else {
+                exports.external_stars.push(request.name.clone());
+                continue;
+            };
+            let star_exports = self.collect_exports(target, is_type || entry.is_type, visited);
+            for (name, binding) in star_exports.bindings {
+                if name != "default" && !exports.contains(&name) {
+                    exports.bindings.push((name, binding));
+                }
+            }
+            exports.external_stars.extend(star_exports.external_stars);
+        }
+        exports
+    }
+
+    /// Find the binding exported by a module under `name`.
+    ///
+    /// `is_type` is whether the binding is only imported as a type, see [`Self::resolve_import`].
+    fn resolve_export(
+        &self,
+        module_id: ModuleId,
+        name: &str,
+        is_type: bool,
+        visited: &mut FxHashSet<(ModuleId, Atom<'a>)>,
+    ) -> Option<Binding<'a>> {
+        if !visited.insert((module_id, self.ast.atom(name))) {
+            return None;
+        }
+        let module = &self.modules[module_id];
+        let record = &module.record;
+        let entry = record.local_export_entries.iter().chain(&record.indirect_export_entries).find(
+            |entry| match &entry.export_name {
+                ExportExportName::Name(export_name) => export_name.name == name,
+                ExportExportName::Default(_) => name == "default",
+                ExportExportName::Null => false,
+            },
+        );
+        if let Some(entry) = entry {
+            return self.resolve_export_entry(module_id, entry, is_type || entry.is_type);
+        }
+        if name == "default" {
+            return None;
+        }
+        let mut external_star = None;
+        for entry in &record.star_export_entries {
+            let Some(request) = &entry.module_request else { continue };
+            match module.resolved_requests.get(&request.name) {
+                Some(&target) => {
+                    let is_type = is_type || entry.is_type;
+                    if let Some(binding) = self.resolve_export(target, name, is_type, visited) {
+                        return Some(binding);
+                    }
+                }
+                None => {
+                    external_star
+                        .get_or_insert_with(|| (request.name.clone(), is_type || entry.is_type));
+                }
+            }
+        }
+        // The name may be re-exported from an external module with `export * from "mod"`.
+        external_star.map(|(source, is_type)| {
+            self.external(source, ExternalName::Name(self.ast.atom(name)), is_type)
+        })
+    }
+
+    fn resolve_export_entry(
+        &self,
+        module_id: ModuleId,
+        entry: &ExportEntry<'a>,
+        is_type: bool,
+    ) -> Option<Binding<'a>> {
+        match (&entry.module_request, &entry.import_name) {
+            (Some(request), ExportImportName::Name(name)) => self.resolve_import(
+                module_id,
+                &request.name,
+                ExternalName::Name(name.name.clone()),
+                is_type,
+            ),
+            (Some(request), ExportImportName::All) => {
+                self.resolve_import(module_id, &request.name, ExternalName::Namespace, is_type)
+            }
+            _ => {
+                let local_name = entry.local_name.name()?;
+                let binding = self.resolve_local(module_id, local_name, is_type);
+                if binding.is_none() && !self.strip_internal {
+                    self.error(bundle_missing_export(
+                        &self.modules[module_id].path.to_string_lossy(),
+                        local_name,
+                    ));
+                }
+                binding
+            }
+        }
+    }
+
+    /// Find the binding of a top level name of a module.
+    fn resolve_local(&self, module_id: ModuleId, name: &str, is_type: bool) -> Option<Binding<'a>> {
+        let module = &self.modules[module_id];
+        if let Some(entry) =
+            module.record.import_entries.iter().find(|entry| entry.local_name.name == name)
+        {
+            let import_name = match &entry.import_name {
+                ImportImportName::Name(name) => ExternalName::Name(name.name.clone()),
+                ImportImportName::Default(_) => ExternalName::Default,
+                ImportImportName::NamespaceObject => ExternalName::Namespace,
+            };
+            return self.resolve_import(
+                module_id,
+                &entry.module_request.name,
+                import_name,
+                is_type || entry.is_type,
+            );
+        }
+        module
+            .declarations
+            .get_key_value(name)
+            .map(|(name, _)| Binding::Local(module_id, name.clone()))
+    }
+
+    /// Find the binding imported by a module from `request`.
+    ///
+    /// `is_type` is whether the binding is imported with `import type`, either by this module or
+    /// by a module re-exporting it.
+    fn resolve_import(
+        &self,
+        module_id: ModuleId,
+        request: &Atom<'a>,
+        import_name: ExternalName<'a>,
+        is_type: bool,
+    ) -> Option<Binding<'a>> {
+        let module = &self.modules[module_id];
+        let Some(&target) = module.resolved_requests.get(request) else {
+            return Some(self.external(request.clone(), import_name, is_type));
+        };
+        let name = match import_name {
+            ExternalName::Namespace => return Some(Binding::Namespace(target)),
+            ExternalName::Default => Atom::from("default"),
+            ExternalName::Name(name) => name,
+        };
+        let binding = self.resolve_export(target, &name, is_type, &mut FxHashSet::default());
+        if binding.is_none() && !self.strip_internal {
+            self.error(bundle_missing_export(request, &name));
+        }
+        binding
+    }
+
+    /// A binding of an external module, recording whether it is imported as a value.
+    fn external(&self, source: Atom<'a>, name: ExternalName<'a>, is_type: bool) -> Binding<'a> {
+        let binding = Binding::External(source, name);
+        if !is_type {
+            self.value_imports.borrow_mut().insert(binding.clone());
+        }
+        binding
+    }
+}
+
+// Linking
+impl<'a> DeclarationBundler<'a> {
+    /// Include a binding and everything referenced by its declarations.
+    fn include(&self, binding: Binding<'a>, hint: &Atom<'a>, linker: &mut Linker<'a>) {
+        linker.hints.entry(binding.clone()).or_insert_with(|| hint.clone());
+        if !linker.included.insert(binding.clone()) {
+            return;
+        }
+        linker.bindings.push(binding.clone());
+        match &binding {
+            Binding::Local(module_id, name) => {
+                for &index in &self.modules[*module_id].declarations[name] {
+                    self.include_statement(*module_id, index, linker);
+                }
+            }
+            Binding::Namespace(module_id) => {
+                let exports = self.collect_exports(*module_id, false, &mut FxHashSet::default());
+                for (name, binding) in &exports.bindings {
+                    self.include(binding.clone(), name, linker);
+                }
+                linker.namespaces.push((binding, exports));
+            }
+            Binding::External(..) => {}
+        }
+    }
+
+    /// Include a top level statement, and the bindings it references.
+    fn include_statement(&self, module_id: ModuleId, index: usize, linker: &mut Linker<'a>) {
+        if linker.statements.contains_key(&(module_id, index)) {
+            return;
+        }
+        let Some(mut stmt) =
+            self.declaration_statement(&self.modules[module_id].program.body[index])
+        else {
+            return;
+        };
+        let renames = FxHashMap::default();
+        let mut references = ReferenceRenamer::new(&renames);
+        references.visit_statement(&mut stmt);
+        linker.statements.insert((module_id, index), stmt);
+
+        let mut declared = vec![];
+        Self::statement_bound_names(&self.modules[module_id].program.body[index], &mut |name| {
+            declared.push(name.clone());
+        });
+        for name in declared {
+            let binding = Binding::Local(module_id, name.clone());
+            linker.links.insert((module_id, name.clone()), Some(binding.clone()));
+            self.include(binding, &name, linker);
+        }
+
+        for name in references.references {
+            let binding = if let Some(binding) = linker.links.get(&(module_id, name.clone())) {
+                binding.clone()
+            } else {
+                let binding = self.resolve_local(module_id, &name, false);
+                linker.links.insert((module_id, name.clone()), binding.clone());
+                binding
+            };
+            match binding {
+                Some(binding) => self.include(binding, &name, linker),
+                None => {
+                    linker.globals.insert(name);
+                }
+            }
+        }
+    }
+
+    /// Whether any statement declaring a binding has an `@internal` annotation.
+    fn is_internal(&self, binding: &Binding<'a>) -> bool {
+        let Binding::Local(module_id, name) = binding else { return false };
+        let module = &self.modules[*module_id];
+        module.declarations[name].iter().any(|&index| {
+            let stmt = &module.program.body[index];
+            let declaration_start = match stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    decl.declaration.as_ref().map(|decl| decl.span().start)
+                }
+                _ => None,
+            };
+            module.internal_annotations.contains(&stmt.span().start)
+                || declaration_start
+                    .is_some_and(|start| module.internal_annotations.contains(&start))
+        })
+    }
+
+    /// Assign a unique name to every included binding.
+    fn assign_names(
+        &self,
+        exports: &Exports<'a>,
+        linker: &Linker<'a>,
+    ) -> FxHashMap<Binding<'a>, Atom<'a>> {
+        let mut used = linker.globals.clone();
+        let mut names = FxHashMap::default();
+        let mut assign = |binding: &Binding<'a>, name: &Atom<'a>| {
+            if names.contains_key(binding) {
+                return;
+            }
+            let mut unique = name.clone();
+            let mut count = 0;
+            while used.contains(&unique) {
+                count += 1;
+                unique = self.ast.atom(&format!("{name}${count}"));
+            }
+            used.insert(unique.clone());
+            names.insert(binding.clone(), unique);
+        };
+
+        // Keep the names of exported bindings
+        for (name, binding) in &exports.bindings {
+            if name != "default" && is_identifier_name(name) {
+                assign(binding, name);
+            }
+        }
+        for binding in &linker.bindings {
+            let name = match binding {
+                Binding::Local(_, name) if name != "default" => name.clone(),
+                _ => linker
+                    .hints
+                    .get(binding)
+                    .filter(|hint| *hint != "default" && is_identifier_name(hint))
+                    .cloned()
+                    .unwrap_or_else(|| Atom::from("_default")),
+            };
+            assign(binding, &name);
+        }
+        names
+    }
+}
+
+// Emitting
+impl<'a> DeclarationBundler<'a> {
+    fn emit_external_imports(
+        &self,
+        linker: &Linker<'a>,
+        names: &FxHashMap<Binding<'a>, Atom<'a>>,
+        body: &mut oxc_allocator::Vec<'a, Statement<'a>>,
+    ) {
+        let value_imports = self.value_imports.borrow();
+        // Value imports and `import type`, grouped by module
+        let mut sources: Vec<(
+            &Atom<'a>,
+            ImportOrExportKind,
+            oxc_allocator::Vec<'a, ImportDeclarationSpecifier<'a>>,
+        )> = vec![];
+        // Imports which can not be grouped: namespaces, and `import type` defaults
+        let mut singles = vec![];
+        for binding in &linker.bindings {
+            let Binding::External(source, import_name) = binding else { continue };
+            let import_kind = if value_imports.contains(binding) {
+                ImportOrExportKind::Value
+            } else {
+                ImportOrExportKind::Type
+            };
+            let local = self.ast.binding_identifier(SPAN, names[binding].clone());
+            let specifier = match import_name {
+                ExternalName::Namespace => {
+                    let specifier = self
+                        .ast
+                        .import_declaration_specifier_import_namespace_specifier(SPAN, local);
+                    singles.push((source, import_kind, specifier));
+                    continue;
+                }
+                ExternalName::Default => {
+                    let specifier =
+                        self.ast.import_declaration_specifier_import_default_specifier(SPAN, local);
+                    // `import type A, { B } from "mod"` is not allowed
+                    if import_kind.is_type() {
+                        singles.push((source, import_kind, specifier));
+                        continue;
+                    }
+                    specifier
+                }
+                ExternalName::Name(name) => self.ast.import_declaration_specifier_import_specifier(
+                    SPAN,
+                    self.module_export_name(name),
+                    local,
+                    ImportOrExportKind::Value,
+                ),
+            };
+            let index = sources
+                .iter()
+                .position(|(s, kind, _)| *s == source && *kind == import_kind)
+                .unwrap_or_else(|| {
+                    sources.push((source, import_kind, self.ast.vec()));
+                    sources.len() - 1
+                });
+            let specifiers = &mut sources[index].2;
+            if matches!(specifier, ImportDeclarationSpecifier::ImportDefaultSpecifier(_)) {
+                specifiers.insert(0, specifier);
+            } else {
+                specifiers.push(specifier);
+            }
+        }
+        for (source, import_kind, specifiers) in sources {
+            body.push(self.import_declaration(source, specifiers, import_kind));
+        }
+        for (source, import_kind, specifier) in singles {
+            body.push(self.import_declaration(source, self.ast.vec1(specifier), import_kind));
+        }
+    }
+
+    fn import_declaration(
+        &self,
+        source: &Atom<'a>,
+        specifiers: oxc_allocator::Vec<'a, ImportDeclarationSpecifier<'a>>,
+        import_kind: ImportOrExportKind,
+    ) -> Statement<'a> {
+        Statement::from(self.ast.module_declaration_import_declaration(
+            SPAN,
+            Some(specifiers),
+            self.ast.string_literal(SPAN, source.clone(), None),
+            None,
+            NONE,
+            import_kind,
+        ))
+    }
+
+    /// Emit the included declarations of every module, returning the source text and comments
+    /// of the bundle.
+    ///
+    /// The spans of the declarations and comments of each module are moved to where the source
+    /// text of the module is in the source text of the bundle, so that codegen prints the leading
+    /// comments of each declaration.
+    fn emit_declarations(
+        &self,
+        order: &[ModuleId],
+        linker: &mut Linker<'a>,
+        names: &FxHashMap<Binding<'a>, Atom<'a>>,
+        body: &mut oxc_allocator::Vec<'a, Statement<'a>>,
+    ) -> (&'a str, oxc_allocator::Vec<'a, Comment>) {
+        let mut source_text = String::new();
+        let mut comments = self.ast.vec();
+        for &module_id in order {
+            let program = &self.modules[module_id].program;
+            #[allow(clippy::cast_possible_truncation)]
+            let offset = source_text.len() as u32;
+            source_text.push_str(program.source_text);
+
+            let renames = linker
+                .links
+                .iter()
+                .filter(|((id, _), _)| *id == module_id)
+                .filter_map(|((_, name), binding)| {
+                    let new_name = names.get(binding.as_ref()?)?;
+                    (name != new_name).then(|| (name.clone(), new_name.clone()))
+                })
+                .collect::<FxHashMap<_, _>>();
+            let mut indexes = linker
+                .statements
+                .keys()
+                .filter(|(id, _)| *id == module_id)
+                .map(|(_, index)| *index)
+                .collect::<Vec<_>>();
+            indexes.sort_unstable();
+            // Comments attached to `export` are moved to the declaration emitted without it
+            let mut attachments = FxHashMap::default();
+            for index in indexes {
+                let mut stmt = linker.statements.remove(&(module_id, index)).unwrap();
+                attachments.insert(program.body[index].span().start, stmt.span().start);
+                ReferenceRenamer::new(&renames).visit_statement(&mut stmt);
+                Self::rename_declaration(&mut stmt, &renames);
+                SpanShifter(offset).visit_statement(&mut stmt);
+                body.push(stmt);
+            }
+            comments.extend(program.comments.iter().map(|comment| {
+                let attached_to =
+                    attachments.get(&comment.attached_to).copied().unwrap_or(comment.attached_to);
+                Comment {
+                    span: Span::new(comment.span.start + offset, comment.span.end + offset),
+                    attached_to: attached_to + offset,
+                    ..*comment
+                }
+            }));
+        }
+        (self.ast.allocator.alloc_str(&source_text), comments)
+    }
+
+    /// `declare namespace ns { export { a, b as c }; }`
+    fn namespace_object(
+        &self,
+        name: &Atom<'a>,
+        exports: &Exports<'a>,
+        names: &FxHashMap<Binding<'a>, Atom<'a>>,
+    ) -> Statement<'a> {
+        let export = self.export_specifiers(exports, names);
+        let body = self.ast.ts_module_declaration_body_module_block(
+            SPAN,
+            self.ast.vec(),
+            self.ast.vec1(export),
+        );
+        Statement::from(self.ast.declaration_ts_module(
+            SPAN,
+            TSModuleDeclarationName::Identifier(self.ast.binding_identifier(SPAN, name.clone())),
+            Some(body),
+            TSModuleDeclarationKind::Namespace,
+            true,
+        ))
+    }
+
+    fn emit_exports(
+        &self,
+        exports: &Exports<'a>,
+        names: &FxHashMap<Binding<'a>, Atom<'a>>,
+        body: &mut oxc_allocator::Vec<'a, Statement<'a>>,
+    ) {
+        if !exports.bindings.is_empty() || exports.external_stars.is_empty() {
+            body.push(self.export_specifiers(exports, names));
+        }
+        let mut seen = FxHashSet::default();
+        for source in &exports.external_stars {
+            if seen.insert(source) {
+                body.push(Statement::from(self.ast.module_declaration_export_all_declaration(
+                    SPAN,
+                    None,
+                    self.ast.string_literal(SPAN, source.clone(), None),
+                    NONE,
+                    ImportOrExportKind::Value,
+                )));
+            }
+        }
+    }
+
+    /// `export { a, b as c };`
+    fn export_specifiers(
+        &self,
+        exports: &Exports<'a>,
+        names: &FxHashMap<Binding<'a>, Atom<'a>>,
+    ) -> Statement<'a> {
+        let specifiers =
+            self.ast.vec_from_iter(exports.bindings.iter().filter_map(|(export_name, binding)| {
+                let local = names.get(binding)?;
+                Some(self.ast.export_specifier(
+                    SPAN,
+                    self.ast.module_export_name_identifier_reference(SPAN, local.clone()),
+                    self.module_export_name(export_name),
+                    ImportOrExportKind::Value,
+                ))
+            }));
+        Statement::from(self.ast.module_declaration_export_named_declaration(
+            SPAN,
+            None,
+            specifiers,
+            None,
+            ImportOrExportKind::Value,
+            NONE,
+        ))
+    }
+
+    fn module_export_name(&self, name: &Atom<'a>) -> ModuleExportName<'a> {
+        if is_identifier_name(name) {
+            self.ast.module_export_name_identifier_name(SPAN, name.clone())
+        } else {
+            ModuleExportName::StringLiteral(self.ast.string_literal(SPAN, name.clone(), None))
+        }
+    }
+}
+
+// Statements
+impl<'a> DeclarationBundler<'a> {
+    /// Clone the declaration of a top level statement, removing `export` from it.
+    fn declaration_statement(&self, stmt: &Statement<'a>) -> Option<Statement<'a>> {
+        let allocator = self.ast.allocator;
+        let mut declaration = match stmt {
+            match_declaration!(Statement) => stmt.to_declaration().clone_in(allocator),
+            Statement::ExportNamedDeclaration(decl) => decl.declaration.clone_in(allocator)?,
+            Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    let mut func = func.clone_in(allocator);
+                    if func.id.is_none() {
+                        func.id = Some(self.ast.binding_identifier(SPAN, "default"));
+                    }
+                    Declaration::FunctionDeclaration(func)
+                }
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                    let mut class = class.clone_in(allocator);
+                    if class.id.is_none() {
+                        class.id = Some(self.ast.binding_identifier(SPAN, "default"));
+                    }
+                    Declaration::ClassDeclaration(class)
+                }
+                ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => {
+                    Declaration::TSInterfaceDeclaration(decl.clone_in(allocator))
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+        match &mut declaration {
+            Declaration::VariableDeclaration(decl) => decl.declare = true,
+            Declaration::FunctionDeclaration(func) => func.declare = true,
+            Declaration::ClassDeclaration(class) => class.declare = true,
+            Declaration::TSEnumDeclaration(decl) => decl.declare = true,
+            Declaration::TSModuleDeclaration(decl) => decl.declare |= !decl.kind.is_global(),
+            Declaration::TSTypeAliasDeclaration(_)
+            | Declaration::TSInterfaceDeclaration(_)
+            | Declaration::TSImportEqualsDeclaration(_) => {}
+        }
+        Some(Statement::from(declaration))
+    }
+
+    /// `declare global { ... }` or `declare module "mod" { ... }`
+    fn is_augmentation(stmt: &Statement<'a>) -> bool {
+        matches!(stmt, Statement::TSModuleDeclaration(decl) if decl.kind.is_global() || decl.id.is_string_literal())
+    }
+
+    /// Names of the top level bindings declared by a statement.
+    fn statement_bound_names<F: FnMut(&Atom<'a>)>(stmt: &Statement<'a>, f: &mut F) {
+        match stmt {
+            match_declaration!(Statement) => {
+                Self::declaration_bound_names(stmt.to_declaration(), f);
+            }
+            Statement::ExportNamedDeclaration(decl) => {
+                if let Some(declaration) = &decl.declaration {
+                    Self::declaration_bound_names(declaration, f);
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                let id = match &decl.declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => func.id.as_ref(),
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => class.id.as_ref(),
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => Some(&decl.id),
+                    _ => return,
+                };
+                match id {
+                    Some(id) => f(&id.name),
+                    None => f(&Atom::from("default")),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn declaration_bound_names<F: FnMut(&Atom<'a>)>(declaration: &Declaration<'a>, f: &mut F) {
+        match declaration {
+            Declaration::VariableDeclaration(_)
+            | Declaration::FunctionDeclaration(_)
+            | Declaration::ClassDeclaration(_) => {
+                declaration.bound_names(&mut |ident| f(&ident.name));
+            }
+            Declaration::TSTypeAliasDeclaration(decl) => f(&decl.id.name),
+            Declaration::TSInterfaceDeclaration(decl) => f(&decl.id.name),
+            Declaration::TSEnumDeclaration(decl) => f(&decl.id.name),
+            Declaration::TSModuleDeclaration(decl) => match &decl.id {
+                TSModuleDeclarationName::Identifier(ident) if !decl.kind.is_global() => {
+                    f(&ident.name);
+                }
+                _ => {}
+            },
+            Declaration::TSImportEqualsDeclaration(decl) => f(&decl.id.name),
+        }
+    }
+
+    /// Rename the bindings declared by a top level statement.
+    fn rename_declaration(stmt: &mut Statement<'a>, renames: &FxHashMap<Atom<'a>, Atom<'a>>) {
+        let rename = |ident: &mut BindingIdentifier<'a>| {
+            if let Some(name) = renames.get(&ident.name) {
+                ident.name = name.clone();
+            }
+        };
+        match stmt {
+            Statement::VariableDeclaration(decl) => {
+                for declarator in decl.declarations.iter_mut() {
+                    if let BindingPatternKind::BindingIdentifier(ident) = &mut declarator.id.kind {
+                        rename(ident);
+                    }
+                }
+            }
+            Statement::FunctionDeclaration(func) => {
+                if let Some(id) = &mut func.id {
+                    rename(id);
+                }
+            }
+            Statement::ClassDeclaration(class) => {
+                if let Some(id) = &mut class.id {
+                    rename(id);
+                }
+            }
+            Statement::TSTypeAliasDeclaration(decl) => rename(&mut decl.id),
+            Statement::TSInterfaceDeclaration(decl) => rename(&mut decl.id),
+            Statement::TSEnumDeclaration(decl) => rename(&mut decl.id),
+            Statement::TSModuleDeclaration(decl) => {
+                if let TSModuleDeclarationName::Identifier(ident) = &mut decl.id {
+                    rename(ident);
+                }
+            }
+            Statement::TSImportEqualsDeclaration(decl) => rename(&mut decl.id),
+            _ => {}
+        }
+    }
+}
+
+/// Normalize `.` and `..` components of a path without touching the file system.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Collects the references of a statement to top level bindings, and renames them.
+struct ReferenceRenamer<'r, 'a> {
+    renames: &'r FxHashMap<Atom<'a>, Atom<'a>>,
+    /// Names declared by the scopes enclosing the current node.
+    scopes: Vec<FxHashSet<Atom<'a>>>,
+    /// Names of references to top level bindings, before renaming.
+    references: Vec<Atom<'a>>,
+}
+
+impl<'r, 'a> ReferenceRenamer<'r, 'a> {
+    fn new(renames: &'r FxHashMap<Atom<'a>, Atom<'a>>) -> Self {
+        Self { renames, scopes: vec![FxHashSet::default()], references: vec![] }
+    }
+
+    fn add_binding(&mut self, name: &Atom<'a>) {
+        self.scopes.last_mut().unwrap().insert(name.clone());
+    }
+
+    fn is_bound(&self, name: &Atom<'a>) -> bool {
+        self.scopes.iter().any(|scope| scope.contains(name))
+    }
+}
+
+impl<'a> VisitMut<'a> for ReferenceRenamer<'_, 'a> {
+    fn enter_scope(&mut self, _: ScopeFlags, _: &Cell<Option<ScopeId>>) {
+        self.scopes.push(FxHashSet::default());
+    }
+
+    fn leave_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if self.is_bound(&ident.name) {
+            return;
+        }
+        if !self.references.contains(&ident.name) {
+            self.references.push(ident.name.clone());
+        }
+        if let Some(name) = self.renames.get(&ident.name) {
+            ident.name = name.clone();
+        }
+    }
+
+    fn visit_ts_type_parameter(&mut self, param: &mut TSTypeParameter<'a>) {
+        self.add_binding(&param.name.name);
+        walk_ts_type_parameter(self, param);
+    }
+
+    fn visit_formal_parameters(&mut self, params: &mut FormalParameters<'a>) {
+        let mut names = vec![];
+        params.bound_names(&mut |ident| names.push(ident.name.clone()));
+        for name in &names {
+            self.add_binding(name);
+        }
+        walk_formal_parameters(self, params);
+    }
+
+    fn visit_ts_module_block(&mut self, block: &mut TSModuleBlock<'a>) {
+        let mut names = vec![];
+        for stmt in &block.body {
+            DeclarationBundler::statement_bound_names(stmt, &mut |name| names.push(name.clone()));
+        }
+        for name in &names {
+            self.add_binding(name);
+        }
+        walk_ts_module_block(self, block);
+    }
+
+    fn visit_ts_function_type(&mut self, ty: &mut TSFunctionType<'a>) {
+        self.scopes.push(FxHashSet::default());
+        walk_ts_function_type(self, ty);
+        self.scopes.pop();
+    }
+
+    fn visit_ts_constructor_type(&mut self, ty: &mut TSConstructorType<'a>) {
+        self.scopes.push(FxHashSet::default());
+        walk_ts_constructor_type(self, ty);
+        self.scopes.pop();
+    }
+
+    fn visit_ts_call_signature_declaration(
+        &mut self,
+        signature: &mut TSCallSignatureDeclaration<'a>,
+    ) {
+        self.scopes.push(FxHashSet::default());
+        walk_ts_call_signature_declaration(self, signature);
+        self.scopes.pop();
+    }
+}
+
+/// Moves every span of a statement by an offset.
+struct SpanShifter(u32);
+
+impl VisitMut<'_> for SpanShifter {
+    fn visit_span(&mut self, span: &mut Span) {
+        span.start += self.0;
+        span.end += self.0;
+    }
+}
@@ -139,3 +139,18 @@ pub fn type_containing_private_name(name: &str, span: Span) -> OxcDiagnostic {
     ))
     .with_label(span)
 }
+
+pub fn bundle_entry_not_found(path: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Entry module '{path}' was not added to the bundle."))
+}
+
+pub fn bundle_missing_export(module: &str, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Module '{module}' has no exported member '{name}'."))
+}
+
+pub fn bundle_export_assignment(path: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Module '{path}' uses `export =`, which cannot be bundled with ES module exports."
+    ))
+    .with_help("Use `export default` instead.")
+}
@@ -0,0 +1,115 @@
+use std::path::{Component, Path, PathBuf};
+
+use oxc_ast::ast::Program;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_sourcemap::SourceMap;
+
+use crate::IsolatedDeclarations;
+
+/// Options for [`IsolatedDeclarations::emit`].
+#[derive(Debug, Default, Clone)]
+pub struct DeclarationEmitOptions {
+    /// Generate a declaration map (`.d.ts.map`) mapping the emitted declarations back to the
+    /// source file, so "Go to Definition" in editors jumps to the `.ts` source instead of the
+    /// `.d.ts` file.
+    ///
+    /// Default: `false`
+    ///
+    /// ## References
+    /// [TSConfig - `declarationMap`](https://www.typescriptlang.org/tsconfig/#declarationMap)
+    pub declaration_map: bool,
+
+    /// Path of the emitted `.d.ts` file.
+    ///
+    /// The `file` and `sources` fields of the declaration map, and the `sourceMappingURL`
+    /// comment appended to the declarations, are relative to this path.
+    ///
+    /// Default: the source path with a `.d.ts` extension, e.g. `src/index.d.ts` for
+    /// `src/index.ts`.
+    pub declaration_path: Option<PathBuf>,
+}
+
+#[non_exhaustive]
+pub struct IsolatedDeclarationsEmitReturn {
+    /// The emitted declarations.
+    pub code: String,
+
+    /// The declaration map, if [`DeclarationEmitOptions::declaration_map`] is set.
+    pub map: Option<SourceMap>,
+
+    /// Errors collected during the transformation. The declarations are emitted even if there
+    /// are errors.
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> IsolatedDeclarations<'a> {
+    /// Transform `program` and print the declarations of the file at `source_path`.
+    pub fn emit(
+        self,
+        program: &Program<'a>,
+        source_path: &Path,
+        options: &DeclarationEmitOptions,
+    ) -> IsolatedDeclarationsEmitReturn {
+        let ret = self.build(program);
+        let declaration_path =
+            options.declaration_path.clone().unwrap_or_else(|| declaration_path(source_path));
+
+        let codegen_options = CodegenOptions {
+            source_map_path: options.declaration_map.then(|| source_path.to_path_buf()),
+            ..CodegenOptions::default()
+        };
+        let mut codegen_ret =
+            CodeGenerator::new().with_options(codegen_options).build(&ret.program);
+
+        let map = codegen_ret.map.map(|map| {
+            let file = file_name(&declaration_path);
+            codegen_ret.code.push_str(&format!("//# sourceMappingURL={file}.map\n"));
+            let source =
+                relative_path(declaration_path.parent().unwrap_or(Path::new("")), source_path);
+            declaration_map(&map, &file, &source)
+        });
+
+        IsolatedDeclarationsEmitReturn { code: codegen_ret.code, map, errors: ret.errors }
+    }
+}
+
+/// Point the source map generated by codegen at the declaration file, without embedding the
+/// source text, like `tsc` does.
+fn declaration_map(map: &SourceMap, file: &str, source: &str) -> SourceMap {
+    SourceMap::new(
+        Some(file.into()),
+        map.get_names().map(Into::into).collect(),
+        Some(String::new()),
+        vec![source.into()],
+        None,
+        map.get_tokens().cloned().collect(),
+        None,
+    )
+}
+
+/// `foo.ts` -> `foo.d.ts`, `foo.mts` -> `foo.d.mts`, `foo.cts` -> `foo.d.cts`
+fn declaration_path(source_path: &Path) -> PathBuf {
+    let extension = match source_path.extension().and_then(|ext| ext.to_str()) {
+        Some("mts" | "mjs") => "d.mts",
+        Some("cts" | "cjs") => "d.cts",
+        _ => "d.ts",
+    };
+    source_path.with_extension(extension)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+/// The path of `to` relative to the directory `from`, with `/` separators.
+fn relative_path(from: &Path, to: &Path) -> String {
+    let from = from.components().filter(|c| *c != Component::CurDir).collect::<Vec<_>>();
+    let to = to.components().filter(|c| *c != Component::CurDir).collect::<Vec<_>>();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    std::iter::repeat("..".to_string())
+        .take(from.len() - common)
+        .chain(to[common..].iter().map(|c| c.as_os_str().to_string_lossy().into_owned()))
+        .collect::<Vec<_>>()
+        .join("/")
+}
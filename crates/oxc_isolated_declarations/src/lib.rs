@@ -16,9 +16,11 @@ use oxc_span::{Atom, GetSpan, SourceType, SPAN};
 
 use crate::{diagnostics::function_with_assigning_properties, scope::ScopeTree};
 
+mod bundle;
 mod class;
 mod declaration;
 mod diagnostics;
+mod emit;
 mod r#enum;
 mod formal_parameter_binding_pattern;
 mod function;
@@ -30,6 +32,11 @@ mod scope;
 mod signatures;
 mod types;
 
+pub use crate::{
+    bundle::{DeclarationBundleReturn, DeclarationBundler},
+    emit::{DeclarationEmitOptions, IsolatedDeclarationsEmitReturn},
+};
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct IsolatedDeclarationsOptions {
     /// Do not emit declarations for code that has an `@internal` annotation in its JSDoc comment.
@@ -0,0 +1,5 @@
+import { Options } from "./options";
+
+export default class {
+  options?: Options;
+}
@@ -0,0 +1,9 @@
+export type Format = "json" | "text";
+
+export const defaultFormat: Format = "json";
+
+declare global {
+  interface Window {
+    format: Format;
+  }
+}
@@ -0,0 +1,9 @@
+import { Options } from "./options";
+import type { Plugin } from "some-package";
+
+export { Logger, createLogger } from "./logger.js";
+export * from "./format";
+export * as utils from "./utils";
+export { default as Config } from "./config";
+
+export function run(options: Options, plugins: Plugin[]): void {}
@@ -0,0 +1,22 @@
+import type { Options } from "./options";
+import { EventEmitter } from "some-package";
+
+interface Level {
+  name: string;
+}
+
+export class Logger extends EventEmitter {
+  level: Level;
+  constructor(options: Options) {
+    super();
+  }
+  child<Level>(level: Level): Logger {
+    return this;
+  }
+}
+
+export function createLogger(options: Options): Logger {
+  return new Logger(options);
+}
+
+function unused(): void {}
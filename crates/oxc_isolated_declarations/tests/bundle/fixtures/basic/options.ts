@@ -0,0 +1,11 @@
+interface Level {
+  value: number;
+}
+
+export interface Options {
+  level: Level;
+}
+
+declare global {
+  var options: Options;
+}
@@ -0,0 +1,5 @@
+export function noop(): void {}
+
+export function identity<T>(value: T): T {
+  return value;
+}
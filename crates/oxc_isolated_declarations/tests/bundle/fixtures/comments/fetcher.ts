@@ -0,0 +1,10 @@
+import type { Headers } from "./headers";
+
+/** Fetches resources. */
+export default class Fetcher {
+  /** Default headers of every request. */
+  headers: Headers;
+}
+
+/** The shared fetcher. */
+export const fetcher: Fetcher = new Fetcher();
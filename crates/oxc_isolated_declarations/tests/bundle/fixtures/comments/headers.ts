@@ -0,0 +1,7 @@
+/**
+ * HTTP headers.
+ */
+export interface Headers {
+  /** The content type. */
+  "content-type"?: string;
+}
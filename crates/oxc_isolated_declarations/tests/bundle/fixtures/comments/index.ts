@@ -0,0 +1,18 @@
+import type Client, { type Request } from "http-client";
+import { type Response, Agent } from "http-client";
+
+export { fetcher } from "./fetcher";
+export type { Headers } from "./headers";
+
+/**
+ * Create a client.
+ * @param agent - The agent used for requests
+ */
+export function createClient(agent: Agent): Client {
+  return new Client(agent);
+}
+
+// Not a JSDoc comment
+export function send(request: Request): Promise<Response> {
+  return fetch(request);
+}
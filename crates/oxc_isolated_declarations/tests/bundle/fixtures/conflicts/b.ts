@@ -0,0 +1,5 @@
+type Promise = string;
+
+export interface Item {
+  name: Promise;
+}
@@ -0,0 +1,9 @@
+import { Item as OtherItem } from "./b";
+import { Item } from "./a";
+
+export { Item };
+export { missing } from "./a";
+
+export default function (item: Item, other: OtherItem): Promise<void> {
+  return Promise.resolve();
+}
@@ -0,0 +1,8 @@
+import { Helper } from "./helper";
+
+export interface Api {
+  helper: Helper;
+}
+
+/** @internal */
+export function internalApi(): void {}
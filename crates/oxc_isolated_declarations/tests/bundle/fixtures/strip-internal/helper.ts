@@ -0,0 +1,8 @@
+/** @internal */
+export interface Helper {
+  run(): void;
+}
+
+export function helper(): Helper {
+  return { run() {} };
+}
@@ -0,0 +1,2 @@
+export * from "./api";
+export { helper } from "./helper";
@@ -0,0 +1,75 @@
+use std::{fs, path::Path};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::CodeGenerator;
+use oxc_isolated_declarations::{
+    DeclarationBundler, IsolatedDeclarations, IsolatedDeclarationsOptions,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn collect_files(dir: &Path, files: &mut Vec<std::path::PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+/// Bundle the declarations of all modules of a fixture directory, starting from `index.ts`.
+fn bundle(dir: &Path) -> String {
+    let allocator = Allocator::default();
+    let mut files = vec![];
+    collect_files(dir, &mut files);
+    files.sort();
+
+    let strip_internal = dir.ends_with("strip-internal");
+    let mut bundler =
+        DeclarationBundler::new(&allocator, IsolatedDeclarationsOptions { strip_internal });
+    for path in files {
+        let source_text = allocator.alloc_str(&fs::read_to_string(&path).unwrap());
+        let source_type = SourceType::from_path(&path).unwrap();
+        let parser_ret = Parser::new(&allocator, source_text, source_type).parse();
+        let id_ret = IsolatedDeclarations::new(&allocator, IsolatedDeclarationsOptions::default())
+            .build(&parser_ret.program);
+        assert!(id_ret.errors.is_empty(), "{:?}", id_ret.errors);
+        bundler.add_module(path.strip_prefix(dir).unwrap(), id_ret.program);
+    }
+
+    let ret = bundler.build("index.ts");
+    let code = CodeGenerator::new().build(&ret.program).code;
+    let mut snapshot =
+        format!("```\n==================== .D.TS ====================\n\n{code}\n\n");
+    if !ret.errors.is_empty() {
+        let error_messages =
+            ret.errors.iter().fold(String::new(), |s, error| s + &format!("{error}\n"));
+        snapshot.push_str(&format!(
+            "==================== Errors ====================\n{error_messages}\n\n```"
+        ));
+    }
+    snapshot
+}
+
+#[test]
+fn bundle_snapshots() {
+    insta::glob!("fixtures/*/index.ts", |path| {
+        let dir = path.parent().unwrap();
+        let snapshot = bundle(dir);
+        let name = format!("bundle-{}", dir.file_name().unwrap().to_str().unwrap());
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!(name, snapshot);
+        });
+    });
+}
+
+#[test]
+fn missing_entry() {
+    let allocator = Allocator::default();
+    let bundler = DeclarationBundler::new(&allocator, IsolatedDeclarationsOptions::default());
+    let ret = bundler.build("index.ts");
+    assert!(ret.program.body.is_empty());
+    assert_eq!(ret.errors.len(), 1);
+}
@@ -1,10 +1,13 @@
+mod bundle;
 mod deno;
 
 use std::{fs, path::Path, sync::Arc};
 
 use oxc_allocator::Allocator;
 use oxc_codegen::CodeGenerator;
-use oxc_isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions};
+use oxc_isolated_declarations::{
+    DeclarationEmitOptions, IsolatedDeclarations, IsolatedDeclarationsOptions,
+};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -47,3 +50,47 @@ fn snapshots() {
         });
     });
 }
+
+#[test]
+fn declaration_map() {
+    let allocator = Allocator::default();
+    let source_text =
+        "export class Foo {}\nexport function foo(a: number): Foo {\n  return new Foo();\n}\n";
+    let source_path = Path::new("src/index.ts");
+    let program = Parser::new(&allocator, source_text, SourceType::ts()).parse().program;
+
+    let options = DeclarationEmitOptions {
+        declaration_map: true,
+        declaration_path: Some("dist/index.d.ts".into()),
+    };
+    let ret = IsolatedDeclarations::new(&allocator, IsolatedDeclarationsOptions::default()).emit(
+        &program,
+        source_path,
+        &options,
+    );
+    assert!(ret.errors.is_empty());
+    assert!(ret.code.ends_with("//# sourceMappingURL=index.d.ts.map\n"));
+
+    let map = ret.map.unwrap();
+    assert_eq!(map.get_file(), Some("index.d.ts"));
+    assert_eq!(map.get_sources().collect::<Vec<_>>(), ["../src/index.ts"]);
+    assert!(map.get_source_contents().is_none());
+
+    // `export declare function foo` maps `foo` back to `export function foo`
+    let lookup_table = map.generate_lookup_table();
+    let token = map.lookup_token(&lookup_table, 1, 24).unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (1, 16));
+
+    // The default declaration path is next to the source file.
+    let program = Parser::new(&allocator, source_text, SourceType::ts()).parse().program;
+    let options =
+        DeclarationEmitOptions { declaration_map: true, ..DeclarationEmitOptions::default() };
+    let ret = IsolatedDeclarations::new(&allocator, IsolatedDeclarationsOptions::default()).emit(
+        &program,
+        source_path,
+        &options,
+    );
+    let map = ret.map.unwrap();
+    assert_eq!(map.get_file(), Some("index.d.ts"));
+    assert_eq!(map.get_sources().collect::<Vec<_>>(), ["index.ts"]);
+}
@@ -0,0 +1,3 @@
+export const foo = 1;
+
+export default function main() {}
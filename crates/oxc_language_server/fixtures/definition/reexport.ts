@@ -0,0 +1,4 @@
+export { foo as bar } from "./foo";
+
+import { foo } from "./foo";
+export { foo };
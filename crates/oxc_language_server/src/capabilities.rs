@@ -1,7 +1,7 @@
 use tower_lsp::lsp_types::{
     ClientCapabilities, CodeActionKind, CodeActionOptions, CodeActionProviderCapability, OneOf,
-    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, WorkDoneProgressOptions,
-    WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+    RenameOptions, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
+    WorkDoneProgressOptions, WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
 };
 
 pub const CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC: CodeActionKind =
@@ -9,20 +9,25 @@ pub const CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC: CodeActionKind =
 
 pub struct Capabilities {
     pub code_action_provider: bool,
+    pub prepare_rename_provider: bool,
 }
 
 impl From<ClientCapabilities> for Capabilities {
     fn from(value: ClientCapabilities) -> Self {
         // check if the client support some code action literal support
-        let code_action_provider = value.text_document.is_some_and(|capability| {
-            capability.code_action.is_some_and(|code_action| {
-                code_action.code_action_literal_support.is_some_and(|literal_support| {
+        let code_action_provider = value.text_document.as_ref().is_some_and(|capability| {
+            capability.code_action.as_ref().is_some_and(|code_action| {
+                code_action.code_action_literal_support.as_ref().is_some_and(|literal_support| {
                     !literal_support.code_action_kind.value_set.is_empty()
                 })
             })
         });
+        // check if the client can ask whether a rename is valid before performing it
+        let prepare_rename_provider = value.text_document.is_some_and(|capability| {
+            capability.rename.is_some_and(|rename| rename.prepare_support == Some(true))
+        });
 
-        Self { code_action_provider }
+        Self { code_action_provider, prepare_rename_provider }
     }
 }
 
@@ -51,6 +56,19 @@ impl From<Capabilities> for ServerCapabilities {
             } else {
                 None
             },
+            definition_provider: Some(OneOf::Left(true)),
+            references_provider: Some(OneOf::Left(true)),
+            document_highlight_provider: Some(OneOf::Left(true)),
+            rename_provider: Some(if value.prepare_rename_provider {
+                OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions {
+                        work_done_progress: None,
+                    },
+                })
+            } else {
+                OneOf::Left(true)
+            }),
             ..ServerCapabilities::default()
         }
     }
@@ -0,0 +1,373 @@
+use std::path::{Path, PathBuf};
+
+use log::debug;
+use rustc_hash::FxHashSet;
+use tower_lsp::lsp_types::{Location, Position, Range, Url};
+
+use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
+use oxc_data_structures::rope::{get_line_column, Rope};
+use oxc_linter::{
+    create_resolver,
+    loader::{JavaScriptSource, Loader},
+    ExportExportName, ExportImportName, ModuleRecord,
+};
+use oxc_parser::Parser;
+use oxc_resolver::Resolver;
+use oxc_semantic::{Semantic, SemanticBuilder, SymbolId};
+use oxc_span::{CompactStr, Span};
+
+/// The name a binding is imported under from another module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportedName {
+    /// `import { name } from "mod"`
+    Name(CompactStr),
+    /// `import name from "mod"`
+    Default,
+    /// `import * as name from "mod"`
+    Namespace,
+}
+
+/// Get the module specifier and imported name of a symbol declared by an import.
+///
+/// Returns [`None`] if the symbol is not declared by an `import` declaration.
+pub fn import_of_symbol(
+    semantic: &Semantic,
+    symbol_id: SymbolId,
+) -> Option<(CompactStr, ImportedName)> {
+    let declaration = semantic.symbol_declaration(symbol_id);
+    let name = match declaration.kind() {
+        AstKind::ImportSpecifier(specifier) => {
+            ImportedName::Name(CompactStr::from(specifier.imported.name().as_str()))
+        }
+        AstKind::ImportDefaultSpecifier(_) => ImportedName::Default,
+        AstKind::ImportNamespaceSpecifier(_) => ImportedName::Namespace,
+        _ => return None,
+    };
+    let AstKind::ImportDeclaration(decl) = semantic.nodes().parent_kind(declaration.id())? else {
+        return None;
+    };
+    Some((CompactStr::from(decl.source.value.as_str()), name))
+}
+
+/// Where an exported binding is found while following a module's exports.
+#[derive(Debug, PartialEq, Eq)]
+enum ExportTarget {
+    /// Declared in the module itself.
+    Local(Span),
+    /// Re-exported from other modules, in order of precedence.
+    Reexport(Vec<(CompactStr, ImportedName)>),
+}
+
+/// Resolves imported bindings to their declarations in other files, following re-exports.
+pub struct DefinitionResolver {
+    resolver: Resolver,
+}
+
+impl DefinitionResolver {
+    pub fn new(root_path: Option<&Path>) -> Self {
+        Self { resolver: create_resolver(root_path.map(|path| path.join("tsconfig.json"))) }
+    }
+
+    /// Find the declaration of `name` exported by the module `specifier`, as imported from
+    /// the file at `importer`.
+    ///
+    /// Modules are read with `read_file`, so that open documents with unsaved changes can be
+    /// used instead of the files on disk.
+    pub fn resolve_import<F>(
+        &self,
+        importer: &Path,
+        specifier: &str,
+        name: &ImportedName,
+        read_file: F,
+    ) -> Option<Location>
+    where
+        F: Fn(&Path) -> Option<String>,
+    {
+        let mut visited = FxHashSet::default();
+        self.find_export(importer, specifier, name, &read_file, &mut visited)
+    }
+
+    fn find_export<F>(
+        &self,
+        importer: &Path,
+        specifier: &str,
+        name: &ImportedName,
+        read_file: &F,
+        visited: &mut FxHashSet<(PathBuf, CompactStr)>,
+    ) -> Option<Location>
+    where
+        F: Fn(&Path) -> Option<String>,
+    {
+        let dir = importer.parent()?;
+        let path = match self.resolver.resolve(dir, specifier) {
+            Ok(resolution) => resolution.into_path_buf(),
+            Err(err) => {
+                debug!("failed to resolve {specifier:?} from {importer:?}: {err}");
+                return None;
+            }
+        };
+        let key = match name {
+            ImportedName::Name(name) => name.clone(),
+            ImportedName::Default => CompactStr::new("default"),
+            ImportedName::Namespace => CompactStr::new("*"),
+        };
+        if !visited.insert((path.clone(), key)) {
+            return None;
+        }
+
+        let source_text = read_file(&path)?;
+        if *name == ImportedName::Namespace {
+            return location(&path, &source_text, Span::default(), 0);
+        }
+
+        // `.vue`, `.astro` and `.svelte` files can have several script blocks
+        let sources = Loader.load_str(&path, &source_text).ok()?;
+        let targets = sources
+            .into_iter()
+            .filter_map(
+                |JavaScriptSource { source_text: module_text, source_type, start, .. }| {
+                    let allocator = Allocator::default();
+                    let ret = Parser::new(&allocator, module_text, source_type).parse();
+                    let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+                    let module_record = ModuleRecord::new(&path, &ret.module_record, &semantic);
+                    find_export_target(&semantic, &module_record, name)
+                        .map(|target| (target, start))
+                },
+            )
+            .collect::<Vec<_>>();
+
+        targets.into_iter().find_map(|(target, start)| match target {
+            ExportTarget::Local(span) => location(&path, &source_text, span, start),
+            ExportTarget::Reexport(requests) => requests.iter().find_map(|(specifier, name)| {
+                self.find_export(&path, specifier, name, read_file, visited)
+            }),
+        })
+    }
+}
+
+fn find_export_target(
+    semantic: &Semantic,
+    module_record: &ModuleRecord,
+    name: &ImportedName,
+) -> Option<ExportTarget> {
+    let is_requested_name = |export_name: &ExportExportName| match (export_name, name) {
+        (ExportExportName::Default(_), ImportedName::Default) => true,
+        (ExportExportName::Name(export_name), ImportedName::Name(name)) => {
+            export_name.name() == name.as_str()
+        }
+        _ => false,
+    };
+
+    // `export { foo }`, `export const foo = 1`, `export default foo`
+    if let Some(entry) = module_record
+        .local_export_entries
+        .iter()
+        .find(|entry| is_requested_name(&entry.export_name))
+    {
+        let binding = entry
+            .local_name
+            .name()
+            .and_then(|local_name| semantic.scopes().get_root_binding(local_name));
+        return Some(match binding {
+            Some(symbol_id) => match import_of_symbol(semantic, symbol_id) {
+                // `import { foo } from "mod"; export { foo }`
+                Some(import) => ExportTarget::Reexport(vec![import]),
+                None => ExportTarget::Local(semantic.symbols().get_span(symbol_id)),
+            },
+            // `export default expression`
+            None => ExportTarget::Local(entry.span),
+        });
+    }
+
+    // `export { foo } from "mod"`, `export * as foo from "mod"`
+    if let Some(entry) = module_record
+        .indirect_export_entries
+        .iter()
+        .find(|entry| is_requested_name(&entry.export_name))
+    {
+        let specifier = entry.module_request.as_ref()?.name.clone();
+        let imported_name = match &entry.import_name {
+            ExportImportName::Name(name) => ImportedName::Name(name.name.clone()),
+            ExportImportName::All => ImportedName::Namespace,
+            _ => return None,
+        };
+        return Some(ExportTarget::Reexport(vec![(specifier, imported_name)]));
+    }
+
+    // `export * from "mod"` never re-exports `default`
+    if *name == ImportedName::Default {
+        return None;
+    }
+    let requests = module_record
+        .star_export_entries
+        .iter()
+        .filter_map(|entry| entry.module_request.as_ref())
+        .map(|request| (request.name.clone(), name.clone()))
+        .collect::<Vec<_>>();
+    (!requests.is_empty()).then_some(ExportTarget::Reexport(requests))
+}
+
+fn location(path: &Path, source_text: &str, span: Span, start: u32) -> Option<Location> {
+    let rope = Rope::from_str(source_text);
+    let position = |offset| {
+        let (line, column) = get_line_column(&rope, offset, source_text);
+        Position::new(line, column)
+    };
+    Some(Location {
+        uri: Url::from_file_path(path).ok()?,
+        range: Range { start: position(span.start + start), end: position(span.end + start) },
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use oxc_allocator::Allocator;
+    use oxc_linter::ModuleRecord;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{CompactStr, SourceType, Span};
+    use tower_lsp::lsp_types::{Position, Range};
+
+    use super::{find_export_target, DefinitionResolver, ExportTarget, ImportedName};
+
+    fn export_target(source_text: &str, name: &ImportedName) -> Option<ExportTarget> {
+        let path = Path::new("test.ts");
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let module_record = ModuleRecord::new(path, &ret.module_record, &semantic);
+        find_export_target(&semantic, &module_record, name)
+    }
+
+    fn name(name: &str) -> ImportedName {
+        ImportedName::Name(CompactStr::new(name))
+    }
+
+    fn reexport(specifier: &str, name: ImportedName) -> ExportTarget {
+        ExportTarget::Reexport(vec![(CompactStr::new(specifier), name)])
+    }
+
+    #[test]
+    fn test_find_export_target() {
+        assert_eq!(
+            export_target("export const foo = 1", &name("foo")),
+            Some(ExportTarget::Local(Span::new(13, 16)))
+        );
+        assert_eq!(
+            export_target("function foo() {} export { foo as bar }", &name("bar")),
+            Some(ExportTarget::Local(Span::new(9, 12)))
+        );
+        assert_eq!(export_target("export const foo = 1", &name("bar")), None);
+    }
+
+    #[test]
+    fn test_find_export_target_default() {
+        assert_eq!(
+            export_target("export default function foo() {}", &ImportedName::Default),
+            Some(ExportTarget::Local(Span::new(24, 27)))
+        );
+        assert_eq!(
+            export_target("export default 1 + 1", &ImportedName::Default),
+            Some(ExportTarget::Local(Span::new(15, 20)))
+        );
+        assert_eq!(
+            export_target("import foo from './foo'; export default foo", &ImportedName::Default),
+            Some(reexport("./foo", ImportedName::Default))
+        );
+        assert_eq!(export_target("export const foo = 1", &ImportedName::Default), None);
+    }
+
+    #[test]
+    fn test_find_export_target_reexport() {
+        assert_eq!(
+            export_target("export { foo as bar } from './foo'", &name("bar")),
+            Some(reexport("./foo", name("foo")))
+        );
+        assert_eq!(
+            export_target("export * as ns from './foo'", &name("ns")),
+            Some(reexport("./foo", ImportedName::Namespace))
+        );
+        assert_eq!(
+            export_target("import { foo } from './foo'; export { foo }", &name("foo")),
+            Some(reexport("./foo", name("foo")))
+        );
+    }
+
+    #[test]
+    fn test_find_export_target_star() {
+        assert_eq!(
+            export_target("export * from './foo'; export * from './bar'", &name("foo")),
+            Some(ExportTarget::Reexport(vec![
+                (CompactStr::new("./foo"), name("foo")),
+                (CompactStr::new("./bar"), name("foo")),
+            ]))
+        );
+        assert_eq!(export_target("export * from './foo'", &ImportedName::Default), None);
+    }
+
+    fn resolve(specifier: &str, name: &ImportedName) -> Option<(String, Range)> {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/definition");
+        let resolver = DefinitionResolver::new(Some(&fixtures));
+        let location =
+            resolver.resolve_import(&fixtures.join("index.ts"), specifier, name, |path| {
+                fs::read_to_string(path).ok()
+            })?;
+        let path = location.uri.to_file_path().unwrap();
+        Some((path.file_name()?.to_string_lossy().to_string(), location.range))
+    }
+
+    fn range(line: u32, start: u32, end: u32) -> Range {
+        Range::new(Position::new(line, start), Position::new(line, end))
+    }
+
+    #[test]
+    fn test_resolve_import() {
+        assert_eq!(resolve("./foo", &name("foo")), Some(("foo.ts".into(), range(0, 13, 16))));
+        assert_eq!(
+            resolve("./foo", &ImportedName::Default),
+            Some(("foo.ts".into(), range(2, 24, 28)))
+        );
+        assert_eq!(
+            resolve("./foo", &ImportedName::Namespace),
+            Some(("foo.ts".into(), range(0, 0, 0)))
+        );
+        assert_eq!(resolve("./foo", &name("bar")), None);
+        assert_eq!(resolve("./missing", &name("foo")), None);
+    }
+
+    #[test]
+    fn test_resolve_import_reexport() {
+        // `export { foo as bar } from "./foo"`
+        assert_eq!(resolve("./reexport", &name("bar")), Some(("foo.ts".into(), range(0, 13, 16))));
+        // `import { foo } from "./foo"; export { foo }`
+        assert_eq!(resolve("./reexport", &name("foo")), Some(("foo.ts".into(), range(0, 13, 16))));
+        // `export * from "./reexport"`
+        assert_eq!(resolve("./star", &name("bar")), Some(("foo.ts".into(), range(0, 13, 16))));
+        assert_eq!(resolve("./star", &ImportedName::Default), None);
+    }
+
+    #[test]
+    fn test_resolve_import_script_blocks() {
+        assert_eq!(resolve("./App.vue", &name("a")), Some(("App.vue".into(), range(1, 13, 14))));
+        assert_eq!(resolve("./App.vue", &name("b")), Some(("App.vue".into(), range(5, 13, 14))));
+    }
+
+    #[test]
+    fn test_resolve_import_unsaved_document() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/definition");
+        let resolver = DefinitionResolver::new(Some(&fixtures));
+        let location = resolver
+            .resolve_import(&fixtures.join("index.ts"), "./foo", &name("foo"), |path| {
+                if path.ends_with("foo.ts") {
+                    Some("\nexport let foo = 2;\n".to_string())
+                } else {
+                    fs::read_to_string(path).ok()
+                }
+            })
+            .unwrap();
+        assert_eq!(location.range, range(1, 11, 14));
+    }
+}
@@ -1,4 +1,4 @@
-use std::{fmt::Debug, path::PathBuf, str::FromStr};
+use std::{fmt::Debug, fs, path::PathBuf, str::FromStr};
 
 use dashmap::DashMap;
 use futures::future::join_all;
@@ -14,8 +14,11 @@ use tower_lsp::{
         CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
         ConfigurationItem, Diagnostic, DidChangeConfigurationParams, DidChangeTextDocumentParams,
         DidChangeWatchedFilesParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
-        DidSaveTextDocumentParams, InitializeParams, InitializeResult, InitializedParams,
-        NumberOrString, Position, Range, ServerInfo, TextEdit, Url, WorkspaceEdit,
+        DidSaveTextDocumentParams, DocumentHighlight, DocumentHighlightParams,
+        GotoDefinitionParams, GotoDefinitionResponse, InitializeParams, InitializeResult,
+        InitializedParams, Location, NumberOrString, Position, PrepareRenameResponse, Range,
+        ReferenceParams, RenameParams, ServerInfo, TextDocumentPositionParams, TextEdit, Url,
+        WorkspaceEdit,
     },
     Client, LanguageServer, LspService, Server,
 };
@@ -23,18 +26,25 @@ use tower_lsp::{
 use oxc_linter::{FixKind, LinterBuilder, Oxlintrc};
 
 use crate::capabilities::{Capabilities, CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC};
+use crate::definition::DefinitionResolver;
 use crate::linter::{DiagnosticReport, ServerLinter};
+use crate::symbols::{Definition, SymbolHandler};
 
 mod capabilities;
+mod definition;
 mod linter;
+mod symbols;
 
 type FxDashMap<K, V> = DashMap<K, V, FxBuildHasher>;
 
 struct Backend {
     client: Client,
     root_uri: OnceCell<Option<Url>>,
+    definition_resolver: OnceCell<DefinitionResolver>,
     server_linter: RwLock<ServerLinter>,
     diagnostics_report_map: FxDashMap<String, Vec<DiagnosticReport>>,
+    /// Contents of open documents, which may not have been written to disk yet.
+    document_content_map: FxDashMap<String, String>,
     options: Mutex<Options>,
     gitignore_glob: Mutex<Vec<Gitignore>>,
 }
@@ -211,6 +221,10 @@ impl LanguageServer for Backend {
     /// When the document changed, it may not be written to disk, so we should
     /// get the file context from the language client
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        if let Some(change) = params.content_changes.first() {
+            self.document_content_map
+                .insert(params.text_document.uri.to_string(), change.text.clone());
+        }
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnType {
             return;
@@ -230,6 +244,8 @@ impl LanguageServer for Backend {
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        self.document_content_map
+            .insert(params.text_document.uri.to_string(), params.text_document.text.clone());
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level <= SyntheticRunLevel::Disable {
             return;
@@ -244,6 +260,92 @@ impl LanguageServer for Backend {
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri.to_string();
         self.diagnostics_report_map.remove(&uri);
+        self.document_content_map.remove(&uri);
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let TextDocumentPositionParams { text_document, position } =
+            params.text_document_position_params;
+        let Some((path, source_text)) = self.document_content(&text_document.uri) else {
+            return Ok(None);
+        };
+        let location = match SymbolHandler::new(&path, &source_text).definition(position) {
+            Some(Definition::Local(range)) => Location { uri: text_document.uri, range },
+            Some(Definition::Import { range, specifier, name }) => self
+                .definition_resolver
+                .get_or_init(|| async {
+                    let root_path =
+                        self.root_uri.get().and_then(|uri| uri.as_ref()?.to_file_path().ok());
+                    DefinitionResolver::new(root_path.as_deref())
+                })
+                .await
+                .resolve_import(&path, &specifier, &name, |path| {
+                    let uri = Url::from_file_path(path).ok()?;
+                    self.document_content(&uri).map(|(_, content)| content)
+                })
+                // fall back to the import binding when the module can't be followed
+                .unwrap_or(Location { uri: text_document.uri, range }),
+            None => return Ok(None),
+        };
+        Ok(Some(GotoDefinitionResponse::Scalar(location)))
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let TextDocumentPositionParams { text_document, position } = params.text_document_position;
+        let Some((path, source_text)) = self.document_content(&text_document.uri) else {
+            return Ok(None);
+        };
+        let ranges = SymbolHandler::new(&path, &source_text)
+            .references(position, params.context.include_declaration);
+        Ok(ranges.map(|ranges| {
+            ranges
+                .into_iter()
+                .map(|range| Location { uri: text_document.uri.clone(), range })
+                .collect()
+        }))
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> Result<Option<Vec<DocumentHighlight>>> {
+        let TextDocumentPositionParams { text_document, position } =
+            params.text_document_position_params;
+        let Some((path, source_text)) = self.document_content(&text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(SymbolHandler::new(&path, &source_text).document_highlights(position))
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let Some((path, source_text)) = self.document_content(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(SymbolHandler::new(&path, &source_text)
+            .prepare_rename(params.position)
+            .map(PrepareRenameResponse::Range))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let TextDocumentPositionParams { text_document, position } = params.text_document_position;
+        let Some((path, source_text)) = self.document_content(&text_document.uri) else {
+            return Ok(None);
+        };
+        match SymbolHandler::new(&path, &source_text).rename(position, &params.new_name) {
+            Some(Ok(edits)) => Ok(Some(WorkspaceEdit {
+                #[expect(clippy::disallowed_types)]
+                changes: Some(std::collections::HashMap::from([(text_document.uri, edits)])),
+                ..WorkspaceEdit::default()
+            })),
+            Some(Err(err)) => Err(Error::invalid_params(err.to_string())),
+            None => Ok(None),
+        }
     }
 
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
@@ -514,6 +616,17 @@ impl Backend {
         }
     }
 
+    /// Get the path and latest content of a document, preferring unsaved
+    /// changes from the client over the file on disk.
+    fn document_content(&self, uri: &Url) -> Option<(PathBuf, String)> {
+        let path = uri.to_file_path().ok()?;
+        let content = match self.document_content_map.get(&uri.to_string()) {
+            Some(content) => content.clone(),
+            None => fs::read_to_string(&path).ok()?,
+        };
+        Some((path, content))
+    }
+
     async fn is_ignored(&self, uri: &Url) -> bool {
         let Some(Some(root_uri)) = self.root_uri.get() else {
             return false;
@@ -556,8 +669,10 @@ async fn main() {
     let (service, socket) = LspService::build(|client| Backend {
         client,
         root_uri: OnceCell::new(),
+        definition_resolver: OnceCell::new(),
         server_linter: RwLock::new(server_linter),
         diagnostics_report_map,
+        document_content_map: FxDashMap::default(),
         options: Mutex::new(Options::default()),
         gitignore_glob: Mutex::new(vec![]),
     })
@@ -0,0 +1,249 @@
+use std::path::Path;
+
+use log::debug;
+use tower_lsp::lsp_types::{DocumentHighlight, DocumentHighlightKind, Position, Range, TextEdit};
+
+use oxc_allocator::Allocator;
+use oxc_data_structures::rope::{get_line_column, get_offset, Rope};
+use oxc_linter::loader::{JavaScriptSource, Loader};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::{OccurrenceKind, RenameError, Semantic, SemanticBuilder, SymbolId};
+use oxc_span::{CompactStr, Span};
+
+use crate::definition::{import_of_symbol, ImportedName};
+
+/// Where the symbol at a position is defined.
+pub enum Definition {
+    /// Declared in the same document.
+    Local(Range),
+    /// Imported from another module.
+    Import {
+        /// Range of the local import binding, used when the module cannot be resolved.
+        range: Range,
+        specifier: CompactStr,
+        name: ImportedName,
+    },
+}
+
+/// Answers symbol queries (references, highlights, rename) for a single document.
+pub struct SymbolHandler<'a> {
+    path: &'a Path,
+    source_text: &'a str,
+    rope: Rope,
+}
+
+impl<'a> SymbolHandler<'a> {
+    pub fn new(path: &'a Path, source_text: &'a str) -> Self {
+        Self { path, source_text, rope: Rope::from_str(source_text) }
+    }
+
+    /// Ranges of all occurrences of the symbol at `position`.
+    pub fn references(&self, position: Position, include_declaration: bool) -> Option<Vec<Range>> {
+        self.with_symbol_at(position, |semantic, symbol_id, start| {
+            semantic
+                .symbol_occurrences(symbol_id)
+                .into_iter()
+                .filter(|occurrence| {
+                    include_declaration || occurrence.kind != OccurrenceKind::Declaration
+                })
+                .map(|occurrence| self.span_to_range(occurrence.span, start))
+                .collect()
+        })
+    }
+
+    pub fn document_highlights(&self, position: Position) -> Option<Vec<DocumentHighlight>> {
+        self.with_symbol_at(position, |semantic, symbol_id, start| {
+            semantic
+                .symbol_occurrences(symbol_id)
+                .into_iter()
+                .map(|occurrence| DocumentHighlight {
+                    range: self.span_to_range(occurrence.span, start),
+                    kind: Some(match occurrence.kind {
+                        OccurrenceKind::Declaration => DocumentHighlightKind::TEXT,
+                        OccurrenceKind::Read => DocumentHighlightKind::READ,
+                        OccurrenceKind::Write => DocumentHighlightKind::WRITE,
+                    }),
+                })
+                .collect()
+        })
+    }
+
+    pub fn definition(&self, position: Position) -> Option<Definition> {
+        self.with_symbol_at(position, |semantic, symbol_id, start| {
+            let range = self.span_to_range(semantic.symbols().get_span(symbol_id), start);
+            match import_of_symbol(semantic, symbol_id) {
+                Some((specifier, name)) => Definition::Import { range, specifier, name },
+                None => Definition::Local(range),
+            }
+        })
+    }
+
+    /// Range of the identifier at `position`, if it can be renamed.
+    pub fn prepare_rename(&self, position: Position) -> Option<Range> {
+        let offset = self.position_to_offset(position)?;
+        self.with_symbol_at(position, |semantic, symbol_id, start| {
+            semantic
+                .symbol_occurrences(symbol_id)
+                .into_iter()
+                .find(|occurrence| {
+                    occurrence.span.start + start <= offset && offset <= occurrence.span.end + start
+                })
+                .map(|occurrence| self.span_to_range(occurrence.span, start))
+        })
+        .flatten()
+    }
+
+    pub fn rename(
+        &self,
+        position: Position,
+        new_name: &str,
+    ) -> Option<Result<Vec<TextEdit>, RenameError>> {
+        self.with_symbol_at(position, |semantic, symbol_id, start| {
+            let edits = semantic.rename_symbol(symbol_id, new_name)?;
+            Ok(edits
+                .into_iter()
+                .map(|edit| TextEdit {
+                    range: self.span_to_range(edit.span, start),
+                    new_text: edit.replacement,
+                })
+                .collect())
+        })
+    }
+
+    /// Build [`Semantic`] for the script block containing `position` and call
+    /// `f` with the symbol at that position and the block's start offset.
+    fn with_symbol_at<T, F>(&self, position: Position, f: F) -> Option<T>
+    where
+        F: FnOnce(&Semantic, SymbolId, u32) -> T,
+    {
+        let offset = self.position_to_offset(position)?;
+        let sources = match Loader.load_str(self.path, self.source_text) {
+            Ok(sources) => sources,
+            Err(e) => {
+                debug!("failed to load {:?}: {e}", self.path);
+                return None;
+            }
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let JavaScriptSource { source_text, source_type, start, .. } =
+            sources.into_iter().find(|source| {
+                source.start <= offset && offset <= source.start + source.source_text.len() as u32
+            })?;
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type)
+            .with_options(ParseOptions {
+                allow_return_outside_function: true,
+                ..ParseOptions::default()
+            })
+            .parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let symbol_id = semantic.symbol_at_offset(offset - start)?;
+        Some(f(&semantic, symbol_id, start))
+    }
+
+    fn position_to_offset(&self, position: Position) -> Option<u32> {
+        get_offset(&self.rope, position.line, position.character, self.source_text)
+    }
+
+    fn span_to_range(&self, span: Span, start: u32) -> Range {
+        let position = |offset| {
+            let (line, column) = get_line_column(&self.rope, offset, self.source_text);
+            Position::new(line, column)
+        };
+        Range { start: position(span.start + start), end: position(span.end + start) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use tower_lsp::lsp_types::{DocumentHighlightKind, Position, Range};
+
+    use super::{Definition, SymbolHandler};
+    use crate::definition::ImportedName;
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> Range {
+        Range::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+    }
+
+    const SOURCE: &str = "let a = 1;\na += 2;\nconsole.log(a);\n";
+
+    #[test]
+    fn test_references() {
+        let handler = SymbolHandler::new(Path::new("test.js"), SOURCE);
+        let position = Position::new(2, 12);
+        assert_eq!(
+            handler.references(position, true),
+            Some(vec![range((0, 4), (0, 5)), range((1, 0), (1, 1)), range((2, 12), (2, 13))])
+        );
+        assert_eq!(
+            handler.references(position, false),
+            Some(vec![range((1, 0), (1, 1)), range((2, 12), (2, 13))])
+        );
+        // `console` is a global
+        assert_eq!(handler.references(Position::new(2, 2), true), None);
+    }
+
+    #[test]
+    fn test_document_highlights() {
+        let handler = SymbolHandler::new(Path::new("test.js"), SOURCE);
+        let highlights = handler.document_highlights(Position::new(0, 4)).unwrap();
+        let kinds = highlights.iter().map(|highlight| highlight.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                Some(DocumentHighlightKind::TEXT),
+                Some(DocumentHighlightKind::WRITE),
+                Some(DocumentHighlightKind::READ)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_definition() {
+        let source = "import { b as c } from './b';\nlet a = c;\na;\n";
+        let handler = SymbolHandler::new(Path::new("test.js"), source);
+        let Some(Definition::Local(local)) = handler.definition(Position::new(2, 0)) else {
+            panic!("expected a local definition");
+        };
+        assert_eq!(local, range((1, 4), (1, 5)));
+
+        let Some(Definition::Import { range: import_range, specifier, name }) =
+            handler.definition(Position::new(1, 8))
+        else {
+            panic!("expected an imported definition");
+        };
+        assert_eq!(import_range, range((0, 14), (0, 15)));
+        assert_eq!(specifier, "./b");
+        assert_eq!(name, ImportedName::Name("b".into()));
+    }
+
+    #[test]
+    fn test_rename() {
+        let handler = SymbolHandler::new(Path::new("test.js"), SOURCE);
+        assert_eq!(handler.prepare_rename(Position::new(1, 1)), Some(range((1, 0), (1, 1))));
+        assert_eq!(handler.prepare_rename(Position::new(0, 8)), None);
+
+        let edits = handler.rename(Position::new(1, 0), "b").unwrap().unwrap();
+        assert_eq!(edits.len(), 3);
+        assert!(edits.iter().all(|edit| edit.new_text == "b"));
+        // would capture the global `console`
+        assert!(handler.rename(Position::new(1, 0), "console").unwrap().is_err());
+        assert!(handler.rename(Position::new(1, 0), "1b").unwrap().is_err());
+    }
+
+    #[test]
+    fn test_vue_script_block() {
+        let source =
+            "<template><div /></template>\n<script>\nconst a = 1;\nexport default a;\n</script>\n";
+        let handler = SymbolHandler::new(Path::new("test.vue"), source);
+        assert_eq!(
+            handler.references(Position::new(3, 15), true),
+            Some(vec![range((2, 6), (2, 7)), range((3, 15), (3, 16))])
+        );
+        // outside of the script block
+        assert_eq!(handler.references(Position::new(0, 11), true), None);
+    }
+}
@@ -0,0 +1 @@
+import { n0 } from './file-0'
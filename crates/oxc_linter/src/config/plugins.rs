@@ -7,9 +7,8 @@ use serde::{
 };
 
 bitflags! {
-    // NOTE: may be increased to a u32 if needed
     #[derive(Debug, Clone, Copy, PartialEq, Hash)]
-    pub struct LintPlugins: u16 {
+    pub struct LintPlugins: u32 {
         /// Not really a plugin. Included for completeness.
         const ESLINT = 0;
         /// `eslint-plugin-react`, plus `eslint-plugin-react-hooks`
@@ -38,6 +37,14 @@ bitflags! {
         const PROMISE = 1 << 11;
         /// `eslint-plugin-node`
         const NODE = 1 << 12;
+        /// `eslint-plugin-regexp`
+        const REGEXP = 1 << 13;
+        /// `eslint-plugin-vue`
+        const VUE = 1 << 14;
+        /// `eslint-plugin-testing-library`
+        const TESTING_LIBRARY = 1 << 15;
+        /// `eslint-plugin-security`, plus `eslint-plugin-no-unsanitized`
+        const SECURITY = 1 << 16;
     }
 }
 impl Default for LintPlugins {
@@ -63,6 +70,10 @@ impl From<LintPluginOptions> for LintPlugins {
         plugins.set(LintPlugins::REACT_PERF, options.react_perf);
         plugins.set(LintPlugins::PROMISE, options.promise);
         plugins.set(LintPlugins::NODE, options.node);
+        plugins.set(LintPlugins::REGEXP, options.regexp);
+        plugins.set(LintPlugins::VUE, options.vue);
+        plugins.set(LintPlugins::TESTING_LIBRARY, options.testing_library);
+        plugins.set(LintPlugins::SECURITY, options.security);
         plugins
     }
 }
@@ -112,6 +123,10 @@ impl From<&str> for LintPlugins {
             "react-perf" | "react_perf" => LintPlugins::REACT_PERF,
             "promise" => LintPlugins::PROMISE,
             "node" => LintPlugins::NODE,
+            "regexp" => LintPlugins::REGEXP,
+            "vue" => LintPlugins::VUE,
+            "testing-library" | "testing_library" => LintPlugins::TESTING_LIBRARY,
+            "security" => LintPlugins::SECURITY,
             // "eslint" is not really a plugin, so it's 'empty'. This has the added benefit of
             // making it the default value.
             _ => LintPlugins::empty(),
@@ -135,6 +150,10 @@ impl From<LintPlugins> for &'static str {
             LintPlugins::REACT_PERF => "react-perf",
             LintPlugins::PROMISE => "promise",
             LintPlugins::NODE => "node",
+            LintPlugins::REGEXP => "regexp",
+            LintPlugins::VUE => "vue",
+            LintPlugins::TESTING_LIBRARY => "testing-library",
+            LintPlugins::SECURITY => "security",
             _ => "",
         }
     }
@@ -242,6 +261,10 @@ pub struct LintPluginOptions {
     pub react_perf: bool,
     pub promise: bool,
     pub node: bool,
+    pub regexp: bool,
+    pub vue: bool,
+    pub testing_library: bool,
+    pub security: bool,
 }
 
 impl Default for LintPluginOptions {
@@ -260,6 +283,10 @@ impl Default for LintPluginOptions {
             react_perf: false,
             promise: false,
             node: false,
+            regexp: false,
+            vue: false,
+            testing_library: false,
+            security: false,
         }
     }
 }
@@ -282,6 +309,10 @@ impl LintPluginOptions {
             react_perf: false,
             promise: false,
             node: false,
+            regexp: false,
+            vue: false,
+            testing_library: false,
+            security: false,
         }
     }
 
@@ -302,6 +333,10 @@ impl LintPluginOptions {
             react_perf: true,
             promise: true,
             node: true,
+            regexp: true,
+            vue: true,
+            testing_library: true,
+            security: true,
         }
     }
 }
@@ -325,6 +360,10 @@ impl<S: AsRef<str>> FromIterator<(S, bool)> for LintPluginOptions {
                 LintPlugins::REACT_PERF => options.react_perf = enabled,
                 LintPlugins::PROMISE => options.promise = enabled,
                 LintPlugins::NODE => options.node = enabled,
+                LintPlugins::REGEXP => options.regexp = enabled,
+                LintPlugins::VUE => options.vue = enabled,
+                LintPlugins::TESTING_LIBRARY => options.testing_library = enabled,
+                LintPlugins::SECURITY => options.security = enabled,
                 _ => {} // ignored
             }
         }
@@ -357,6 +396,10 @@ mod test {
                 && self.react_perf == other.react_perf
                 && self.promise == other.promise
                 && self.node == other.node
+                && self.regexp == other.regexp
+                && self.vue == other.vue
+                && self.testing_library == other.testing_library
+                && self.security == other.security
         }
     }
 
@@ -396,6 +439,10 @@ mod test {
             react_perf: false,
             promise: false,
             node: false,
+            regexp: false,
+            vue: false,
+            testing_library: false,
+            security: false,
         };
         assert_eq!(plugins, expected);
     }
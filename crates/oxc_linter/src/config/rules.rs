@@ -1,5 +1,6 @@
 use std::{borrow::Cow, fmt};
 
+use cow_utils::CowUtils;
 use rustc_hash::{FxHashMap, FxHashSet};
 use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{
@@ -11,6 +12,7 @@ use serde::{
 use oxc_diagnostics::{Error, OxcDiagnostic};
 
 use crate::{
+    config::LintPlugins,
     rules::{RuleEnum, RULES},
     utils::{is_eslint_rule_adapted_to_typescript, is_jest_rule_adapted_to_vitest},
     AllowWarnDeny, RuleWithSeverity,
@@ -260,17 +262,31 @@ fn parse_rule_key(name: &str) -> (String, String) {
         return (
             RULES
                 .iter()
-                .find(|r| r.name() == name)
+                .filter(|r| r.name() == name)
+                // Prefer rules of plugins which are enabled by default, e.g. `no-process-exit`
+                // is `unicorn/no-process-exit` rather than `node/no-process-exit`.
+                .min_by_key(|r| {
+                    !LintPlugins::default().contains(LintPlugins::from(r.plugin_name()))
+                })
                 .map_or("unknown_plugin", RuleEnum::plugin_name)
                 .to_string(),
             name.to_string(),
         );
     };
 
+    // `eslint-plugin-no-unsanitized` rules reside in the security plugin, e.g.
+    // "no-unsanitized/property" is "security/no-unsanitized-property".
+    if plugin_name == "no-unsanitized" {
+        return ("security".to_string(), format!("no-unsanitized-{rule_name}"));
+    }
+
     let (oxlint_plugin_name, rule_name) = match plugin_name {
         "@typescript-eslint" => ("typescript", rule_name),
         "jsx-a11y" => ("jsx_a11y", rule_name),
         "react-perf" => ("react_perf", rule_name),
+        "testing-library" => ("testing_library", rule_name),
+        // `eslint-plugin-n` is the maintained fork of `eslint-plugin-node`.
+        "n" => ("node", rule_name),
         // e.g. "@next/next/google-font-display"
         "@next" => ("nextjs", rule_name.trim_start_matches("next/")),
         // For backwards compatibility, react hook rules reside in the react plugin.
@@ -280,7 +296,8 @@ fn parse_rule_key(name: &str) -> (String, String) {
         _ => (plugin_name, rule_name),
     };
 
-    (oxlint_plugin_name.to_string(), rule_name.to_string())
+    // Nested rule names are flattened, e.g. "node/no-unsupported-features/node-builtins".
+    (oxlint_plugin_name.to_string(), rule_name.cow_replace('/', "-").into_owned())
 }
 
 fn parse_rule_value(
@@ -359,6 +376,8 @@ mod test {
             "foo/no-unused-vars": [1],
             "dummy": ["error", "arg1", "args2"],
             "@next/next/noop": 2,
+            "n/no-unsupported-features/node-builtins": "warn",
+            "no-unsanitized/property": "error",
         }))
         .unwrap();
         let mut rules = rules.rules.iter();
@@ -386,6 +405,32 @@ mod test {
         assert_eq!(r4.plugin_name, "nextjs");
         assert!(r4.severity.is_warn_deny());
         assert!(r4.config.is_none());
+
+        let r5 = rules.next().unwrap();
+        assert_eq!(r5.rule_name, "no-unsupported-features-node-builtins");
+        assert_eq!(r5.plugin_name, "node");
+        assert!(r5.severity.is_warn_deny());
+
+        let r6 = rules.next().unwrap();
+        assert_eq!(r6.rule_name, "no-unsanitized-property");
+        assert_eq!(r6.plugin_name, "security");
+        assert!(r6.severity.is_warn_deny());
+    }
+
+    #[test]
+    fn test_parse_rules_prefer_default_plugins() {
+        // `no-process-exit` is both a `node` and a `unicorn` rule, and only `unicorn` is
+        // enabled by default.
+        let rules = OxlintRules::deserialize(&json!({ "no-process-exit": "error" })).unwrap();
+        let rule = rules.rules.first().unwrap();
+        assert_eq!(rule.rule_name, "no-process-exit");
+        assert_eq!(rule.plugin_name, "unicorn");
+
+        let mut rules = RuleSet::default();
+        r#override(&mut rules, &json!({ "no-process-exit": "error" }));
+        assert_eq!(rules.len(), 1);
+        let rule = rules.iter().next().unwrap();
+        assert_eq!(rule.plugin_name(), "unicorn");
     }
 
     #[test]
@@ -0,0 +1,30 @@
+use oxc_span::CompactStr;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Configure import plugin rules.
+///
+/// The settings use the same `import/`-prefixed keys as `eslint-plugin-import`.
+///
+/// ```json
+/// {
+///   "settings": {
+///     "import/core-modules": ["electron"]
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize, Default, Serialize, JsonSchema)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct ImportPluginSettings {
+    /// Modules which are provided by the runtime, and are therefore neither resolved nor listed
+    /// in `package.json`, e.g. `electron`. Node.js builtin modules are always considered core
+    /// modules.
+    #[serde(default, rename = "import/core-modules")]
+    pub core_modules: Vec<CompactStr>,
+}
+
+impl ImportPluginSettings {
+    pub fn is_core_module(&self, specifier: &str) -> bool {
+        self.core_modules.iter().any(|module| module == specifier)
+    }
+}
@@ -40,6 +40,10 @@ pub struct JSDocPluginSettings {
 
     #[serde(default, rename = "tagNamePreference")]
     tag_name_preference: FxHashMap<String, TagNamePreference>,
+
+    /// Only for `check-types` and `no-undefined-types` rule
+    #[serde(default, rename = "preferredTypes")]
+    preferred_types: FxHashMap<String, TagNamePreference>,
     // Not planning to support for now
     // min_lines: number
     // max_lines: number
@@ -47,16 +51,6 @@ pub struct JSDocPluginSettings {
     //
     // TODO: Need more investigation to understand these usage...
     //
-    // Only for `check-types` and `no-undefined-types` rule
-    // preferred_types: Record<
-    //   string,
-    //   false | string | {
-    //     message: string;
-    //     replacement?: false | string;
-    //     skipRootChecking?: boolean;
-    //   }
-    // >
-    //
     // structured_tags: Record<
     //   string,
     //   {
@@ -93,6 +87,7 @@ impl Default for JSDocPluginSettings {
             implements_replaces_docs: false,
             exempt_destructured_roots_from_checks: false,
             tag_name_preference: FxHashMap::default(),
+            preferred_types: FxHashMap::default(),
         }
     }
 }
@@ -168,6 +163,55 @@ impl JSDocPluginSettings {
             .collect()
     }
 
+    /// Only for `check-types` rule
+    /// Return `Some(reason)` if the type is blocked without replacement
+    pub fn check_blocked_type_name(&self, type_name: &str) -> Option<Cow<str>> {
+        match self.preferred_types.get(type_name) {
+            Some(TagNamePreference::FalseOnly(false)) => {
+                Some(Cow::Owned(format!("Unexpected type `{type_name}`.")))
+            }
+            Some(TagNamePreference::ObjectWithMessage { message }) => Some(Cow::Borrowed(message)),
+            _ => None,
+        }
+    }
+
+    /// Only for `check-types` rule
+    /// Return `Some((reason, replacement))` if the type has a user preferred replacement
+    pub fn check_preferred_type_name(&self, type_name: &str) -> Option<(Cow<str>, &str)> {
+        match self.preferred_types.get(type_name) {
+            Some(TagNamePreference::TagNameOnly(replacement)) if replacement != type_name => {
+                Some((
+                    Cow::Owned(format!("Replace type `{type_name}` with `{replacement}`.")),
+                    replacement,
+                ))
+            }
+            Some(TagNamePreference::ObjectWithMessageAndReplacement { message, replacement }) => {
+                Some((Cow::Borrowed(message), replacement))
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the type has any preference, including blocking one
+    pub fn has_type_preference(&self, type_name: &str) -> bool {
+        self.preferred_types.contains_key(type_name)
+    }
+
+    /// Only for `no-undefined-types` rule
+    /// Return all user replacement type names
+    pub fn list_preferred_type_names(&self) -> Vec<&str> {
+        self.preferred_types
+            .iter()
+            .filter_map(|(_, pref)| match pref {
+                TagNamePreference::TagNameOnly(replacement)
+                | TagNamePreference::ObjectWithMessageAndReplacement { replacement, .. } => {
+                    Some(replacement.as_str())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Resolve original, known tag name to user preferred name
     /// If not defined, return original name
     pub fn resolve_tag_name<'s>(&'s self, original_name: &'s str) -> &'s str {
@@ -181,6 +225,8 @@ impl JSDocPluginSettings {
     }
 }
 
+/// Also used for `preferredTypes`, which shares the same shape.
+/// Unknown fields like `skipRootChecking` of `preferredTypes` are ignored.
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[cfg_attr(test, derive(PartialEq))]
 #[serde(untagged)]
@@ -212,6 +258,7 @@ mod test {
         assert!(!settings.ignore_private);
         assert!(!settings.ignore_internal);
         assert_eq!(settings.tag_name_preference.len(), 0);
+        assert_eq!(settings.preferred_types.len(), 0);
         assert!(settings.ignore_replaces_docs);
         assert!(settings.override_replaces_docs);
         assert!(!settings.augments_extends_replaces_docs);
@@ -222,6 +269,7 @@ mod test {
         assert!(!settings.ignore_private);
         assert!(!settings.ignore_internal);
         assert_eq!(settings.tag_name_preference.len(), 0);
+        assert_eq!(settings.preferred_types.len(), 0);
         assert!(settings.ignore_replaces_docs);
         assert!(settings.override_replaces_docs);
         assert!(!settings.augments_extends_replaces_docs);
@@ -304,6 +352,63 @@ mod test {
         assert_eq!(settings.check_blocked_tag_name("baz"), None);
     }
 
+    #[test]
+    fn check_blocked_type_name() {
+        let settings = JSDocPluginSettings::deserialize(&serde_json::json!({})).unwrap();
+        assert_eq!(settings.check_blocked_type_name("Object"), None);
+
+        let settings = JSDocPluginSettings::deserialize(&serde_json::json!({
+            "preferredTypes": {
+                "foo": false,
+                "bar": { "message": "do not use bar" },
+                "baz": { "message": "do not use baz", "replacement": false },
+                "qux": { "message": "qux is quux now", "replacement": "quux" }
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            settings.check_blocked_type_name("foo"),
+            Some(Cow::Borrowed("Unexpected type `foo`."))
+        );
+        assert_eq!(settings.check_blocked_type_name("bar"), Some(Cow::Borrowed("do not use bar")));
+        assert_eq!(settings.check_blocked_type_name("baz"), Some(Cow::Borrowed("do not use baz")));
+        assert_eq!(settings.check_blocked_type_name("qux"), None);
+    }
+
+    #[test]
+    fn check_preferred_type_name() {
+        let settings = JSDocPluginSettings::deserialize(&serde_json::json!({})).unwrap();
+        assert_eq!(settings.check_preferred_type_name("Object"), None);
+
+        let settings = JSDocPluginSettings::deserialize(&serde_json::json!({
+            "preferredTypes": {
+                "foo": false,
+                "bar": { "message": "do not use bar" },
+                "Object": "object",
+                "object": "object",
+                "qux": { "message": "qux is quux now", "replacement": "quux" }
+            }
+        }))
+        .unwrap();
+        assert_eq!(settings.check_preferred_type_name("foo"), None);
+        assert_eq!(settings.check_preferred_type_name("bar"), None);
+        assert_eq!(settings.check_preferred_type_name("object"), None);
+        assert_eq!(
+            settings.check_preferred_type_name("Object"),
+            Some(("Replace type `Object` with `object`.".into(), "object"))
+        );
+        assert_eq!(
+            settings.check_preferred_type_name("qux"),
+            Some(("qux is quux now".into(), "quux"))
+        );
+        assert!(settings.has_type_preference("foo"));
+        assert!(!settings.has_type_preference("String"));
+
+        let mut preferred = settings.list_preferred_type_names();
+        preferred.sort_unstable();
+        assert_eq!(preferred, vec!["object", "object", "quux"]);
+    }
+
     #[test]
     fn check_preferred_tag_name() {
         let settings = JSDocPluginSettings::deserialize(&serde_json::json!({})).unwrap();
@@ -1,3 +1,4 @@
+mod import;
 pub mod jsdoc;
 mod jsx_a11y;
 mod next;
@@ -7,8 +8,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use self::{
-    jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings, next::NextPluginSettings,
-    react::ReactPluginSettings,
+    import::ImportPluginSettings, jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings,
+    next::NextPluginSettings, react::ReactPluginSettings,
 };
 
 /// # Oxlint Plugin Settings
@@ -33,7 +34,8 @@ use self::{
 ///         "Link": "a",
 ///         "Button": "button"
 ///       }
-///     }
+///     },
+///     "import/core-modules": ["electron"]
 ///   }
 /// }
 /// ```
@@ -52,6 +54,9 @@ pub struct OxlintSettings {
 
     #[serde(default)]
     pub jsdoc: JSDocPluginSettings,
+
+    #[serde(flatten)]
+    pub import: ImportPluginSettings,
 }
 
 #[cfg(test)]
@@ -83,6 +88,7 @@ mod test {
             "next": {
                 "rootDir": "app"
             },
+            "import/core-modules": ["electron"],
             "react": {
                 "formComponents": [
                     "CustomForm",
@@ -101,6 +107,7 @@ mod test {
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".into()));
         assert_eq!(settings.jsx_a11y.components.get("Link"), Some(&"Anchor".into()));
         assert!(settings.next.get_root_dirs().contains(&"app".to_string()));
+        assert!(settings.import.is_core_module("electron"));
         assert_eq!(
             settings.react.get_form_component_attrs("CustomForm").unwrap(),
             as_attrs::<CompactStr, _>(vec![])
@@ -125,5 +132,6 @@ mod test {
         let settings = OxlintSettings::default();
         assert!(settings.jsx_a11y.polymorphic_prop_name.is_none());
         assert!(settings.jsx_a11y.components.is_empty());
+        assert!(settings.import.core_modules.is_empty());
     }
 }
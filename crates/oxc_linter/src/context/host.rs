@@ -10,6 +10,7 @@ use crate::{
     frameworks,
     module_record::ModuleRecord,
     options::LintOptions,
+    package_json::PackageJsonCache,
     utils, FrameworkFlags, RuleWithSeverity,
 };
 
@@ -63,6 +64,8 @@ pub(crate) struct ContextHost<'a> {
     pub(super) frameworks: FrameworkFlags,
     /// A list of all available linter plugins.
     pub(super) plugins: LintPlugins,
+    /// The `package.json` files read by rules, shared with the other files of the lint run.
+    pub(super) package_jsons: Arc<PackageJsonCache>,
 }
 
 impl<'a> ContextHost<'a> {
@@ -100,10 +103,18 @@ impl<'a> ContextHost<'a> {
             config,
             frameworks: options.framework_hints,
             plugins,
+            package_jsons: Arc::default(),
         }
         .sniff_for_frameworks()
     }
 
+    /// Share the `package.json` files read by rules with the other files of the lint run.
+    #[inline]
+    pub fn with_package_jsons(mut self, package_jsons: &Arc<PackageJsonCache>) -> Self {
+        self.package_jsons = Arc::clone(package_jsons);
+        self
+    }
+
     /// Set the linter configuration for this context.
     #[inline]
     #[allow(dead_code)] // will be used in up-stack PR
@@ -13,6 +13,7 @@ use crate::{
     disable_directives::DisableDirectives,
     fixer::{FixKind, Message, RuleFix, RuleFixer},
     javascript_globals::GLOBALS,
+    package_json::PackageJsonCache,
     AllowWarnDeny, FrameworkFlags, ModuleRecord, OxlintEnv, OxlintGlobals, OxlintSettings,
 };
 
@@ -136,6 +137,12 @@ impl<'a> LintContext<'a> {
         &self.parent.file_path
     }
 
+    /// The `package.json` files which have been read during this lint run.
+    #[inline]
+    pub fn package_jsons(&self) -> &PackageJsonCache {
+        &self.parent.package_jsons
+    }
+
     /// Plugin settings
     #[inline]
     pub fn settings(&self) -> &OxlintSettings {
@@ -350,4 +357,8 @@ const PLUGIN_PREFIXES: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "unicorn" => "eslint-plugin-unicorn",
     "vitest" => "eslint-plugin-vitest",
     "node" => "eslint-plugin-node",
+    "regexp" => "eslint-plugin-regexp",
+    "vue" => "eslint-plugin-vue",
+    "testing_library" => "eslint-plugin-testing-library",
+    "security" => "eslint-plugin-security",
 };
@@ -15,6 +15,7 @@ mod javascript_globals;
 mod module_graph_visitor;
 mod module_record;
 mod options;
+mod package_json;
 mod rule;
 mod rules;
 mod service;
@@ -34,10 +35,10 @@ pub use crate::{
     context::LintContext,
     fixer::FixKind,
     frameworks::FrameworkFlags,
-    module_record::ModuleRecord,
+    module_record::{ExportExportName, ExportImportName, ModuleRecord},
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleWithSeverity},
-    service::{LintService, LintServiceOptions},
+    service::{create_resolver, LintService, LintServiceOptions},
 };
 use crate::{
     config::{
@@ -46,6 +47,7 @@ use crate::{
     context::ContextHost,
     fixer::{Fixer, Message},
     options::LintOptions,
+    package_json::PackageJsonCache,
     rules::RuleEnum,
     table::RuleTable,
     utils::iter_possible_jest_call_node,
@@ -114,16 +116,38 @@ impl Linter {
         self.config.rules()
     }
 
+    /// Whether a rule needs to know which modules import the module being linted, which
+    /// requires loading all modules before linting any of them.
+    pub(crate) fn needs_module_graph(&self) -> bool {
+        self.rules().iter().any(|rule| {
+            matches!(&rule.rule, RuleEnum::ImportNoUnusedModules(rule) if rule.checks_unused_exports())
+        })
+    }
+
     pub fn run<'a>(
         &self,
         path: &Path,
         semantic: Rc<Semantic<'a>>,
         module_record: Arc<ModuleRecord>,
+    ) -> Vec<Message<'a>> {
+        self.run_with_package_jsons(path, semantic, module_record, &Arc::default())
+    }
+
+    /// Lint a file, sharing the `package.json` files read by rules with the other files of a
+    /// lint run. The cache must not outlive the run, since it does not see later edits.
+    pub(crate) fn run_with_package_jsons<'a>(
+        &self,
+        path: &Path,
+        semantic: Rc<Semantic<'a>>,
+        module_record: Arc<ModuleRecord>,
+        package_jsons: &Arc<PackageJsonCache>,
     ) -> Vec<Message<'a>> {
         // Get config + rules for this file. Takes base rules and applies glob-based overrides.
         let ResolvedLinterState { rules, config } = self.config.resolve(path);
-        let ctx_host =
-            Rc::new(ContextHost::new(path, semantic, module_record, self.options, config));
+        let ctx_host = Rc::new(
+            ContextHost::new(path, semantic, module_record, self.options, config)
+                .with_package_jsons(package_jsons),
+        );
 
         let rules = rules
             .iter()
@@ -3,16 +3,22 @@
 use std::{
     fmt,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, Weak},
 };
 
 use dashmap::DashMap;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
 use oxc_semantic::Semantic;
 use oxc_span::{CompactStr, Span};
 pub use oxc_syntax::module_record::RequestedModule;
 
+use crate::ast_util::is_global_require_call;
+
 type FxDashMap<K, V> = DashMap<K, V, FxBuildHasher>;
 
 /// ESM Module Record
@@ -51,6 +57,45 @@ pub struct ModuleRecord {
     /// [`ModuleRecord`] is created. You must link the module records yourself.
     pub loaded_modules: FxDashMap<CompactStr, Arc<ModuleRecord>>,
 
+    /// A map from the specifier strings in `requested_modules` to the paths they resolve to.
+    ///
+    /// Specifiers which failed to resolve are missing from this map. Unlike `loaded_modules`, it
+    /// also contains specifiers of files which are not parsed, e.g. `.json` or `.css` files.
+    ///
+    /// This map is only populated when cross-module analysis is enabled, i.e. by the import
+    /// plugin. When the module graph is preloaded, it also contains `dynamic_imports`.
+    pub resolved_modules: FxDashMap<CompactStr, PathBuf>,
+
+    /// `true` if the specifiers of this module were resolved into `resolved_modules`.
+    ///
+    /// Module records which are not created by the lint service with cross-module analysis,
+    /// e.g. by the language server, are not resolved, so a specifier missing from
+    /// `resolved_modules` does not mean that it cannot be resolved.
+    pub resolved: bool,
+
+    /// Modules which are requested with a string literal by dynamic `import()` or a global
+    /// `require()` call.
+    ///
+    /// ```js
+    /// import("./foo");
+    /// //     ^^^^^^^
+    /// const bar = require("./bar");
+    /// //                  ^^^^^^^
+    /// ```
+    ///
+    /// This list is only collected when the module graph is preloaded, since it is only needed
+    /// to find the importers of a module.
+    pub dynamic_imports: Vec<NameSpan>,
+
+    /// The modules which import, re-export, dynamically import or require this module, keyed by
+    /// their resolved path.
+    ///
+    /// This map is only populated when the module graph is preloaded, i.e. by
+    /// `import/no-unused-modules` with `unusedExports`, since the importers of a module are not
+    /// known until all modules have been loaded. The module records are owned by the module
+    /// cache of the lint service, which outlives linting.
+    pub importers: FxDashMap<PathBuf, Weak<ModuleRecord>>,
+
     /// `[[ImportEntries]]`
     ///
     /// A List of `ImportEntry` records derived from the code of this module
@@ -98,11 +143,16 @@ impl fmt::Debug for ModuleRecord {
             .reduce(|acc, key| format!("{acc}, {key}"))
             .unwrap_or_default();
         let loaded_modules = format!("{{ {loaded_modules} }}");
+        let importers = self.importers.iter().map(|entry| entry.key().clone()).collect::<Vec<_>>();
         f.debug_struct("ModuleRecord")
             .field("has_module_syntax", &self.has_module_syntax)
             .field("resolved_absolute_path", &self.resolved_absolute_path)
             .field("requested_modules", &self.requested_modules)
             .field("loaded_modules", &loaded_modules)
+            .field("resolved_modules", &self.resolved_modules)
+            .field("resolved", &self.resolved)
+            .field("dynamic_imports", &self.dynamic_imports)
+            .field("importers", &importers)
             .field("import_entries", &self.import_entries)
             .field("local_export_entries", &self.local_export_entries)
             .field("indirect_export_entries", &self.indirect_export_entries)
@@ -442,6 +492,33 @@ impl<'a> From<&oxc_syntax::module_record::ExportLocalName<'a>> for ExportLocalNa
 }
 
 impl ModuleRecord {
+    /// Collect the modules which are requested by dynamic `import()` or a global `require()`
+    /// call with a string literal.
+    ///
+    /// This walks every node of `semantic`, so it is not done by [`ModuleRecord::new`].
+    pub fn collect_dynamic_imports(semantic: &Semantic) -> Vec<NameSpan> {
+        semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| {
+                let source = match node.kind() {
+                    AstKind::ImportExpression(import) => match &import.source {
+                        Expression::StringLiteral(source) => source,
+                        _ => return None,
+                    },
+                    AstKind::CallExpression(call) if is_global_require_call(call, semantic) => {
+                        match call.arguments.first() {
+                            Some(Argument::StringLiteral(source)) => source,
+                            _ => return None,
+                        }
+                    }
+                    _ => return None,
+                };
+                Some(NameSpan::new(CompactStr::from(source.value.as_str()), source.span))
+            })
+            .collect()
+    }
+
     pub fn new(
         path: &Path,
         other: &oxc_syntax::module_record::ModuleRecord,
@@ -0,0 +1,98 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use rustc_hash::FxBuildHasher;
+use serde_json::Value;
+
+use crate::utils::read_to_string;
+
+type FxDashMap<K, V> = DashMap<K, V, FxBuildHasher>;
+
+/// A parsed `package.json` file.
+#[derive(Debug)]
+pub struct PackageJson {
+    /// Path to the `package.json` file.
+    pub path: PathBuf,
+    pub value: Value,
+}
+
+/// The `package.json` files read by lint rules.
+///
+/// The cache is shared by all files of a [`LintService`](crate::LintService) run, so that
+/// each `package.json` is only looked up, read and parsed once. It is never invalidated, so it
+/// must not be kept across runs, e.g. by the language server.
+#[derive(Debug, Default)]
+pub struct PackageJsonCache {
+    /// Parsed `package.json` files by path, `None` if the file can not be read or parsed.
+    files: FxDashMap<PathBuf, Option<Arc<PackageJson>>>,
+    /// The path of the nearest `package.json` of each directory, `None` if there is none.
+    nearest: FxDashMap<PathBuf, Option<PathBuf>>,
+}
+
+impl PackageJsonCache {
+    /// The `package.json` file at `path`, or `None` if it can not be read or parsed.
+    pub fn read(&self, path: &Path) -> Option<Arc<PackageJson>> {
+        if let Some(package_json) = self.files.get(path) {
+            return package_json.clone();
+        }
+        let package_json = read_to_string(path)
+            .ok()
+            .and_then(|source_text| serde_json::from_str(&source_text).ok())
+            .map(|value| Arc::new(PackageJson { path: path.to_path_buf(), value }));
+        self.files.insert(path.to_path_buf(), package_json.clone());
+        package_json
+    }
+
+    /// The nearest `package.json` file of `file_path`, in its directory or any of its parents.
+    ///
+    /// Returns `None` if there is no `package.json`, or the nearest one can not be read or
+    /// parsed.
+    pub fn nearest(&self, file_path: &Path) -> Option<Arc<PackageJson>> {
+        let path = self.nearest_path(file_path.parent()?)?;
+        self.read(&path)
+    }
+
+    fn nearest_path(&self, dir: &Path) -> Option<PathBuf> {
+        if let Some(path) = self.nearest.get(dir) {
+            return path.clone();
+        }
+        let candidate = dir.join("package.json");
+        let path = if candidate.is_file() {
+            Some(candidate)
+        } else {
+            dir.parent().and_then(|parent| self.nearest_path(parent))
+        };
+        self.nearest.insert(dir.to_path_buf(), path.clone());
+        path
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::Arc};
+
+    use super::PackageJsonCache;
+
+    #[test]
+    fn nearest() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/import");
+        let cache = PackageJsonCache::default();
+
+        let nested = cache.nearest(&fixtures.join("monorepo/packages/nested-package/index.js"));
+        assert_eq!(
+            nested.unwrap().path,
+            fixtures.join("monorepo/packages/nested-package/package.json")
+        );
+
+        // Files in the same package share the parsed `package.json`.
+        let root = cache.nearest(&fixtures.join("index.js")).unwrap();
+        let deep = cache.nearest(&fixtures.join("does-not-exist/deep/index.js")).unwrap();
+        assert_eq!(root.path, fixtures.join("package.json"));
+        assert!(Arc::ptr_eq(&root, &deep));
+
+        assert!(cache.nearest(&fixtures.join("with-syntax-error/index.js")).is_none());
+    }
+}
@@ -8,7 +8,6 @@
 /// <https://github.com/import-js/eslint-plugin-import>
 mod import {
     // pub mod no_deprecated;
-    // pub mod no_unused_modules;
     pub mod default;
     pub mod export;
     pub mod first;
@@ -21,12 +20,16 @@ mod import {
     pub mod no_default_export;
     pub mod no_duplicates;
     pub mod no_dynamic_require;
+    pub mod no_extraneous_dependencies;
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
     pub mod no_named_default;
     pub mod no_namespace;
     pub mod no_self_import;
+    pub mod no_unresolved;
+    pub mod no_unused_modules;
     pub mod no_webpack_loader_syntax;
+    pub mod order;
     pub mod unambiguous;
 }
 
@@ -128,6 +131,7 @@ mod eslint {
     pub mod no_unused_labels;
     pub mod no_unused_private_class_members;
     pub mod no_unused_vars;
+    pub mod no_useless_assignment;
     pub mod no_useless_catch;
     pub mod no_useless_concat;
     pub mod no_useless_constructor;
@@ -438,6 +442,7 @@ mod oxc {
     pub mod no_const_enum;
     pub mod no_map_spread;
     pub mod no_optional_chaining;
+    pub mod no_redos;
     pub mod no_rest_spread_properties;
     pub mod number_arg_out_of_range;
     pub mod only_used_in_recursion;
@@ -472,9 +477,11 @@ mod jsdoc {
     pub mod check_access;
     pub mod check_property_names;
     pub mod check_tag_names;
+    pub mod check_types;
     pub mod empty_tags;
     pub mod implements_on_classes;
     pub mod no_defaults;
+    pub mod no_undefined_types;
     pub mod require_param;
     pub mod require_param_description;
     pub mod require_param_name;
@@ -487,6 +494,7 @@ mod jsdoc {
     pub mod require_returns_description;
     pub mod require_returns_type;
     pub mod require_yields;
+    pub mod valid_types;
 }
 
 mod promise {
@@ -514,13 +522,51 @@ mod vitest {
 }
 
 mod node {
+    pub mod no_deprecated_api;
     pub mod no_exports_assign;
     pub mod no_new_require;
+    pub mod no_process_exit;
+    pub mod no_sync;
+    pub mod no_unsupported_features_node_builtins;
+    pub mod prefer_node_protocol;
+}
+
+mod regexp {
+    pub mod no_dupe_characters_character_class;
+    pub mod no_super_linear_backtracking;
+    pub mod no_useless_escape;
+    pub mod optimal_quantifier_concatenation;
+    pub mod prefer_character_class;
+}
+
+mod testing_library {
+    pub mod await_async_queries;
+    pub mod no_await_sync_events;
+    pub mod no_node_access;
+    pub mod no_unnecessary_act;
+    pub mod prefer_find_by;
+    pub mod prefer_screen_queries;
+}
+
+mod security {
+    pub mod detect_child_process;
+    pub mod detect_non_literal_fs_filename;
+    pub mod detect_object_injection;
+    pub mod detect_unsafe_regex;
+    pub mod no_eval_with_expression;
+    pub mod no_unsanitized_property;
+}
+
+mod vue {
+    pub mod no_mutating_props;
+    pub mod no_ref_as_operand;
+    pub mod no_setup_props_reactivity_loss;
+    pub mod require_default_prop;
+    pub mod valid_define_emits;
 }
 
 oxc_macros::declare_all_lint_rules! {
     // import::no_deprecated,
-    // import::no_unused_modules,
     eslint::array_callback_return,
     eslint::constructor_super,
     eslint::default_case,
@@ -618,6 +664,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_unused_labels,
     eslint::no_unused_private_class_members,
     eslint::no_unused_vars,
+    eslint::no_useless_assignment,
     eslint::no_useless_catch,
     eslint::no_useless_concat,
     eslint::no_useless_constructor,
@@ -655,10 +702,14 @@ oxc_macros::declare_all_lint_rules! {
     import::no_default_export,
     import::no_duplicates,
     import::no_dynamic_require,
+    import::no_extraneous_dependencies,
     import::no_named_as_default,
     import::no_named_as_default_member,
     import::no_self_import,
+    import::no_unresolved,
+    import::no_unused_modules,
     import::no_webpack_loader_syntax,
+    import::order,
     import::unambiguous,
     jest::consistent_test_it,
     jest::expect_expect,
@@ -711,9 +762,11 @@ oxc_macros::declare_all_lint_rules! {
     jsdoc::check_access,
     jsdoc::check_property_names,
     jsdoc::check_tag_names,
+    jsdoc::check_types,
     jsdoc::empty_tags,
     jsdoc::implements_on_classes,
     jsdoc::no_defaults,
+    jsdoc::no_undefined_types,
     jsdoc::require_param,
     jsdoc::require_param_description,
     jsdoc::require_param_name,
@@ -726,6 +779,7 @@ oxc_macros::declare_all_lint_rules! {
     jsdoc::require_returns_description,
     jsdoc::require_returns_type,
     jsdoc::require_yields,
+    jsdoc::valid_types,
     jsx_a11y::alt_text,
     jsx_a11y::anchor_has_content,
     jsx_a11y::anchor_is_valid,
@@ -774,8 +828,13 @@ oxc_macros::declare_all_lint_rules! {
     nextjs::no_title_in_document_head,
     nextjs::no_typos,
     nextjs::no_unwanted_polyfillio,
+    node::no_deprecated_api,
     node::no_exports_assign,
     node::no_new_require,
+    node::no_process_exit,
+    node::no_sync,
+    node::no_unsupported_features_node_builtins,
+    node::prefer_node_protocol,
     oxc::approx_constant,
     oxc::bad_array_method_on_arguments,
     oxc::bad_bitwise_operator,
@@ -796,6 +855,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::no_const_enum,
     oxc::no_map_spread,
     oxc::no_optional_chaining,
+    oxc::no_redos,
     oxc::no_rest_spread_properties,
     oxc::number_arg_out_of_range,
     oxc::only_used_in_recursion,
@@ -848,6 +908,11 @@ oxc_macros::declare_all_lint_rules! {
     react_perf::jsx_no_new_array_as_prop,
     react_perf::jsx_no_new_function_as_prop,
     react_perf::jsx_no_new_object_as_prop,
+    regexp::no_dupe_characters_character_class,
+    regexp::no_super_linear_backtracking,
+    regexp::no_useless_escape,
+    regexp::optimal_quantifier_concatenation,
+    regexp::prefer_character_class,
     typescript::adjacent_overload_signatures,
     typescript::array_type,
     typescript::ban_ts_comment,
@@ -987,4 +1052,21 @@ oxc_macros::declare_all_lint_rules! {
     vitest::prefer_to_be_object,
     vitest::prefer_to_be_truthy,
     vitest::require_local_test_context_for_concurrent_snapshots,
+    testing_library::await_async_queries,
+    testing_library::no_await_sync_events,
+    testing_library::no_node_access,
+    testing_library::no_unnecessary_act,
+    testing_library::prefer_find_by,
+    testing_library::prefer_screen_queries,
+    security::detect_child_process,
+    security::detect_non_literal_fs_filename,
+    security::detect_object_injection,
+    security::detect_unsafe_regex,
+    security::no_eval_with_expression,
+    security::no_unsanitized_property,
+    vue::no_mutating_props,
+    vue::no_ref_as_operand,
+    vue::no_setup_props_reactivity_loss,
+    vue::require_default_prop,
+    vue::valid_define_emits,
 }
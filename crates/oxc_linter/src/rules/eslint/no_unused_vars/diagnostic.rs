@@ -97,6 +97,19 @@ where
         .with_help("Did you mean to use this variable?")
 }
 
+/// Variable 'x' is assigned a value that is never read.
+pub fn unread_assignment(symbol: &Symbol<'_, '_>, assign_span: Span) -> OxcDiagnostic {
+    let name = symbol.name();
+    let (pronoun, _) = pronoun_for_symbol(symbol.flags());
+
+    OxcDiagnostic::warn(format!("{pronoun} '{name}' is assigned a value that is never read."))
+        .with_labels([
+            symbol.span().label(format!("'{name}' is declared here")),
+            assign_span.label("this value is overwritten or goes out of scope before it is read"),
+        ])
+        .with_help("Consider removing this assignment.")
+}
+
 /// Parameter 'x' is declared but never used.
 pub fn param<R>(symbol: &Symbol<'_, '_>, pat: &IgnorePattern<R>) -> OxcDiagnostic
 where
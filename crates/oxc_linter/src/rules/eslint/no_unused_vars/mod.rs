@@ -14,7 +14,10 @@ use std::ops::Deref;
 use options::{IgnorePattern, NoUnusedVarsOptions};
 use oxc_ast::AstKind;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::{AstNode, ScopeFlags, SymbolFlags, SymbolId};
+use oxc_semantic::{
+    dataflow::{Definition, Liveness},
+    AstNode, ScopeFlags, SymbolFlags, SymbolId,
+};
 use oxc_span::GetSpan;
 use symbol::Symbol;
 
@@ -204,6 +207,12 @@ impl Rule for NoUnusedVars {
         Self(Box::new(NoUnusedVarsOptions::try_from(value).unwrap()))
     }
 
+    fn run_once(&self, ctx: &LintContext) {
+        if self.report_unused_assignments {
+            self.report_unread_assignments(ctx);
+        }
+    }
+
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
         let symbol = Symbol::new(ctx.semantic().as_ref(), ctx.module_record(), symbol_id);
         if Self::should_skip_symbol(&symbol) {
@@ -335,6 +344,27 @@ impl NoUnusedVars {
         };
     }
 
+    /// Report writes to used variables whose value is never read afterwards.
+    /// Unused variables are already reported by [`Rule::run_on_symbol`].
+    fn report_unread_assignments(&self, ctx: &LintContext<'_>) {
+        let Some(liveness) = Liveness::new(ctx.semantic()) else { return };
+        for (symbol_id, definition) in liveness.dead_definitions() {
+            let Definition::Write(reference_id) = definition else { continue };
+            let symbol = Symbol::new(ctx.semantic().as_ref(), ctx.module_record(), symbol_id);
+            if Self::should_skip_symbol(&symbol)
+                || self.is_ignored(&symbol)
+                || !(symbol.is_exported() || symbol.has_usages(self))
+            {
+                continue;
+            }
+            let node_id = ctx.symbols().get_reference(reference_id).node_id();
+            ctx.diagnostic(diagnostic::unread_assignment(
+                &symbol,
+                ctx.nodes().kind(node_id).span(),
+            ));
+        }
+    }
+
     fn should_skip_symbol(symbol: &Symbol<'_, '_>) -> bool {
         const AMBIENT_NAMESPACE_FLAGS: SymbolFlags =
             SymbolFlags::NameSpaceModule.union(SymbolFlags::Ambient);
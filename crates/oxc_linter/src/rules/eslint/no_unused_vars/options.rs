@@ -205,6 +205,43 @@ pub struct NoUnusedVarsOptions {
     /// console.log(firstVar, secondVar);
     /// ```
     pub report_used_ignore_pattern: bool,
+
+    /// The `reportUnusedAssignments` option is a boolean (default: `false`).
+    /// Using this option will also report assignments to used variables
+    /// whose value is never read on any path, because the variable is
+    /// overwritten or goes out of scope first. Variables referenced from
+    /// nested functions are never reported this way.
+    ///
+    /// This option is not part of the original ESLint rule.
+    ///
+    /// ## Example
+    ///
+    /// Examples of **incorrect** code for the `{ "reportUnusedAssignments": true }` option:
+    ///
+    /// ```javascript
+    /// /*eslint no-unused-vars: ["error", { "reportUnusedAssignments": true }]*/
+    ///
+    /// function foo() {
+    ///     let x = getDefault();
+    ///     x = compute();
+    ///     return x;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for the `{ "reportUnusedAssignments": true }` option:
+    ///
+    /// ```javascript
+    /// /*eslint no-unused-vars: ["error", { "reportUnusedAssignments": true }]*/
+    ///
+    /// function foo() {
+    ///     let x = getDefault();
+    ///     if (condition) {
+    ///         x = compute();
+    ///     }
+    ///     return x;
+    /// }
+    /// ```
+    pub report_unused_assignments: bool,
 }
 
 /// Represents an `Option<Regex>` with an additional `Default` variant,
@@ -315,6 +352,7 @@ impl Default for NoUnusedVarsOptions {
             destructured_array_ignore_pattern: IgnorePattern::None,
             ignore_class_with_static_init_block: false,
             report_used_ignore_pattern: false,
+            report_unused_assignments: false,
         }
     }
 }
@@ -556,6 +594,11 @@ impl TryFrom<Value> for NoUnusedVarsOptions {
                     .map_or(Some(false), Value::as_bool)
                     .unwrap_or(false);
 
+                let report_unused_assignments: bool = config
+                    .get("reportUnusedAssignments")
+                    .map_or(Some(false), Value::as_bool)
+                    .unwrap_or(false);
+
                 Ok(Self {
                     vars,
                     vars_ignore_pattern,
@@ -567,6 +610,7 @@ impl TryFrom<Value> for NoUnusedVarsOptions {
                     destructured_array_ignore_pattern,
                     ignore_class_with_static_init_block,
                     report_used_ignore_pattern,
+                    report_unused_assignments,
                 })
             }
             Value::Null => Ok(Self::default()),
@@ -596,6 +640,7 @@ mod tests {
         assert!(!rule.ignore_rest_siblings);
         assert!(!rule.ignore_class_with_static_init_block);
         assert!(!rule.report_used_ignore_pattern);
+        assert!(!rule.report_unused_assignments);
     }
 
     #[test]
@@ -619,7 +664,8 @@ mod tests {
                 "caughtErrorsIgnorePattern": "^_",
                 "destructuredArrayIgnorePattern": "^_",
                 "ignoreRestSiblings": true,
-                "reportUsedIgnorePattern": true
+                "reportUsedIgnorePattern": true,
+                "reportUnusedAssignments": true
             }
         ])
         .try_into()
@@ -635,6 +681,7 @@ mod tests {
         assert!(rule.ignore_rest_siblings);
         assert!(!rule.ignore_class_with_static_init_block);
         assert!(rule.report_used_ignore_pattern);
+        assert!(rule.report_unused_assignments);
     }
 
     #[test]
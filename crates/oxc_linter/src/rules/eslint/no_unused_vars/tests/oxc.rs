@@ -1198,6 +1198,36 @@ fn test_type_references() {
         .test_and_snapshot();
 }
 
+#[test]
+fn test_report_unused_assignments() {
+    let options = || Some(json!([{ "reportUnusedAssignments": true }]));
+    let pass = vec![
+        ("let a = 1; a = 2; console.log(a);", None),
+        ("let a = 1; if (b) { a = 2 } console.log(a);", options()),
+        ("let a; if (b) { a = 1 } else { a = 2 } console.log(a);", options()),
+        ("let i = 0; while (i < 10) { console.log(i); i++; }", options()),
+        ("let a = 1; function f() { return a } a = 2; f();", options()),
+        (
+            "let _a = 1; _a = 2; console.log(_a);",
+            Some(json!([{ "reportUnusedAssignments": true, "varsIgnorePattern": "^_" }])),
+        ),
+        ("export let a = 1; a = 2;", options()),
+    ];
+    let fail = vec![
+        ("let a = 1; console.log(a); a = 2;", options()),
+        ("let a; a = 1; a = 2; console.log(a);", options()),
+        ("function f(x) { x = 1; x = 2; return x; } f();", options()),
+        ("let a = 0; a++; console.log(a); a++;", options()),
+        // unused variables are reported once, not for each assignment
+        ("let a = 1; a = 2;", options()),
+    ];
+
+    Tester::new(NoUnusedVars::NAME, NoUnusedVars::CATEGORY, pass, fail)
+        .intentionally_allow_no_fix_tests()
+        .with_snapshot_suffix("oxc-report-unused-assignments")
+        .test_and_snapshot();
+}
+
 // #[test]
 // fn test_template() {
 //     let pass = vec![];
@@ -0,0 +1,193 @@
+use oxc_ast::{ast::VariableDeclarationKind, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{
+    dataflow::{Definition, Liveness},
+    NodeId, SymbolId,
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_useless_assignment_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This assigned value is not used in subsequent statements.")
+        .with_help(format!(
+            "Remove the assignment or use the value of '{name}' before it is overwritten."
+        ))
+        .with_label(span)
+}
+
+/// <https://eslint.org/docs/latest/rules/no-useless-assignment>
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessAssignment;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow variable assignments when the value is not used.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Wasted assignments are confusing for readers and often a sign of a bug,
+    /// such as a value that is overwritten before it is read, or a variable
+    /// that was meant to be returned.
+    ///
+    /// The rule follows the control flow graph of each function, so a value is
+    /// only reported when it is not read on any path before being overwritten
+    /// or going out of scope. Variables that are never read at all are left to
+    /// `no-unused-vars`, and variables referenced from nested functions are
+    /// never reported.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function fn1() {
+    ///     let v = 'used';
+    ///     doSomething(v);
+    ///     v = 'unused';
+    /// }
+    ///
+    /// function fn2() {
+    ///     let v = 'used';
+    ///     if (condition) {
+    ///         v = 'unused';
+    ///         return;
+    ///     }
+    ///     doSomething(v);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function fn1() {
+    ///     let v = 'used';
+    ///     doSomething(v);
+    ///     v = 'used-2';
+    ///     doSomething(v);
+    /// }
+    ///
+    /// function fn2() {
+    ///     let v = 'used';
+    ///     if (condition) {
+    ///         v = 'used-2';
+    ///     }
+    ///     doSomething(v);
+    /// }
+    /// ```
+    NoUselessAssignment,
+    nursery
+);
+
+impl Rule for NoUselessAssignment {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(liveness) = Liveness::new(ctx.semantic()) else { return };
+
+        for (symbol_id, definition) in liveness.dead_definitions() {
+            if !Self::is_checked_symbol(symbol_id, ctx) {
+                continue;
+            }
+            let node_id = match definition {
+                Definition::Declaration(node_id) => {
+                    if !Self::is_initialized_declaration(node_id, ctx) {
+                        continue;
+                    }
+                    node_id
+                }
+                Definition::Write(reference_id) => {
+                    ctx.symbols().get_reference(reference_id).node_id()
+                }
+            };
+            let node = ctx.nodes().get_node(node_id);
+            // unreachable code is reported by `no-unreachable`
+            if ctx.cfg().basic_block(node.cfg_id()).is_unreachable() {
+                continue;
+            }
+            ctx.diagnostic(no_useless_assignment_diagnostic(
+                ctx.symbols().get_name(symbol_id),
+                node.kind().span(),
+            ));
+        }
+    }
+}
+
+impl NoUselessAssignment {
+    fn is_checked_symbol(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+        // variables that are never read are reported by `no-unused-vars`
+        let is_read =
+            ctx.symbols().get_resolved_references(symbol_id).any(oxc_semantic::Reference::is_read);
+        if !is_read {
+            return false;
+        }
+        // top-level bindings of scripts are shared with other scripts
+        let scope_id = ctx.symbols().get_scope_id(symbol_id);
+        scope_id != ctx.scopes().root_scope_id() || ctx.source_type().is_module()
+    }
+
+    /// Whether a declared binding is given a value by its declarator, e.g.
+    /// `let a = 1` but not a parameter or the binding of a `for...of` loop.
+    fn is_initialized_declaration(node_id: NodeId, ctx: &LintContext) -> bool {
+        for kind in ctx.nodes().ancestor_kinds(node_id).skip(1) {
+            match kind {
+                AstKind::VariableDeclarator(declarator) => {
+                    return declarator.init.is_some()
+                        && !matches!(
+                            declarator.kind,
+                            VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing
+                        );
+                }
+                AstKind::BindingRestElement(_)
+                | AstKind::ArrayPattern(_)
+                | AstKind::ObjectPattern(_)
+                | AstKind::AssignmentPattern(_) => {}
+                _ => return false,
+            }
+        }
+        false
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "export function fn1() { let v = 'used'; doSomething(v); v = 'used-2'; doSomething(v); }",
+        "export function fn2() { let v = 'used'; if (condition) { v = 'used-2'; doSomething(v); return; } doSomething(v); }",
+        "export function fn3() { let v = 'used'; if (condition) { doSomething(v); } else { v = 'used-2'; doSomething(v); } }",
+        "export function fn4() { let v = 'used'; for (let i = 0; i < 10; i++) { doSomething(v); v = 'used in next iteration'; } }",
+        "export function fn5() { let v; if (condition) { v = 'a' } else { v = 'b' } doSomething(v); }",
+        "export function fn6() { let v = 'used'; function inner() { v = 'changed' } inner(); doSomething(v); }",
+        "export function fn7() { let v = 'used'; const g = () => v; v = 'used later'; return g; }",
+        "export function fn8() { let v = 1; try { v = 2; mayThrow(); v = 3; } catch { doSomething(v) } }",
+        "export function fn9() { let v = 'unused'; }",
+        "export function fn10(x) { x = x || 1; return x; }",
+        "export function fn11() { for (const x of xs) { doSomething(x) } }",
+        "export function fn12() { let i = 0; while (i < 10) { i++ } }",
+        "export let v = 1; v = 2;",
+        "export function fn13() { return; let v = 1; v = 2; doSomething(v); }",
+        "export function fn14() { using v = getResource(); doSomething(v); }",
+    ];
+
+    let fail = vec![
+        "export function fn1() { let v = 'used'; doSomething(v); v = 'unused'; }",
+        "export function fn2() { let v = 'used'; if (condition) { v = 'unused'; return; } doSomething(v); }",
+        "export function fn3() { let v = 'used'; if (condition) { doSomething(v); } else { v = 'unused'; } }",
+        "export function fn4() { let v = 'unused'; if (condition) { v = 'used'; doSomething(v); return; } }",
+        "export function fn5() { let v = 'used'; if (condition) { let v = 'used'; console.log(v); v = 'unused'; } console.log(v); }",
+        "export function fn6() { let v = 'unused'; v = 'used'; doSomething(v); }",
+        "export function fn7() { let { a, b } = obj; a = 1; return a + b; }",
+        "export function fn8(x) { x = 1; x = 2; return x; }",
+        "export function fn9() { let v = 0; v++; v = 1; return v; }",
+        "let v = 'unused'; v = 'used'; doSomething(v);",
+    ];
+
+    Tester::new(NoUselessAssignment::NAME, NoUselessAssignment::CATEGORY, pass, fail)
+        .test_and_snapshot();
+
+    // top-level bindings of scripts may be read by other scripts
+    let pass = vec!["var v = 1; v = 2; doSomething(v); v = 3;"];
+    Tester::new(NoUselessAssignment::NAME, NoUselessAssignment::CATEGORY, pass, vec![])
+        .change_rule_path_extension("cjs")
+        .test();
+}
@@ -14,7 +14,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::ImportBlock};
 
 fn unexpected_syntax_order_diagnostic(
     curr_kind: &ImportKind,
@@ -65,6 +65,11 @@ declare_oxc_lint!(
     /// When declaring multiple imports, a sorted list of import declarations make it easier for developers to read
     /// the code and find necessary imports later.
     ///
+    /// Unsorted members are fixed, and so are unsorted declarations unless there is a side effect
+    /// import, e.g. `import 'polyfill'`, among them, since the order in which those are evaluated
+    /// may be significant. Comments on the lines directly above a declaration, and after it on the
+    /// same line, move together with it.
+    ///
     /// ### Why is this bad?
     ///
     /// ### Example
@@ -149,33 +154,37 @@ impl Rule for SortImports {
             }
         }
 
-        let mut previous: Option<&ImportDeclaration> = None;
-
+        // ```js
+        // import b from 'foo.js'
+        //
+        // import a from 'foo.js'
+        // ```
+        // with `allowSeparatedGroups`, line 1 and line 3 are in different groups, and are
+        // sorted independently.
+        let mut groups: Vec<Vec<&ImportDeclaration>> = vec![];
         for current in import_declarations {
-            if !self.ignore_declaration_sort {
-                // ```js
-                // import b from 'foo.js'
-                //
-                // import a from 'foo.js'
-                // ```
-                // when visit line 3, line 2 will make `previous` to be `None`
-                if self.allow_separated_groups
-                    && previous.is_some_and(|previous| {
-                        get_number_of_lines_between(previous.span, current.span, ctx) > 0
-                    })
+            match groups.last_mut() {
+                Some(group)
+                    if !self.allow_separated_groups
+                        || group.last().is_some_and(|previous| {
+                            get_number_of_lines_between(previous.span, current.span, ctx) == 0
+                        }) =>
                 {
-                    previous = None;
+                    group.push(current);
                 }
+                _ => groups.push(vec![current]),
+            }
+        }
 
-                if let Some(previous) = previous {
-                    self.check_syntax_order_and_member_order(previous, current, ctx);
+        for group in &groups {
+            for (index, current) in group.iter().enumerate() {
+                if !self.ignore_declaration_sort && index > 0 {
+                    self.check_syntax_order_and_member_order(group[index - 1], current, group, ctx);
                 }
 
-                previous = Some(current);
-            }
-
-            if !self.ignore_member_sort {
-                self.check_member_sort_with_fix(current, ctx);
+                if !self.ignore_member_sort {
+                    self.check_member_sort_with_fix(current, ctx);
+                }
             }
         }
     }
@@ -200,6 +209,7 @@ impl SortImports {
         &self,
         previous: &ImportDeclaration,
         current: &ImportDeclaration,
+        group: &[&ImportDeclaration],
         ctx: &LintContext,
     ) {
         let current_member_syntax_group_index =
@@ -229,11 +239,15 @@ impl SortImports {
                 let previous_kind =
                     self.member_syntax_sort_order.get(previous_member_syntax_group_index);
                 if let Some((current_kind, previous_kind)) = current_kind.zip(previous_kind) {
-                    ctx.diagnostic(unexpected_syntax_order_diagnostic(
-                        current_kind,
-                        previous_kind,
-                        current.span,
-                    ));
+                    self.report_with_declaration_sort_fix(
+                        unexpected_syntax_order_diagnostic(
+                            current_kind,
+                            previous_kind,
+                            current.span,
+                        ),
+                        group,
+                        ctx,
+                    );
                 }
             }
             std::cmp::Ordering::Equal => {
@@ -245,7 +259,11 @@ impl SortImports {
                     current_local_member_name.zip(previous_local_member_name)
                 {
                     if current_name < previous_name {
-                        ctx.diagnostic(sort_imports_alphabetically_diagnostic(current.span));
+                        self.report_with_declaration_sort_fix(
+                            sort_imports_alphabetically_diagnostic(current.span),
+                            group,
+                            ctx,
+                        );
                     }
                 }
             }
@@ -253,6 +271,46 @@ impl SortImports {
         }
     }
 
+    // Sort the declarations of a group, moving the comments attached to them along.
+    //
+    // Not fixable if any of them is a side effect import, since the order in which those are
+    // evaluated may be significant, or if a comment is between two declarations.
+    // ```js
+    // import 'polyfill'
+    // import b from 'foo.js'
+    // ```
+    fn report_with_declaration_sort_fix(
+        &self,
+        diagnostic: OxcDiagnostic,
+        group: &[&ImportDeclaration],
+        ctx: &LintContext,
+    ) {
+        let block = ImportBlock::new(&group.iter().map(|decl| decl.span).collect::<Vec<_>>(), ctx);
+        if !block.is_fixable() || group.iter().any(|decl| get_import_kind(decl) == ImportKind::None)
+        {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        let mut order: Vec<usize> = (0..group.len()).collect();
+        order.sort_by_cached_key(|&index| {
+            let decl = group[index];
+            let name = get_first_local_member_name(decl).map(|name| {
+                if self.ignore_case {
+                    Cow::Owned(name.cow_to_lowercase().into_owned())
+                } else {
+                    name
+                }
+            });
+            (self.member_syntax_sort_order.get_group_index_by_import_decl(decl), name)
+        });
+
+        let sorted_text = block.reorder(&order, |_, _, separator| Cow::Borrowed(separator));
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            fixer.replace(block.span(), sorted_text).with_message("Sort import declarations")
+        });
+    }
+
     // Check member sort in a import declaration
     // ```js
     // import { b, a } from 'foo.js'
@@ -378,32 +436,33 @@ impl std::ops::Deref for MemberSyntaxSortOrder {
 }
 impl MemberSyntaxSortOrder {
     fn get_group_index_by_import_decl(&self, decl: &ImportDeclaration) -> usize {
-        // import "foo.js" -> ImportKind::None
-        // import * as foo from "foo.js" -> ImportKind::All
-        // import { a, b } from "foo.js" -> ImportKind::Multiple
-        // import a from "foo.js" -> ImportKind::Single
-        // import { a } from 'foo.js' -> ImportKind::Single
-        let import_kind = match &decl.specifiers {
-            Some(specifiers) => {
-                if specifiers.is_empty() {
-                    ImportKind::None
-                } else if specifiers.len() == 1 {
-                    if matches!(
-                        specifiers[0],
-                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(_)
-                    ) {
-                        ImportKind::All
-                    } else {
-                        ImportKind::Single
-                    }
+        let import_kind = get_import_kind(decl);
+        self.iter().position(|kind| kind == &import_kind).unwrap_or_default()
+    }
+}
+
+// import "foo.js" -> ImportKind::None
+// import * as foo from "foo.js" -> ImportKind::All
+// import { a, b } from "foo.js" -> ImportKind::Multiple
+// import a from "foo.js" -> ImportKind::Single
+// import { a } from 'foo.js' -> ImportKind::Single
+fn get_import_kind(decl: &ImportDeclaration) -> ImportKind {
+    match &decl.specifiers {
+        Some(specifiers) => {
+            if specifiers.is_empty() {
+                ImportKind::None
+            } else if specifiers.len() == 1 {
+                if matches!(specifiers[0], ImportDeclarationSpecifier::ImportNamespaceSpecifier(_))
+                {
+                    ImportKind::All
                 } else {
-                    ImportKind::Multiple
+                    ImportKind::Single
                 }
+            } else {
+                ImportKind::Multiple
             }
-            None => ImportKind::None,
-        };
-
-        self.iter().position(|kind| kind == &import_kind).unwrap_or_default()
+        }
+        None => ImportKind::None,
     }
 }
 
@@ -459,15 +518,8 @@ fn get_number_of_lines_between(left: Span, right: Span, ctx: &LintContext) -> us
         return 0;
     }
     let between_span = Span::new(left.end, right.start);
-    let count = ctx.source_range(between_span).lines().count();
-
-    // In same line
-    if count < 2 {
-        return 0;
-    }
-
-    // In different lines, need to subtract 2 because the count includes the first and last line.
-    count - 2
+    // Each line break after the first one ends an empty line.
+    ctx.source_range(between_span).matches('\n').count().saturating_sub(1)
 }
 
 #[test]
@@ -799,11 +851,51 @@ fn test() {
             "
               import b from 'b';
 
+              import { c, a } from 'c';",
+            "
+              import { c, a } from 'c';
+
+              import b from 'b';",
+            None,
+        ),
+        (
+            "
+              import b from 'b';
+
               import { c, a } from 'c';",
             "
               import b from 'b';
 
               import { a, c } from 'c';",
+            Some(serde_json::json!([{ "allowSeparatedGroups": true }])),
+        ),
+        ("import b from 'b';\nimport a from 'a';", "import a from 'a';\nimport b from 'b';", None),
+        (
+            "import a from 'a';\nimport * as b from 'b';\nimport {c, d} from 'c';",
+            "import * as b from 'b';\nimport {c, d} from 'c';\nimport a from 'a';",
+            None,
+        ),
+        (
+            "import B from 'b';\nimport a from 'a';",
+            "import a from 'a';\nimport B from 'b';",
+            Some(serde_json::json!([{ "ignoreCase": true }])),
+        ),
+        (
+            "// b\nimport b from 'b';\nimport a from 'a'; // a\nfoo();",
+            "import a from 'a'; // a\n// b\nimport b from 'b';\nfoo();",
+            None,
+        ),
+        (
+            "import c from 'c';\n\nimport b from 'b';\nimport a from 'a';",
+            "import c from 'c';\n\nimport a from 'a';\nimport b from 'b';",
+            Some(serde_json::json!([{ "allowSeparatedGroups": true }])),
+        ),
+        // Not fixed due to side effect import
+        ("import b from 'b';\nimport 'a';", "import b from 'b';\nimport 'a';", None),
+        // Not fixed due to comment which is not attached to either declaration
+        (
+            "import b from 'b';\n\n// comment\n\nimport a from 'a';",
+            "import b from 'b';\n\n// comment\n\nimport a from 'a';",
             None,
         ),
         // Not fixed due to comment
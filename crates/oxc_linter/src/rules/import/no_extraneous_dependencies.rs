@@ -0,0 +1,452 @@
+use std::path::{Path, PathBuf};
+
+use cow_utils::CowUtils;
+use fast_glob::glob_match;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use rustc_hash::FxHashSet;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    module_record::ModuleRecord,
+    package_json::PackageJson,
+    rule::Rule,
+    utils::{get_package_name, is_node_builtin_module},
+};
+
+fn missing_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' should be listed in the project's dependencies."))
+        .with_help(format!("Run `npm install {name}` to add it."))
+        .with_label(span)
+}
+
+fn wrong_field_diagnostic(name: &str, field: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'{name}' should be listed in the project's dependencies, not {field}."
+    ))
+    .with_help(format!(
+        "Move '{name}' to `dependencies`, or allow {field} in this file with the `{field}` option."
+    ))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraneousDependencies(Box<NoExtraneousDependenciesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraneousDependenciesConfig {
+    dev_dependencies: AllowedIn,
+    optional_dependencies: AllowedIn,
+    peer_dependencies: AllowedIn,
+    bundled_dependencies: AllowedIn,
+    /// Also check type-only imports, e.g. `import type { Foo } from "foo"`.
+    include_types: bool,
+    /// Directories of the `package.json` files to read, instead of the nearest one.
+    package_dirs: Vec<PathBuf>,
+}
+
+impl std::ops::Deref for NoExtraneousDependencies {
+    type Target = NoExtraneousDependenciesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Where the packages of a dependency field of `package.json` may be imported.
+#[derive(Debug, Default, Clone)]
+enum AllowedIn {
+    #[default]
+    AllFiles,
+    NoFiles,
+    /// Files matching any of the globs.
+    Files(Vec<CompactStr>),
+}
+
+impl AllowedIn {
+    fn from_configuration(value: Option<&Value>) -> Self {
+        match value {
+            Some(Value::Bool(false)) => Self::NoFiles,
+            Some(Value::Array(globs)) => {
+                Self::Files(globs.iter().filter_map(Value::as_str).map(CompactStr::from).collect())
+            }
+            _ => Self::AllFiles,
+        }
+    }
+
+    /// `paths` are the absolute and relative paths of the linted file.
+    fn allows(&self, paths: &[&str]) -> bool {
+        match self {
+            Self::AllFiles => true,
+            Self::NoFiles => false,
+            Self::Files(globs) => {
+                globs.iter().any(|glob| paths.iter().any(|path| glob_match(glob.as_str(), path)))
+            }
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbid the import of external packages which are not declared in the `dependencies`,
+    /// `devDependencies`, `optionalDependencies`, `peerDependencies` or `bundledDependencies`
+    /// of the nearest `package.json`.
+    ///
+    /// `import` declarations, `export ... from` declarations, `require()` calls and dynamic
+    /// `import()` calls with a string literal are checked. Node.js builtin modules, modules
+    /// listed in the `import/core-modules` setting, and imports which resolve to a file outside
+    /// of `node_modules`, e.g. through a `tsconfig.json` path alias, are ignored.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A package which is not declared in `package.json` is only available by accident, e.g.
+    /// as a dependency of another package. It can disappear or change its version on the next
+    /// install, and is missing when the project is installed by someone else. Packages in
+    /// `devDependencies` are not installed in production.
+    ///
+    /// ### Options
+    ///
+    /// - `devDependencies`, `optionalDependencies`, `peerDependencies`,
+    ///   `bundledDependencies` (default: `true`): whether packages of the field may be imported.
+    ///   `false` forbids them, and an array of globs only allows them in matching files, e.g.
+    ///   `["**/*.test.js", "**/__tests__/**"]`. Globs are matched against the absolute path of
+    ///   the file, and its paths relative to the current working directory and to the directory
+    ///   of the `package.json`.
+    /// - `includeTypes` (default: `false`): also check type-only imports. A type-only import of
+    ///   `foo` is also allowed if `@types/foo` is declared.
+    /// - `packageDir`: a directory, or an array of directories, of the `package.json` files to
+    ///   read instead of the nearest one, e.g. in a monorepo.
+    ///
+    /// ```json
+    /// {
+    ///   "import/no-extraneous-dependencies": ["error", {
+    ///     "devDependencies": ["**/*.test.ts", "scripts/**"],
+    ///     "peerDependencies": false,
+    ///     "packageDir": ["./", "./packages/app"]
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule, with `lodash` not in `package.json`:
+    /// ```js
+    /// import _ from 'lodash';
+    /// const get = require('lodash/get');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import react from 'react'; // declared in `dependencies`
+    /// import fs from 'node:fs';
+    /// import foo from './foo';
+    /// ```
+    NoExtraneousDependencies,
+    suspicious
+);
+
+impl Rule for NoExtraneousDependencies {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let package_dirs = match config.and_then(|config| config.get("packageDir")) {
+            Some(Value::String(dir)) => vec![PathBuf::from(dir)],
+            Some(Value::Array(dirs)) => {
+                dirs.iter().filter_map(Value::as_str).map(PathBuf::from).collect()
+            }
+            _ => vec![],
+        };
+
+        Self(Box::new(NoExtraneousDependenciesConfig {
+            dev_dependencies: AllowedIn::from_configuration(
+                config.and_then(|config| config.get("devDependencies")),
+            ),
+            optional_dependencies: AllowedIn::from_configuration(
+                config.and_then(|config| config.get("optionalDependencies")),
+            ),
+            peer_dependencies: AllowedIn::from_configuration(
+                config.and_then(|config| config.get("peerDependencies")),
+            ),
+            bundled_dependencies: AllowedIn::from_configuration(
+                config.and_then(|config| config.get("bundledDependencies")),
+            ),
+            include_types: config
+                .and_then(|config| config.get("includeTypes"))
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+            package_dirs,
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(package) = self.read_package_json(ctx) else {
+            return;
+        };
+
+        let file_path = ctx.file_path();
+        let cwd = std::env::current_dir().ok();
+        let paths = std::iter::once(file_path)
+            .chain(
+                cwd.iter().chain(&package.dirs).filter_map(|dir| file_path.strip_prefix(dir).ok()),
+            )
+            .filter_map(Path::to_str)
+            .collect::<Vec<_>>();
+
+        let module_record = ctx.module_record();
+        // (specifier, span, is_type)
+        let mut imports: Vec<(&str, Span, bool)> = vec![];
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            // Path aliases, e.g. `@/utils`, resolve to files outside of `node_modules`.
+            let is_local_file = module_record.resolved_modules.get(specifier).is_some_and(|path| {
+                !path.components().any(|component| component.as_os_str() == "node_modules")
+            });
+            if is_local_file {
+                continue;
+            }
+            imports.extend(requested_modules.iter().map(|requested_module| {
+                (specifier.as_str(), requested_module.span, requested_module.is_type)
+            }));
+        }
+        let dynamic_imports = ModuleRecord::collect_dynamic_imports(ctx.semantic());
+        imports
+            .extend(dynamic_imports.iter().map(|request| (request.name(), request.span(), false)));
+
+        // `requested_modules` is unordered.
+        imports.sort_unstable_by_key(|(_, span, _)| span.start);
+        for (specifier, span, is_type) in imports {
+            self.check_import(specifier, span, is_type, &package, &paths, ctx);
+        }
+    }
+}
+
+impl NoExtraneousDependencies {
+    fn check_import(
+        &self,
+        specifier: &str,
+        span: Span,
+        is_type: bool,
+        package: &PackageDependencies,
+        paths: &[&str],
+        ctx: &LintContext<'_>,
+    ) {
+        if is_type && !self.include_types {
+            return;
+        }
+        let settings = &ctx.settings().import;
+        if is_node_builtin_module(specifier) || settings.is_core_module(specifier) {
+            return;
+        }
+        let Some(name) = get_package_name(specifier) else {
+            return;
+        };
+        if settings.is_core_module(name) || package.names.contains(name) {
+            return;
+        }
+
+        // A type-only import of `foo` can be provided by `@types/foo`.
+        let types_name = is_type.then(|| types_package_name(name));
+        let is_in = |field: &FxHashSet<String>| {
+            field.contains(name) || types_name.as_ref().is_some_and(|types| field.contains(types))
+        };
+
+        let in_dev = is_in(&package.dev_dependencies);
+        let in_optional = is_in(&package.optional_dependencies);
+        if is_in(&package.dependencies)
+            || (in_dev && self.dev_dependencies.allows(paths))
+            || (in_optional && self.optional_dependencies.allows(paths))
+            || (is_in(&package.peer_dependencies) && self.peer_dependencies.allows(paths))
+            || (is_in(&package.bundled_dependencies) && self.bundled_dependencies.allows(paths))
+        {
+            return;
+        }
+
+        if in_dev {
+            ctx.diagnostic(wrong_field_diagnostic(name, "devDependencies", span));
+        } else if in_optional {
+            ctx.diagnostic(wrong_field_diagnostic(name, "optionalDependencies", span));
+        } else {
+            ctx.diagnostic(missing_diagnostic(name, span));
+        }
+    }
+
+    /// Read the `package.json` files of `packageDir`, or the nearest `package.json` of the
+    /// linted file.
+    fn read_package_json(&self, ctx: &LintContext<'_>) -> Option<PackageDependencies> {
+        let package_jsons = ctx.package_jsons();
+        let mut package = PackageDependencies::default();
+        if self.package_dirs.is_empty() {
+            package.extend(&*package_jsons.nearest(ctx.file_path())?);
+        } else {
+            let cwd = std::env::current_dir().ok()?;
+            for dir in &self.package_dirs {
+                package.extend(&*package_jsons.read(&cwd.join(dir).join("package.json"))?);
+            }
+        }
+        Some(package)
+    }
+}
+
+/// The packages declared in `package.json` files, by dependency field.
+#[derive(Debug, Default)]
+struct PackageDependencies {
+    /// The directories of the `package.json` files.
+    dirs: Vec<PathBuf>,
+    /// The `name`s of the packages themselves, which may be imported by the package.
+    names: FxHashSet<String>,
+    dependencies: FxHashSet<String>,
+    dev_dependencies: FxHashSet<String>,
+    optional_dependencies: FxHashSet<String>,
+    peer_dependencies: FxHashSet<String>,
+    bundled_dependencies: FxHashSet<String>,
+}
+
+impl PackageDependencies {
+    fn extend(&mut self, package_json: &PackageJson) {
+        self.dirs.extend(package_json.path.parent().map(Path::to_path_buf));
+
+        let package_json = &package_json.value;
+        if let Some(name) = package_json.get("name").and_then(Value::as_str) {
+            self.names.insert(name.to_string());
+        }
+        for (field, packages) in [
+            ("dependencies", &mut self.dependencies),
+            ("devDependencies", &mut self.dev_dependencies),
+            ("optionalDependencies", &mut self.optional_dependencies),
+            ("peerDependencies", &mut self.peer_dependencies),
+        ] {
+            if let Some(declared) = package_json.get(field).and_then(Value::as_object) {
+                packages.extend(declared.keys().cloned());
+            }
+        }
+        for field in ["bundledDependencies", "bundleDependencies"] {
+            if let Some(declared) = package_json.get(field).and_then(Value::as_array) {
+                self.bundled_dependencies
+                    .extend(declared.iter().filter_map(Value::as_str).map(String::from));
+            }
+        }
+    }
+}
+
+/// The DefinitelyTyped package of `name`, e.g. `@types/node` or `@types/babel__core`.
+fn types_package_name(name: &str) -> String {
+    match name.strip_prefix('@') {
+        Some(scoped) => format!("@types/{}", scoped.cow_replacen('/', "__", 1)),
+        None => format!("@types/{name}"),
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import 'lodash.cond'", None, None, None),
+        (
+            "import 'pkg-up'",
+            None,
+            Some(json!({ "settings": { "import/core-modules": ["pkg-up"] } })),
+            None,
+        ),
+        ("import fs from 'fs'", None, None, None),
+        ("import fs from 'node:fs'", None, None, None),
+        ("import foo from './foo'", None, None, None),
+        ("import foo from '../foo'", None, None, None),
+        ("import $ from 'jquery'", None, None, None),
+        ("import cond from 'lodash.cond/index.js'", None, None, None),
+        ("import { a } from '@org/package'", None, None, None),
+        ("import esm from 'esm-package/esm-module'", None, None, None),
+        ("export { a } from '@org/package'", None, None, None),
+        ("import glob from 'glob'", None, None, None),
+        ("import eslint from 'eslint'", None, None, None),
+        ("import isArray from 'lodash.isarray'", None, None, None),
+        ("import foo from '@generated/foo'", None, None, None),
+        ("var foo = require('lodash.cond')", None, None, None),
+        ("var foo = require('./does-not-exist')", None, None, None),
+        ("import('jquery')", None, None, None),
+        ("var foo = require(dynamic)", None, None, None),
+        ("function f(require) { require('not-a-dependency') }", None, None, None),
+        ("import type { Foo } from 'not-a-dependency'", None, None, None),
+        ("import type { Foo } from 'jquery'", Some(json!([{ "includeTypes": true }])), None, None),
+        (
+            "import glob from 'glob'",
+            Some(json!([{ "devDependencies": ["**/*.test.ts"] }])),
+            None,
+            Some(PathBuf::from("foo.test.ts")),
+        ),
+        (
+            "import glob from 'glob'",
+            Some(json!([{ "devDependencies": ["test/**"] }])),
+            None,
+            Some(PathBuf::from("test/foo.ts")),
+        ),
+        (
+            "import rightpad from 'right-pad'",
+            Some(json!([{ "packageDir": "fixtures/import/monorepo" }])),
+            None,
+            None,
+        ),
+        (
+            "import $ from 'jquery'",
+            Some(json!([{ "packageDir": ["fixtures/import/monorepo", "fixtures/import"] }])),
+            None,
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        ("import 'not-a-dependency'", None, None, None),
+        ("import 'not-a-dependency/foo'", None, None, None),
+        ("import '@org/not-a-dependency'", None, None, None),
+        ("var donthaveit = require('not-a-dependency')", None, None, None),
+        ("import('not-a-dependency')", None, None, None),
+        ("export { foo } from 'not-a-dependency'", None, None, None),
+        ("import 'not-installed-anywhere'", None, None, None),
+        ("import glob from 'glob'", Some(json!([{ "devDependencies": false }])), None, None),
+        (
+            "import glob from 'glob'",
+            Some(json!([{ "devDependencies": ["**/*.test.ts"] }])),
+            None,
+            Some(PathBuf::from("foo.ts")),
+        ),
+        (
+            "import isArray from 'lodash.isarray'",
+            Some(json!([{ "optionalDependencies": false }])),
+            None,
+            None,
+        ),
+        (
+            "import eslint from 'eslint'",
+            Some(json!([{ "peerDependencies": false, "devDependencies": false }])),
+            None,
+            None,
+        ),
+        (
+            "import foo from '@generated/foo'",
+            Some(json!([{ "bundledDependencies": false }])),
+            None,
+            None,
+        ),
+        (
+            "import type { Foo } from 'not-a-dependency'",
+            Some(json!([{ "includeTypes": true }])),
+            None,
+            None,
+        ),
+        (
+            "import $ from 'jquery'",
+            Some(json!([{ "packageDir": "fixtures/import/monorepo" }])),
+            None,
+            None,
+        ),
+        ("import electron from 'electron'", None, None, None),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, NoExtraneousDependencies::CATEGORY, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
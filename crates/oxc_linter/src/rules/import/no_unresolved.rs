@@ -0,0 +1,202 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use regex::Regex;
+
+use crate::{context::LintContext, rule::Rule, utils::is_node_builtin_module};
+
+fn no_unresolved_diagnostic(specifier: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unable to resolve path to module '{specifier}'."))
+        .with_help("Check that the path is correct, or install the missing package.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved(Box<NoUnresolvedConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolvedConfig {
+    /// Specifiers matching any of these patterns are not reported.
+    ignore: Vec<Regex>,
+}
+
+impl std::ops::Deref for NoUnresolved {
+    type Target = NoUnresolvedConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures that imported modules can be resolved to a file, using the same resolver as
+    /// the rest of the import plugin, including `tsconfig.json` path aliases.
+    ///
+    /// Node.js builtin modules, modules listed in the `import/core-modules` setting and
+    /// type-only imports, e.g. `import type { Foo } from "foo"`, are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An import which can not be resolved throws when the module is loaded, or fails the
+    /// build. This is usually caused by a typo in the path, a moved file, or a package which is
+    /// not installed.
+    ///
+    /// ### Options
+    ///
+    /// `ignore`: an array of regular expressions. Specifiers matching any of them are not
+    /// reported, e.g. `["\\.svg$", "^virtual:"]`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import foo from './does-not-exist';
+    /// export { bar } from 'not-installed';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import foo from './foo';
+    /// import fs from 'node:fs';
+    /// import type { Bar } from './types-only';
+    /// ```
+    NoUnresolved,
+    suspicious
+);
+
+impl Rule for NoUnresolved {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore = value
+            .get(0)
+            .and_then(|config| config.get("ignore"))
+            .and_then(serde_json::Value::as_array)
+            .map(|patterns| {
+                patterns
+                    .iter()
+                    .filter_map(serde_json::Value::as_str)
+                    .filter_map(|pattern| Regex::new(pattern).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self(Box::new(NoUnresolvedConfig { ignore }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        // Without resolution, e.g. in the language server, every specifier would be reported.
+        if !module_record.resolved {
+            return;
+        }
+        let settings = &ctx.settings().import;
+
+        let mut unresolved: Vec<(Span, &CompactStr)> = vec![];
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            if module_record.resolved_modules.contains_key(specifier)
+                || is_node_builtin_module(specifier)
+                || settings.is_core_module(specifier)
+                || self.ignore.iter().any(|pattern| pattern.is_match(specifier))
+            {
+                continue;
+            }
+            unresolved.extend(
+                requested_modules
+                    .iter()
+                    .filter(|requested_module| !requested_module.is_type)
+                    .map(|requested_module| (requested_module.span, specifier)),
+            );
+        }
+
+        // `requested_modules` is unordered.
+        unresolved.sort_unstable_by_key(|(span, _)| span.start);
+        for (span, specifier) in unresolved {
+            ctx.diagnostic(no_unresolved_diagnostic(specifier, span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import foo from './bar'", None, None),
+        ("import foo from './bar.js'", None, None),
+        ("import data from './data.json'", None, None),
+        ("import { foo } from './deep/a'", None, None),
+        ("import $ from 'jquery/dist/jquery'", None, None),
+        ("import pkg from '@org/package'", None, None),
+        ("import esm from 'esm-package'", None, None),
+        ("export { bar } from './bar'", None, None),
+        ("export * from './bar'", None, None),
+        ("import fs from 'fs'", None, None),
+        ("import { readFile } from 'fs/promises'", None, None),
+        ("import fs from 'node:fs'", None, None),
+        ("import test from 'node:test'", None, None),
+        ("import type { Foo } from './does-not-exist'", None, None),
+        ("export type { Foo } from './does-not-exist'", None, None),
+        ("var foo = require('./does-not-exist')", None, None),
+        (
+            "import electron from 'electron'",
+            None,
+            Some(json!({ "settings": { "import/core-modules": ["electron"] } })),
+        ),
+        (
+            "import foo from './does-not-exist'",
+            Some(json!([{ "ignore": ["^\\./does-not"] }])),
+            None,
+        ),
+        ("import icon from './icon.svg'", Some(json!([{ "ignore": ["\\.svg$"] }])), None),
+    ];
+
+    let fail = vec![
+        ("import foo from './does-not-exist'", None, None),
+        ("import foo from '../does-not-exist'", None, None),
+        ("import foo from 'not-installed'", None, None),
+        // `jquery` has neither `main` nor `index.js`.
+        ("import $ from 'jquery'", None, None),
+        ("import '@org/not-installed'", None, None),
+        ("export * from './does-not-exist'", None, None),
+        ("export { foo } from 'not-installed'", None, None),
+        ("import fs from 'node:does-not-exist'", None, None),
+        ("import electron from 'electron'", None, None),
+        ("import foo from './bar'; import baz from './baz'; import qux from './qux'", None, None),
+        ("import icon from './icon.svg'", Some(json!([{ "ignore": ["\\.png$"] }])), None),
+    ];
+
+    Tester::new(NoUnresolved::NAME, NoUnresolved::CATEGORY, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_unresolved_module_record() {
+    use std::{path::Path, rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::{rules::RuleEnum, AllowWarnDeny, Linter, ModuleRecord, RuleWithSeverity};
+
+    let allocator = Allocator::default();
+    let path = Path::new("index.ts");
+    let source_text = "import foo from './does-not-exist'";
+    let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+    let module_record = ModuleRecord::new(path, &ret.module_record, &semantic);
+    assert!(!module_record.resolved);
+
+    let linter = Linter::default().with_rules(vec![RuleWithSeverity::new(
+        RuleEnum::ImportNoUnresolved(NoUnresolved::default()),
+        AllowWarnDeny::Warn,
+    )]);
+    let messages = linter.run(path, Rc::new(semantic), Arc::new(module_record));
+    assert!(messages.is_empty());
+}
@@ -1,8 +1,15 @@
+use std::path::{Path, PathBuf};
+
+use fast_glob::glob_match;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{CompactStr, Span};
+use rustc_hash::FxHashSet;
+use serde_json::Value;
 
 use crate::{
-    context::{ContextHost, LintContext},
+    context::LintContext,
+    module_record::{ExportExportName, ExportImportName, ImportImportName, ModuleRecord},
     rule::Rule,
 };
 
@@ -10,11 +17,32 @@ fn no_exports_found(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("No exports found").with_label(span)
 }
 
+fn unused_export(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("exported declaration '{name}' not used within other modules"))
+        .with_help("Remove the export, or the declaration if it is not used in this module either.")
+        .with_label(span)
+}
+
 /// <https://github.com/import-js/eslint-plugin-import/blob/v2.29.1/docs/rules/no-unused-modules.md>
 #[derive(Debug, Default, Clone)]
-pub struct NoUnusedModules {
+pub struct NoUnusedModules(Box<NoUnusedModulesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedModulesConfig {
     missing_exports: bool,
     unused_exports: bool,
+    /// Only modules matching any of these globs are checked. All modules are checked when empty.
+    src: Vec<CompactStr>,
+    /// Exports of modules matching any of these globs are never reported, e.g. entry points.
+    ignore_exports: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoUnusedModules {
+    type Target = NoUnusedModulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
 }
 
 declare_oxc_lint!(
@@ -22,36 +50,79 @@ declare_oxc_lint!(
     ///
     /// Reports:
     /// * modules without any exports
-    /// * individual exports not being statically imported or requireed from other modules in the same project
-    /// * dynamic imports are supported if argument is a literal string
+    /// * individual exports not being statically imported or required from other modules in
+    ///   the same project
+    ///
+    /// An export counts as used when another linted module imports it by name, imports the
+    /// module as a namespace, re-exports it, or loads the module with a dynamic `import()` or
+    /// `require()` call with a string literal. Exports reached through `export * from` are used
+    /// when the re-exporting module's export is used.
     ///
     /// ### Why is this bad?
     ///
+    /// Exports which are not imported anywhere are dead code, which makes it harder to tell
+    /// what a module is actually used for.
+    ///
+    /// Note that only the files being linted are part of the module graph, so lint the whole
+    /// project when checking for unused exports. Entry points, whose exports are used outside
+    /// of the project, should be listed in `ignoreExports`.
+    ///
+    /// `unusedExports` is expensive: the importers of a module are only known once all modules
+    /// have been loaded, so every linted file and its dependencies are parsed and semantically
+    /// analyzed once to build the module graph, and the linted files a second time to lint
+    /// them.
+    ///
+    /// ### Options
+    ///
+    /// * `missingExports`: report modules without any exports. Defaults to `false`.
+    /// * `unusedExports`: report exports which no other module imports. Defaults to `false`.
+    /// * `src`: an array of globs. Only modules matching any of them are checked for unused
+    ///   exports. Defaults to all modules.
+    /// * `ignoreExports`: an array of globs. Exports of matching modules are never reported.
+    ///
+    /// Globs are matched against both the absolute path of a module and the path relative to
+    /// the current working directory.
+    ///
     /// ### Examples
     ///
-    /// Examples of **incorrect** code for this rule:
+    /// Given `{ "unusedExports": true }`, and a module importing `{ used }` from this one,
+    /// examples of **incorrect** code for this rule:
     /// ```javascript
+    /// export const used = 1;
+    /// export const unused = 2;
     /// ```
     ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
+    /// export const used = 1;
+    /// const unused = 2;
     /// ```
     NoUnusedModules,
     nursery
 );
 
 impl Rule for NoUnusedModules {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            missing_exports: value
-                .get("missingExports")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            unused_exports: value
-                .get("unusedExports")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0).unwrap_or(&value);
+        let globs = |key: &str| -> Vec<CompactStr> {
+            config
+                .get(key)
+                .and_then(Value::as_array)
+                .map(|globs| {
+                    globs
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(|glob| CompactStr::from(glob.trim_start_matches("./")))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Self(Box::new(NoUnusedModulesConfig {
+            missing_exports: config.get("missingExports").and_then(Value::as_bool).unwrap_or(false),
+            unused_exports: config.get("unusedExports").and_then(Value::as_bool).unwrap_or(false),
+            src: globs("src"),
+            ignore_exports: globs("ignoreExports"),
+        }))
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
@@ -59,17 +130,106 @@ impl Rule for NoUnusedModules {
         if self.missing_exports && module_record.local_export_entries.is_empty() {
             ctx.diagnostic(no_exports_found(Span::new(0, 0)));
         }
-        if self.unused_exports {
-            // TODO: implement unused exports
+        if self.unused_exports && self.should_check(ctx.file_path()) {
+            Self::check_unused_exports(module_record, ctx);
+        }
+    }
+}
+
+impl NoUnusedModules {
+    /// Unused exports can only be found when the linter loads all modules before linting them.
+    pub(crate) fn checks_unused_exports(&self) -> bool {
+        self.unused_exports
+    }
+
+    fn should_check(&self, path: &Path) -> bool {
+        let cwd = std::env::current_dir().ok();
+        let paths = std::iter::once(path)
+            .chain(cwd.as_deref().and_then(|cwd| path.strip_prefix(cwd).ok()))
+            .filter_map(Path::to_str)
+            .collect::<Vec<_>>();
+        let matches = |globs: &[CompactStr]| {
+            globs.iter().any(|glob| paths.iter().any(|path| glob_match(glob, path)))
+        };
+        (self.src.is_empty() || matches(&self.src)) && !matches(&self.ignore_exports)
+    }
+
+    fn check_unused_exports(module_record: &ModuleRecord, ctx: &LintContext<'_>) {
+        let mut exports = module_record
+            .local_export_entries
+            .iter()
+            .chain(&module_record.indirect_export_entries)
+            .filter_map(|entry| match &entry.export_name {
+                ExportExportName::Name(name) => Some((name.name(), name.span())),
+                ExportExportName::Default(span) => Some(("default", *span)),
+                ExportExportName::Null => None,
+            })
+            .collect::<Vec<_>>();
+        exports.sort_unstable_by_key(|(_, span)| *span);
+        exports.dedup();
+
+        for (name, span) in exports {
+            if !is_used(module_record, name, &mut FxHashSet::default()) {
+                ctx.diagnostic(unused_export(name, span));
+            }
         }
     }
 }
 
+/// Whether any module importing `module` uses its export `name`.
+fn is_used(module: &ModuleRecord, name: &str, visited: &mut FxHashSet<PathBuf>) -> bool {
+    if !visited.insert(module.resolved_absolute_path.clone()) {
+        return false;
+    }
+    let importers =
+        module.importers.iter().filter_map(|entry| entry.value().upgrade()).collect::<Vec<_>>();
+    importers.iter().any(|importer| {
+        let specifiers = importer
+            .resolved_modules
+            .iter()
+            .filter(|entry| *entry.value() == module.resolved_absolute_path)
+            .map(|entry| entry.key().clone())
+            .collect::<Vec<_>>();
+        specifiers.iter().any(|specifier| {
+            let requests = |request: &str| request == specifier.as_str();
+            if importer.dynamic_imports.iter().any(|import| requests(import.name())) {
+                return true;
+            }
+            let imported = importer.import_entries.iter().any(|entry| {
+                requests(entry.module_request.name())
+                    && match &entry.import_name {
+                        ImportImportName::Name(import_name) => import_name.name() == name,
+                        ImportImportName::NamespaceObject => true,
+                        ImportImportName::Default(_) => name == "default",
+                    }
+            });
+            let reexported = importer.indirect_export_entries.iter().any(|entry| {
+                entry.module_request.as_ref().is_some_and(|request| requests(request.name()))
+                    && match &entry.import_name {
+                        ExportImportName::Name(import_name) => import_name.name() == name,
+                        ExportImportName::All | ExportImportName::AllButDefault => true,
+                        ExportImportName::Null => false,
+                    }
+            });
+            // `export * from` does not re-export the default export, and the re-exported name is
+            // used if the importer's export of the same name is used.
+            let star_reexported = name != "default"
+                && importer.star_export_entries.iter().any(|entry| {
+                    entry.module_request.as_ref().is_some_and(|request| requests(request.name()))
+                });
+            imported || reexported || (star_reexported && is_used(importer, name, visited))
+        })
+    })
+}
+
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use std::path::PathBuf;
+
     use serde_json::json;
 
+    use crate::tester::Tester;
+
     let missing_exports_options = json!({
       "missingExports": true,
     });
@@ -108,22 +268,98 @@ fn test() {
         .with_import_plugin(true)
         .test_and_snapshot();
 
-    // TODO: support unused exports
-    // let unused_exports_options = json!({
-    //   "unusedExports": true,
-    //   "src": ["./no-unused-modules/**/*.js"],
-    //   "ignoreExports": ["./no-unused-modules/*ignored*.js"],
-    // });
+    let unused_exports_options = json!([{
+      "unusedExports": true,
+      "ignoreExports": ["**/no-unused-modules/file-ignored-*.js"],
+    }]);
+    let options = || Some(unused_exports_options.clone());
+    let path = |path: &str| Some(PathBuf::from("no-unused-modules").join(path));
 
-    // let pass = vec![
-    //     ("export default function noOptions() {}", None),
-    //     ("export default () => 1", Some(unused_exports_options)),
-    // ];
+    let pass = vec![
+        ("export const b = 2", options(), None, path("file-b.js")),
+        (
+            "const c1 = 3; function c2() { return 3 }; export { c1, c2 }",
+            options(),
+            None,
+            path("file-c.js"),
+        ),
+        // `o0` and `default` are re-exported, `o2` is imported
+        (
+            "const o0 = 0; const o1 = 1; export { o0, o1 as o2 }; export default () => {}",
+            options(),
+            None,
+            path("file-o.js"),
+        ),
+        // namespace import
+        (
+            "const l0 = 5; const l = 10; export { l0 as l1, l }; export default () => {}",
+            options(),
+            None,
+            path("file-l.js"),
+        ),
+        // `n0` is imported from a module which does `export * from './file-n'`
+        ("export const n0 = 'n0'", options(), None, path("file-n.js")),
+        // dynamic import
+        (
+            "export const a = 10; export const b = 20; export default 40",
+            options(),
+            None,
+            path("exports-for-dynamic-js.js"),
+        ),
+        // imported and re-exported
+        ("export const a = 5; export const b = 'b'", options(), None, path("import-export-1.js")),
+        (
+            "export { h2, h3 }; function h2() {}; const h3 = true",
+            options(),
+            None,
+            path("file-h.js"),
+        ),
+        ("export default () => 1", options(), None, path("file-ignored-a.js")),
+        ("const a = 1", options(), None, path("file-m.js")),
+        (
+            "export const b2 = 2",
+            Some(json!([{ "unusedExports": true, "src": ["**/no-unused-modules/file-a.js"] }])),
+            None,
+            path("file-b.js"),
+        ),
+    ];
 
-    // let fail = vec![];
+    let fail = vec![
+        ("export const b = 2; export const b2 = 2", options(), None, path("file-b.js")),
+        ("export default () => 1", options(), None, path("file-f.js")),
+        // `export * from` does not re-export the default export
+        (
+            "const n0 = 'n0'; const n1 = 42; export { n0, n1 }; export default () => {}",
+            options(),
+            None,
+            path("file-n.js"),
+        ),
+        (
+            "const h1 = 3; function h2() {}; const h3 = true; export { h1, h2, h3 }",
+            options(),
+            None,
+            path("file-h.js"),
+        ),
+        ("export { p } from './file-p'", options(), None, path("file-0.js")),
+        (
+            "import { o2 } from './file-o'; export default () => 1; export const extra = 1",
+            options(),
+            None,
+            path("file-a.js"),
+        ),
+    ];
 
-    // Tester::new(NoUnusedModules::NAME, NoUnusedModules::CATEGORY, pass, fail)
-    //     .change_rule_path("unused-exports.js")
-    //     .with_import_plugin(true)
-    //     .test_and_snapshot();
+    Tester::new(NoUnusedModules::NAME, NoUnusedModules::CATEGORY, pass, fail)
+        .with_import_plugin(true)
+        .with_linted_paths(&[
+            "no-unused-modules/file-0.js",
+            "no-unused-modules/file-a.js",
+            "no-unused-modules/file-p.js",
+            "no-unused-modules/file-s.js",
+            "no-unused-modules/file-star-importer.js",
+            "no-unused-modules/dynamic-import-js.js",
+            "no-unused-modules/import-export-2.js",
+        ])
+        .with_snapshot_suffix("unused_exports")
+        .test_and_snapshot();
 }
@@ -0,0 +1,865 @@
+use std::{borrow::Cow, cmp::Ordering, path::Component};
+
+use cow_utils::CowUtils;
+use fast_glob::glob_match;
+use oxc_ast::{
+    ast::{ImportDeclaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_package_name, is_node_builtin_module, ImportBlock},
+};
+
+fn out_of_order_diagnostic(specifier: &str, other: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{specifier}` import should occur before import of `{other}`"))
+        .with_label(span)
+}
+
+fn missing_empty_line_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be at least one empty line between import groups")
+        .with_label(span)
+}
+
+fn empty_line_within_group_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line within import group").with_label(span)
+}
+
+fn empty_line_between_groups_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line between import groups").with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Order(Box<OrderConfig>);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OrderConfig {
+    /// The order of the groups. Types in the same array share a rank, and types which are
+    /// not listed are ranked together after all others.
+    groups: Vec<Group>,
+    path_groups: Vec<PathGroup>,
+    /// Import types to which `pathGroups` are not applied.
+    path_groups_excluded_import_types: Vec<ImportType>,
+    #[serde(rename = "newlines-between")]
+    newlines_between: NewlinesBetween,
+    alphabetize: Alphabetize,
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        Self {
+            groups: [
+                ImportType::Builtin,
+                ImportType::External,
+                ImportType::Parent,
+                ImportType::Sibling,
+                ImportType::Index,
+            ]
+            .into_iter()
+            .map(Group::Single)
+            .collect(),
+            path_groups: vec![],
+            path_groups_excluded_import_types: vec![
+                ImportType::Builtin,
+                ImportType::External,
+                ImportType::Object,
+            ],
+            newlines_between: NewlinesBetween::default(),
+            alphabetize: Alphabetize::default(),
+        }
+    }
+}
+
+impl std::ops::Deref for Order {
+    type Target = OrderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ImportType {
+    Builtin,
+    External,
+    Internal,
+    Parent,
+    Sibling,
+    Index,
+    Object,
+    Type,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum Group {
+    Single(ImportType),
+    Multiple(Vec<ImportType>),
+}
+
+impl Group {
+    fn contains(&self, import_type: ImportType) -> bool {
+        match self {
+            Self::Single(single) => *single == import_type,
+            Self::Multiple(multiple) => multiple.contains(&import_type),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct PathGroup {
+    /// A glob matched against the module specifier.
+    pattern: String,
+    group: ImportType,
+    #[serde(default)]
+    position: Option<PathGroupPosition>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PathGroupPosition {
+    Before,
+    After,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum NewlinesBetween {
+    #[default]
+    Ignore,
+    Always,
+    AlwaysAndInsideGroups,
+    Never,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Alphabetize {
+    order: AlphabetizeOrder,
+    case_insensitive: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum AlphabetizeOrder {
+    #[default]
+    Ignore,
+    Asc,
+    Desc,
+}
+
+/// The position of an import: the index of its group, and its offset from the group caused
+/// by a path group positioned `before` (negative) or `after` (positive) it.
+type Rank = (usize, i32);
+
+struct RankedImport<'a> {
+    specifier: &'a str,
+    span: Span,
+    rank: Rank,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a convention in the order of `import` statements, by the kind of module they
+    /// import:
+    ///
+    /// - `builtin`: Node.js builtin modules, and modules in the `import/core-modules` setting,
+    ///   e.g. `import fs from "fs"`
+    /// - `external`: packages, e.g. `import React from "react"`
+    /// - `internal`: packages which resolve outside of `node_modules`, e.g. through path aliases
+    /// - `parent`: e.g. `import foo from "../foo"`
+    /// - `sibling`: e.g. `import bar from "./bar"`
+    /// - `index`: e.g. `import main from "./"`
+    /// - `type`: type-only imports, only if `type` is one of the `groups`
+    /// - `unknown`: everything else, e.g. absolute paths
+    ///
+    /// Imports without any bindings, e.g. `import "./polyfill"`, are not ordered, since the
+    /// order in which they are evaluated may be significant. Imports which are not adjacent,
+    /// i.e. separated by such an import or any other statement, are reported but not fixed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A consistent order makes it easier to see at a glance what a module depends on, and
+    /// avoids merge conflicts caused by imports added in arbitrary places.
+    ///
+    /// ### Options
+    ///
+    /// - `groups`: the order of the import types. Types grouped in a nested array share a rank,
+    ///   and types which are not listed are ranked together at the end. Defaults to
+    ///   `["builtin", "external", "parent", "sibling", "index"]`.
+    /// - `pathGroups`: an array of `{ "pattern": "~/**", "group": "internal", "position":
+    ///   "before" }`, which ranks imports matching the glob `pattern` relative to `group`.
+    ///   Without a `position`, they share the rank of `group`.
+    /// - `pathGroupsExcludedImportTypes`: import types to which `pathGroups` are not applied.
+    ///   Defaults to `["builtin", "external", "object"]`.
+    /// - `newlines-between`: `"ignore"` (default), `"always"` to require an empty line between
+    ///   groups and none within them, `"always-and-inside-groups"` to also allow empty lines
+    ///   within groups, or `"never"` to disallow empty lines.
+    /// - `alphabetize`: `{ "order": "asc" | "desc" | "ignore", "caseInsensitive": boolean }`
+    ///   sorts imports within each group by their module specifier.
+    ///
+    /// The fix moves each import together with the comments on the lines directly above it and
+    /// on the same line. It is not applied if there is a comment between two imports which is
+    /// attached to neither of them.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import foo from "./foo";
+    /// import fs from "fs";
+    /// import React from "react";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import fs from "fs";
+    /// import React from "react";
+    /// import foo from "./foo";
+    /// ```
+    Order,
+    style,
+    conditional_fix
+);
+
+impl Rule for Order {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config: OrderConfig = value
+            .get(0)
+            .map(|config| {
+                serde_json::from_value(config.clone())
+                    .expect("Invalid configuration for `import/order`")
+            })
+            .unwrap_or_default();
+
+        Self(Box::new(config))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(root) = ctx.nodes().root_node() else {
+            return;
+        };
+        let AstKind::Program(program) = root.kind() else { unreachable!() };
+
+        // Ranked imports in source order, and the runs of adjacent ones as indices into it.
+        let mut imports: Vec<RankedImport> = vec![];
+        let mut chunks: Vec<Vec<usize>> = vec![vec![]];
+        for statement in &program.body {
+            match statement {
+                Statement::ImportDeclaration(decl)
+                    if decl
+                        .specifiers
+                        .as_ref()
+                        .is_some_and(|specifiers| !specifiers.is_empty()) =>
+                {
+                    chunks.last_mut().unwrap().push(imports.len());
+                    imports.push(RankedImport {
+                        specifier: decl.source.value.as_str(),
+                        span: decl.span,
+                        rank: self.rank(decl, ctx),
+                    });
+                }
+                _ => {
+                    if !chunks.last().unwrap().is_empty() {
+                        chunks.push(vec![]);
+                    }
+                }
+            }
+        }
+        chunks.retain(|chunk| !chunk.is_empty());
+
+        for chunk in &chunks {
+            let block = ImportBlock::new(
+                &chunk.iter().map(|&index| imports[index].span).collect::<Vec<_>>(),
+                ctx,
+            );
+
+            let mut has_order_errors = false;
+            for &index in chunk {
+                let current = &imports[index];
+                let Some(previous) = imports[..index]
+                    .iter()
+                    .find(|previous| self.compare(previous, current) == Ordering::Greater)
+                else {
+                    continue;
+                };
+                has_order_errors = true;
+
+                let diagnostic =
+                    out_of_order_diagnostic(current.specifier, previous.specifier, current.span);
+                // Imports before this run can not be moved past the statements in between.
+                if block.is_fixable() && previous.span.start >= block.span().start {
+                    let text = self.sorted_text(chunk, &imports, &block);
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        fixer.replace(block.span(), text).with_message("Reorder imports")
+                    });
+                } else {
+                    ctx.diagnostic(diagnostic);
+                }
+            }
+
+            // Empty lines are fixed together with the order.
+            if !has_order_errors {
+                self.check_newlines_between(chunk, &imports, &block, ctx);
+            }
+        }
+    }
+}
+
+impl Order {
+    fn rank(&self, decl: &ImportDeclaration, ctx: &LintContext) -> Rank {
+        let specifier = decl.source.value.as_str();
+        let import_type = if decl.import_kind.is_type()
+            && self.groups.iter().any(|g| g.contains(ImportType::Type))
+        {
+            ImportType::Type
+        } else {
+            import_type(specifier, ctx)
+        };
+
+        if !self.path_groups_excluded_import_types.contains(&import_type) {
+            if let Some(index) = self
+                .path_groups
+                .iter()
+                .position(|path_group| glob_match(&path_group.pattern, specifier))
+            {
+                return self.path_group_rank(index);
+            }
+        }
+
+        (self.group_index(import_type), 0)
+    }
+
+    fn group_index(&self, import_type: ImportType) -> usize {
+        self.groups
+            .iter()
+            .position(|group| group.contains(import_type))
+            .unwrap_or(self.groups.len())
+    }
+
+    /// Path groups positioned relative to the same group are ordered as they are declared.
+    fn path_group_rank(&self, index: usize) -> Rank {
+        let path_group = &self.path_groups[index];
+        let siblings = || {
+            self.path_groups.iter().enumerate().filter(|(_, other)| {
+                other.group == path_group.group && other.position == path_group.position
+            })
+        };
+        let offset = match path_group.position {
+            None => 0,
+            Some(PathGroupPosition::Before) => {
+                -i32::try_from(siblings().filter(|(i, _)| *i >= index).count()).unwrap_or(i32::MAX)
+            }
+            Some(PathGroupPosition::After) => {
+                i32::try_from(siblings().filter(|(i, _)| *i <= index).count()).unwrap_or(i32::MAX)
+            }
+        };
+        (self.group_index(path_group.group), offset)
+    }
+
+    fn compare(&self, a: &RankedImport, b: &RankedImport) -> Ordering {
+        a.rank.cmp(&b.rank).then_with(|| self.compare_specifiers(a.specifier, b.specifier))
+    }
+
+    /// Compares module specifiers segment by segment, so that `a/b` sorts before `a-b`.
+    fn compare_specifiers(&self, a: &str, b: &str) -> Ordering {
+        let ordering = if self.alphabetize.case_insensitive {
+            a.split('/')
+                .map(CowUtils::cow_to_lowercase)
+                .cmp(b.split('/').map(CowUtils::cow_to_lowercase))
+        } else {
+            a.split('/').cmp(b.split('/'))
+        };
+        match self.alphabetize.order {
+            AlphabetizeOrder::Ignore => Ordering::Equal,
+            AlphabetizeOrder::Asc => ordering,
+            AlphabetizeOrder::Desc => ordering.reverse(),
+        }
+    }
+
+    fn sorted_text(
+        &self,
+        chunk: &[usize],
+        imports: &[RankedImport],
+        block: &ImportBlock,
+    ) -> String {
+        let mut order: Vec<usize> = (0..chunk.len()).collect();
+        order.sort_by(|&a, &b| self.compare(&imports[chunk[a]], &imports[chunk[b]]));
+
+        block.reorder(&order, |previous, next, original| {
+            let same_group = imports[chunk[previous]].rank == imports[chunk[next]].rank;
+            match self.newlines_between {
+                NewlinesBetween::Ignore => Cow::Borrowed(original),
+                NewlinesBetween::Always if same_group => Cow::Borrowed("\n"),
+                NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups if !same_group => {
+                    Cow::Borrowed("\n\n")
+                }
+                NewlinesBetween::AlwaysAndInsideGroups if original.contains('\n') => {
+                    Cow::Borrowed(original)
+                }
+                _ => Cow::Borrowed("\n"),
+            }
+        })
+    }
+
+    fn check_newlines_between(
+        &self,
+        chunk: &[usize],
+        imports: &[RankedImport],
+        block: &ImportBlock,
+        ctx: &LintContext,
+    ) {
+        if self.newlines_between == NewlinesBetween::Ignore {
+            return;
+        }
+        for (position, pair) in chunk.windows(2).enumerate() {
+            let (previous, next) = (&imports[pair[0]], &imports[pair[1]]);
+            let same_group = previous.rank == next.rank;
+            let empty_lines = block.empty_lines_after(position);
+
+            let (diagnostic, separator, message) = match self.newlines_between {
+                NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups
+                    if !same_group && empty_lines == 0 =>
+                {
+                    (missing_empty_line_diagnostic(previous.span), "\n\n", "Insert an empty line")
+                }
+                NewlinesBetween::Always if same_group && empty_lines > 0 => {
+                    (empty_line_within_group_diagnostic(previous.span), "\n", "Remove empty lines")
+                }
+                NewlinesBetween::Never if empty_lines > 0 => (
+                    empty_line_between_groups_diagnostic(previous.span),
+                    "\n",
+                    "Remove empty lines",
+                ),
+                _ => continue,
+            };
+
+            let separator_span = block.separator_span(position);
+            if ctx.semantic().has_comments_between(separator_span) {
+                ctx.diagnostic(diagnostic);
+            } else {
+                ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    fixer.replace(separator_span, separator).with_message(message)
+                });
+            }
+        }
+    }
+}
+
+fn import_type(specifier: &str, ctx: &LintContext) -> ImportType {
+    if is_node_builtin_module(specifier) || ctx.settings().import.is_core_module(specifier) {
+        return ImportType::Builtin;
+    }
+    if is_index(specifier) {
+        return ImportType::Index;
+    }
+    if specifier == ".." || specifier.starts_with("../") {
+        return ImportType::Parent;
+    }
+    if specifier.starts_with("./") {
+        return ImportType::Sibling;
+    }
+    // Aliases like `~/foo` do not look like packages.
+    if get_package_name(specifier).is_none()
+        || !specifier.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '@')
+    {
+        return ImportType::Unknown;
+    }
+    // Packages which resolve outside of `node_modules`, e.g. through a path alias, are internal.
+    match ctx.module_record().resolved_modules.get(specifier) {
+        Some(path)
+            if !path
+                .components()
+                .any(|component| component == Component::Normal("node_modules".as_ref())) =>
+        {
+            ImportType::Internal
+        }
+        _ => ImportType::External,
+    }
+}
+
+/// `.`, `./`, `./index` or `./index.js`
+fn is_index(specifier: &str) -> bool {
+    if specifier == "." {
+        return true;
+    }
+    let Some(rest) = specifier.strip_prefix("./") else {
+        return false;
+    };
+    rest.is_empty()
+        || rest.strip_prefix("index").is_some_and(|extension| {
+            extension.is_empty()
+                || extension
+                    .strip_prefix('.')
+                    .is_some_and(|extension| extension.chars().all(|c| c.is_ascii_alphabetic()))
+        })
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r#"
+                import fs from "fs";
+                import { join } from "node:path";
+                import React from "react";
+                import foo from "../foo";
+                import bar from "./bar";
+                import main from "./";
+            "#,
+            None,
+        ),
+        // Unranked types are grouped together at the end.
+        (
+            r#"
+                import bar from "./bar";
+                import abs from "/absolute";
+                import alias from "@/alias";
+            "#,
+            None,
+        ),
+        // Imports without bindings are not ordered.
+        (
+            r#"
+                import bar from "./bar";
+                import "./polyfill";
+                import {} from "./polyfill";
+                import fs from "fs";
+            "#,
+            Some(json!([{ "groups": ["sibling", "builtin"] }])),
+        ),
+        (
+            r#"
+                import bar from "./bar";
+                import React from "react";
+                import fs from "fs";
+            "#,
+            Some(json!([{ "groups": ["sibling", "external", "builtin"] }])),
+        ),
+        (
+            r#"
+                import bar from "./bar";
+                import fs from "fs";
+                import foo from "../foo";
+                import React from "react";
+            "#,
+            Some(json!([{ "groups": [["builtin", "sibling"], ["parent", "external"]] }])),
+        ),
+        (
+            r#"
+                import React from "react";
+                import type { Foo } from "fs";
+                import type { Bar } from "./bar";
+            "#,
+            Some(json!([{ "groups": ["builtin", "external", "type"] }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import type { Foo } from "./foo";
+                import React from "react";
+            "#,
+            Some(json!([{ "groups": ["builtin", "sibling", "external"] }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import React from "react";
+                import button from "~/components/button";
+                import foo from "../foo";
+            "#,
+            Some(json!([{
+                "groups": ["builtin", "external", "internal", "parent"],
+                "pathGroups": [{ "pattern": "~/**", "group": "internal" }],
+            }])),
+        ),
+        (
+            r#"
+                import React from "react";
+                import fs from "fs";
+                import lodash from "lodash";
+            "#,
+            Some(json!([{
+                "pathGroups": [{ "pattern": "react", "group": "builtin", "position": "before" }],
+                "pathGroupsExcludedImportTypes": [],
+            }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import React from "react";
+                import axios from "axios";
+            "#,
+            Some(json!([{
+                "pathGroups": [{ "pattern": "react", "group": "external", "position": "before" }],
+                "pathGroupsExcludedImportTypes": ["builtin"],
+                "alphabetize": { "order": "asc" },
+            }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+
+                import lodash from "lodash";
+                import React from "react";
+
+                import bar from "./bar";
+                import foo from "../foo";
+            "#,
+            Some(json!([{
+                "groups": ["builtin", "external", ["parent", "sibling"]],
+                "newlines-between": "always",
+                "alphabetize": { "order": "asc", "caseInsensitive": true },
+            }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+
+                import lodash from "lodash";
+
+                import React from "react";
+            "#,
+            Some(json!([{ "newlines-between": "always-and-inside-groups" }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import React from "react";
+                import bar from "./bar";
+            "#,
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r#"
+                import a from "a";
+                import B from "B";
+                import c from "c";
+            "#,
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+        (
+            r#"
+                import c from "c";
+                import b from "b";
+                import a from "a";
+            "#,
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        (
+            r#"
+                import a from "a/b";
+                import b from "a-b";
+            "#,
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r#"
+                import b from "b";
+                import a from "a";
+            "#,
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (
+            r#"
+                import axios from "axios";
+                import React from "react";
+            "#,
+            Some(json!([{
+                "pathGroups": [{ "pattern": "react", "group": "external", "position": "before" }],
+                "pathGroupsExcludedImportTypes": ["builtin"],
+            }])),
+        ),
+        (
+            r#"
+                import bar from "./bar";
+                import fs from "fs";
+            "#,
+            None,
+        ),
+        (
+            r#"
+                import foo from "../foo";
+                import React from "react";
+                import main from "./";
+                import bar from "./bar";
+            "#,
+            None,
+        ),
+        (
+            r#"
+                import bar from "./bar";
+                import "./polyfill";
+                import fs from "fs";
+            "#,
+            None,
+        ),
+        (
+            r#"
+                import bar from "./bar";
+                foo();
+                import fs from "fs";
+            "#,
+            None,
+        ),
+        (
+            r#"
+                import type { Bar } from "./bar";
+                import fs from "fs";
+            "#,
+            Some(json!([{ "groups": ["builtin", "external", "type"] }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import button from "~/components/button";
+                import React from "react";
+            "#,
+            Some(json!([{
+                "groups": ["builtin", "external", "internal"],
+                "pathGroups": [{ "pattern": "~/**", "group": "internal" }],
+            }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import React from "react";
+            "#,
+            Some(json!([{
+                "pathGroups": [{ "pattern": "react", "group": "builtin", "position": "before" }],
+                "pathGroupsExcludedImportTypes": [],
+            }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import React from "react";
+                import bar from "./bar";
+            "#,
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import path from "path";
+
+                import React from "react";
+
+                import lodash from "lodash";
+            "#,
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+
+                import React from "react";
+            "#,
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import React from "react";
+                import bar from "./bar";
+            "#,
+            Some(json!([{ "newlines-between": "always-and-inside-groups" }])),
+        ),
+        (
+            r#"
+                import c from "c";
+                import a from "a";
+                import B from "B";
+            "#,
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r#"
+                import a from "a";
+                import b from "b";
+            "#,
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        (
+            r#"
+                import bar from "./bar";
+
+                // not attached to any import
+
+                import fs from "fs";
+            "#,
+            None,
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "import bar from './bar';\nimport fs from 'fs';",
+            "import fs from 'fs';\nimport bar from './bar';",
+            None,
+        ),
+        (
+            "import bar from './bar'; // bar\n// fs\nimport fs from 'fs';\nimport foo from '../foo';\n",
+            "// fs\nimport fs from 'fs';\nimport foo from '../foo';\nimport bar from './bar'; // bar\n",
+            None,
+        ),
+        (
+            "/**\n * bar\n */\nimport bar from './bar';\n\nimport fs from 'fs';\nfoo();",
+            "import fs from 'fs';\n\n/**\n * bar\n */\nimport bar from './bar';\nfoo();",
+            None,
+        ),
+        (
+            "import bar from './bar';\nimport React from 'react';\nimport fs from 'fs';\nimport path from 'path';",
+            "import fs from 'fs';\nimport path from 'path';\n\nimport React from 'react';\n\nimport bar from './bar';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import bar from './bar';\n\nimport fs from 'fs';",
+            "import fs from 'fs';\nimport bar from './bar';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import c from 'c';\nimport a from 'a';\nimport b from 'b';",
+            "import a from 'a';\nimport b from 'b';\nimport c from 'c';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            "import fs from 'fs';\nimport React from 'react';",
+            "import fs from 'fs';\n\nimport React from 'react';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport path from 'path';",
+            "import fs from 'fs';\nimport path from 'path';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\n\nimport React from 'react';",
+            "import fs from 'fs';\nimport React from 'react';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+    ];
+
+    Tester::new(Order::NAME, Order::CATEGORY, pass, fail)
+        .expect_fix(fix)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
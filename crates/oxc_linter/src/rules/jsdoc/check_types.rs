@@ -0,0 +1,418 @@
+use std::borrow::Cow;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::JSDocType;
+use oxc_span::{GetSpan, Span};
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_tag_type, should_ignore_as_internal, should_ignore_as_private},
+};
+
+fn check_types_diagnostic(span: Span, x1: &str, x2: &str, x3: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Invalid JSDoc @{x1} type \"{x2}\"."))
+        .with_help(x3.to_string())
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckTypes(Box<CheckTypesConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports types which are not preferred, and fixes them to the preferred ones.
+    ///
+    /// By default, native types must be written in their canonical case,
+    /// e.g. `string` instead of `String`, `Array` instead of `array`.
+    /// `Object` is preferred as `object`, except as the base of a generic type
+    /// like `Object<string, number>`.
+    ///
+    /// The `preferredTypes` setting can block or replace any other type names,
+    /// and overrides these defaults.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Types like `String` mean the wrapper object rather than the primitive,
+    /// which is rarely intended.
+    /// Consistent type names also make the documentation easier to read.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /** @param {String} foo */
+    /// function quux (foo) {}
+    ///
+    /// /** @returns {Array<Number>} */
+    /// function quux () {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /** @param {string} foo */
+    /// function quux (foo) {}
+    ///
+    /// /** @returns {Array<number>} */
+    /// function quux () {}
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "jsdoc/check-types": ["error", {
+    ///     "noDefaults": false,
+    ///     "exemptTagContexts": [{ "tag": "typedef", "types": ["Object"] }]
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// - `noDefaults`: Only check the types of the `preferredTypes` setting,
+    ///   without the defaults above.
+    /// - `exemptTagContexts`: Tags which are not checked, for all types with `"types": true`,
+    ///   or for the listed types.
+    CheckTypes,
+    pedantic,
+    fix
+);
+
+#[derive(Debug, Default, Clone, Deserialize)]
+struct CheckTypesConfig {
+    #[serde(default, rename = "noDefaults")]
+    no_defaults: bool,
+    #[serde(default, rename = "exemptTagContexts")]
+    exempt_tag_contexts: Vec<ExemptTagContext>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ExemptTagContext {
+    tag: String,
+    types: ExemptTypes,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum ExemptTypes {
+    All(bool),
+    Some(Vec<String>),
+}
+
+/// Native types which should be written in this case, e.g. `string` instead of `String`.
+// https://github.com/gajus/eslint-plugin-jsdoc/blob/v50.5.0/src/rules/checkTypes.js
+const STRICT_NATIVE_TYPES: [&str; 11] = [
+    "undefined",
+    "null",
+    "boolean",
+    "number",
+    "bigint",
+    "string",
+    "symbol",
+    "object",
+    "Array",
+    "Date",
+    "RegExp",
+];
+
+impl Rule for CheckTypes {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let settings = &ctx.settings().jsdoc;
+
+        for jsdoc in ctx
+            .semantic()
+            .jsdoc()
+            .iter_all()
+            .filter(|jsdoc| !should_ignore_as_internal(jsdoc, settings))
+            .filter(|jsdoc| !should_ignore_as_private(jsdoc, settings))
+        {
+            for tag in jsdoc.tags() {
+                let tag_name = tag.kind.parsed();
+                let Some(Ok(ty)) =
+                    get_tag_type(tag, settings).map(|type_part| type_part.parse_type())
+                else {
+                    continue;
+                };
+
+                let mut generic_base_spans = vec![];
+                ty.walk(&mut |ty| {
+                    let name = match ty {
+                        JSDocType::Generic(generic) => {
+                            generic_base_spans.push(generic.base.span());
+                            return;
+                        }
+                        JSDocType::Name(name) => name,
+                        _ => return,
+                    };
+                    if self.is_exempted(tag_name, name.name) {
+                        return;
+                    }
+
+                    if let Some(reason) = settings.check_blocked_type_name(name.name) {
+                        ctx.diagnostic(check_types_diagnostic(
+                            name.span, tag_name, name.name, &reason,
+                        ));
+                        return;
+                    }
+
+                    let (reason, replacement) = match settings.check_preferred_type_name(name.name)
+                    {
+                        Some(preferred) => preferred,
+                        None if self.0.no_defaults || settings.has_type_preference(name.name) => {
+                            return;
+                        }
+                        None => {
+                            let is_generic_base = generic_base_spans.contains(&name.span);
+                            let Some(replacement) =
+                                default_preferred_type(name.name, is_generic_base)
+                            else {
+                                return;
+                            };
+                            let reason = Cow::Owned(format!(
+                                "Replace type `{}` with `{replacement}`.",
+                                name.name
+                            ));
+                            (reason, replacement)
+                        }
+                    };
+                    ctx.diagnostic_with_fix(
+                        check_types_diagnostic(name.span, tag_name, name.name, &reason),
+                        |fixer| fixer.replace(name.span, replacement.to_string()),
+                    );
+                });
+            }
+        }
+    }
+}
+
+impl CheckTypes {
+    fn is_exempted(&self, tag_name: &str, type_name: &str) -> bool {
+        self.0.exempt_tag_contexts.iter().any(|context| {
+            context.tag == tag_name
+                && match &context.types {
+                    ExemptTypes::All(all) => *all,
+                    ExemptTypes::Some(types) => types.iter().any(|ty| ty == type_name),
+                }
+        })
+    }
+}
+
+fn default_preferred_type(type_name: &str, is_generic_base: bool) -> Option<&'static str> {
+    if type_name == "Object" {
+        // `Object<string, number>` is a map type, which `object` can not be
+        return if is_generic_base { None } else { Some("object") };
+    }
+    STRICT_NATIVE_TYPES.iter().copied().find(|native_type| {
+        *native_type != type_name && native_type.eq_ignore_ascii_case(type_name)
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+			      /**
+			       * @param {string} foo
+			       * @param {number|boolean|bigint|symbol|null|undefined} bar
+			       * @returns {object}
+			       */
+			      function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {Array<string>} foo
+			       * @param {Object<string, number>} bar
+			       * @param {Object.<string, Date>} baz
+			       * @returns {RegExp}
+			       */
+			      function quux (foo, bar, baz) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param foo - A String
+			       * @see {String}
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {String} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            Some(serde_json::json!([{ "noDefaults": true }])),
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @typedef {Object} Foo
+			       * @param {Object} foo
+			       */
+			      ",
+            Some(serde_json::json!([{ "exemptTagContexts": [
+                { "tag": "typedef", "types": true },
+                { "tag": "param", "types": ["Object"] },
+            ] }])),
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {Object} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            Some(serde_json::json!({
+              "settings": { "jsdoc": {
+                "preferredTypes": {
+                  "Object": "Object",
+                },
+              } }
+            })),
+        ),
+        (
+            "
+			      /**
+			       * @param {String} foo
+			       * @private
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            Some(serde_json::json!({
+              "settings": { "jsdoc": {
+                "ignorePrivate": true,
+              } }
+            })),
+        ),
+        (
+            "
+			      /**
+			       * @param {Array<} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "
+			      /**
+			       * @param {String} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {Boolean|Number} foo
+			       * @returns {Array<Symbol>}
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @type {Object}
+			       * @arg {array} foo
+			       * @returns {function(Date, regexp): BigInt}
+			       */
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {Object<string, Object>} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {abc} foo
+			       * @param {def} bar
+			       * @param {ghi} baz
+			       * @param {String} qux
+			       */
+			      function quux (foo, bar, baz, qux) {}
+			      ",
+            Some(serde_json::json!([{ "noDefaults": true }])),
+            Some(serde_json::json!({
+              "settings": { "jsdoc": {
+                "preferredTypes": {
+                  "abc": "Abc",
+                  "def": false,
+                  "ghi": { "message": "Use `Ghi` for now", "replacement": "Ghi" },
+                },
+              } }
+            })),
+        ),
+        (
+            "
+			      /**
+			       * @typedef {Object} Foo
+			       * @param {Object} foo
+			       */
+			      ",
+            Some(serde_json::json!([{ "exemptTagContexts": [
+                { "tag": "param", "types": ["Array"] },
+            ] }])),
+            None,
+        ),
+    ];
+
+    let fix = vec![
+        ("/** @param {String} foo */", "/** @param {string} foo */", None),
+        (
+            "/** @returns {Array<Boolean|Number>|Object} */",
+            "/** @returns {Array<boolean|number>|object} */",
+            None,
+        ),
+        ("/** @type {function(DATE): REGEXP} */", "/** @type {function(Date): RegExp} */", None),
+    ];
+
+    Tester::new(CheckTypes::NAME, CheckTypes::CATEGORY, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
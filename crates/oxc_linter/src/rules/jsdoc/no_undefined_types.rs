@@ -0,0 +1,515 @@
+use std::borrow::Cow;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{JSDoc, JSDocType};
+use oxc_span::Span;
+use phf::phf_set;
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use crate::{
+    config::JSDocPluginSettings,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_tag_type, should_ignore_as_internal, should_ignore_as_private},
+};
+
+fn no_undefined_types_diagnostic(span: Span, x1: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("The type '{x1}' is undefined."))
+        .with_help("Declare or import the type, or add it to the `definedTypes` option.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUndefinedTypes(Box<NoUndefinedTypesConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Checks that types in JSDoc comments are defined.
+    ///
+    /// A type is defined when it is
+    /// - a builtin type of JSDoc or TypeScript, e.g. `string`, `object` or `Record`
+    /// - a global variable of the enabled environments or `globals`
+    /// - declared in the file, e.g. by a class, an import or a variable
+    /// - declared by `@typedef`, `@callback` or `@template` in the file
+    /// - a replacement of the `preferredTypes` setting
+    /// - listed in the `definedTypes` option
+    ///
+    /// Only the first name of a name path is checked, e.g. `foo` of `foo.Bar`.
+    /// Name paths of modules, e.g. `module:foo/bar`, and types of `import()` are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Undefined types are usually typos or missing imports.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /** @type {strnig} */
+    /// let foo;
+    ///
+    /// /** @param {HerType} bar */
+    /// function quux (bar) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /** @type {string} */
+    /// let foo;
+    ///
+    /// /** @typedef {object} HerType */
+    /// /** @param {HerType} bar */
+    /// function quux (bar) {}
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "jsdoc/no-undefined-types": ["error", { "definedTypes": ["MyType"] }]
+    /// }
+    /// ```
+    ///
+    /// `definedTypes` is a list of types which are defined elsewhere.
+    NoUndefinedTypes,
+    suspicious
+);
+
+#[derive(Debug, Default, Clone, Deserialize)]
+struct NoUndefinedTypesConfig {
+    #[serde(default, rename = "definedTypes")]
+    defined_types: Vec<String>,
+}
+
+/// Types which are always defined, in addition to the builtin globals of JavaScript.
+const BUILTIN_TYPES: phf::Set<&'static str> = phf_set! {
+    // JSDoc and TypeScript
+    "any",
+    "bigint",
+    "boolean",
+    "const",
+    "false",
+    "function",
+    "never",
+    "null",
+    "number",
+    "object",
+    "string",
+    "symbol",
+    "this",
+    "true",
+    "undefined",
+    "unknown",
+    "void",
+    // TypeScript utility types
+    "ArrayLike",
+    "AsyncGenerator",
+    "AsyncIterable",
+    "AsyncIterableIterator",
+    "AsyncIterator",
+    "Awaited",
+    "Capitalize",
+    "ConstructorParameters",
+    "Exclude",
+    "Extract",
+    "Generator",
+    "InstanceType",
+    "Iterable",
+    "IterableIterator",
+    "Iterator",
+    "Lowercase",
+    "NonNullable",
+    "Omit",
+    "OmitThisParameter",
+    "Parameters",
+    "Partial",
+    "Pick",
+    "PromiseLike",
+    "PropertyKey",
+    "Readonly",
+    "ReadonlyArray",
+    "Record",
+    "Required",
+    "ReturnType",
+    "ThisParameterType",
+    "ThisType",
+    "Uncapitalize",
+    "Uppercase",
+};
+
+impl Rule for NoUndefinedTypes {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let settings = &ctx.settings().jsdoc;
+
+        let mut defined_types = ctx
+            .symbols()
+            .names
+            .iter()
+            .map(|name| Cow::Borrowed(name.as_str()))
+            .collect::<FxHashSet<_>>();
+        defined_types.extend(self.0.defined_types.iter().map(|name| Cow::Borrowed(name.as_str())));
+        defined_types.extend(settings.list_preferred_type_names().into_iter().map(Cow::Borrowed));
+        for jsdoc in ctx.semantic().jsdoc().iter_all() {
+            collect_jsdoc_defined_types(jsdoc, settings, &mut defined_types);
+        }
+        let is_defined = |name: &str| {
+            BUILTIN_TYPES.contains(name)
+                || defined_types.contains(name)
+                || ctx.env_contains_var(name)
+                || ctx.globals().is_enabled(name)
+        };
+
+        for jsdoc in ctx
+            .semantic()
+            .jsdoc()
+            .iter_all()
+            .filter(|jsdoc| !should_ignore_as_internal(jsdoc, settings))
+            .filter(|jsdoc| !should_ignore_as_private(jsdoc, settings))
+        {
+            for tag in jsdoc.tags() {
+                let Some(Ok(ty)) =
+                    get_tag_type(tag, settings).map(|type_part| type_part.parse_type())
+                else {
+                    continue;
+                };
+
+                ty.walk(&mut |ty| {
+                    let JSDocType::Name(name) = ty else {
+                        return;
+                    };
+                    let Some(root) = name.root() else {
+                        return;
+                    };
+                    if !is_defined(root.name) {
+                        ctx.diagnostic(no_undefined_types_diagnostic(root.span, root.name));
+                    }
+                });
+            }
+        }
+    }
+}
+
+/// Collect the names of types declared in JSDoc, e.g. `Foo` of `@typedef {object} Foo`.
+fn collect_jsdoc_defined_types<'a: 's, 's>(
+    jsdoc: &JSDoc<'a>,
+    settings: &JSDocPluginSettings,
+    defined_types: &mut FxHashSet<Cow<'s, str>>,
+) {
+    let resolved_typedef_tag_name = settings.resolve_tag_name("typedef");
+    let resolved_callback_tag_name = settings.resolve_tag_name("callback");
+    let resolved_template_tag_name = settings.resolve_tag_name("template");
+
+    for tag in jsdoc.tags() {
+        let tag_name = tag.kind.parsed();
+        if tag_name == resolved_typedef_tag_name || tag_name == resolved_callback_tag_name {
+            if let (_, Some(name_part), _) = tag.type_name_comment() {
+                defined_types.insert(Cow::Borrowed(name_part.parsed()));
+            }
+        } else if tag_name == resolved_template_tag_name {
+            let body = tag.comment().parsed();
+            // Skip the constraint, e.g. `{string}` of `@template {string} K`
+            let names = match tag.r#type() {
+                Some(type_part) if body.starts_with('{') => body
+                    .split_once(type_part.parsed())
+                    .map_or("", |(_, rest)| rest.trim_start().strip_prefix('}').unwrap_or(rest)),
+                _ => &body,
+            };
+            defined_types
+                .extend(parse_template_names(names).map(|name| Cow::Owned(name.to_string())));
+        }
+    }
+}
+
+/// Names of `@template`, e.g. `T` and `U` of `T, U description` or `K` of `[K=string]`.
+fn parse_template_names(names: &str) -> impl Iterator<Item = &str> {
+    let mut has_description = false;
+    names.split(',').map_while(move |segment| {
+        if has_description {
+            return None;
+        }
+        let mut words = segment.split_whitespace();
+        let name = words.next()?;
+        // The description follows the last name
+        has_description = words.next().is_some();
+        let name = name.trim_start_matches('[').trim_end_matches(']');
+        Some(name.split_once('=').map_or(name, |(name, _)| name))
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+			      /**
+			       * @param {string} foo
+			       * @param {number|boolean|null|undefined} bar
+			       * @returns {void}
+			       */
+			      function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {Array<string>} foo
+			       * @param {Promise<Map<string, Set<number>>>} bar
+			       * @returns {Record<string, Partial<object>>}
+			       */
+			      function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @type {*|?|any|unknown|never|'foo'|1}
+			       */
+			      let foo;
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      class Foo {}
+			      /**
+			       * @param {Foo} foo
+			       * @param {Foo#bar} bar
+			       * @param {Foo.Baz} baz
+			       */
+			      function quux (foo, bar, baz) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      import { Foo } from './foo';
+			      import * as bar from './bar';
+			      /**
+			       * @param {Foo} foo
+			       * @param {bar.Baz} baz
+			       */
+			      function quux (foo, baz) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @typedef {object} HerType
+			       * @property {string} name
+			       */
+			      /**
+			       * @callback HerCallback
+			       * @param {HerType} her
+			       */
+			      /**
+			       * @param {HerType} foo
+			       * @param {HerCallback} bar
+			       */
+			      function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @template T, U Description
+			       * @template {string} [K=string]
+			       * @param {T} foo
+			       * @param {U} bar
+			       * @returns {K}
+			       */
+			      function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {module:foo/bar~Baz} foo
+			       * @param {import('./foo').Bar} bar
+			       * @param {(a: string) => void} baz
+			       * @param {{ name: string }} qux
+			       */
+			      function quux (foo, bar, baz, qux) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {MyType} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            Some(serde_json::json!([{ "definedTypes": ["MyType"] }])),
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {MyType} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            Some(serde_json::json!({
+              "settings": { "jsdoc": {
+                "preferredTypes": {
+                  "object": "MyType",
+                },
+              } }
+            })),
+        ),
+        (
+            "
+			      /**
+			       * @param {HTMLElement} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            Some(serde_json::json!({ "env": { "browser": true } })),
+        ),
+        (
+            "
+			      /**
+			       * @param {MyGlobal} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            Some(serde_json::json!({ "globals": { "MyGlobal": "readonly" } })),
+        ),
+        (
+            "
+			      /**
+			       * @param {Foo} foo
+			       * @private
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            Some(serde_json::json!({
+              "settings": { "jsdoc": {
+                "ignorePrivate": true,
+              } }
+            })),
+        ),
+        (
+            "
+			      /**
+			       * @param {Array<} foo
+			       * @see {Foo}
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "
+			      /**
+			       * @type {strnig}
+			       */
+			      let foo;
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {HerType} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {Array<Foo>|Bar.Baz} foo
+			       * @returns {function(Qux): Quux}
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {{ name: Foo }} foo
+			       * @param {(a: Bar) => void} bar
+			       */
+			      function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @template T
+			       */
+			      function quux () {}
+			      /**
+			       * @param {U} foo
+			       */
+			      function corge (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {HTMLElement} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {MyType} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            Some(serde_json::json!([{ "definedTypes": ["OtherType"] }])),
+            None,
+        ),
+    ];
+
+    Tester::new(NoUndefinedTypes::NAME, NoUndefinedTypes::CATEGORY, pass, fail).test_and_snapshot();
+}
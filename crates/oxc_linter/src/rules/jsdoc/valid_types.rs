@@ -0,0 +1,268 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_tag_type, should_ignore_as_internal, should_ignore_as_private},
+};
+
+fn valid_types_diagnostic(span: Span, x1: &str, x2: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Syntax error in type: {x1}"))
+        .with_help(x2.to_string())
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ValidTypes;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires all types to be valid JSDoc, Closure Compiler or TypeScript type expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Invalid types can not be understood by tools like TypeScript or documentation generators,
+    /// and are usually typos.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /** @param {Array<string} foo */
+    /// function quux (foo) {}
+    ///
+    /// /** @returns {string|} */
+    /// function quux () {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /** @param {Array<string>} foo */
+    /// function quux (foo) {}
+    ///
+    /// /** @returns {?string} */
+    /// function quux () {}
+    /// ```
+    ValidTypes,
+    correctness
+);
+
+impl Rule for ValidTypes {
+    fn run_once(&self, ctx: &LintContext) {
+        let settings = &ctx.settings().jsdoc;
+
+        for jsdoc in ctx
+            .semantic()
+            .jsdoc()
+            .iter_all()
+            .filter(|jsdoc| !should_ignore_as_internal(jsdoc, settings))
+            .filter(|jsdoc| !should_ignore_as_private(jsdoc, settings))
+        {
+            for tag in jsdoc.tags() {
+                let Some(type_part) = get_tag_type(tag, settings) else {
+                    continue;
+                };
+                let Err(error) = type_part.parse_type() else {
+                    continue;
+                };
+
+                // Errors at the end of the type have no span to point
+                let span = if error.span.is_empty() { type_part.span } else { error.span };
+                ctx.diagnostic(valid_types_diagnostic(span, type_part.parsed(), &error.message));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+			      /**
+			       * @param {Array<string>} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {Array.<string>} foo
+			       * @param {?Object} [bar]
+			       * @param {...number} baz
+			       * @returns {string|number|null}
+			       */
+			      function quux (foo, bar, ...baz) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {function(this:Foo, string, number=): boolean} callback
+			       * @param {(a: string, b?: number) => void} listener
+			       */
+			      function quux (callback, listener) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @typedef {{ a: string, b?: number, [key: string]: any }} Foo
+			       * @type {[string, number]}
+			       */
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @type {import('./foo').Bar<typeof baz>}
+			       * @returns {module:foo/bar~Baz}
+			       */
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {string
+			       *   | number} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param foo
+			       * @see {@link foo<}
+			       * @example {Array<}
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param foo - Like {a: }
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @arg {Array<} foo
+			       * @private
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            Some(serde_json::json!({
+              "settings": { "jsdoc": {
+                "ignorePrivate": true,
+              } }
+            })),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "
+			      /**
+			       * @param {Array<string} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @returns {string|}
+			       */
+			      function quux () {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @type {string number}
+			       */
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @param {} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @typedef {{ a: }} Foo
+			       */
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @arg {function(string} foo
+			       * @throws {#Error}
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			      /**
+			       * @baz {Array<} foo
+			       */
+			      function quux (foo) {}
+			      ",
+            None,
+            Some(serde_json::json!({
+              "settings": { "jsdoc": {
+                "tagNamePreference": {
+                  "param": "baz",
+                },
+              } }
+            })),
+        ),
+    ];
+
+    Tester::new(ValidTypes::NAME, ValidTypes::CATEGORY, pass, fail).test_and_snapshot();
+}
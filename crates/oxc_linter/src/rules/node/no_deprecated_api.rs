@@ -0,0 +1,255 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{collect_node_api_references, NodeApiReference, NodeApiUsage},
+};
+
+fn no_deprecated_api_diagnostic(name: &str, api: &DeprecatedApi, span: Span) -> OxcDiagnostic {
+    let message = match api.replacement {
+        Some(replacement) => {
+            format!("{name} was deprecated since v{}. Use {replacement} instead.", api.since)
+        }
+        None => format!("{name} was deprecated since v{}.", api.since),
+    };
+    OxcDiagnostic::warn(message).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDeprecatedApi;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow deprecated APIs of Node.js, e.g. `new Buffer()`, `fs.exists()` or the `domain`
+    /// module.
+    ///
+    /// APIs are found through `import` declarations, `require()` calls with or without the
+    /// `node:` protocol, the variables they are assigned to, and the global variables
+    /// `Buffer`, `process` and `require`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deprecated APIs may be removed in a future version of Node.js, and some of them are
+    /// deprecated because they are unsafe, e.g. `new Buffer(size)` which returns uninitialized
+    /// memory in old versions of Node.js.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const fs = require('fs');
+    /// const buffer = new Buffer(10);
+    /// fs.exists('./file.txt', callback);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const fs = require('fs');
+    /// const buffer = Buffer.alloc(10);
+    /// fs.access('./file.txt', callback);
+    /// ```
+    NoDeprecatedApi,
+    suspicious
+);
+
+/// How a deprecated API is used to be reported.
+#[derive(Debug, Clone, Copy)]
+enum DeprecatedKind {
+    /// The module is imported or required.
+    Module,
+    /// The member is read.
+    Member,
+    /// The member is called or constructed, e.g. `Buffer()`, but not `Buffer.from()`.
+    Call,
+}
+
+#[derive(Debug)]
+struct DeprecatedApi {
+    path: &'static str,
+    kind: DeprecatedKind,
+    since: &'static str,
+    replacement: Option<&'static str>,
+}
+
+const fn module(
+    path: &'static str,
+    since: &'static str,
+    replacement: Option<&'static str>,
+) -> DeprecatedApi {
+    DeprecatedApi { path, kind: DeprecatedKind::Module, since, replacement }
+}
+
+const fn member(
+    path: &'static str,
+    since: &'static str,
+    replacement: Option<&'static str>,
+) -> DeprecatedApi {
+    DeprecatedApi { path, kind: DeprecatedKind::Member, since, replacement }
+}
+
+/// Global variables which refer to builtin modules or deprecated APIs.
+const GLOBALS: [(&str, &str); 5] = [
+    ("Buffer", "buffer.Buffer"),
+    ("GLOBAL", "GLOBAL"),
+    ("process", "process"),
+    ("require", "require"),
+    ("root", "root"),
+];
+
+#[rustfmt::skip]
+const DEPRECATED_APIS: &[DeprecatedApi] = &[
+    module("_linklist", "5.0.0", None),
+    module("_stream_wrap", "12.0.0", None),
+    module("constants", "6.3.0", Some("the 'constants' property of each module")),
+    module("domain", "4.0.0", None),
+    module("punycode", "7.0.0", Some("'https://www.npmjs.com/package/punycode'")),
+    module("sys", "1.0.0", Some("the 'util' module")),
+    DeprecatedApi {
+        path: "buffer.Buffer",
+        kind: DeprecatedKind::Call,
+        since: "6.0.0",
+        replacement: Some("'Buffer.alloc()' or 'Buffer.from()'"),
+    },
+    member("buffer.SlowBuffer", "6.0.0", Some("'Buffer.allocUnsafeSlow()'")),
+    member("crypto.Credentials", "0.12.0", Some("'tls.SecureContext'")),
+    member("crypto.DEFAULT_ENCODING", "10.0.0", None),
+    member("crypto.createCipher", "10.0.0", Some("'crypto.createCipheriv()'")),
+    member("crypto.createCredentials", "0.12.0", Some("'tls.createSecureContext()'")),
+    member("crypto.createDecipher", "10.0.0", Some("'crypto.createDecipheriv()'")),
+    member("crypto.fips", "10.0.0", Some("'crypto.getFips()' and 'crypto.setFips()'")),
+    member("crypto.prng", "11.0.0", Some("'crypto.randomBytes()'")),
+    member("crypto.pseudoRandomBytes", "11.0.0", Some("'crypto.randomBytes()'")),
+    member("crypto.rng", "11.0.0", Some("'crypto.randomBytes()'")),
+    member("events.EventEmitter.listenerCount", "3.2.0", Some("'emitter.listenerCount()'")),
+    member("events.listenerCount", "3.2.0", Some("'emitter.listenerCount()'")),
+    member("fs.SyncWriteStream", "8.0.0", None),
+    member("fs.exists", "4.0.0", Some("'fs.stat()' or 'fs.access()'")),
+    member("fs.lchmod", "0.4.0", None),
+    member("fs.lchmodSync", "0.4.0", None),
+    member("module.createRequireFromPath", "12.2.0", Some("'module.createRequire()'")),
+    member("os.getNetworkInterfaces", "0.6.0", Some("'os.networkInterfaces()'")),
+    member("os.tmpDir", "7.0.0", Some("'os.tmpdir()'")),
+    member("path._makeLong", "9.0.0", Some("'path.toNamespacedPath()'")),
+    member("process.EventEmitter", "0.6.0", Some("the 'events' module")),
+    member("process.assert", "10.0.0", Some("the 'assert' module")),
+    member("process.binding", "10.12.0", None),
+    member("tls.CryptoStream", "0.11.3", Some("'tls.TLSSocket'")),
+    member("tls.SecurePair", "6.0.0", Some("'tls.TLSSocket'")),
+    member("tls.createSecurePair", "6.0.0", Some("'tls.TLSSocket'")),
+    member("tls.parseCertString", "8.6.0", Some("'querystring.parse()'")),
+    member("url.parse", "11.0.0", Some("the 'url.URL' constructor")),
+    member("url.resolve", "11.0.0", Some("the 'url.URL' constructor")),
+    member("util._extend", "6.0.0", Some("'Object.assign()'")),
+    member("util.debug", "0.12.0", Some("'console.error()'")),
+    member("util.error", "0.12.0", Some("'console.error()'")),
+    member("util.isArray", "4.0.0", Some("'Array.isArray()'")),
+    member("util.isBoolean", "4.0.0", Some("'typeof value === \"boolean\"'")),
+    member("util.isBuffer", "4.0.0", Some("'Buffer.isBuffer()'")),
+    member("util.isDate", "4.0.0", Some("'value instanceof Date'")),
+    member("util.isError", "4.0.0", Some("'value instanceof Error'")),
+    member("util.isFunction", "4.0.0", Some("'typeof value === \"function\"'")),
+    member("util.isNull", "4.0.0", Some("'value === null'")),
+    member("util.isNullOrUndefined", "4.0.0", Some("'value == null'")),
+    member("util.isNumber", "4.0.0", Some("'typeof value === \"number\"'")),
+    member("util.isObject", "4.0.0", Some("'value !== null && typeof value === \"object\"'")),
+    member("util.isPrimitive", "4.0.0", None),
+    member("util.isRegExp", "4.0.0", Some("'value instanceof RegExp'")),
+    member("util.isString", "4.0.0", Some("'typeof value === \"string\"'")),
+    member("util.isSymbol", "4.0.0", Some("'typeof value === \"symbol\"'")),
+    member("util.isUndefined", "4.0.0", Some("'value === undefined'")),
+    member("util.log", "6.0.0", Some("a third party module")),
+    member("util.print", "0.12.0", Some("'console.log()'")),
+    member("util.pump", "0.10.0", Some("'stream.Readable.prototype.pipe()'")),
+    member("util.puts", "0.12.0", Some("'console.log()'")),
+    member("GLOBAL", "6.0.0", Some("'global'")),
+    member("root", "6.0.0", Some("'global'")),
+    member("require.extensions", "0.12.0", None),
+];
+
+impl Rule for NoDeprecatedApi {
+    fn run_once(&self, ctx: &LintContext) {
+        for reference in collect_node_api_references(ctx, &GLOBALS) {
+            let Some(api) = DEPRECATED_APIS.iter().find(|api| matches_reference(api, &reference))
+            else {
+                continue;
+            };
+            let name = match reference.usage {
+                NodeApiUsage::Module => format!("The '{}' module", api.path),
+                NodeApiUsage::Read => format!("'{}'", api.path),
+                NodeApiUsage::Call(_) => format!("'{}()'", api.path),
+                NodeApiUsage::New(_) => format!("'new {}()'", api.path),
+            };
+            ctx.diagnostic(no_deprecated_api_diagnostic(&name, api, reference.span));
+        }
+    }
+}
+
+fn matches_reference(api: &DeprecatedApi, reference: &NodeApiReference) -> bool {
+    if api.path != reference.path {
+        return false;
+    }
+    match api.kind {
+        DeprecatedKind::Module => reference.usage == NodeApiUsage::Module,
+        DeprecatedKind::Member => reference.usage == NodeApiUsage::Read,
+        DeprecatedKind::Call => {
+            matches!(reference.usage, NodeApiUsage::Call(_) | NodeApiUsage::New(_))
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const buffer = Buffer.alloc(10);",
+        "const buffer = Buffer.from('foo');",
+        "if (Buffer.isBuffer(value)) {}",
+        "const fs = require('fs'); fs.access('./file.txt', callback);",
+        "import { stat } from 'fs'; stat('./file.txt', callback);",
+        "const { isArray } = Array; isArray(value);",
+        "const url = new URL('https://oxc.rs');",
+        "const util = require('./util'); util.isArray(value);",
+        "import util from 'util-deprecate'; util.isArray(value);",
+        "function foo(fs) { fs.exists('./file.txt'); }",
+        "function foo(Buffer) { new Buffer(10); }",
+        "const root = document.getElementById('root'); root.render();",
+        "const os = require('os'); os.tmpdir();",
+        "require('crypto').createCipheriv('aes-256-cbc', key, iv);",
+        "import type { exists } from 'fs';",
+    ];
+
+    let fail = vec![
+        "const buffer = new Buffer(10);",
+        "const buffer = Buffer('foo');",
+        "const { Buffer } = require('buffer'); new Buffer(10);",
+        "import { Buffer } from 'node:buffer'; Buffer(10);",
+        "const fs = require('fs'); fs.exists('./file.txt', callback);",
+        "require('fs').exists('./file.txt', callback);",
+        "import fs from 'fs'; fs.exists('./file.txt', callback);",
+        "import * as fs from 'node:fs'; if (fs.existsSync) { fs.exists('./file.txt', callback); }",
+        "import { exists } from 'fs'; exists('./file.txt', callback);",
+        "const { exists: fileExists } = require('fs');",
+        "const util = require('util'); util.isArray(value); util.isString(value);",
+        "const { promisify, isArray } = require('util');",
+        "const exists = require('fs').exists;",
+        "import url from 'url'; url.parse('https://oxc.rs');",
+        "const domain = require('domain');",
+        "import punycode from 'punycode';",
+        "export { toASCII } from 'node:punycode';",
+        "const { EventEmitter } = require('events'); EventEmitter.listenerCount(emitter, 'foo');",
+        "process.binding('fs');",
+        "const binding = process.binding;",
+        "require.extensions['.txt'] = handler;",
+        "GLOBAL.foo = 1;",
+        "const crypto = require('crypto'); crypto.createCipher('aes-256-cbc', 'password');",
+        "const path = require('path'); path._makeLong('./foo');",
+        "const os = require('os'); const tmpDir = os.tmpDir;",
+    ];
+
+    Tester::new(NoDeprecatedApi::NAME, NoDeprecatedApi::CATEGORY, pass, fail).test_and_snapshot();
+}
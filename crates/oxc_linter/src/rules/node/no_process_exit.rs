@@ -0,0 +1,94 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_process_exit_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Don't use process.exit(); throw an error instead.")
+        .with_help("Throw an error or set `process.exitCode`, and let the process exit by itself.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoProcessExit;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow calls of `process.exit()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `process.exit()` ends the process immediately, even if there are pending asynchronous
+    /// operations, e.g. writes to `process.stdout` or to a file, which are then lost. In a
+    /// library, it also takes the decision to exit away from the application.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// if (!config) {
+    ///   console.error('Missing config');
+    ///   process.exit(1);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// if (!config) {
+    ///   throw new Error('Missing config');
+    /// }
+    ///
+    /// process.exitCode = 1;
+    /// ```
+    NoProcessExit,
+    restriction
+);
+
+impl Rule for NoProcessExit {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(member) = call.callee.get_inner_expression().as_member_expression() else {
+            return;
+        };
+        if member.static_property_name() != Some("exit") {
+            return;
+        }
+        let Expression::Identifier(object) = member.object().get_inner_expression() else {
+            return;
+        };
+        if object.name == "process" && ctx.semantic().is_reference_to_global_variable(object) {
+            ctx.diagnostic(no_process_exit_diagnostic(call.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "process.exitCode = 1;",
+        "process.exit",
+        "const exit = process.exit;",
+        "exit(1);",
+        "foo.exit(1);",
+        "function foo(process) { process.exit(1); }",
+        "const process = { exit() {} }; process.exit();",
+    ];
+
+    let fail = vec![
+        "process.exit(0);",
+        "process.exit(1);",
+        "process.exit();",
+        "if (error) { process.exit(1); }",
+        "process['exit'](1);",
+        "(process).exit?.(1);",
+    ];
+
+    Tester::new(NoProcessExit::NAME, NoProcessExit::CATEGORY, pass, fail).test_and_snapshot();
+}
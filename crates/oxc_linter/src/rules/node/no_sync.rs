@@ -0,0 +1,159 @@
+use oxc_ast::{
+    ast::{Expression, MemberExpression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_sync_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected sync method: '{name}'."))
+        .with_help("Use the asynchronous version of the method instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSync(Box<NoSyncConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSyncConfig {
+    allow_at_root_level: bool,
+    ignores: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoSync {
+    type Target = NoSyncConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow calls of synchronous methods, i.e. methods whose name ends with `Sync`, e.g.
+    /// `fs.readFileSync()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Synchronous methods block the event loop until they are done, so a server can not
+    /// handle any other request in the meantime.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const content = fs.readFileSync('./file.txt', 'utf8');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const content = await fs.promises.readFile('./file.txt', 'utf8');
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "node/no-sync": ["error", { "allowAtRootLevel": true, "ignores": ["existsSync"] }]
+    /// }
+    /// ```
+    ///
+    /// - `allowAtRootLevel`: allow sync methods outside of functions, e.g. to read a config file
+    ///   once when a module is loaded. Defaults to `false`.
+    /// - `ignores`: names of sync methods to allow.
+    NoSync,
+    restriction
+);
+
+impl Rule for NoSync {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self(Box::new(NoSyncConfig {
+            allow_at_root_level: config
+                .and_then(|config| config.get("allowAtRootLevel"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+            ignores: config
+                .and_then(|config| config.get("ignores"))
+                .and_then(serde_json::Value::as_array)
+                .map(|ignores| {
+                    ignores
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(CompactStr::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let (name, span) = match call.callee.get_inner_expression() {
+            Expression::Identifier(ident) => (ident.name.as_str(), ident.span),
+            callee => {
+                match callee.as_member_expression().and_then(MemberExpression::static_property_info)
+                {
+                    Some((span, name)) => (name, span),
+                    None => return,
+                }
+            }
+        };
+        if name.len() <= "Sync".len() || !name.ends_with("Sync") {
+            return;
+        }
+        if self.ignores.iter().any(|ignore| ignore == name) {
+            return;
+        }
+        if self.allow_at_root_level && !is_inside_function(node, ctx) {
+            return;
+        }
+        ctx.diagnostic(no_sync_diagnostic(name, span));
+    }
+}
+
+fn is_inside_function(node: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes().ancestors(node.id()).any(|ancestor| {
+        matches!(ancestor.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("const content = await fs.promises.readFile('./file.txt');", None),
+        ("fs.readFile('./file.txt', callback);", None),
+        ("const sync = fs.readFileSync;", None),
+        ("Sync();", None),
+        ("foo.Sync();", None),
+        ("fs.syncFile();", None),
+        (
+            "const config = fs.readFileSync('./config.json');",
+            Some(json!([{ "allowAtRootLevel": true }])),
+        ),
+        ("if (fs.existsSync(path)) {}", Some(json!([{ "allowAtRootLevel": true }]))),
+        ("function foo() { fs.existsSync(path); }", Some(json!([{ "ignores": ["existsSync"] }]))),
+    ];
+
+    let fail = vec![
+        ("const content = fs.readFileSync('./file.txt');", None),
+        ("fs['writeFileSync']('./file.txt', content);", None),
+        ("readFileSync('./file.txt');", None),
+        ("require('child_process').execSync('ls');", None),
+        ("function foo() { fs.existsSync(path); }", None),
+        ("function foo() { fs.readFileSync(path); }", Some(json!([{ "allowAtRootLevel": true }]))),
+        ("const foo = () => fs.statSync(path);", Some(json!([{ "allowAtRootLevel": true }]))),
+        ("fs.readFileSync(path);", Some(json!([{ "ignores": ["existsSync"] }]))),
+    ];
+
+    Tester::new(NoSync::NAME, NoSync::CATEGORY, pass, fail).test_and_snapshot();
+}
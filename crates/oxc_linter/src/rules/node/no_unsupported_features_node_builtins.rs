@@ -0,0 +1,502 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        collect_node_api_references, get_node_version_range, min_node_version, NodeApiUsage,
+        NodeVersion,
+    },
+};
+
+fn no_unsupported_features_node_builtins_diagnostic(
+    name: &str,
+    supported: NodeVersion,
+    range: &str,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "{name} is not supported until Node.js {supported}. The configured version range is '{range}'."
+    ))
+    .with_help("Raise the minimum supported version of Node.js, or avoid this API.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsupportedFeaturesNodeBuiltins(Box<NoUnsupportedFeaturesNodeBuiltinsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsupportedFeaturesNodeBuiltinsConfig {
+    version: Option<CompactStr>,
+    ignores: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoUnsupportedFeaturesNodeBuiltins {
+    type Target = NoUnsupportedFeaturesNodeBuiltinsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow builtin modules, module members and globals of Node.js which are not supported
+    /// by all of the Node.js versions which the project supports, e.g. `fs.rm()` with
+    /// `"engines": { "node": ">=12.0.0" }`.
+    ///
+    /// The supported versions are read from the `engines.node` field of the nearest
+    /// `package.json`, and default to `>=16.0.0`. APIs which were added in a later release
+    /// line and backported to an earlier one are reported unless all supported versions
+    /// include them.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Using an API which is missing from a supported version of Node.js fails at runtime, for
+    /// users of that version only.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule, with `"engines": { "node": ">=14.0.0" }`:
+    /// ```js
+    /// const { rm } = require('fs');
+    /// const response = await fetch('https://oxc.rs');
+    /// const copy = structuredClone(value);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule, with `"engines": { "node": ">=14.0.0" }`:
+    /// ```js
+    /// const { rmdir } = require('fs');
+    /// const copy = JSON.parse(JSON.stringify(value));
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "node/no-unsupported-features/node-builtins": [
+    ///     "error",
+    ///     { "version": ">=14.0.0", "ignores": ["fetch", "fs.rm"] }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// - `version`: the range of supported Node.js versions, instead of the `engines.node`
+    ///   field of `package.json`.
+    /// - `ignores`: APIs to allow, e.g. `fetch`, `fs.rm` or `fs/promises`.
+    NoUnsupportedFeaturesNodeBuiltins,
+    correctness
+);
+
+#[derive(Debug, Clone, Copy)]
+enum BuiltinKind {
+    /// A builtin module, e.g. `fs/promises`.
+    Module,
+    /// A member of a builtin module, e.g. `fs.rm`.
+    Member,
+    /// A global variable, e.g. `fetch`.
+    Global,
+}
+
+#[derive(Debug)]
+struct BuiltinApi {
+    path: &'static str,
+    kind: BuiltinKind,
+    supported: NodeVersion,
+}
+
+const fn module(name: &'static str, major: u32, minor: u32, patch: u32) -> BuiltinApi {
+    BuiltinApi {
+        path: name,
+        kind: BuiltinKind::Module,
+        supported: NodeVersion::new(major, minor, patch),
+    }
+}
+
+const fn member(name: &'static str, major: u32, minor: u32, patch: u32) -> BuiltinApi {
+    BuiltinApi {
+        path: name,
+        kind: BuiltinKind::Member,
+        supported: NodeVersion::new(major, minor, patch),
+    }
+}
+
+const fn global(name: &'static str, major: u32, minor: u32, patch: u32) -> BuiltinApi {
+    BuiltinApi {
+        path: name,
+        kind: BuiltinKind::Global,
+        supported: NodeVersion::new(major, minor, patch),
+    }
+}
+
+/// Global variables whose members are in [`BUILTIN_APIS`], with the path that they refer to.
+const MODULE_GLOBALS: [(&str, &str); 2] = [("Buffer", "buffer.Buffer"), ("process", "process")];
+
+/// The first version of Node.js which supports each API, without experimental flags.
+#[rustfmt::skip]
+const BUILTIN_APIS: &[BuiltinApi] = &[
+    // modules
+    module("assert/strict", 15, 0, 0),
+    module("async_hooks", 8, 1, 0),
+    module("diagnostics_channel", 15, 1, 0),
+    module("dns/promises", 15, 0, 0),
+    module("fs/promises", 14, 0, 0),
+    module("http2", 8, 4, 0),
+    module("inspector", 8, 0, 0),
+    module("inspector/promises", 19, 0, 0),
+    module("path/posix", 15, 3, 0),
+    module("path/win32", 15, 3, 0),
+    module("perf_hooks", 8, 5, 0),
+    module("readline/promises", 17, 0, 0),
+    module("sea", 21, 7, 0),
+    module("sqlite", 22, 5, 0),
+    module("stream/consumers", 16, 7, 0),
+    module("stream/promises", 15, 0, 0),
+    module("stream/web", 16, 5, 0),
+    module("test", 18, 0, 0),
+    module("test/reporters", 19, 9, 0),
+    module("timers/promises", 15, 0, 0),
+    module("trace_events", 10, 0, 0),
+    module("util/types", 15, 3, 0),
+    module("wasi", 13, 3, 0),
+    module("worker_threads", 11, 7, 0),
+    // members of modules
+    member("assert.doesNotMatch", 16, 0, 0),
+    member("assert.match", 16, 0, 0),
+    member("buffer.Blob", 15, 7, 0),
+    member("buffer.File", 19, 2, 0),
+    member("buffer.atob", 16, 0, 0),
+    member("buffer.btoa", 16, 0, 0),
+    member("buffer.isAscii", 19, 6, 0),
+    member("buffer.isUtf8", 19, 4, 0),
+    member("buffer.resolveObjectURL", 16, 7, 0),
+    member("crypto.X509Certificate", 15, 6, 0),
+    member("crypto.checkPrime", 15, 8, 0),
+    member("crypto.generateKey", 15, 0, 0),
+    member("crypto.generatePrime", 15, 8, 0),
+    member("crypto.getRandomValues", 17, 4, 0),
+    member("crypto.hash", 21, 7, 0),
+    member("crypto.hkdf", 15, 0, 0),
+    member("crypto.randomUUID", 15, 6, 0),
+    member("crypto.subtle", 17, 4, 0),
+    member("crypto.webcrypto", 15, 0, 0),
+    member("events.addAbortListener", 20, 5, 0),
+    member("events.getEventListeners", 15, 2, 0),
+    member("events.on", 12, 16, 0),
+    member("events.once", 11, 13, 0),
+    member("events.setMaxListeners", 15, 4, 0),
+    member("fs.cp", 16, 7, 0),
+    member("fs.cpSync", 16, 7, 0),
+    member("fs.lutimes", 14, 5, 0),
+    member("fs.lutimesSync", 14, 5, 0),
+    member("fs.openAsBlob", 19, 8, 0),
+    member("fs.opendir", 12, 12, 0),
+    member("fs.opendirSync", 12, 12, 0),
+    member("fs.promises", 10, 0, 0),
+    member("fs.rm", 14, 14, 0),
+    member("fs.rmSync", 14, 14, 0),
+    member("fs.statfs", 19, 6, 0),
+    member("fs.statfsSync", 19, 6, 0),
+    member("http.validateHeaderName", 14, 3, 0),
+    member("http.validateHeaderValue", 14, 3, 0),
+    member("module.isBuiltin", 18, 6, 0),
+    member("module.register", 20, 6, 0),
+    member("os.availableParallelism", 19, 4, 0),
+    member("os.devNull", 16, 3, 0),
+    member("os.machine", 18, 9, 0),
+    member("path.matchesGlob", 22, 5, 0),
+    member("process.getActiveResourcesInfo", 17, 3, 0),
+    member("process.getBuiltinModule", 22, 3, 0),
+    member("process.loadEnvFile", 21, 7, 0),
+    member("stream.addAbortSignal", 15, 4, 0),
+    member("stream.compose", 16, 9, 0),
+    member("stream.getDefaultHighWaterMark", 19, 9, 0),
+    member("stream.isErrored", 17, 3, 0),
+    member("stream.isReadable", 17, 4, 0),
+    member("timers/promises.scheduler", 17, 3, 0),
+    member("url.fileURLToPath", 10, 12, 0),
+    member("url.pathToFileURL", 10, 12, 0),
+    member("url.urlToHttpOptions", 15, 7, 0),
+    member("util.MIMEType", 19, 1, 0),
+    member("util.aborted", 19, 7, 0),
+    member("util.getSystemErrorMap", 16, 0, 0),
+    member("util.parseArgs", 18, 3, 0),
+    member("util.stripVTControlCharacters", 16, 11, 0),
+    member("util.styleText", 21, 7, 0),
+    member("util.toUSVString", 16, 8, 0),
+    member("util.transferableAbortController", 18, 11, 0),
+    member("util.types", 10, 0, 0),
+    member("v8.setHeapSnapshotNearHeapLimit", 18, 10, 0),
+    member("v8.startupSnapshot", 18, 6, 0),
+    member("worker_threads.BroadcastChannel", 15, 4, 0),
+    member("worker_threads.getEnvironmentData", 15, 12, 0),
+    member("worker_threads.markAsUntransferable", 14, 5, 0),
+    member("worker_threads.setEnvironmentData", 15, 12, 0),
+    // globals
+    global("AbortController", 15, 0, 0),
+    global("AbortSignal", 15, 0, 0),
+    global("Blob", 18, 0, 0),
+    global("BroadcastChannel", 18, 0, 0),
+    global("ByteLengthQueuingStrategy", 18, 0, 0),
+    global("CompressionStream", 18, 0, 0),
+    global("CountQueuingStrategy", 18, 0, 0),
+    global("CustomEvent", 19, 0, 0),
+    global("DecompressionStream", 18, 0, 0),
+    global("Event", 15, 0, 0),
+    global("EventTarget", 15, 0, 0),
+    global("File", 20, 0, 0),
+    global("FormData", 18, 0, 0),
+    global("Headers", 18, 0, 0),
+    global("MessageChannel", 15, 0, 0),
+    global("MessageEvent", 15, 0, 0),
+    global("MessagePort", 15, 0, 0),
+    global("ReadableStream", 18, 0, 0),
+    global("Request", 18, 0, 0),
+    global("Response", 18, 0, 0),
+    global("TextDecoder", 11, 0, 0),
+    global("TextDecoderStream", 18, 0, 0),
+    global("TextEncoder", 11, 0, 0),
+    global("TextEncoderStream", 18, 0, 0),
+    global("TransformStream", 18, 0, 0),
+    global("URL", 10, 0, 0),
+    global("URLSearchParams", 10, 0, 0),
+    global("WebSocket", 22, 0, 0),
+    global("WritableStream", 18, 0, 0),
+    global("atob", 16, 0, 0),
+    global("btoa", 16, 0, 0),
+    global("crypto", 19, 0, 0),
+    global("fetch", 18, 0, 0),
+    global("globalThis", 12, 0, 0),
+    global("navigator", 21, 0, 0),
+    global("performance", 16, 0, 0),
+    global("queueMicrotask", 11, 0, 0),
+    global("structuredClone", 17, 0, 0),
+];
+
+impl Rule for NoUnsupportedFeaturesNodeBuiltins {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self(Box::new(NoUnsupportedFeaturesNodeBuiltinsConfig {
+            version: config
+                .and_then(|config| config.get("version"))
+                .and_then(serde_json::Value::as_str)
+                .map(CompactStr::from),
+            ignores: config
+                .and_then(|config| config.get("ignores"))
+                .and_then(serde_json::Value::as_array)
+                .map(|ignores| {
+                    ignores
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(CompactStr::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let range = get_node_version_range(self.version.as_deref(), ctx);
+        let Some(min_version) = min_node_version(&range) else {
+            return;
+        };
+        let unsupported = BUILTIN_APIS
+            .iter()
+            .filter(|api| api.supported > min_version)
+            .filter(|api| !self.ignores.iter().any(|ignore| ignore == api.path))
+            .collect::<Vec<_>>();
+        if unsupported.is_empty() {
+            return;
+        }
+
+        let globals = MODULE_GLOBALS
+            .into_iter()
+            .chain(
+                unsupported
+                    .iter()
+                    .filter(|api| matches!(api.kind, BuiltinKind::Global))
+                    .map(|api| (api.path, api.path)),
+            )
+            .collect::<Vec<_>>();
+        for reference in collect_node_api_references(ctx, &globals) {
+            let Some(api) = unsupported.iter().find(|api| {
+                api.path == reference.path
+                    && match api.kind {
+                        BuiltinKind::Module => reference.usage == NodeApiUsage::Module,
+                        BuiltinKind::Member | BuiltinKind::Global => {
+                            reference.usage == NodeApiUsage::Read
+                        }
+                    }
+            }) else {
+                continue;
+            };
+            let name = match api.kind {
+                BuiltinKind::Module => format!("The '{}' module", api.path),
+                BuiltinKind::Member | BuiltinKind::Global => format!("'{}'", api.path),
+            };
+            ctx.diagnostic(no_unsupported_features_node_builtins_diagnostic(
+                &name,
+                api.supported,
+                &range,
+                reference.span,
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        // the default version range is `>=16.0.0`
+        ("import { readFile } from 'fs/promises';", None, None, None),
+        ("const { rm } = require('node:fs'); rm(path, callback);", None, None, None),
+        ("const controller = new AbortController();", None, None, None),
+        ("const encoded = btoa('foo');", None, None, None),
+        (
+            "const fs = require('fs'); fs.rmdir(path, callback);",
+            Some(json!([{ "version": ">=12.0.0" }])),
+            None,
+            None,
+        ),
+        ("const { rmdir } = require('fs');", Some(json!([{ "version": ">=12.0.0" }])), None, None),
+        (
+            "const copy = JSON.parse(JSON.stringify(value));",
+            Some(json!([{ "version": ">=14.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "const response = await fetch(url);",
+            Some(json!([{ "version": ">=18.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "const copy = structuredClone(value);",
+            Some(json!([{ "version": "^17.0.0 || >=18.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "const response = await fetch(url);",
+            Some(json!([{ "version": ">=14.0.0", "ignores": ["fetch"] }])),
+            None,
+            None,
+        ),
+        (
+            "function foo(fetch) { fetch(url); }",
+            Some(json!([{ "version": ">=14.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "const fetch = require('node-fetch'); fetch(url);",
+            Some(json!([{ "version": ">=14.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "const fs = require('./fs'); fs.rm(path);",
+            Some(json!([{ "version": ">=12.0.0" }])),
+            None,
+            None,
+        ),
+        // `engines` of `package.json` is `^14.17.0 || >=16.0.0`
+        ("import { rm } from 'fs';", None, None, Some(PathBuf::from("node-engines/index.js"))),
+        (
+            "import { readFile } from 'fs/promises';",
+            None,
+            None,
+            Some(PathBuf::from("node-engines/index.js")),
+        ),
+    ];
+
+    let fail = vec![
+        ("const { rm } = require('fs');", Some(json!([{ "version": ">=12.0.0" }])), None, None),
+        (
+            "const fs = require('node:fs'); fs.rm(path, callback);",
+            Some(json!([{ "version": ">=12.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "import fs from 'fs'; await fs.promises.readFile(path);",
+            Some(json!([{ "version": ">=8.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "import { readFile } from 'fs/promises';",
+            Some(json!([{ "version": ">=12.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "const { setTimeout } = require('timers/promises');",
+            Some(json!([{ "version": "^14.0.0 || >=16.0.0" }])),
+            None,
+            None,
+        ),
+        ("import test from 'node:test';", Some(json!([{ "version": ">=16.0.0" }])), None, None),
+        (
+            "const response = await fetch(url);",
+            Some(json!([{ "version": ">=14.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "const copy = structuredClone(value);",
+            Some(json!([{ "version": ">=16.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "const controller = new AbortController();",
+            Some(json!([{ "version": ">=14.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "const { parseArgs } = require('util'); parseArgs({ options });",
+            Some(json!([{ "version": ">=16.0.0" }])),
+            None,
+            None,
+        ),
+        (
+            "import { randomUUID } from 'crypto';",
+            Some(json!([{ "version": ">=14.0.0" }])),
+            None,
+            None,
+        ),
+        ("process.loadEnvFile();", Some(json!([{ "version": ">=20.0.0" }])), None, None),
+        (
+            "const response = await fetch(url);",
+            Some(json!([{ "version": ">=14.0.0", "ignores": ["fs.rm"] }])),
+            None,
+            None,
+        ),
+        (
+            "const response = await fetch(url);",
+            None,
+            None,
+            Some(PathBuf::from("node-engines/index.js")),
+        ),
+        ("import { cp } from 'fs';", None, None, Some(PathBuf::from("node-engines/index.js"))),
+    ];
+
+    Tester::new(
+        NoUnsupportedFeaturesNodeBuiltins::NAME,
+        NoUnsupportedFeaturesNodeBuiltins::CATEGORY,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
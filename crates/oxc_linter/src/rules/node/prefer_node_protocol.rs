@@ -0,0 +1,201 @@
+use oxc_ast::{
+    ast::{Expression, StringLiteral},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_global_require_source, get_node_version_range, is_node_builtin_module,
+        min_node_version, NodeVersion,
+    },
+};
+
+fn prefer_node_protocol_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Prefer `node:{name}` over `{name}`."))
+        .with_help("Add the `node:` protocol to make it clear that this is a builtin module.")
+        .with_label(span)
+}
+
+/// The first versions which support the `node:` protocol in all later versions, for `import`
+/// and for `require()`. Earlier release lines with backports, e.g. `12.20.0` for `import` and
+/// `14.18.0` for `require()`, are not taken into account.
+const ESM_NODE_PROTOCOL_VERSION: NodeVersion = NodeVersion::new(14, 13, 1);
+const CJS_NODE_PROTOCOL_VERSION: NodeVersion = NodeVersion::new(16, 0, 0);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferNodeProtocol(Box<PreferNodeProtocolConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferNodeProtocolConfig {
+    version: Option<CompactStr>,
+}
+
+impl std::ops::Deref for PreferNodeProtocol {
+    type Target = PreferNodeProtocolConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using the `node:` protocol when importing Node.js builtin modules.
+    ///
+    /// The rule only reports imports when the supported Node.js versions support the protocol:
+    /// from `14.13.1` for `import`, and from `16.0.0` for `require()`. The versions are read
+    /// from the `engines.node` field of the nearest `package.json`, and default to
+    /// `>=16.0.0`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Without the protocol, a builtin module can not be told apart from a package of the same
+    /// name, for both readers and tools. With the protocol, the builtin module is always
+    /// imported, even if a package of the same name is installed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import fs from 'fs';
+    /// export { promises } from 'fs';
+    /// const { readFile } = require('fs/promises');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import fs from 'node:fs';
+    /// export { promises } from 'node:fs';
+    /// const { readFile } = require('node:fs/promises');
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "node/prefer-node-protocol": ["error", { "version": ">=14.0.0" }]
+    /// }
+    /// ```
+    ///
+    /// `version` is the range of supported Node.js versions, instead of the `engines.node`
+    /// field of `package.json`.
+    PreferNodeProtocol,
+    style,
+    fix
+);
+
+impl Rule for PreferNodeProtocol {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(PreferNodeProtocolConfig {
+            version: value
+                .get(0)
+                .and_then(|config| config.get("version"))
+                .and_then(serde_json::Value::as_str)
+                .map(CompactStr::from),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let range = get_node_version_range(self.version.as_deref(), ctx);
+        let min_version = min_node_version(&range);
+        let supports = |version: NodeVersion| min_version.map_or(true, |min| min >= version);
+        let (esm, cjs) = (supports(ESM_NODE_PROTOCOL_VERSION), supports(CJS_NODE_PROTOCOL_VERSION));
+        if !esm && !cjs {
+            return;
+        }
+
+        for node in ctx.nodes().iter() {
+            let source = match node.kind() {
+                AstKind::ImportDeclaration(decl) if esm => &decl.source,
+                AstKind::ExportNamedDeclaration(decl) if esm => match &decl.source {
+                    Some(source) => source,
+                    None => continue,
+                },
+                AstKind::ExportAllDeclaration(decl) if esm => &decl.source,
+                AstKind::ImportExpression(import) if esm => match &import.source {
+                    Expression::StringLiteral(source) => source,
+                    _ => continue,
+                },
+                AstKind::CallExpression(call) if cjs => {
+                    match get_global_require_source(call, ctx) {
+                        Some(source) => source,
+                        None => continue,
+                    }
+                }
+                _ => continue,
+            };
+            check_source(source, ctx);
+        }
+    }
+}
+
+fn check_source(source: &StringLiteral, ctx: &LintContext) {
+    let name = source.value.as_str();
+    if name.starts_with("node:") || !is_node_builtin_module(name) {
+        return;
+    }
+    ctx.diagnostic_with_fix(prefer_node_protocol_diagnostic(name, source.span), |fixer| {
+        // after the opening quote
+        fixer.insert_text_after_range(Span::new(source.span.start, source.span.start + 1), "node:")
+    });
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import fs from 'node:fs';", None, None, None),
+        ("import { readFile } from 'node:fs/promises';", None, None, None),
+        ("export { promises } from 'node:fs';", None, None, None),
+        ("export * from 'node:path';", None, None, None),
+        ("const fs = require('node:fs');", None, None, None),
+        ("const fs = await import('node:fs');", None, None, None),
+        ("import test from 'node:test';", None, None, None),
+        // not builtin modules
+        ("import foo from 'foo';", None, None, None),
+        ("import test from 'test';", None, None, None),
+        ("import fs from './fs';", None, None, None),
+        ("const fs = require(`fs`);", None, None, None),
+        ("function foo(require) { require('fs'); }", None, None, None),
+        // the `node:` protocol is not supported by the configured versions
+        ("import fs from 'fs';", Some(json!([{ "version": ">=12.0.0" }])), None, None),
+        ("const fs = require('fs');", Some(json!([{ "version": ">=14.13.1" }])), None, None),
+        ("const fs = require('fs');", None, None, Some(PathBuf::from("node-engines/index.js"))),
+    ];
+
+    let fail = vec![
+        ("import fs from 'fs';", None, None, None),
+        ("import { readFile } from 'fs/promises';", None, None, None),
+        ("import * as path from \"path\";", None, None, None),
+        ("import 'util';", None, None, None),
+        ("export { promises } from 'fs';", None, None, None),
+        ("export * from 'path';", None, None, None),
+        ("const fs = require('fs');", None, None, None),
+        ("const { spawn } = require('child_process');", None, None, None),
+        ("const fs = await import('fs');", None, None, None),
+        ("import fs from 'fs';", Some(json!([{ "version": ">=14.13.1" }])), None, None),
+        ("import fs from 'fs';", None, None, Some(PathBuf::from("node-engines/index.js"))),
+    ];
+
+    let fix = vec![
+        ("import fs from 'fs';", "import fs from 'node:fs';", None),
+        ("import * as path from \"path\";", "import * as path from \"node:path\";", None),
+        ("export * from 'fs/promises';", "export * from 'node:fs/promises';", None),
+        ("const fs = require('fs');", "const fs = require('node:fs');", None),
+        ("const fs = await import('fs');", "const fs = await import('node:fs');", None),
+    ];
+
+    Tester::new(PreferNodeProtocol::NAME, PreferNodeProtocol::CATEGORY, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,121 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::analysis::{
+    analyze_backtracking, AttackString, Backtracking, BacktrackingKind,
+};
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::run_on_regex_node, AstNode};
+
+fn exponential_diagnostic(attack: &AttackString, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This regular expression can backtrack exponentially.")
+        .with_help(format!(
+            "Matching an input like `{attack}` takes exponential time. Rewrite the pattern so that it can only match each input in one way."
+        ))
+        .with_label(span.label("This can match the same input in exponentially many ways"))
+}
+
+fn polynomial_diagnostic(attack: &AttackString, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This regular expression can backtrack polynomially.")
+        .with_help(format!(
+            "Matching an input like `{attack}` takes polynomial time. Make the characters matched by the quantifiers disjoint, or merge them."
+        ))
+        .with_label(span.label("These quantifiers can exchange characters"))
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRedos;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow regular expressions which can backtrack catastrophically, and reports the
+    /// offending sub-pattern together with an input which triggers it.
+    ///
+    /// Two kinds of backtracking are detected:
+    /// - Exponential: an unbounded quantifier whose body can match the same input in more than
+    ///   one way, e.g. `(a+)+`, `(\w+\s?)*` or `(\d|\w)+`.
+    /// - Polynomial: unbounded quantifiers in sequence which can match the same characters, e.g.
+    ///   `\s*\s*` or `\d+\.?\d+`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When such a pattern fails to match, the regex engine tries every way of matching the
+    /// input before giving up. For a crafted input of a few dozen characters, this can block
+    /// the event loop for seconds or longer, which can be exploited as a denial of service
+    /// attack, also known as ReDoS.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /^(a+)+$/;
+    /// var foo = /^(\w+\s?)*$/;
+    /// var foo = /(\d|\w)+!/;
+    /// var foo = /\s*\s*$/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /^a+$/;
+    /// var foo = /^(\w+\s)*\w*$/;
+    /// var foo = /\w+!/;
+    /// var foo = /\s*$/;
+    /// ```
+    NoRedos,
+    suspicious
+);
+
+impl Rule for NoRedos {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |regexp| {
+            for Backtracking { kind, span, attack } in
+                analyze_backtracking(regexp.pattern, regexp.match_flags())
+            {
+                let diagnostic = match kind {
+                    BacktrackingKind::Exponential => exponential_diagnostic(&attack, span),
+                    BacktrackingKind::Polynomial => polynomial_diagnostic(&attack, span),
+                };
+                ctx.diagnostic(diagnostic);
+            }
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var re = /regexp/",
+        "var re = /^a+$/",
+        r"var re = /^(\w+\s)*\w*$/",
+        r"var re = /\w+!/",
+        r"var re = /\s*$/",
+        r"var re = /\d+\.\d+/",
+        "var re = /^[a-z]+(?:-[a-z]+)*$/",
+        "var re = /(a|b)*c/",
+        "var re = /(ab|cd)+/",
+        "var re = /(a+){2}/",
+        r"var re = /(\p{L}+)+/u",
+        "var re = new RegExp(pattern)",
+        "var re = new RegExp('(a+)+', flags)",
+    ];
+
+    let fail = vec![
+        "var re = /^(a+)+$/",
+        r"var re = /^(\w+\s?)*$/",
+        r"var re = /(\d|\w)+!/",
+        "var re = /x(?:a|a)*y/",
+        r"var re = /^(.|\s)*$/",
+        "var re = /(?:a+|A)+$/i",
+        r"var re = /\s*\s*$/",
+        r"var re = /\d+\.?\d+$/",
+        "var re = /a*a*a*$/",
+        r"var re = /^(\w+)+@(\w+\.)+\w+$/",
+        "var re = new RegExp('^(a+)+$')",
+        r"var re = RegExp('\\s*\\s*$', 'g')",
+    ];
+
+    Tester::new(NoRedos::NAME, NoRedos::CATEGORY, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,231 @@
+use oxc_allocator::{Allocator, CloneIn, Vec as ArenaVec};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    analysis::CharSet,
+    ast::{CharacterClass, CharacterClassContents, CharacterClassContentsKind, CharacterKind},
+    visit::{walk, Visit},
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{run_on_regex_node, RegExpPattern},
+    AstNode,
+};
+
+fn duplicate_diagnostic(element: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected duplicate `{element}` in character class."))
+        .with_help("Remove the duplicate.")
+        .with_label(span)
+}
+
+fn included_diagnostic(element: &str, other: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Unexpected duplicate. `{element}` is already included in `{other}`."
+    ))
+    .with_help(format!("Remove `{element}`."))
+    .with_label(span)
+}
+
+fn overlap_diagnostic(element: &str, other: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected overlap of `{element}` and `{other}`."))
+        .with_help("Make the ranges of the character class disjoint.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDupeCharactersCharacterClass;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate characters, and characters which are already matched by another
+    /// element, in character classes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Duplicates in a character class do not change what it matches, and often hide a typo,
+    /// e.g. `[A-z]` instead of `[A-Za-z]`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /[\\(\\)]/;
+    /// var foo = /[a-z\s\r\n]/;
+    /// var foo = /[\w0-9]/;
+    /// var foo = /[a-zA-Za]/i;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /[\(\)]/;
+    /// var foo = /[a-z\s]/;
+    /// var foo = /[\w]/;
+    /// var foo = /[a-z]/i;
+    /// ```
+    NoDupeCharactersCharacterClass,
+    suspicious,
+    fix
+);
+
+impl Rule for NoDupeCharactersCharacterClass {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |regexp| {
+            let mut finder = CharacterClassFinder::default();
+            finder.visit_pattern(regexp.pattern);
+            for class in finder.classes {
+                check_character_class(class, regexp, ctx);
+            }
+        });
+    }
+}
+
+#[derive(Default)]
+struct CharacterClassFinder<'a> {
+    classes: Vec<&'a CharacterClass<'a>>,
+}
+
+impl<'a> Visit<'a> for CharacterClassFinder<'a> {
+    fn visit_character_class(&mut self, class: &CharacterClass<'a>) {
+        if class.kind == CharacterClassContentsKind::Union {
+            self.classes.push(self.alloc(class));
+        }
+        walk::walk_character_class(self, class);
+    }
+}
+
+fn check_character_class(class: &CharacterClass, regexp: &RegExpPattern, ctx: &LintContext) {
+    let source_text = ctx.source_text();
+    let elements = class
+        .body
+        .iter()
+        .map(|contents| {
+            // Outside of unicode mode, each half of a surrogate pair is a separate character.
+            if let CharacterClassContents::Character(ch) = contents {
+                if !regexp.unicode_mode() && (0xD800..=0xDFFF).contains(&ch.value) {
+                    return None;
+                }
+            }
+            CharSet::from_class_contents(contents, regexp.match_flags())
+        })
+        .collect::<Vec<_>>();
+
+    let mut diagnostics = vec![];
+    let mut removed = vec![false; elements.len()];
+    for (i, chars) in elements.iter().enumerate() {
+        let Some(chars) = chars else { continue };
+        let element = &class.body[i];
+        let text = element.span().source_text(source_text);
+        for (j, other_chars) in elements.iter().enumerate() {
+            let Some(other_chars) = other_chars else { continue };
+            if i == j || removed[j] {
+                continue;
+            }
+            let other = class.body[j].span().source_text(source_text);
+            if chars == other_chars {
+                // Report the later one of identical elements.
+                if i > j {
+                    diagnostics.push(duplicate_diagnostic(text, element.span()));
+                    removed[i] = true;
+                    break;
+                }
+            } else if chars.is_subset_of(other_chars) {
+                diagnostics.push(included_diagnostic(text, other, element.span()));
+                removed[i] = true;
+                break;
+            } else if i > j
+                && chars.intersects(other_chars)
+                && matches!(element, CharacterClassContents::CharacterClassRange(_))
+                && matches!(class.body[j], CharacterClassContents::CharacterClassRange(_))
+            {
+                diagnostics.push(overlap_diagnostic(text, other, element.span()));
+                break;
+            }
+        }
+    }
+
+    if diagnostics.is_empty() {
+        return;
+    }
+
+    // A `-` next to a removed element could form a new range, e.g. `[\w-a]`.
+    let has_dash = class.body.iter().any(|contents| {
+        matches!(contents, CharacterClassContents::Character(ch) if ch.kind == CharacterKind::Symbol && ch.value == u32::from('-'))
+    });
+    let fix = (!has_dash && removed.contains(&true)).then(|| {
+        let allocator = Allocator::default();
+        let mut body = ArenaVec::new_in(&allocator);
+        for (contents, removed) in class.body.iter().zip(&removed) {
+            if !removed {
+                body.push(contents.clone_in(&allocator));
+            }
+        }
+        let class = CharacterClass { body, ..class.clone_in(&allocator) };
+        (class.span, class.to_string())
+    });
+
+    for diagnostic in diagnostics {
+        regexp.report(ctx, diagnostic, fix.clone());
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var re = /regexp/",
+        "var re = /[a-z]/",
+        "var re = /[a-zA-Z0-9_]/",
+        r"var re = /[\(\)]/",
+        r"var re = /[\s\S]/",
+        r"var re = /[\w\-]/",
+        r"var re = /[\d.]/",
+        "var re = /[a-z][a-z]/",
+        "var re = /[aA]/",
+        "var re = /[😀😁]/",
+        r"var re = new RegExp('[a-z]')",
+        "var re = new RegExp(pattern)",
+        r"var re = /[\p{L}\p{L}]/u",
+    ];
+
+    let fail = vec![
+        "var re = /[aa]/",
+        r"var re = /[\\(\\)]/",
+        r"var re = /[a-z\s\r\n]/",
+        r"var re = /[\w0-9]/",
+        "var re = /[a-zA-Za]/i",
+        "var re = /[aA]/i",
+        "var re = /[a-mk-z]/",
+        r"var re = /[\d\d]/",
+        r"var re = /[\s\n]/",
+        "var re = /[😀😀]/u",
+        r"var re = /[\x61a]/",
+        r"var re = /[\w-a]/",
+        r"var re = new RegExp('[aa]')",
+        r"var re = RegExp('[a-z]_[a-zq]', 'g')",
+    ];
+
+    let fix = vec![
+        ("var re = /[aa]/", "var re = /[a]/"),
+        (r"var re = /[\\(\\)]/", r"var re = /[\\()]/"),
+        (r"var re = /[a-z\s\r\n]/", r"var re = /[a-z\s]/"),
+        (r"var re = /[\w0-9]/g", r"var re = /[\w]/g"),
+        ("var re = /[a-zA-Za]/i", "var re = /[a-z]/i"),
+        (r"var re = /[\d\d]/", r"var re = /[\d]/"),
+        ("var re = /[😀😀]/u", "var re = /[😀]/u"),
+    ];
+
+    Tester::new(
+        NoDupeCharactersCharacterClass::NAME,
+        NoDupeCharactersCharacterClass::CATEGORY,
+        pass,
+        fail,
+    )
+    .with_regexp_plugin(true)
+    .expect_fix(fix)
+    .test_and_snapshot();
+}
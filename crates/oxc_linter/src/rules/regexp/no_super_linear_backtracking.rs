@@ -0,0 +1,156 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::analysis::{
+    analyze_backtracking, AttackString, Backtracking, BacktrackingKind,
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        collect_alternatives, run_on_regex_node, simplify_quantified_chars, QuantifiedChars,
+        RegExpPattern,
+    },
+    AstNode,
+};
+
+fn no_super_linear_backtracking_diagnostic(
+    quantifiers: &str,
+    attack: &AttackString,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The quantifiers of `{quantifiers}` can exchange characters, which can cause polynomial backtracking."
+    ))
+    .with_help(format!(
+        "Matching an input like `{attack}` takes polynomial time. Merge the quantifiers, or make the characters they match disjoint."
+    ))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSuperLinearBacktracking;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow adjacent unbounded quantifiers which can match the same characters, e.g.
+    /// `\s*\s*` or `\d+\w*`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When the rest of the pattern fails to match, the regex engine tries every way of
+    /// splitting the matched characters between the two quantifiers. For an input of `n`
+    /// characters, this takes `O(n^2)` steps per starting position, which can be exploited to
+    /// make the regex take very long to fail, also known as ReDoS.
+    ///
+    /// Quantifiers of single characters are checked, also when they are only separated by
+    /// elements which can match the empty string, e.g. `\d+\.?\d+`. Nested quantifiers, which
+    /// can backtrack exponentially, are reported by `oxc/no-redos`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /\s*\s*$/;
+    /// var foo = /\d+\w+/;
+    /// var foo = /[a-z]*[a-f0-9]*/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /\s*$/;
+    /// var foo = /\d\w+/;
+    /// var foo = /[a-z]*[0-9]*/;
+    /// ```
+    NoSuperLinearBacktracking,
+    perf,
+    fix
+);
+
+impl Rule for NoSuperLinearBacktracking {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |regexp| {
+            for Backtracking { kind, span, attack } in
+                analyze_backtracking(regexp.pattern, regexp.match_flags())
+            {
+                if kind != BacktrackingKind::Polynomial {
+                    continue;
+                }
+                regexp.report(
+                    ctx,
+                    no_super_linear_backtracking_diagnostic(
+                        span.source_text(ctx.source_text()),
+                        &attack,
+                        span,
+                    ),
+                    adjacent_quantifiers_fix(span, regexp),
+                );
+            }
+        });
+    }
+}
+
+/// Merge the quantifiers of a reported `span`, if they are adjacent, e.g. `\s*\s*` -> `\s*`.
+fn adjacent_quantifiers_fix(span: Span, regexp: &RegExpPattern) -> Option<(Span, String)> {
+    collect_alternatives(regexp.pattern).into_iter().find_map(|alternative| {
+        let index = alternative.body.iter().position(|term| term.span().start == span.start)?;
+        let [a, b] = alternative.body.get(index..index + 2)? else { return None };
+        if b.span().end != span.end {
+            return None;
+        }
+        let a = QuantifiedChars::from_term(a, regexp)?;
+        let b = QuantifiedChars::from_term(b, regexp)?;
+        simplify_quantified_chars(&a, &b).map(|replacement| (span, replacement))
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var re = /regexp/",
+        r"var re = /\s*$/",
+        r"var re = /\d\w+/",
+        "var re = /[a-z]*[0-9]*/",
+        r"var re = /a+b+/",
+        r"var re = /\d+\.\d+/",
+        r"var re = /a+a?/",
+        r"var re = /\p{L}+\p{L}+/u",
+        "var re = new RegExp(pattern)",
+        "var re = /^(a+)+$/",
+    ];
+
+    let fail = vec![
+        r"var re = /\s*\s*$/",
+        r"var re = /\d+\w+/",
+        r"var re = /[a-z]*[a-f0-9]*/",
+        r"var re = /a+a*/",
+        r"var re = /.*a+/",
+        r"var re = /(?:\w+\d+)+/",
+        r"var re = /a*A*/i",
+        r"var re = /a*?a*/",
+        r"var re = /a*a*a*/",
+        r"var re = /\d+\.?\d+/",
+        r"var re = new RegExp('\\s*\\s*$')",
+    ];
+
+    let fix = vec![
+        (r"var re = /\s*\s*$/", r"var re = /\s*$/"),
+        (r"var re = /\d+\w+/", r"var re = /\d\w+/"),
+        (r"var re = /\w+\d+/g", r"var re = /\w+\d/g"),
+        (r"var re = /a+a*/", r"var re = /a+/"),
+        (r"var re = /\w*\d*/", r"var re = /\w*/"),
+        (r"var re = /.*a+/", r"var re = /.*a/"),
+        (r"var re = /(?:\w+\d+)+/", r"var re = /(?:\w+\d)+/"),
+        (r"var re = /a*A*/i", r"var re = /a*/i"),
+        (r"var re = /a*a*a*/", r"var re = /a*a*/"),
+    ];
+
+    Tester::new(NoSuperLinearBacktracking::NAME, NoSuperLinearBacktracking::CATEGORY, pass, fail)
+        .with_regexp_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
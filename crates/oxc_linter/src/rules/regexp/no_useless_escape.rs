@@ -0,0 +1,178 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{Character, CharacterClass, CharacterClassContents, CharacterKind},
+    visit::{walk, Visit},
+};
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{run_on_regex_node, RegExpPattern},
+    AstNode,
+};
+
+fn no_useless_escape_diagnostic(escape: char, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unnecessary escape character: `\\{escape}`."))
+        .with_help(format!("Replace `\\{escape}` with `{escape}`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessEscape;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary escape characters in regular expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Escaping a character which has no special meaning does not change what the pattern
+    /// matches, and makes it harder to read. It can also be confused with an escape sequence
+    /// which has a meaning, e.g. `\a` with `\d`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /\#/;
+    /// var foo = /\a/;
+    /// var foo = /[\.]/;
+    /// var foo = /[a\^]/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /#/;
+    /// var foo = /\./;
+    /// var foo = /[.]/;
+    /// var foo = /[\^a]/;
+    /// var foo = /[a\-z]/;
+    /// ```
+    NoUselessEscape,
+    style,
+    fix
+);
+
+impl Rule for NoUselessEscape {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |regexp| {
+            let mut finder =
+                UselessEscapeFinder { regexp, character_classes: vec![], useless_escapes: vec![] };
+            finder.visit_pattern(regexp.pattern);
+            for ch in finder.useless_escapes {
+                let Some(escape) = char::from_u32(ch.value) else { continue };
+                let unescaped = Character { kind: CharacterKind::Symbol, ..ch };
+                regexp.report(
+                    ctx,
+                    no_useless_escape_diagnostic(escape, ch.span),
+                    Some((ch.span, unescaped.to_string())),
+                );
+            }
+        });
+    }
+}
+
+struct UselessEscapeFinder<'r, 'p, 'a> {
+    regexp: &'r RegExpPattern<'p, 'a>,
+    character_classes: Vec<&'a CharacterClass<'a>>,
+    useless_escapes: Vec<Character>,
+}
+
+impl<'a> Visit<'a> for UselessEscapeFinder<'_, '_, 'a> {
+    fn visit_character_class(&mut self, class: &CharacterClass<'a>) {
+        self.character_classes.push(self.alloc(class));
+        walk::walk_character_class(self, class);
+        self.character_classes.pop();
+    }
+
+    fn visit_character(&mut self, ch: &Character) {
+        // Only identity escapes, e.g. `\#`, can be useless.
+        if ch.kind != CharacterKind::Identifier {
+            return;
+        }
+        let Some(escape) = char::from_u32(ch.value) else { return };
+        if !self.is_useful_escape(escape, ch) {
+            self.useless_escapes.push(*ch);
+        }
+    }
+}
+
+impl UselessEscapeFinder<'_, '_, '_> {
+    fn is_useful_escape(&self, escape: char, ch: &Character) -> bool {
+        // `/` must be escaped in regular expression literals.
+        if escape == '/' {
+            return true;
+        }
+        let Some(class) = self.character_classes.last() else {
+            return matches!(
+                escape,
+                '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+            );
+        };
+        if self.regexp.unicode_sets() {
+            // Most punctuators are either syntax characters or reserved in `v` mode classes.
+            return escape.is_ascii_punctuation();
+        }
+        match escape {
+            '\\' | ']' | '-' => true,
+            // `[\^a]`, but not `[a\^]`
+            '^' => matches!(
+                class.body.first(),
+                Some(CharacterClassContents::Character(first)) if first.span == ch.span
+            ),
+            _ => false,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var re = /regexp/",
+        r"var re = /\d\w\s\b\n\t/",
+        r"var re = /\^\$\\\.\*\+\?\(\)\[\]\{\}\|\//",
+        r"var re = /[\\\]\-\/]/",
+        r"var re = /[\^a]/",
+        r"var re = /[a\-z]/",
+        r"var re = /A\x41\cJ\0/",
+        r"var re = /(a)\1/",
+        r"var re = /(?<a>.)\k<a>/",
+        r"var re = /[\q{abc}]/v",
+        r"var re = /[\&\&]/v",
+        r"var re = /[\(\)]/v",
+        r"var re = new RegExp('\\d')",
+    ];
+
+    let fail = vec![
+        r"var re = /\#/",
+        r"var re = /\a/",
+        r"var re = /\-/",
+        r"var re = /[\.]/",
+        r"var re = /[\*\+]/",
+        r"var re = /[a\^]/",
+        r"var re = /[\(\)]/",
+        r"var re = /\ /",
+        r"var re = /\:\:/g",
+        r"var re = new RegExp('\\#')",
+        r"var re = RegExp('[\\.]', 'i')",
+    ];
+
+    let fix = vec![
+        (r"var re = /\#/", "var re = /#/"),
+        (r"var re = /\a/g", "var re = /a/g"),
+        (r"var re = /[\.]/", "var re = /[.]/"),
+        (r"var re = /[a\^]/", "var re = /[a^]/"),
+        (r"var re = /\:\:/", "var re = /::/"),
+        (r"var re = /[\(\)]/", "var re = /[()]/"),
+    ];
+
+    Tester::new(NoUselessEscape::NAME, NoUselessEscape::CATEGORY, pass, fail)
+        .with_regexp_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
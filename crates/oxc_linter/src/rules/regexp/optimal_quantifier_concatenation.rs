@@ -0,0 +1,153 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{collect_alternatives, run_on_regex_node, simplify_quantified_chars, QuantifiedChars},
+    AstNode,
+};
+
+fn optimal_quantifier_concatenation_diagnostic(
+    original: &str,
+    replacement: &str,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{original}` can be simplified."))
+        .with_help(format!("Replace `{original}` with `{replacement}`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct OptimalQuantifierConcatenation;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require adjacent quantified elements to be combined, if they can be replaced by a
+    /// simpler equivalent, e.g. `a+` instead of `aa*`, or `\w+` instead of `\w+\d?`.
+    ///
+    /// Adjacent quantifiers which are both unbounded, e.g. `\w+\d*`, are reported by
+    /// `regexp/no-super-linear-backtracking` instead.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The simplified pattern is shorter and easier to read, and the regex engine does not
+    /// have to try how to split the characters between the elements.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /aa*/;
+    /// var foo = /a{2}a{3}/;
+    /// var foo = /\w+\d?/;
+    /// var foo = /\d{1,3}\w+/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /a+/;
+    /// var foo = /a{5}/;
+    /// var foo = /\w+/;
+    /// var foo = /\d\w+/;
+    /// ```
+    OptimalQuantifierConcatenation,
+    style,
+    fix
+);
+
+impl Rule for OptimalQuantifierConcatenation {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |regexp| {
+            for alternative in collect_alternatives(regexp.pattern) {
+                let elements = alternative
+                    .body
+                    .iter()
+                    .map(|term| QuantifiedChars::from_term(term, regexp))
+                    .collect::<Vec<_>>();
+                let mut i = 0;
+                while i + 1 < elements.len() {
+                    let (Some(a), Some(b)) = (&elements[i], &elements[i + 1]) else {
+                        i += 1;
+                        continue;
+                    };
+                    let both_unbounded = a.max.is_none() && b.max.is_none();
+                    if both_unbounded || !(a.is_quantified() || b.is_quantified()) {
+                        i += 1;
+                        continue;
+                    }
+                    let Some(replacement) = simplify_quantified_chars(a, b) else {
+                        i += 1;
+                        continue;
+                    };
+                    let span = Span::new(a.span.start, b.span.end);
+                    regexp.report(
+                        ctx,
+                        optimal_quantifier_concatenation_diagnostic(
+                            span.source_text(ctx.source_text()),
+                            &replacement,
+                            span,
+                        ),
+                        Some((span, replacement)),
+                    );
+                    // Skip `b`, so the fixes of overlapping pairs do not conflict.
+                    i += 2;
+                }
+            }
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var re = /regexp/",
+        "var re = /aa/",
+        "var re = /a+/",
+        "var re = /a+b?/",
+        r"var re = /\d?\w/",
+        r"var re = /\w+\d{2}/",
+        r"var re = /[a-z]+A/i",
+        r"var re = /a*?a/",
+        // Reported by `regexp/no-super-linear-backtracking`.
+        r"var re = /\w+\d*/",
+        "var re = new RegExp(pattern)",
+    ];
+
+    let fail = vec![
+        "var re = /aa*/",
+        "var re = /a*a/",
+        "var re = /a{2}a{3}/",
+        r"var re = /\w+\d?/",
+        r"var re = /\d{1,3}\w+/",
+        r"var re = /\d\d+/",
+        r"var re = /(?:a?a)+/",
+        r"var re = /[a-z]+a?/i",
+        r"var re = new RegExp('\\w+\\d?')",
+    ];
+
+    let fix = vec![
+        ("var re = /aa*/", "var re = /a+/"),
+        ("var re = /a*a/g", "var re = /a+/g"),
+        ("var re = /a{2}a{3}/", "var re = /a{5}/"),
+        (r"var re = /\w+\d?/", r"var re = /\w+/"),
+        (r"var re = /\d{1,3}\w+/", r"var re = /\d\w+/"),
+        (r"var re = /\d\d+/", r"var re = /\d{2,}/"),
+        (r"var re = /(?:a?a)+/", r"var re = /(?:a{1,2})+/"),
+        (r"var re = /[a-z]+A?/i", r"var re = /[a-z]+/i"),
+    ];
+
+    Tester::new(
+        OptimalQuantifierConcatenation::NAME,
+        OptimalQuantifierConcatenation::CATEGORY,
+        pass,
+        fail,
+    )
+    .with_regexp_plugin(true)
+    .expect_fix(fix)
+    .test_and_snapshot();
+}
@@ -0,0 +1,260 @@
+use oxc_allocator::{Allocator, Box, CloneIn, Vec as ArenaVec};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{
+        CapturingGroup, Character, CharacterClass, CharacterClassContents,
+        CharacterClassContentsKind, CharacterKind, Disjunction, IgnoreGroup, LookAroundAssertion,
+        Pattern, Term,
+    },
+    visit::{walk, Visit},
+};
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{run_on_regex_node, RegExpPattern},
+    AstNode,
+};
+
+fn prefer_character_class_diagnostic(class: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected disjunction of single characters.")
+        .with_help(format!("Use the character class `{class}` instead."))
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct PreferCharacterClass {
+    min_alternatives: usize,
+}
+
+impl Default for PreferCharacterClass {
+    fn default() -> Self {
+        Self { min_alternatives: 3 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using character classes instead of disjunctions of single characters, e.g.
+    /// `[abc]` instead of `(?:a|b|c)`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A character class is shorter, and much faster to match than a disjunction, which is
+    /// tried one alternative at a time.
+    ///
+    /// ### Options
+    ///
+    /// `minAlternatives` (default: `3`): the minimum number of alternatives of a disjunction to
+    /// report.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /a|b|c/;
+    /// var foo = /(?:a|b|c)+/;
+    /// var foo = /(a|\d|[xy])/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /[abc]/;
+    /// var foo = /[abc]+/;
+    /// var foo = /([a\dxy])/;
+    /// var foo = /a|bc|d/;
+    /// ```
+    PreferCharacterClass,
+    style,
+    fix
+);
+
+impl Rule for PreferCharacterClass {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let min_alternatives = value
+            .get(0)
+            .and_then(|v| v.get("minAlternatives"))
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|v| usize::try_from(v).ok())
+            .unwrap_or(3);
+
+        Self { min_alternatives }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |regexp| {
+            let mut finder = DisjunctionFinder::default();
+            finder.visit_pattern(regexp.pattern);
+            for (disjunction, span) in finder.disjunctions {
+                if disjunction.body.len() < self.min_alternatives.max(2) {
+                    continue;
+                }
+                let allocator = Allocator::default();
+                let Some(class) = to_character_class(disjunction, regexp, &allocator) else {
+                    continue;
+                };
+                let class = class.to_string();
+                regexp.report(
+                    ctx,
+                    prefer_character_class_diagnostic(&class, span),
+                    Some((span, class)),
+                );
+            }
+        });
+    }
+}
+
+/// Collects disjunctions, with the span which is replaced by the character class.
+#[derive(Default)]
+struct DisjunctionFinder<'a> {
+    disjunctions: Vec<(&'a Disjunction<'a>, Span)>,
+}
+
+impl<'a> Visit<'a> for DisjunctionFinder<'a> {
+    fn visit_pattern(&mut self, it: &Pattern<'a>) {
+        self.disjunctions.push((self.alloc(&it.body), it.body.span));
+        walk::walk_pattern(self, it);
+    }
+
+    fn visit_capturing_group(&mut self, it: &CapturingGroup<'a>) {
+        self.disjunctions.push((self.alloc(&it.body), it.body.span));
+        walk::walk_capturing_group(self, it);
+    }
+
+    fn visit_ignore_group(&mut self, it: &IgnoreGroup<'a>) {
+        // `(?:a|b|c)` -> `[abc]`, but `(?i:a|b|c)` -> `(?i:[abc])`
+        let span = if it.modifiers.is_some() { it.body.span } else { it.span };
+        self.disjunctions.push((self.alloc(&it.body), span));
+        walk::walk_ignore_group(self, it);
+    }
+
+    fn visit_lookaround_assertion(&mut self, it: &LookAroundAssertion<'a>) {
+        self.disjunctions.push((self.alloc(&it.body), it.body.span));
+        walk::walk_lookaround_assertion(self, it);
+    }
+}
+
+/// The character class matching the same characters as `disjunction`, if each alternative is a
+/// single character, character class escape, or character class.
+fn to_character_class<'b>(
+    disjunction: &Disjunction,
+    regexp: &RegExpPattern,
+    allocator: &'b Allocator,
+) -> Option<CharacterClass<'b>> {
+    let mut body = ArenaVec::new_in(allocator);
+    for alternative in &disjunction.body {
+        let [term] = alternative.body.as_slice() else {
+            return None;
+        };
+        match term {
+            Term::Character(ch) => body.push(CharacterClassContents::Character(Box::new_in(
+                escape_in_class(ch, regexp),
+                allocator,
+            ))),
+            Term::CharacterClassEscape(escape) => {
+                body.push(CharacterClassContents::CharacterClassEscape(escape.clone_in(allocator)));
+            }
+            Term::UnicodePropertyEscape(escape) if !escape.strings => {
+                body.push(CharacterClassContents::UnicodePropertyEscape(
+                    escape.clone_in(allocator),
+                ));
+            }
+            Term::CharacterClass(class)
+                if !class.negative
+                    && !class.strings
+                    && class.kind == CharacterClassContentsKind::Union =>
+            {
+                for contents in &class.body {
+                    match contents {
+                        CharacterClassContents::Character(ch) => {
+                            body.push(CharacterClassContents::Character(Box::new_in(
+                                escape_in_class(ch, regexp),
+                                allocator,
+                            )));
+                        }
+                        CharacterClassContents::NestedCharacterClass(_)
+                        | CharacterClassContents::ClassStringDisjunction(_) => return None,
+                        contents => body.push(contents.clone_in(allocator)),
+                    }
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    Some(CharacterClass {
+        span: Span::default(),
+        negative: false,
+        strings: false,
+        kind: CharacterClassContentsKind::Union,
+        body,
+    })
+}
+
+/// Escape characters which have a special meaning in character classes, e.g. `^` and `-`.
+fn escape_in_class(ch: &Character, regexp: &RegExpPattern) -> Character {
+    if ch.kind != CharacterKind::Symbol {
+        return *ch;
+    }
+    let special: &[char] = if regexp.unicode_sets() {
+        &[
+            '(', ')', '[', ']', '{', '}', '/', '-', '\\', '|', '&', '!', '#', '%', ',', ':', ';',
+            '<', '=', '>', '@', '`', '~', '^', '$', '.', '*', '+', '?',
+        ]
+    } else {
+        &['\\', ']', '[', '^', '-']
+    };
+    match char::from_u32(ch.value) {
+        Some(c) if special.contains(&c) => Character { kind: CharacterKind::Identifier, ..*ch },
+        _ => *ch,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var re = /regexp/", None),
+        ("var re = /[abc]/", None),
+        ("var re = /a|b/", None),
+        ("var re = /(?:a|b)/", None),
+        ("var re = /a|bc|d/", None),
+        ("var re = /(?:a|b|.)/", None),
+        (r"var re = /(x)(?:a|b|\1)/", None),
+        ("var re = /(?:a|b|[^c])/", None),
+        ("var re = /(?:a|b)/", Some(serde_json::json!([{ "minAlternatives": 3 }]))),
+        ("var re = new RegExp(pattern)", None),
+    ];
+
+    let fail = vec![
+        ("var re = /a|b|c/", None),
+        ("var re = /(?:a|b|c)+/", None),
+        (r"var re = /(a|\d|[xy])/", None),
+        ("var re = /(?=a|b|c)/", None),
+        ("var re = /(?i:a|b|c)/", None),
+        ("var re = /x(?:]|-|a)/", None),
+        (r"var re = /(?:\p{L}|_|\$)/u", None),
+        ("var re = /(?:a|b)/", Some(serde_json::json!([{ "minAlternatives": 2 }]))),
+        ("var re = new RegExp('a|b|c')", None),
+    ];
+
+    let fix = vec![
+        ("var re = /a|b|c/", "var re = /[abc]/"),
+        ("var re = /(?:a|b|c)+/g", "var re = /[abc]+/g"),
+        (r"var re = /(a|\d|[xy])/", r"var re = /([a\dxy])/"),
+        ("var re = /(?=a|b|c)/", "var re = /(?=[abc])/"),
+        ("var re = /(?i:a|b|c)/", "var re = /(?i:[abc])/"),
+        ("var re = /x(?:]|-|a)/", r"var re = /x[\]\-a]/"),
+        (r"var re = /(?:\p{L}|_|\$)/u", r"var re = /[\p{L}_\$]/u"),
+        ("var re = /(?:a|-|&)/v", r"var re = /[a\-\&]/v"),
+    ];
+
+    Tester::new(PreferCharacterClass::NAME, PreferCharacterClass::CATEGORY, pass, fail)
+        .with_regexp_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,144 @@
+use oxc_ast::{
+    ast::{Argument, CallExpression, Expression, ObjectPropertyKind},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{collect_node_api_references, is_static_expression, NodeApiUsage},
+};
+
+fn detect_child_process_diagnostic(path: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{path}` runs a non-literal command in a shell."))
+        .with_help("A command built from untrusted data allows command injection. Use `execFile()` or `spawn()` without a shell, and pass untrusted data as separate arguments.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectChildProcess;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow running commands which are not literals in a shell with the `child_process`
+    /// module, i.e. with `exec()` and `execSync()`, or with `spawn()`, `spawnSync()`,
+    /// `execFile()` and `execFileSync()` when the `shell` option is enabled.
+    ///
+    /// The methods are found through `import` declarations, `require()` calls and the
+    /// variables they are assigned to, and variables are followed to their values.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A shell interprets the command, so when it contains data from outside of the source
+    /// code, e.g. user input, an attacker can run arbitrary commands, e.g. with `; rm -rf /`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const { exec, spawn } = require('child_process');
+    /// exec(`git log ${branch}`);
+    /// spawn(command, { shell: true });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const { exec, execFile } = require('child_process');
+    /// exec('git log');
+    /// execFile('git', ['log', branch]);
+    /// ```
+    DetectChildProcess,
+    suspicious
+);
+
+impl Rule for DetectChildProcess {
+    fn run_once(&self, ctx: &LintContext) {
+        for reference in collect_node_api_references(ctx, &[]) {
+            let NodeApiUsage::Call(call_id) = reference.usage else {
+                continue;
+            };
+            let Some(method) = reference.path.strip_prefix("child_process.") else {
+                continue;
+            };
+            let AstKind::CallExpression(call) = ctx.nodes().kind(call_id) else {
+                continue;
+            };
+            let runs_in_shell = match method {
+                "exec" | "execSync" => true,
+                "execFile" | "execFileSync" | "spawn" | "spawnSync" => has_shell_option(call),
+                _ => false,
+            };
+            if !runs_in_shell {
+                continue;
+            }
+            let Some(command) = call.arguments.first() else {
+                continue;
+            };
+            if command.as_expression().is_some_and(|command| is_static_expression(command, ctx)) {
+                continue;
+            }
+            ctx.diagnostic(detect_child_process_diagnostic(&reference.path, command.span()));
+        }
+    }
+}
+
+/// Whether the options of `spawn()` or `execFile()` enable the `shell` option, e.g.
+/// `{ shell: true }` or `{ shell: '/bin/bash' }`.
+fn has_shell_option(call: &CallExpression) -> bool {
+    call.arguments.iter().skip(1).any(|argument| {
+        let Argument::ObjectExpression(options) = argument else {
+            return false;
+        };
+        options.properties.iter().any(|property| match property {
+            ObjectPropertyKind::ObjectProperty(property) => {
+                property.key.is_specific_static_name("shell")
+                    && match property.value.get_inner_expression() {
+                        Expression::BooleanLiteral(value) => value.value,
+                        Expression::NullLiteral(_) => false,
+                        value => !value.is_undefined(),
+                    }
+            }
+            // The options of a spread object are unknown.
+            ObjectPropertyKind::SpreadProperty(_) => false,
+        })
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const { exec } = require('child_process'); exec('ls -la');",
+        "const child_process = require('child_process'); child_process.execSync(`git log --oneline`);",
+        "import { exec } from 'node:child_process'; const command = 'ls'; exec(command);",
+        "import { exec } from 'child_process'; let command = 'ls'; if (all) { command += ' -a'; } exec(command);",
+        "const { execFile } = require('child_process'); execFile('git', ['log', branch]);",
+        "const { spawn } = require('child_process'); spawn(command, args);",
+        "const { spawn } = require('child_process'); spawn(command, args, { shell: false });",
+        "const { spawn } = require('child_process'); spawn('ls -la', { shell: true });",
+        "const { fork } = require('child_process'); fork(modulePath);",
+        "const exec = require('./exec'); exec(command);",
+        "function foo(exec) { exec(command); }",
+        "exec(command);",
+    ];
+
+    let fail = vec![
+        "const { exec } = require('child_process'); exec(command);",
+        "const child_process = require('child_process'); function run(branch) { child_process.execSync(`git log ${branch}`); }",
+        "import { exec } from 'node:child_process'; exec('ls ' + req.query.path, callback);",
+        "import * as cp from 'child_process'; let command = 'ls'; command = input; cp.exec(command);",
+        "require('child_process').exec(command);",
+        "const { spawn } = require('child_process'); spawn(command, { shell: true });",
+        "const { spawnSync } = require('child_process'); spawnSync(command, args, { cwd, shell: '/bin/bash' });",
+        "const { execFile } = require('child_process'); execFile(file, args, { shell: true }, callback);",
+    ];
+
+    Tester::new(DetectChildProcess::NAME, DetectChildProcess::CATEGORY, pass, fail)
+        .with_security_plugin(true)
+        .test_and_snapshot();
+}
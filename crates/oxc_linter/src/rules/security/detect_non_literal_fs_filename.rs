@@ -0,0 +1,183 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{collect_node_api_references, is_static_expression, NodeApiUsage},
+};
+
+fn detect_non_literal_fs_filename_diagnostic(
+    path: &str,
+    index: usize,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{path}` is called with a non-literal file name at argument index {index}."
+    ))
+    .with_help("A file name built from untrusted data allows access to any file. Validate it, or only use literal file names.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectNonLiteralFsFilename;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow calls of `fs` methods with a file name which is not a literal, e.g. a function
+    /// parameter or a string built from one.
+    ///
+    /// The methods of `fs`, `fs.promises` and `fs/promises` are found through `import`
+    /// declarations, `require()` calls and the variables they are assigned to. Variables are
+    /// followed to their values, and `path.join()` and `path.resolve()` of literals,
+    /// `__dirname` and `import.meta.url` are allowed as well.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When a file name contains data from outside of the source code, e.g. user input, an
+    /// attacker may read or write any file with a path traversal like `../../etc/passwd`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const fs = require('fs');
+    /// function read(name) {
+    ///     return fs.readFileSync(name);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const fs = require('fs');
+    /// const path = require('path');
+    /// const config = fs.readFileSync(path.join(__dirname, 'config.json'));
+    /// ```
+    DetectNonLiteralFsFilename,
+    restriction
+);
+
+/// The methods of `fs` which take file names, with the indices of those arguments.
+const FS_METHODS: [(&str, &[usize]); 37] = [
+    ("access", &[0]),
+    ("appendFile", &[0]),
+    ("chmod", &[0]),
+    ("chown", &[0]),
+    ("copyFile", &[0, 1]),
+    ("cp", &[0, 1]),
+    ("createReadStream", &[0]),
+    ("createWriteStream", &[0]),
+    ("exists", &[0]),
+    ("glob", &[0]),
+    ("lchmod", &[0]),
+    ("lchown", &[0]),
+    ("link", &[0, 1]),
+    ("lstat", &[0]),
+    ("lutimes", &[0]),
+    ("mkdir", &[0]),
+    ("mkdtemp", &[0]),
+    ("open", &[0]),
+    ("openAsBlob", &[0]),
+    ("opendir", &[0]),
+    ("readFile", &[0]),
+    ("readdir", &[0]),
+    ("readlink", &[0]),
+    ("realpath", &[0]),
+    ("rename", &[0, 1]),
+    ("rm", &[0]),
+    ("rmdir", &[0]),
+    ("stat", &[0]),
+    ("statfs", &[0]),
+    ("symlink", &[0, 1]),
+    ("truncate", &[0]),
+    ("unlink", &[0]),
+    ("unwatchFile", &[0]),
+    ("utimes", &[0]),
+    ("watch", &[0]),
+    ("watchFile", &[0]),
+    ("writeFile", &[0]),
+];
+
+impl Rule for DetectNonLiteralFsFilename {
+    fn run_once(&self, ctx: &LintContext) {
+        for reference in collect_node_api_references(ctx, &[]) {
+            let NodeApiUsage::Call(call_id) = reference.usage else {
+                continue;
+            };
+            let Some(indices) = get_file_name_indices(&reference.path) else {
+                continue;
+            };
+            let AstKind::CallExpression(call) = ctx.nodes().kind(call_id) else {
+                continue;
+            };
+            for &index in indices {
+                let Some(argument) = call.arguments.get(index) else {
+                    continue;
+                };
+                if argument.as_expression().is_some_and(|arg| is_static_expression(arg, ctx)) {
+                    continue;
+                }
+                ctx.diagnostic(detect_non_literal_fs_filename_diagnostic(
+                    &reference.path,
+                    index,
+                    argument.span(),
+                ));
+            }
+        }
+    }
+}
+
+/// The indices of the file name arguments of an `fs` method, e.g. `[0]` for `fs.readFileSync`.
+fn get_file_name_indices(path: &str) -> Option<&'static [usize]> {
+    let method = if let Some(method) =
+        path.strip_prefix("fs/promises.").or_else(|| path.strip_prefix("fs.promises."))
+    {
+        method
+    } else {
+        let method = path.strip_prefix("fs.")?;
+        method.strip_suffix("Sync").unwrap_or(method)
+    };
+    FS_METHODS.iter().find(|(name, _)| *name == method).map(|(_, indices)| *indices)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const fs = require('fs'); fs.readFile('./file.txt', callback);",
+        "const fs = require('fs'); fs.readFileSync(`${__dirname}/file.txt`);",
+        "const fs = require('node:fs'); const name = './file.txt'; fs.readFileSync(name);",
+        "const fs = require('fs'); let name = 'a.txt'; if (foo) { name = 'b.txt'; } fs.readFileSync(name);",
+        "const fs = require('fs'); const path = require('path'); fs.readFileSync(path.join(__dirname, 'file.txt'));",
+        "import fs from 'fs'; import * as path from 'node:path'; fs.readFileSync(path.resolve(__dirname, '..', 'file.txt'));",
+        "import { readFile } from 'fs/promises'; await readFile(new URL('./file.txt', import.meta.url));",
+        "const fs = require('fs'); fs.writeFile('./file.txt', content);",
+        "const fs = require('fs'); fs.watchFile(__filename, listener);",
+        "const fs = require('fs'); fs.read(fd, buffer);",
+        "const fs = require('./fs'); fs.readFile(name);",
+        "function foo(fs) { fs.readFile(name); }",
+        "fs.readFile(name);",
+    ];
+
+    let fail = vec![
+        "const fs = require('fs'); fs.readFile(name, callback);",
+        "const fs = require('fs'); function read(name) { return fs.readFileSync(name); }",
+        "import fs from 'fs'; fs.promises.readFile(`./files/${name}`);",
+        "import { readFile } from 'node:fs/promises'; await readFile(name);",
+        "const { createReadStream } = require('fs'); createReadStream(req.query.file);",
+        "const fs = require('fs'); fs.rename(from, to);",
+        "const fs = require('fs'); fs.copyFileSync('./file.txt', target);",
+        "const fs = require('fs'); let name = 'a.txt'; name = input; fs.unlinkSync(name);",
+        "const fs = require('fs'); const path = require('path'); fs.readFileSync(path.join(__dirname, name));",
+        "const fs = require('fs'); const path = { join }; fs.readFileSync(path.join(__dirname, 'file.txt'));",
+        "const fs = require('fs'); fs.readFileSync(...args);",
+    ];
+
+    Tester::new(DetectNonLiteralFsFilename::NAME, DetectNonLiteralFsFilename::CATEGORY, pass, fail)
+        .with_security_plugin(true)
+        .test_and_snapshot();
+}
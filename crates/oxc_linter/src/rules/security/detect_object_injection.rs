@@ -0,0 +1,139 @@
+use oxc_ast::{
+    ast::{Expression, IdentifierReference, MemberExpression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::is_static_expression, AstNode};
+
+fn detect_object_injection_diagnostic(sink: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("{sink} Object Injection Sink"))
+        .with_help("A property key from untrusted data can access any property, e.g. `__proto__` or `constructor`. Validate the key, or use a `Map` instead of an object.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectObjectInjection;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow bracket notation with a variable as the key, e.g. `obj[key]`, unless the
+    /// variable only holds literals or is the counter of a `for` loop.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When the key contains data from outside of the source code, e.g. user input, an
+    /// attacker can read or write any property of the object, including inherited ones like
+    /// `__proto__` and `constructor`. This can lead to prototype pollution, or to calls of
+    /// arbitrary functions.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function update(obj, key, value) {
+    ///     obj[key] = value;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const key = 'name';
+    /// obj[key] = value;
+    /// for (let i = 0; i < items.length; i++) {
+    ///     items[i] = 0;
+    /// }
+    /// ```
+    DetectObjectInjection,
+    restriction
+);
+
+impl Rule for DetectObjectInjection {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::MemberExpression(MemberExpression::ComputedMemberExpression(member)) =
+            node.kind()
+        else {
+            return;
+        };
+        let Expression::Identifier(key) = member.expression.get_inner_expression() else {
+            return;
+        };
+        if is_static_expression(&member.expression, ctx) || is_numeric_loop_counter(key, ctx) {
+            return;
+        }
+
+        let member_span = member.span;
+        let sink = match ctx.nodes().parent_kind(node.id()) {
+            Some(AstKind::VariableDeclarator(declarator))
+                if declarator.init.as_ref().is_some_and(|init| init.span() == member_span) =>
+            {
+                "Variable Assigned to"
+            }
+            Some(AstKind::CallExpression(call)) if call.callee.span() == member_span => {
+                "Function Call"
+            }
+            _ => "Generic",
+        };
+        ctx.diagnostic(detect_object_injection_diagnostic(sink, member_span));
+    }
+}
+
+/// Whether `ident` is a counter declared in a `for` loop with a number, e.g. `i` in
+/// `for (let i = 0; i < items.length; i++)`.
+fn is_numeric_loop_counter(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    let Some(symbol_id) = ctx.symbols().get_reference(ident.reference_id()).symbol_id() else {
+        return false;
+    };
+    let declaration = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+    let AstKind::VariableDeclarator(declarator) = declaration.kind() else {
+        return false;
+    };
+    matches!(declarator.init, Some(Expression::NumericLiteral(_)))
+        && ctx
+            .nodes()
+            .ancestors(declaration.id())
+            .skip(1)
+            .find(|ancestor| {
+                !matches!(
+                    ancestor.kind(),
+                    AstKind::VariableDeclaration(_) | AstKind::ForStatementInit(_)
+                )
+            })
+            .is_some_and(|ancestor| matches!(ancestor.kind(), AstKind::ForStatement(_)))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "obj.name = value;",
+        "obj['name'] = value;",
+        "obj[0] = value;",
+        "const key = 'name'; obj[key] = value;",
+        "let key = 'a'; if (foo) { key = 'b'; } const value = obj[key];",
+        "for (let i = 0; i < items.length; i++) { items[i] = 0; }",
+        "for (var i = items.length - 1, j = 0; i >= 0; i--) { items[j] = 0; }",
+        "obj[`name`]();",
+        "obj[foo.bar] = value;",
+    ];
+
+    let fail = vec![
+        "function update(obj, key, value) { obj[key] = value; }",
+        "const value = obj[key];",
+        "obj[method]();",
+        "let key = 'a'; key = input; obj[key] = value;",
+        "for (const key in source) { target[key] = source[key]; }",
+        "for (const key of Object.keys(source)) { target[key] = 0; }",
+        "const key = getKey(); obj[key] = value;",
+        "for (let i = start; i < end; i++) { items[i] = 0; }",
+        "let i = 0; while (i < items.length) { items[i++] = 0; items[i] = 0; }",
+    ];
+
+    Tester::new(DetectObjectInjection::NAME, DetectObjectInjection::CATEGORY, pass, fail)
+        .with_security_plugin(true)
+        .test_and_snapshot();
+}
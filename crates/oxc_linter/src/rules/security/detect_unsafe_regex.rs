@@ -0,0 +1,93 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::analysis::{
+    analyze_backtracking, AttackString, Backtracking, BacktrackingKind,
+};
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::run_on_regex_node, AstNode};
+
+fn detect_unsafe_regex_diagnostic(attack: &AttackString, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unsafe regular expression, which can be exploited for a ReDoS attack.")
+        .with_help(format!(
+            "Matching an input like `{attack}` takes exponential time. Avoid nested quantifiers which can match the same input."
+        ))
+        .with_label(span.label("This quantifier can match the same input in exponentially many ways"))
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectUnsafeRegex;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow regular expressions which can backtrack exponentially, e.g. with nested
+    /// quantifiers like `(a+)+`. Both regular expression literals and `RegExp` calls with a
+    /// literal pattern are checked.
+    ///
+    /// Unlike `oxc/no-redos`, polynomial backtracking is not reported.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When such a pattern fails to match a crafted input of a few dozen characters, the regex
+    /// engine can block the event loop for seconds or longer, which an attacker can exploit as
+    /// a denial of service attack, also known as ReDoS.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const email = /^([a-zA-Z0-9]+\.?)+@example\.com$/;
+    /// const re = new RegExp('^(a+)+$');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const email = /^[a-zA-Z0-9]+(\.[a-zA-Z0-9]+)*@example\.com$/;
+    /// const re = new RegExp('^a+$');
+    /// ```
+    DetectUnsafeRegex,
+    suspicious
+);
+
+impl Rule for DetectUnsafeRegex {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |regexp| {
+            // One report is enough for a regular expression, as one fix removes most of them.
+            let exponential = analyze_backtracking(regexp.pattern, regexp.match_flags())
+                .into_iter()
+                .find(|backtracking| backtracking.kind == BacktrackingKind::Exponential);
+            if let Some(Backtracking { span, attack, .. }) = exponential {
+                ctx.diagnostic(detect_unsafe_regex_diagnostic(&attack, span));
+            }
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const re = /^a+$/;",
+        r"const re = /^[a-zA-Z0-9]+(\.[a-zA-Z0-9]+)*@example\.com$/;",
+        "const re = /(a|b)*c/;",
+        r"const re = /\s*\s*$/;",
+        "const re = new RegExp('^a+$');",
+        "const re = new RegExp(pattern);",
+        "const re = new RegExp('(a+)+', flags);",
+    ];
+
+    let fail = vec![
+        "const re = /^(a+)+$/;",
+        r"const re = /^([a-zA-Z0-9]+\.?)+@example\.com$/;",
+        r"const re = /(\d|\w)+!/;",
+        r"const re = /^(\w+)+@(\w+\.)+\w+$/;",
+        "const re = new RegExp('^(a+)+$');",
+        r"const re = RegExp('^(\\w+\\s?)*$', 'u');",
+    ];
+
+    Tester::new(DetectUnsafeRegex::NAME, DetectUnsafeRegex::CATEGORY, pass, fail)
+        .with_security_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,102 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::is_static_expression, AstNode};
+
+fn no_eval_with_expression_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`eval` is called with a non-literal argument.")
+        .with_help("Code built from untrusted data allows code injection. Avoid `eval`, or only pass it literal code.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoEvalWithExpression;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow calls of `eval` with an argument which is not a literal, e.g. a function
+    /// parameter or a string built from one.
+    ///
+    /// Variables are followed to their values, so `eval` of a variable which only holds
+    /// literals is allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When the evaluated code contains data from outside of the source code, e.g. user input,
+    /// an attacker can run arbitrary code.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function run(code) {
+    ///     eval(code);
+    /// }
+    /// eval(`console.log(${message})`);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// eval('console.log(1)');
+    /// const code = 'console.log(1)';
+    /// eval(code);
+    /// ```
+    NoEvalWithExpression,
+    suspicious
+);
+
+impl Rule for NoEvalWithExpression {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Expression::Identifier(callee) = call.callee.get_inner_expression() else {
+            return;
+        };
+        if callee.name != "eval" || !ctx.semantic().is_reference_to_global_variable(callee) {
+            return;
+        }
+        let Some(argument) = call.arguments.first() else {
+            return;
+        };
+        if argument.as_expression().is_some_and(|argument| is_static_expression(argument, ctx)) {
+            return;
+        }
+        ctx.diagnostic(no_eval_with_expression_diagnostic(argument.span()));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "eval('console.log(1)');",
+        "eval(`console.log(${1 + 2})`);",
+        "eval();",
+        "const code = 'console.log(1)'; eval(code);",
+        "let code = 'a'; code += 'b'; eval(code);",
+        "function foo(eval) { eval(code); }",
+        "foo.eval(code);",
+        "new Function(code);",
+    ];
+
+    let fail = vec![
+        "eval(code);",
+        "function run(code) { eval(code); }",
+        "eval(`console.log(${message})`);",
+        "eval('console.log(' + message + ')');",
+        "let code = 'a'; code = input; eval(code);",
+        "let code; for (code of codes) { eval(code); }",
+        "const code = getCode(); eval(code);",
+        "eval(...codes);",
+        "(eval)(code);",
+    ];
+
+    Tester::new(NoEvalWithExpression::NAME, NoEvalWithExpression::CATEGORY, pass, fail)
+        .with_security_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,226 @@
+use oxc_ast::{
+    ast::{AssignmentOperator, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::is_static_expression, AstNode};
+
+fn no_unsanitized_property_diagnostic(property: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unsafe assignment to {property}"))
+        .with_help("HTML built from untrusted data allows cross-site scripting. Escape the data, or use `textContent` for text.")
+        .with_label(span)
+}
+
+/// The tagged templates which escape HTML by default.
+const DEFAULT_TAGGED_TEMPLATES: [&str; 2] = ["Sanitizer.escapeHTML", "escapeHTML"];
+
+/// The methods which return sanitized HTML by default.
+const DEFAULT_METHODS: [&str; 2] = ["Sanitizer.unwrapSafeHTML", "unwrapSafeHTML"];
+
+#[derive(Debug, Clone)]
+pub struct NoUnsanitizedProperty(Box<NoUnsanitizedPropertyConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoUnsanitizedPropertyConfig {
+    tagged_templates: Vec<CompactStr>,
+    methods: Vec<CompactStr>,
+}
+
+impl Default for NoUnsanitizedProperty {
+    fn default() -> Self {
+        Self(Box::new(NoUnsanitizedPropertyConfig {
+            tagged_templates: DEFAULT_TAGGED_TEMPLATES.into_iter().map(CompactStr::from).collect(),
+            methods: DEFAULT_METHODS.into_iter().map(CompactStr::from).collect(),
+        }))
+    }
+}
+
+impl std::ops::Deref for NoUnsanitizedProperty {
+    type Target = NoUnsanitizedPropertyConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow assigning HTML which is not a literal to `innerHTML` or `outerHTML`, unless it
+    /// is escaped or sanitized.
+    ///
+    /// HTML is escaped with the `escapeHTML` and `Sanitizer.escapeHTML` tagged templates, and
+    /// sanitized with the `unwrapSafeHTML` and `Sanitizer.unwrapSafeHTML` methods. Variables
+    /// are followed to their values, so variables which only hold literals are allowed.
+    ///
+    /// This is the `property` rule of `eslint-plugin-no-unsanitized`, which is also enabled
+    /// as `no-unsanitized/property`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When the HTML contains data from outside of the source code, e.g. user input, an
+    /// attacker can inject scripts into the page, also known as cross-site scripting (XSS).
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// element.innerHTML = `<p>${comment}</p>`;
+    /// element.outerHTML += html;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// element.innerHTML = '<p>Loading...</p>';
+    /// element.innerHTML = escapeHTML`<p>${comment}</p>`;
+    /// element.textContent = comment;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "security/no-unsanitized-property": ["error", {
+    ///     "escape": { "taggedTemplates": ["html"], "methods": ["DOMPurify.sanitize"] }
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// `escape.taggedTemplates` and `escape.methods` are the tagged templates and methods which
+    /// escape or sanitize HTML, instead of the default ones.
+    NoUnsanitizedProperty,
+    suspicious
+);
+
+impl Rule for NoUnsanitizedProperty {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let escape = value.get(0).and_then(|config| config.get("escape"));
+        let get_names = |key: &str, defaults: &[&str]| -> Vec<CompactStr> {
+            match escape.and_then(|escape| escape.get(key)).and_then(serde_json::Value::as_array) {
+                Some(names) => names
+                    .iter()
+                    .filter_map(serde_json::Value::as_str)
+                    .map(CompactStr::from)
+                    .collect(),
+                None => defaults.iter().copied().map(CompactStr::from).collect(),
+            }
+        };
+        Self(Box::new(NoUnsanitizedPropertyConfig {
+            tagged_templates: get_names("taggedTemplates", &DEFAULT_TAGGED_TEMPLATES),
+            methods: get_names("methods", &DEFAULT_METHODS),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AssignmentExpression(assign) = node.kind() else {
+            return;
+        };
+        if !matches!(
+            assign.operator,
+            AssignmentOperator::Assign
+                | AssignmentOperator::Addition
+                | AssignmentOperator::LogicalOr
+                | AssignmentOperator::LogicalAnd
+                | AssignmentOperator::LogicalNullish
+        ) {
+            return;
+        }
+        let Some(member) = assign.left.as_member_expression() else {
+            return;
+        };
+        let Some(property) = member.static_property_name() else {
+            return;
+        };
+        if !matches!(property, "innerHTML" | "outerHTML") {
+            return;
+        }
+        if self.is_safe_html(&assign.right, ctx) {
+            return;
+        }
+        ctx.diagnostic(no_unsanitized_property_diagnostic(property, assign.span));
+    }
+}
+
+impl NoUnsanitizedProperty {
+    fn is_safe_html<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+        match expr.get_inner_expression() {
+            Expression::TaggedTemplateExpression(tagged) => {
+                is_allowed_callee(&tagged.tag, &self.tagged_templates)
+            }
+            Expression::CallExpression(call) => is_allowed_callee(&call.callee, &self.methods),
+            // `html = foo ? escapeHTML`...` : ''`
+            Expression::ConditionalExpression(conditional) => {
+                self.is_safe_html(&conditional.consequent, ctx)
+                    && self.is_safe_html(&conditional.alternate, ctx)
+            }
+            expr => is_static_expression(expr, ctx),
+        }
+    }
+}
+
+/// Whether `callee` is one of `names`, e.g. `Sanitizer.escapeHTML`.
+fn is_allowed_callee(callee: &Expression, names: &[CompactStr]) -> bool {
+    let callee = callee.get_inner_expression();
+    names.iter().any(|name| match name.rsplit_once('.') {
+        Some((object, property)) => callee.as_member_expression().is_some_and(|member| {
+            member.static_property_name() == Some(property)
+                && matches!(member.object().get_inner_expression(), Expression::Identifier(ident) if ident.name == object)
+        }),
+        None => callee.is_specific_id(name),
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("element.innerHTML = '<p>Loading...</p>';", None),
+        ("element.innerHTML = `<p>${1 + 1}</p>`;", None),
+        ("element.innerHTML = '';", None),
+        ("element.textContent = comment;", None),
+        ("element.innerHTML = escapeHTML`<p>${comment}</p>`;", None),
+        ("element.outerHTML = Sanitizer.escapeHTML`<p>${comment}</p>`;", None),
+        ("element.innerHTML = Sanitizer.unwrapSafeHTML(html);", None),
+        ("element.innerHTML = loading ? '<p>Loading...</p>' : escapeHTML`${comment}`;", None),
+        ("const html = '<p>Loading...</p>'; element.innerHTML = html;", None),
+        ("let html = '<ul>'; html += '</ul>'; element.innerHTML += html;", None),
+        ("element['innerHTML'] = '<br>';", None),
+        ("element.innerHTML == html;", None),
+        ("element[property] = html;", None),
+        (
+            "element.innerHTML = DOMPurify.sanitize(html);",
+            Some(json!([{ "escape": { "methods": ["DOMPurify.sanitize"] } }])),
+        ),
+        (
+            "element.innerHTML = html`<p>${comment}</p>`;",
+            Some(json!([{ "escape": { "taggedTemplates": ["html"] } }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("element.innerHTML = comment;", None),
+        ("element.innerHTML = `<p>${comment}</p>`;", None),
+        ("element.innerHTML = '<p>' + comment + '</p>';", None),
+        ("element.outerHTML += html;", None),
+        ("element['innerHTML'] = html;", None),
+        ("function render(html) { document.body.innerHTML = html; }", None),
+        ("let html = '<ul>'; html += items.join(''); element.innerHTML = html;", None),
+        ("element.innerHTML = foo`<p>${comment}</p>`;", None),
+        ("element.innerHTML = escape(comment);", None),
+        ("element.innerHTML = loading ? '<p>Loading...</p>' : comment;", None),
+        ("element.innerHTML = DOMPurify.sanitize(html);", None),
+        (
+            "element.innerHTML = escapeHTML`<p>${comment}</p>`;",
+            Some(json!([{ "escape": { "taggedTemplates": ["html"] } }])),
+        ),
+    ];
+
+    Tester::new(NoUnsanitizedProperty::NAME, NoUnsanitizedProperty::CATEGORY, pass, fail)
+        .with_security_plugin(true)
+        .test_and_snapshot();
+}
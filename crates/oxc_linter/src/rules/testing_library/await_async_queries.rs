@@ -0,0 +1,180 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{get_testing_library_query_call, is_testing_library_file, PROMISE_STATIC_METHODS},
+    AstNode,
+};
+
+fn await_async_queries_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Promise returned from async query `{name}` must be handled."))
+        .with_help("Await the query, return it, or chain it with `.then()`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AwaitAsyncQueries;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that the promises returned by async queries (`findBy*` and `findAllBy*`) are
+    /// handled: awaited, returned, chained with `.then()`, or passed to `Promise.all()` and
+    /// similar. A query assigned to a variable is handled when the variable is.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An async query which is not awaited does not wait for the element to appear, so the
+    /// test continues with a pending promise and its assertions never fail.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// test('shows the title', () => {
+    ///   render(<App />);
+    ///   screen.findByText('Title');
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// test('shows the title', async () => {
+    ///   render(<App />);
+    ///   await screen.findByText('Title');
+    /// });
+    /// ```
+    AwaitAsyncQueries,
+    correctness
+);
+
+impl Rule for AwaitAsyncQueries {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(query) = get_testing_library_query_call(call) else {
+            return;
+        };
+        if !query.variant.is_async() || is_promise_handled(node, ctx) {
+            return;
+        }
+        ctx.diagnostic(await_async_queries_diagnostic(
+            &query.name_with_variant(query.variant),
+            call.callee.span(),
+        ));
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        is_testing_library_file(ctx)
+    }
+}
+
+/// Whether the promise created at `node` is awaited, returned, chained, or passed to a method of
+/// `Promise`, directly or through a variable.
+fn is_promise_handled(node: &AstNode, ctx: &LintContext) -> bool {
+    let mut current = node;
+    loop {
+        let Some(parent) = ctx.nodes().parent_node(current.id()) else {
+            return false;
+        };
+        let span = current.kind().span();
+        match parent.kind() {
+            AstKind::ParenthesizedExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSNonNullExpression(_)
+            | AstKind::ArrayExpressionElement(_)
+            | AstKind::ArrayExpression(_)
+            | AstKind::Argument(_) => {}
+            AstKind::AwaitExpression(_) | AstKind::ReturnStatement(_) => return true,
+            // `() => screen.findByText('foo')`
+            AstKind::ExpressionStatement(_) => {
+                return ctx.nodes().parent_node(parent.id()).is_some_and(|body| {
+                    matches!(
+                        ctx.nodes().parent_kind(body.id()),
+                        Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+                    )
+                });
+            }
+            // `screen.findByText('foo').then(...)`
+            AstKind::MemberExpression(member) => {
+                return member.object().span() == span
+                    && matches!(member.static_property_name(), Some("then" | "catch" | "finally"));
+            }
+            // `expect(screen.findByText('foo')).resolves`
+            AstKind::CallExpression(call) if call.callee.is_specific_id("expect") => {
+                return matches!(
+                    ctx.nodes().parent_kind(parent.id()),
+                    Some(AstKind::MemberExpression(member))
+                        if matches!(member.static_property_name(), Some("resolves" | "rejects"))
+                );
+            }
+            // `Promise.all([screen.findByText('foo')])`
+            AstKind::CallExpression(call) => {
+                let is_promise_method = matches!(
+                    &call.callee,
+                    Expression::StaticMemberExpression(member)
+                        if member.object.is_specific_id("Promise")
+                            && PROMISE_STATIC_METHODS.contains(member.property.name.as_str())
+                );
+                if !is_promise_method {
+                    return false;
+                }
+            }
+            // `const promise = screen.findByText('foo'); await promise;`
+            AstKind::VariableDeclarator(declarator) => {
+                let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                    return false;
+                };
+                return ctx.symbols().get_resolved_references(ident.symbol_id()).any(|reference| {
+                    is_promise_handled(ctx.nodes().get_node(reference.node_id()), ctx)
+                });
+            }
+            _ => return false,
+        }
+        current = parent;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "test('foo', async () => { await screen.findByText('foo'); });",
+        "test('foo', async () => { await findByRole('button'); });",
+        "test('foo', async () => { const button = await screen.findAllByRole('button'); });",
+        "test('foo', () => { return screen.findByText('foo'); });",
+        "test('foo', () => screen.findByText('foo'));",
+        "test('foo', () => { screen.findByText('foo').then((element) => {}); });",
+        "test('foo', async () => { await Promise.all([screen.findByText('foo'), findByRole('button')]); });",
+        "test('foo', async () => { const promise = screen.findByText('foo'); await promise; });",
+        "test('foo', async () => { await expect(screen.findByText('foo')).resolves.toBeInTheDocument(); });",
+        "test('foo', async () => { await within(container).findByText('foo'); });",
+        // sync queries
+        "test('foo', () => { screen.getByText('foo'); screen.queryAllByRole('button'); });",
+        // not a query
+        "test('foo', () => { screen.findByFoo('foo'); findElement(); });",
+    ];
+
+    let fail = vec![
+        "test('foo', () => { screen.findByText('foo'); });",
+        "test('foo', () => { findByRole('button'); });",
+        "test('foo', () => { const buttons = screen.findAllByRole('button'); });",
+        "test('foo', async () => { const promise = screen.findByText('foo'); expect(promise).toBeTruthy(); });",
+        "test('foo', () => { within(container).findByText('foo'); });",
+        "test('foo', () => { Promise.all([screen.findByText('foo')]); });",
+    ];
+
+    Tester::new(AwaitAsyncQueries::NAME, AwaitAsyncQueries::CATEGORY, pass, fail)
+        .with_testing_library_plugin(true)
+        .change_rule_path_extension("test.tsx")
+        .test_and_snapshot();
+}
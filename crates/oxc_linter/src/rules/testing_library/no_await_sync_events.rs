@@ -0,0 +1,187 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    ast::{Argument, Expression, ObjectPropertyKind},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::is_testing_library_file,
+    AstNode,
+};
+
+fn no_await_sync_events_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` is sync event and does not need to be awaited."))
+        .with_help("Remove the `await`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoAwaitSyncEvents {
+    /// Check `fireEvent` (the `fire-event` module).
+    fire_event: bool,
+    /// Check `userEvent` (the `user-event` module), which is only sync before v14.
+    user_event: bool,
+}
+
+impl Default for NoAwaitSyncEvents {
+    fn default() -> Self {
+        Self { fire_event: true, user_event: false }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow awaiting the sync events of `fireEvent`, and of `userEvent` before v14.
+    ///
+    /// `userEvent` calls with a non-zero `delay` option are async, and are allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Awaiting a sync event has no effect, and misleads readers into thinking that the test
+    /// waits for something to happen after the event.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// test('submits the form', async () => {
+    ///   await fireEvent.click(screen.getByText('Submit'));
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// test('submits the form', () => {
+    ///   fireEvent.click(screen.getByText('Submit'));
+    /// });
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "testing-library/no-await-sync-events": ["error", { "eventModules": ["fire-event", "user-event"] }]
+    /// }
+    /// ```
+    ///
+    /// `eventModules` lists the event modules to check, and defaults to `["fire-event"]`.
+    NoAwaitSyncEvents,
+    correctness
+);
+
+impl Rule for NoAwaitSyncEvents {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(modules) = value
+            .get(0)
+            .and_then(|config| config.get("eventModules"))
+            .and_then(serde_json::Value::as_array)
+        else {
+            return Self::default();
+        };
+        let has_module = |name: &str| modules.iter().any(|module| module.as_str() == Some(name));
+        Self { fire_event: has_module("fire-event"), user_event: has_module("user-event") }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AwaitExpression(await_expr) = node.kind() else {
+            return;
+        };
+        let Expression::CallExpression(call) = await_expr.argument.get_inner_expression() else {
+            return;
+        };
+        let (module, name) = match call.callee.get_inner_expression() {
+            // `fireEvent(element, event)`
+            Expression::Identifier(ident) if ident.name == "fireEvent" => {
+                ("fireEvent", Cow::Borrowed("fireEvent"))
+            }
+            Expression::StaticMemberExpression(member) => match &member.object {
+                Expression::Identifier(object)
+                    if matches!(object.name.as_str(), "fireEvent" | "userEvent") =>
+                {
+                    (
+                        object.name.as_str(),
+                        Cow::Owned(format!("{}.{}", object.name, member.property.name)),
+                    )
+                }
+                _ => return,
+            },
+            _ => return,
+        };
+        let is_checked = match module {
+            "fireEvent" => self.fire_event,
+            _ => self.user_event && !has_delay(&call.arguments),
+        };
+        if is_checked {
+            ctx.diagnostic(no_await_sync_events_diagnostic(&name, await_expr.span));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        is_testing_library_file(ctx)
+    }
+}
+
+/// Whether the last argument is an options object with a `delay` other than `0`, e.g.
+/// `userEvent.type(input, 'foo', { delay: 10 })`.
+fn has_delay(arguments: &[Argument]) -> bool {
+    let Some(Argument::ObjectExpression(options)) = arguments.last() else {
+        return false;
+    };
+    options.properties.iter().any(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property) => {
+            property.key.is_specific_static_name("delay")
+                && !matches!(
+                    property.value.get_inner_expression(),
+                    Expression::NumericLiteral(delay) if delay.value == 0.0
+                )
+        }
+        ObjectPropertyKind::SpreadProperty(_) => false,
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("test('foo', () => { fireEvent.click(button); });", None),
+        ("test('foo', () => { fireEvent(button, new MouseEvent('click')); });", None),
+        ("test('foo', async () => { await userEvent.click(button); });", None),
+        ("test('foo', async () => { await screen.findByText('foo'); await waitFor(() => {}); });", None),
+        (
+            "test('foo', async () => { await userEvent.type(input, 'foo', { delay: 10 }); });",
+            Some(json!([{ "eventModules": ["user-event"] }])),
+        ),
+        (
+            "test('foo', async () => { await fireEvent.click(button); });",
+            Some(json!([{ "eventModules": ["user-event"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("test('foo', async () => { await fireEvent.click(button); });", None),
+        ("test('foo', async () => { await fireEvent.change(input, { target: { value: 'a' } }); });", None),
+        ("test('foo', async () => { await fireEvent(button, new MouseEvent('click')); });", None),
+        (
+            "test('foo', async () => { await userEvent.click(button); });",
+            Some(json!([{ "eventModules": ["fire-event", "user-event"] }])),
+        ),
+        (
+            "test('foo', async () => { await userEvent.type(input, 'foo', { delay: 0 }); });",
+            Some(json!([{ "eventModules": ["user-event"] }])),
+        ),
+    ];
+
+    Tester::new(NoAwaitSyncEvents::NAME, NoAwaitSyncEvents::CATEGORY, pass, fail)
+        .with_testing_library_plugin(true)
+        .change_rule_path_extension("test.tsx")
+        .test_and_snapshot();
+}
@@ -0,0 +1,156 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use phf::phf_set;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::is_testing_library_imported,
+    AstNode,
+};
+
+fn no_node_access_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Avoid direct Node access. Prefer using the methods from Testing Library.")
+        .with_help("Query the element by its role, label or text instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNodeAccess {
+    allow_container_first_child: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow DOM traversal through properties and methods of nodes, e.g. `parentElement`,
+    /// `children` or `querySelector()`, in files which import Testing Library.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Testing Library encourages testing the component the way users interact with it.
+    /// Traversing the DOM couples the test to the markup, which users do not see, and breaks
+    /// when the markup changes.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import { screen } from '@testing-library/react';
+    ///
+    /// const button = screen.getByText('Submit').closest('button');
+    /// const items = screen.getByRole('list').children;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import { screen, within } from '@testing-library/react';
+    ///
+    /// const button = screen.getByRole('button', { name: 'Submit' });
+    /// const items = within(screen.getByRole('list')).getAllByRole('listitem');
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "testing-library/no-node-access": ["error", { "allowContainerFirstChild": true }]
+    /// }
+    /// ```
+    ///
+    /// `allowContainerFirstChild` allows `container.firstChild`, e.g. to snapshot the rendered
+    /// component. Defaults to `false`.
+    NoNodeAccess,
+    restriction
+);
+
+/// Properties and methods of DOM nodes which return other nodes.
+const NODE_ACCESS_PROPERTIES: phf::Set<&'static str> = phf_set! {
+    "childElementCount",
+    "childNodes",
+    "children",
+    "closest",
+    "firstChild",
+    "firstElementChild",
+    "getElementById",
+    "getElementsByClassName",
+    "getElementsByName",
+    "getElementsByTagName",
+    "getElementsByTagNameNS",
+    "lastChild",
+    "lastElementChild",
+    "nextElementSibling",
+    "nextSibling",
+    "parentElement",
+    "parentNode",
+    "previousElementSibling",
+    "previousSibling",
+    "querySelector",
+    "querySelectorAll",
+};
+
+impl Rule for NoNodeAccess {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_container_first_child: value
+                .get(0)
+                .and_then(|config| config.get("allowContainerFirstChild"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::MemberExpression(member) = node.kind() else {
+            return;
+        };
+        let Some((span, name)) = member.static_property_info() else {
+            return;
+        };
+        if !NODE_ACCESS_PROPERTIES.contains(name) {
+            return;
+        }
+        if self.allow_container_first_child
+            && name == "firstChild"
+            && matches!(member.object(), Expression::Identifier(ident) if ident.name == "container")
+        {
+            return;
+        }
+        ctx.diagnostic(no_node_access_diagnostic(span));
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        is_testing_library_imported(ctx)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import { screen } from '@testing-library/react'; screen.getByRole('button', { name: 'Submit' });", None),
+        ("import { screen, within } from '@testing-library/react'; within(screen.getByRole('list')).getAllByRole('listitem');", None),
+        ("import { render } from '@testing-library/react'; const { container } = render(<Foo />); expect(container).toMatchSnapshot();", None),
+        ("import { render } from '@testing-library/react'; const { container } = render(<Foo />); expect(container.firstChild).toMatchSnapshot();", Some(json!([{ "allowContainerFirstChild": true }]))),
+        // Testing Library is not imported
+        ("const button = document.getElementById('submit').closest('form');", None),
+    ];
+
+    let fail = vec![
+        ("import { screen } from '@testing-library/react'; screen.getByText('Submit').closest('button');", None),
+        ("import { screen } from '@testing-library/react'; const items = screen.getByRole('list').children;", None),
+        ("import { render } from '@testing-library/react'; const { container } = render(<Foo />); container.querySelector('.foo');", None),
+        ("import { render } from '@testing-library/react'; const { container } = render(<Foo />); expect(container.firstChild).toMatchSnapshot();", None),
+        ("import { screen } from '@testing-library/dom'; screen.getByText('foo').parentElement.nextSibling;", None),
+        ("import { render } from '@testing-library/react'; document.getElementById('foo');", None),
+    ];
+
+    Tester::new(NoNodeAccess::NAME, NoNodeAccess::CATEGORY, pass, fail)
+        .with_testing_library_plugin(true)
+        .change_rule_path_extension("test.tsx")
+        .test_and_snapshot();
+}
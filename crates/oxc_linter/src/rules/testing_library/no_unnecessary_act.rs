@@ -0,0 +1,230 @@
+use oxc_ast::{
+    ast::{Argument, Expression, FunctionBody, Statement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{is_testing_library_file, parse_testing_library_query},
+    AstNode,
+};
+
+fn testing_library_util_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Avoid wrapping Testing Library util calls in `act`.")
+        .with_help("Testing Library already wraps renders, events and queries in `act`.")
+        .with_label(span)
+}
+
+fn empty_function_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Avoid wrapping empty function in `act`.")
+        .with_help("Remove the `act` call.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoUnnecessaryAct {
+    is_strict: bool,
+}
+
+impl Default for NoUnnecessaryAct {
+    fn default() -> Self {
+        Self { is_strict: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow wrapping Testing Library utils, e.g. `render`, `fireEvent` and queries, or empty
+    /// functions in `act()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Testing Library already wraps its utils in `act()`, so wrapping them again is noise,
+    /// and is often a sign of silencing an `act()` warning instead of waiting for the update
+    /// which causes it.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// act(() => {
+    ///   fireEvent.click(screen.getByText('Submit'));
+    /// });
+    ///
+    /// await act(async () => {});
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// fireEvent.click(screen.getByText('Submit'));
+    ///
+    /// act(() => {
+    ///   jest.advanceTimersByTime(1000);
+    /// });
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "testing-library/no-unnecessary-act": ["error", { "isStrict": false }]
+    /// }
+    /// ```
+    ///
+    /// With `isStrict` (the default), an `act()` which calls any Testing Library util is
+    /// reported. Otherwise, it is only reported when all of its statements are Testing Library
+    /// util calls.
+    NoUnnecessaryAct,
+    suspicious
+);
+
+/// Testing Library utils which are already wrapped in `act()`, or which do not update the
+/// component.
+const TESTING_LIBRARY_UTILS: [&str; 9] = [
+    "cleanup",
+    "fireEvent",
+    "render",
+    "renderHook",
+    "screen",
+    "userEvent",
+    "waitFor",
+    "waitForElementToBeRemoved",
+    "within",
+];
+
+impl Rule for NoUnnecessaryAct {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            is_strict: value
+                .get(0)
+                .and_then(|config| config.get("isStrict"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let is_act = match &call.callee {
+            Expression::Identifier(ident) => ident.name == "act",
+            // `TestUtils.act()`
+            Expression::StaticMemberExpression(member) => member.property.name == "act",
+            _ => false,
+        };
+        if !is_act {
+            return;
+        }
+        let body = match call.arguments.first() {
+            Some(Argument::ArrowFunctionExpression(arrow)) => &arrow.body,
+            Some(Argument::FunctionExpression(function)) => match &function.body {
+                Some(body) => body,
+                None => return,
+            },
+            _ => return,
+        };
+
+        if body.statements.is_empty() {
+            ctx.diagnostic(empty_function_diagnostic(call.span));
+            return;
+        }
+        let mut util_calls = get_statement_expressions(body).map(is_testing_library_util_call);
+        let is_unnecessary = if self.is_strict {
+            util_calls.any(|is_util_call| is_util_call)
+        } else {
+            util_calls.all(|is_util_call| is_util_call)
+        };
+        if is_unnecessary {
+            ctx.diagnostic(testing_library_util_diagnostic(call.span));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        is_testing_library_file(ctx)
+    }
+}
+
+/// Returns the expression of each statement in `body`, or `None` for statements other than
+/// expression statements and returns.
+fn get_statement_expressions<'a, 'b>(
+    body: &'b FunctionBody<'a>,
+) -> impl Iterator<Item = Option<&'b Expression<'a>>> {
+    body.statements.iter().map(|statement| match statement {
+        Statement::ExpressionStatement(statement) => Some(&statement.expression),
+        Statement::ReturnStatement(statement) => statement.argument.as_ref(),
+        _ => None,
+    })
+}
+
+/// Whether `expr` calls a Testing Library util, e.g. `fireEvent.click(button)`,
+/// `await userEvent.type(input, 'foo')` or `screen.getByText('foo')`.
+fn is_testing_library_util_call(expr: Option<&Expression>) -> bool {
+    let Some(mut expr) = expr.map(Expression::get_inner_expression) else {
+        return false;
+    };
+    if let Expression::AwaitExpression(await_expr) = expr {
+        expr = await_expr.argument.get_inner_expression();
+    }
+    let Expression::CallExpression(call) = expr else {
+        return false;
+    };
+    let mut callee = call.callee.get_inner_expression();
+    loop {
+        match callee {
+            Expression::Identifier(ident) => {
+                return TESTING_LIBRARY_UTILS.contains(&ident.name.as_str())
+                    || parse_testing_library_query(&ident.name).is_some();
+            }
+            Expression::StaticMemberExpression(member) => {
+                callee = member.object.get_inner_expression();
+            }
+            // `within(element).getByText('foo')`
+            Expression::CallExpression(call) => callee = call.callee.get_inner_expression(),
+            _ => return false,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("act(() => { jest.advanceTimersByTime(1000); });", None),
+        ("act(() => { result.current.increment(); });", None),
+        ("await act(async () => { await promise; });", None),
+        ("act(() => stuffThatDoesNotUseRTL());", None),
+        (
+            "act(() => { fireEvent.click(button); jest.runAllTimers(); });",
+            Some(json!([{ "isStrict": false }])),
+        ),
+        // not `act`
+        ("React.startTransition(() => { fireEvent.click(button); });", None),
+    ];
+
+    let fail = vec![
+        ("act(() => { fireEvent.click(screen.getByText('Submit')); });", None),
+        ("act(() => { render(<Foo />); });", None),
+        ("act(() => render(<Foo />));", None),
+        ("await act(async () => { await userEvent.click(button); });", None),
+        ("act(() => { within(screen.getByRole('list')).getByText('foo'); });", None),
+        ("act(() => { getByText('foo'); });", None),
+        ("act(() => { fireEvent.click(button); jest.runAllTimers(); });", None),
+        ("TestUtils.act(() => { render(<Foo />); });", None),
+        ("act(() => {});", None),
+        ("await act(async function () {});", None),
+        ("act(() => { fireEvent.click(button); });", Some(json!([{ "isStrict": false }]))),
+    ];
+
+    Tester::new(NoUnnecessaryAct::NAME, NoUnnecessaryAct::CATEGORY, pass, fail)
+        .with_testing_library_plugin(true)
+        .change_rule_path_extension("test.tsx")
+        .test_and_snapshot();
+}
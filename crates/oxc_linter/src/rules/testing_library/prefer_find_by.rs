@@ -0,0 +1,228 @@
+use oxc_ast::{
+    ast::{Argument, CallExpression, Expression, FunctionBody, Statement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{get_testing_library_query_call, is_testing_library_file, QueryVariant},
+    AstNode,
+};
+
+fn prefer_find_by_diagnostic(find_query: &str, query: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Prefer `{find_query}` query over using `waitFor` + `{query}`."))
+        .with_help(format!("Use `await {find_query}(...)`, which waits for the element to appear."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferFindBy;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Suggest using `findBy*` queries instead of waiting for `getBy*` or `queryBy*` queries
+    /// with `waitFor`, e.g. `await waitFor(() => screen.getByText('foo'))` or
+    /// `await waitFor(() => expect(screen.queryByText('foo')).toBeInTheDocument())`.
+    ///
+    /// The fix is only available for queries of an object, e.g. `screen`, and when `waitFor`
+    /// has no options.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `findBy*` queries are the combination of `waitFor` and `getBy*` queries, and are shorter
+    /// and easier to read.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const button = await waitFor(() => screen.getByRole('button'));
+    /// await waitFor(() => expect(screen.queryByText('foo')).toBeInTheDocument());
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const button = await screen.findByRole('button');
+    /// await screen.findByText('foo');
+    /// ```
+    PreferFindBy,
+    style,
+    conditional_fix
+);
+
+/// Matchers which assert that the element exists, e.g. `expect(query).toBeInTheDocument()`.
+const PRESENCE_MATCHERS: [&str; 3] = ["toBeDefined", "toBeInTheDocument", "toBeTruthy"];
+
+impl Rule for PreferFindBy {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AwaitExpression(await_expr) = node.kind() else {
+            return;
+        };
+        let Expression::CallExpression(wait_for) = await_expr.argument.get_inner_expression()
+        else {
+            return;
+        };
+        if !wait_for.callee.is_specific_id("waitFor") {
+            return;
+        }
+        let body = match wait_for.arguments.first() {
+            Some(Argument::ArrowFunctionExpression(arrow)) => &arrow.body,
+            Some(Argument::FunctionExpression(function)) => match &function.body {
+                Some(body) => body,
+                None => return,
+            },
+            _ => return,
+        };
+        let Some(query_call) = get_single_expression(body).and_then(get_waited_query_call) else {
+            return;
+        };
+        let Some(query) = get_testing_library_query_call(query_call) else {
+            return;
+        };
+        let find_variant = match query.variant {
+            QueryVariant::Get | QueryVariant::Query => QueryVariant::Find,
+            QueryVariant::GetAll | QueryVariant::QueryAll => QueryVariant::FindAll,
+            QueryVariant::Find | QueryVariant::FindAll => return,
+        };
+        let find_query = query.name_with_variant(find_variant);
+        let diagnostic = prefer_find_by_diagnostic(
+            &find_query,
+            &query.name_with_variant(query.variant),
+            wait_for.span,
+        );
+
+        // `findBy*` is not in scope for destructured queries, and `waitFor` options would have
+        // to be merged into the query options.
+        let Expression::StaticMemberExpression(member) = query_call.callee.get_inner_expression()
+        else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+        if wait_for.arguments.len() > 1 {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            let before_name = Span::new(query_call.span.start, member.property.span.start);
+            let after_name = Span::new(member.property.span.end, query_call.span.end);
+            let replacement = format!(
+                "{}{find_query}{}",
+                fixer.source_range(before_name),
+                fixer.source_range(after_name)
+            );
+            fixer.replace(wait_for.span, replacement)
+        });
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        is_testing_library_file(ctx)
+    }
+}
+
+/// Returns the expression returned by a function with a single statement.
+fn get_single_expression<'a, 'b>(body: &'b FunctionBody<'a>) -> Option<&'b Expression<'a>> {
+    match body.statements.as_slice() {
+        [Statement::ExpressionStatement(statement)] => Some(&statement.expression),
+        [Statement::ReturnStatement(statement)] => statement.argument.as_ref(),
+        _ => None,
+    }
+}
+
+/// Returns the query call which is waited for: `screen.getByText('foo')`, or
+/// `expect(screen.getByText('foo')).toBeInTheDocument()`.
+fn get_waited_query_call<'a, 'b>(expr: &'b Expression<'a>) -> Option<&'b CallExpression<'a>> {
+    let Expression::CallExpression(call) = expr.get_inner_expression() else {
+        return None;
+    };
+    if get_testing_library_query_call(call).is_some() {
+        return Some(call);
+    }
+    // `expect(query).toBeInTheDocument()`
+    let Expression::StaticMemberExpression(matcher) = &call.callee else {
+        return None;
+    };
+    if !PRESENCE_MATCHERS.contains(&matcher.property.name.as_str()) {
+        return None;
+    }
+    let Expression::CallExpression(expect) = &matcher.object else {
+        return None;
+    };
+    if !expect.callee.is_specific_id("expect") {
+        return None;
+    }
+    match expect.arguments.first() {
+        Some(Argument::CallExpression(query_call))
+            if get_testing_library_query_call(query_call)
+                .is_some_and(|query| !query.variant.is_async()) =>
+        {
+            Some(query_call)
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const button = await screen.findByRole('button');",
+        "await waitFor(() => expect(mockFn).toHaveBeenCalled());",
+        "await waitFor(() => { screen.getByText('foo'); fireEvent.click(button); });",
+        "await waitFor(() => expect(screen.queryByText('foo')).not.toBeInTheDocument());",
+        "await waitFor(() => expect(screen.getByText('foo')).toHaveTextContent('foo'));",
+        "await waitFor(() => screen.findByText('foo'));",
+        // not awaited
+        "waitFor(() => screen.getByText('foo'));",
+    ];
+
+    let fail = vec![
+        "const button = await waitFor(() => screen.getByRole('button'));",
+        "await waitFor(() => screen.queryAllByText('foo'));",
+        "await waitFor(() => { return screen.getByLabelText('Name', { selector: 'input' }); });",
+        "await waitFor(function () { return within(list).getAllByRole('listitem'); });",
+        "await waitFor(() => expect(screen.queryByText('foo')).toBeInTheDocument());",
+        "await waitFor(() => expect(screen.getByText('foo')).toBeTruthy());",
+        "const { getByText } = render(<Foo />); await waitFor(() => getByText('foo'));",
+        "await waitFor(() => screen.getByText('foo'), { timeout: 1000 });",
+    ];
+
+    let fix = vec![
+        (
+            "const button = await waitFor(() => screen.getByRole('button'));",
+            "const button = await screen.findByRole('button');",
+            None,
+        ),
+        (
+            "await waitFor(() => screen.queryAllByText('foo'));",
+            "await screen.findAllByText('foo');",
+            None,
+        ),
+        (
+            "await waitFor(() => { return screen.getByLabelText('Name', { selector: 'input' }); });",
+            "await screen.findByLabelText('Name', { selector: 'input' });",
+            None,
+        ),
+        (
+            "await waitFor(function () { return within(list).getAllByRole('listitem'); });",
+            "await within(list).findAllByRole('listitem');",
+            None,
+        ),
+        (
+            "await waitFor(() => expect(screen.queryByText('foo')).toBeInTheDocument());",
+            "await screen.findByText('foo');",
+            None,
+        ),
+    ];
+
+    Tester::new(PreferFindBy::NAME, PreferFindBy::CATEGORY, pass, fail)
+        .expect_fix(fix)
+        .with_testing_library_plugin(true)
+        .change_rule_path_extension("test.tsx")
+        .test_and_snapshot();
+}
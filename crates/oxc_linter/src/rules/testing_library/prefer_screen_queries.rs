@@ -0,0 +1,176 @@
+use oxc_ast::{
+    ast::{Argument, CallExpression, Expression, IdentifierReference, ObjectPropertyKind},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{get_testing_library_query_call, is_testing_library_file},
+    AstNode,
+};
+
+fn prefer_screen_queries_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Avoid destructuring queries from `render` result, use `screen.{name}` instead."
+    ))
+    .with_help(
+        "`screen` queries the whole document, and does not need to be kept in sync with `render`.",
+    )
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferScreenQueries;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Suggest using the queries of `screen` instead of the queries returned by `render`.
+    ///
+    /// Queries of `within()`, and of `render` results with a custom `container` or `baseElement`,
+    /// are allowed since they are bound to another element.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Destructuring queries from `render` means keeping the destructuring in sync with the
+    /// queries used by the test, while `screen` is always available and queries the same
+    /// `document.body`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const { getByText } = render(<Component />);
+    /// getByText('foo');
+    ///
+    /// const utils = render(<Component />);
+    /// utils.getByText('foo');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// render(<Component />);
+    /// screen.getByText('foo');
+    ///
+    /// within(screen.getByRole('dialog')).getByText('foo');
+    /// ```
+    PreferScreenQueries,
+    style
+);
+
+impl Rule for PreferScreenQueries {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(query) = get_testing_library_query_call(call) else {
+            return;
+        };
+        let is_bound_query = match call.callee.get_inner_expression() {
+            // `getByText()`, destructured from `render()` or `within()`
+            Expression::Identifier(ident) => match get_declarator_init(ident, ctx) {
+                Some(init) => is_bound_queries(init),
+                // imports and parameters
+                None => true,
+            },
+            Expression::StaticMemberExpression(member) => {
+                match member.object.get_inner_expression() {
+                    Expression::Identifier(object) if object.name == "screen" => true,
+                    Expression::Identifier(object) => {
+                        get_declarator_init(object, ctx).is_some_and(is_bound_queries)
+                    }
+                    object => is_bound_queries(object),
+                }
+            }
+            _ => true,
+        };
+        if !is_bound_query {
+            ctx.diagnostic(prefer_screen_queries_diagnostic(
+                &query.name_with_variant(query.variant),
+                call.callee.span(),
+            ));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        is_testing_library_file(ctx)
+    }
+}
+
+/// Returns the initializer of the variable which `ident` refers to, e.g. `render(<Foo />)` for
+/// `getByText` in `const { getByText } = render(<Foo />)`.
+fn get_declarator_init<'a>(
+    ident: &IdentifierReference,
+    ctx: &LintContext<'a>,
+) -> Option<&'a Expression<'a>> {
+    let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id()?;
+    let declaration = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+    match declaration.kind() {
+        AstKind::VariableDeclarator(declarator) => declarator.init.as_ref(),
+        _ => None,
+    }
+}
+
+/// Whether the queries of `expr` are bound to a custom element: `within(element)`, or
+/// `render(ui, { container })`.
+fn is_bound_queries(expr: &Expression) -> bool {
+    let Expression::CallExpression(call) = expr.get_inner_expression() else {
+        return false;
+    };
+    if call.callee.is_specific_id("within") || call.callee.is_specific_id("getQueriesForElement") {
+        return true;
+    }
+    call.callee.is_specific_id("render") && has_custom_container(call)
+}
+
+/// `render(ui, { container })` or `render(ui, { baseElement })`
+fn has_custom_container(call: &CallExpression) -> bool {
+    let Some(Argument::ObjectExpression(options)) = call.arguments.get(1) else {
+        return false;
+    };
+    options.properties.iter().any(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property) => {
+            property.key.is_specific_static_name("container")
+                || property.key.is_specific_static_name("baseElement")
+        }
+        ObjectPropertyKind::SpreadProperty(_) => false,
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "render(<Foo />); screen.getByText('foo');",
+        "render(<Foo />); await screen.findAllByRole('button');",
+        "within(screen.getByRole('dialog')).getByText('foo');",
+        "const section = within(screen.getByRole('region')); section.queryByText('foo');",
+        "const { getByText } = within(screen.getByRole('region')); getByText('foo');",
+        "const { getByText } = render(<Foo />, { container: table }); getByText('foo');",
+        "const utils = render(<Foo />, { baseElement: document.body }); utils.getByText('foo');",
+        "import { getByText } from '@testing-library/dom'; getByText(container, 'foo');",
+        "function assertTitle(getByText) { getByText('foo'); }",
+        // not a query
+        "const { getFoo } = render(<Foo />); getFoo('foo');",
+        "const { container } = render(<Foo />); container.querySelector('div');",
+    ];
+
+    let fail = vec![
+        "const { getByText } = render(<Foo />); getByText('foo');",
+        "const { findAllByRole } = render(<Foo />); await findAllByRole('button');",
+        "const utils = render(<Foo />); utils.queryByLabelText('foo');",
+        "render(<Foo />).getByText('foo');",
+        "const { getByText } = renderWithProviders(<Foo />); getByText('foo');",
+        "component.getByTestId('foo');",
+    ];
+
+    Tester::new(PreferScreenQueries::NAME, PreferScreenQueries::CATEGORY, pass, fail)
+        .with_testing_library_plugin(true)
+        .change_rule_path_extension("test.tsx")
+        .test_and_snapshot();
+}
@@ -0,0 +1,392 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    ast::{BindingPattern, BindingPatternKind, Expression, ObjectExpression, UnaryOperator},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_define_props_call, get_vue_component_option, get_vue_runtime_props,
+        is_vue_component_object,
+    },
+    AstNode,
+};
+
+fn no_mutating_props_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected mutation of \"{name}\" prop."))
+        .with_help("Props are owned by the parent component. Emit an event to ask the parent to change the value, or copy the prop into local state.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMutatingProps;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow mutating the props of a Vue component, through the `props` object of
+    /// `defineProps()` or `setup(props)`, props destructured from them, or `this` in the
+    /// Options API.
+    ///
+    /// Assignments, `++`/`--`, `delete`, and calls of array methods which mutate the array,
+    /// e.g. `props.list.push(item)`, are reported.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Props form a one-way data flow from the parent component. Mutating a prop in the child
+    /// is overwritten the next time the parent re-renders, and mutating a nested object or
+    /// array changes the parent's state behind its back.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const props = defineProps({ count: Number, list: Array });
+    /// props.count++;
+    /// props.list.push(1);
+    ///
+    /// export default {
+    ///   props: ['value'],
+    ///   methods: {
+    ///     clear() {
+    ///       this.value = '';
+    ///     },
+    ///   },
+    /// };
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const props = defineProps({ count: Number });
+    /// const emit = defineEmits(['update:count']);
+    /// emit('update:count', props.count + 1);
+    /// ```
+    NoMutatingProps,
+    correctness
+);
+
+/// Array methods which mutate the array.
+const MUTATING_ARRAY_METHODS: [&str; 9] =
+    ["copyWithin", "fill", "pop", "push", "reverse", "shift", "sort", "splice", "unshift"];
+
+impl Rule for NoMutatingProps {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(declarator) => {
+                if declarator
+                    .init
+                    .as_ref()
+                    .is_some_and(|init| get_define_props_call(init, ctx).is_some())
+                {
+                    check_props_binding(&declarator.id, ctx);
+                }
+            }
+            AstKind::ObjectExpression(object) if is_vue_component_object(node, ctx) => {
+                check_component(object, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Which props a binding refers to.
+enum PropsBinding<'a> {
+    /// The object of all props, e.g. `props` in `setup(props)`.
+    All,
+    /// A single prop, e.g. `foo` in `const { foo } = defineProps()`.
+    Prop(Cow<'a, str>),
+}
+
+fn check_props_binding(pattern: &BindingPattern, ctx: &LintContext) {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => {
+            check_symbol(ident.symbol_id(), &PropsBinding::All, ctx);
+        }
+        BindingPatternKind::ObjectPattern(object) => {
+            for property in &object.properties {
+                let Some(name) = property.key.static_name() else {
+                    continue;
+                };
+                let binding = match &property.value.kind {
+                    BindingPatternKind::BindingIdentifier(ident) => ident,
+                    BindingPatternKind::AssignmentPattern(pattern) => {
+                        let BindingPatternKind::BindingIdentifier(ident) = &pattern.left.kind
+                        else {
+                            continue;
+                        };
+                        ident
+                    }
+                    _ => continue,
+                };
+                check_symbol(binding.symbol_id(), &PropsBinding::Prop(name), ctx);
+            }
+            if let Some(rest) = &object.rest {
+                if let BindingPatternKind::BindingIdentifier(ident) = &rest.argument.kind {
+                    check_symbol(ident.symbol_id(), &PropsBinding::All, ctx);
+                }
+            }
+        }
+        BindingPatternKind::AssignmentPattern(pattern) => check_props_binding(&pattern.left, ctx),
+        BindingPatternKind::ArrayPattern(_) => {}
+    }
+}
+
+fn check_symbol(symbol_id: SymbolId, binding: &PropsBinding, ctx: &LintContext) {
+    for reference in ctx.symbols().get_resolved_references(symbol_id) {
+        let node = ctx.nodes().get_node(reference.node_id());
+        let Some((path, span)) = find_mutation(node, ctx) else {
+            continue;
+        };
+        let name = match binding {
+            PropsBinding::All => match path.first() {
+                Some(Some(name)) => name.as_ref(),
+                _ => continue,
+            },
+            PropsBinding::Prop(name) => name.as_ref(),
+        };
+        ctx.diagnostic(no_mutating_props_diagnostic(name, span));
+    }
+}
+
+/// Check `setup(props)`, and `this.foo` in functions of the component options, e.g. in
+/// `methods`, `computed` and lifecycle hooks.
+fn check_component<'a>(object: &ObjectExpression<'a>, ctx: &LintContext<'a>) {
+    let setup_params = match get_vue_component_option(object, "setup") {
+        Some(Expression::FunctionExpression(setup)) => Some(&setup.params),
+        Some(Expression::ArrowFunctionExpression(setup)) => Some(&setup.params),
+        _ => None,
+    };
+    if let Some(props) = setup_params.and_then(|params| params.items.first()) {
+        check_props_binding(&props.pattern, ctx);
+    }
+
+    let Some(props) = get_vue_component_option(object, "props") else {
+        return;
+    };
+    let props = get_vue_runtime_props(props);
+    if props.is_empty() {
+        return;
+    }
+
+    for node in ctx.nodes().iter() {
+        let AstKind::ThisExpression(this) = node.kind() else {
+            continue;
+        };
+        if !object.span.contains_inclusive(this.span) || !is_this_of_component(node, object, ctx) {
+            continue;
+        }
+        let Some(member_node) = ctx.nodes().parent_node(node.id()) else {
+            continue;
+        };
+        let AstKind::MemberExpression(member) = member_node.kind() else {
+            continue;
+        };
+        let Some(name) = member.static_property_name() else {
+            continue;
+        };
+        if !props.iter().any(|prop| prop.name == name) {
+            continue;
+        }
+        if let Some((_, span)) = find_mutation(member_node, ctx) {
+            ctx.diagnostic(no_mutating_props_diagnostic(name, span));
+        }
+    }
+}
+
+/// Whether `this` refers to the component instance: it is in a function which is a component
+/// option, e.g. `mounted() {}`, or an entry of an option, e.g. `methods: { foo() {} }`.
+fn is_this_of_component(node: &AstNode, object: &ObjectExpression, ctx: &LintContext) -> bool {
+    let Some(function) = ctx.nodes().ancestors(node.id()).find(|node| {
+        matches!(node.kind(), AstKind::Function(_) | AstKind::Class(_) | AstKind::StaticBlock(_))
+    }) else {
+        return false;
+    };
+    if !matches!(function.kind(), AstKind::Function(_)) {
+        return false;
+    }
+    let mut objects = ctx
+        .nodes()
+        .ancestors(function.id())
+        .skip(1)
+        .filter(|node| !matches!(node.kind(), AstKind::ParenthesizedExpression(_)));
+    let is_object = |node: Option<&AstNode>, ctx_object: &ObjectExpression| matches!(node.map(AstNode::kind), Some(AstKind::ObjectExpression(object)) if object.span == ctx_object.span);
+    // `mounted() {}`
+    if !matches!(objects.next().map(AstNode::kind), Some(AstKind::ObjectProperty(_))) {
+        return false;
+    }
+    let parent_object = objects.next();
+    if is_object(parent_object, object) {
+        return true;
+    }
+    // `methods: { foo() {} }`
+    matches!(objects.next().map(AstNode::kind), Some(AstKind::ObjectProperty(_)))
+        && is_object(objects.next(), object)
+}
+
+/// Follows the member accesses on the value of `node`, and returns the accessed property names
+/// along with the span of the expression which mutates the value, e.g. `["foo", "bar"]` for
+/// `props.foo.bar = 1` where `node` is `props`.
+///
+/// Property names of computed member expressions are `None`.
+fn find_mutation<'a>(
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(Vec<Option<Cow<'a, str>>>, Span)> {
+    let mut current = node;
+    let mut path = vec![];
+    loop {
+        let parent = ctx.nodes().parent_node(current.id())?;
+        match parent.kind() {
+            AstKind::MemberExpression(member)
+                if member.object().span() == current.kind().span() =>
+            {
+                path.push(member.static_property_name().map(Cow::Borrowed));
+            }
+            AstKind::ParenthesizedExpression(_)
+            | AstKind::TSNonNullExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_) => {}
+            _ => break,
+        }
+        current = parent;
+    }
+
+    let parent = ctx.nodes().parent_node(current.id())?;
+    let span = match parent.kind() {
+        AstKind::SimpleAssignmentTarget(_) => {
+            let target_parent = ctx.nodes().parent_node(parent.id())?;
+            match target_parent.kind() {
+                AstKind::UpdateExpression(update) => update.span,
+                AstKind::AssignmentTarget(_) => {
+                    match ctx.nodes().parent_kind(target_parent.id())? {
+                        AstKind::AssignmentExpression(assignment) => assignment.span,
+                        _ => return None,
+                    }
+                }
+                _ => return None,
+            }
+        }
+        AstKind::UnaryExpression(unary) if unary.operator == UnaryOperator::Delete => unary.span,
+        AstKind::CallExpression(call) if call.callee.span() == current.kind().span() => {
+            let Some(Some(method)) = path.pop() else {
+                return None;
+            };
+            if !MUTATING_ARRAY_METHODS.contains(&method.as_ref()) {
+                return None;
+            }
+            call.span
+        }
+        _ => return None,
+    };
+    Some((path, span))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const props = defineProps({ count: Number }); const doubled = props.count * 2;",
+        "const props = defineProps(['list']); const sorted = [...props.list].sort();",
+        "const props = defineProps(['list']); const copy = props.list.slice(); copy.push(1);",
+        "const { count } = defineProps(['count']); const local = count + 1;",
+        "const props = defineProps(['count']); let count = props.count; count++;",
+        "const state = reactive({ count: 0 }); state.count++;",
+        "
+        export default {
+          props: ['value'],
+          data() {
+            return { local: this.value };
+          },
+          methods: {
+            clear() {
+              this.local = '';
+            },
+          },
+        };
+        ",
+        "
+        export default {
+          props: ['value'],
+          setup(props) {
+            const local = ref(props.value);
+            local.value = '';
+          },
+        };
+        ",
+        // `this` of a nested function is not the component
+        "
+        export default {
+          props: ['value'],
+          methods: {
+            clear() {
+              const object = { reset() { this.value = ''; } };
+            },
+          },
+        };
+        ",
+        // not a component
+        "const options = { props: ['value'], methods: { clear() { this.value = ''; } } };",
+        // local binding which is not the compiler macro
+        "import { defineProps } from './utils'; const props = defineProps(); props.count++;",
+    ];
+
+    let fail = vec![
+        "const props = defineProps({ count: Number }); props.count++;",
+        "const props = defineProps({ count: Number }); props.count = 1;",
+        "const props = defineProps({ count: Number }); props.count += 1;",
+        "const props = defineProps(['user']); props.user.name = 'foo';",
+        "const props = defineProps(['user']); delete props.user.name;",
+        "const props = defineProps(['list']); props.list.push(1);",
+        "const props = withDefaults(defineProps<{ list?: number[] }>(), { list: () => [] }); props.list.sort();",
+        "const { user } = defineProps(['user']); user.name = 'foo';",
+        "const { list = [] } = defineProps(['list']); list.splice(0, 1);",
+        "
+        export default {
+          props: ['value'],
+          methods: {
+            clear() {
+              this.value = '';
+            },
+          },
+        };
+        ",
+        "
+        export default defineComponent({
+          props: { items: Array },
+          mounted() {
+            this.items.push(1);
+          },
+        });
+        ",
+        "
+        export default {
+          props: ['value'],
+          setup(props) {
+            props.value = '';
+          },
+        };
+        ",
+        "
+        export default {
+          props: ['user'],
+          setup({ user }) {
+            const update = () => {
+              user.name = 'foo';
+            };
+          },
+        };
+        ",
+    ];
+
+    Tester::new(NoMutatingProps::NAME, NoMutatingProps::CATEGORY, pass, fail)
+        .with_vue_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,205 @@
+use oxc_ast::{
+    ast::{
+        AssignmentOperator, BindingPatternKind, Expression, UnaryOperator, VariableDeclarationKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::Reference;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::get_vue_import_name, AstNode};
+
+fn no_ref_as_operand_diagnostic(method: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Must use `.value` to read or write the value wrapped by `{method}()`."
+    ))
+    .with_help("Add `.value` to access the wrapped value.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRefAsOperand;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow using a ref object created by `ref()`, `computed()`, `shallowRef()`,
+    /// `customRef()` or `toRef()` from `vue` as an operand, e.g. in arithmetic, comparisons,
+    /// conditions and template literals, instead of its `.value`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A ref is an object wrapping the value, so using it as an operand is almost always a
+    /// mistake: the object is always truthy, and `count + 1` concatenates `"[object Object]"`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import { ref } from 'vue';
+    ///
+    /// const count = ref(0);
+    /// const doubled = count * 2;
+    /// if (count) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import { ref } from 'vue';
+    ///
+    /// const count = ref(0);
+    /// const doubled = count.value * 2;
+    /// if (count.value) {}
+    /// ```
+    NoRefAsOperand,
+    correctness,
+    fix
+);
+
+/// Functions of `vue` which return a ref object.
+const REF_FUNCTIONS: [&str; 5] = ["computed", "customRef", "ref", "shallowRef", "toRef"];
+
+impl Rule for NoRefAsOperand {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclarator(declarator) = node.kind() else {
+            return;
+        };
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            return;
+        };
+        let Some(Expression::CallExpression(call)) =
+            declarator.init.as_ref().map(Expression::get_inner_expression)
+        else {
+            return;
+        };
+        let Expression::Identifier(callee) = &call.callee else {
+            return;
+        };
+        let Some(method) = get_vue_import_name(callee, ctx) else {
+            return;
+        };
+        if !REF_FUNCTIONS.contains(&method) {
+            return;
+        }
+
+        let symbol_id = ident.symbol_id();
+        match declarator.kind {
+            VariableDeclarationKind::Const => {}
+            // a reassigned variable may no longer hold the ref
+            VariableDeclarationKind::Let => {
+                if ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write) {
+                    return;
+                }
+            }
+            _ => return,
+        }
+
+        for reference in ctx.symbols().get_resolved_references(symbol_id) {
+            let reference_node = ctx.nodes().get_node(reference.node_id());
+            let span = reference_node.kind().span();
+            if is_operand(reference_node, span, ctx) {
+                ctx.diagnostic_with_fix(no_ref_as_operand_diagnostic(method, span), |fixer| {
+                    fixer.insert_text_after_range(span, ".value")
+                });
+            }
+        }
+    }
+}
+
+/// Whether the reference at `node` with `span` is used as an operand.
+fn is_operand(node: &AstNode, span: Span, ctx: &LintContext) -> bool {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+        return false;
+    };
+    match parent.kind() {
+        AstKind::BinaryExpression(_) | AstKind::TemplateLiteral(_) => true,
+        AstKind::UnaryExpression(unary) => !matches!(
+            unary.operator,
+            UnaryOperator::Typeof | UnaryOperator::Void | UnaryOperator::Delete
+        ),
+        AstKind::LogicalExpression(logical) => logical.left.span() == span,
+        AstKind::ConditionalExpression(conditional) => conditional.test.span() == span,
+        AstKind::IfStatement(statement) => statement.test.span() == span,
+        AstKind::WhileStatement(statement) => statement.test.span() == span,
+        AstKind::DoWhileStatement(statement) => statement.test.span() == span,
+        AstKind::ForStatement(statement) => {
+            statement.test.as_ref().is_some_and(|test| test.span() == span)
+        }
+        AstKind::SwitchStatement(statement) => statement.discriminant.span() == span,
+        // `count++`, `count += 1`
+        AstKind::SimpleAssignmentTarget(_) => {
+            let Some(target_parent) = ctx.nodes().parent_node(parent.id()) else {
+                return false;
+            };
+            match target_parent.kind() {
+                AstKind::UpdateExpression(_) => true,
+                AstKind::AssignmentTarget(_) => matches!(
+                    ctx.nodes().parent_kind(target_parent.id()),
+                    Some(AstKind::AssignmentExpression(assignment))
+                        if assignment.operator != AssignmentOperator::Assign
+                ),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import { ref } from 'vue'; const count = ref(0); console.log(count.value + 1);",
+        "import { ref } from 'vue'; const count = ref(0); const doubled = count.value * 2;",
+        "import { ref } from 'vue'; const count = ref(0); if (count.value) {}",
+        "import { ref } from 'vue'; const count = ref(0); count.value++;",
+        "import { ref } from 'vue'; const count = ref(0); const other = count; fn(count);",
+        "import { ref } from 'vue'; const count = ref(0); typeof count;",
+        "import { ref } from 'vue'; const count = ref(0); const value = other || count;",
+        "import { computed } from 'vue'; const doubled = computed(() => 2); `${doubled.value}`;",
+        // reassigned
+        "import { ref } from 'vue'; let count = ref(0); count = 1; count + 1;",
+        // not imported from vue
+        "import { ref } from './ref'; const count = ref(0); count + 1;",
+        "const count = ref(0); count + 1;",
+        "import { reactive } from 'vue'; const state = reactive({}); if (state) {}",
+    ];
+
+    let fail = vec![
+        "import { ref } from 'vue'; const count = ref(0); count + 1;",
+        "import { ref } from 'vue'; const count = ref(0); const doubled = count * 2;",
+        "import { ref } from 'vue'; const count = ref(0); count++;",
+        "import { ref } from 'vue'; const count = ref(0); count += 1;",
+        "import { ref } from 'vue'; const count = ref(0); -count;",
+        "import { ref } from 'vue'; const ok = ref(false); if (ok) {}",
+        "import { ref } from 'vue'; const ok = ref(false); const value = ok ? 1 : 2;",
+        "import { ref } from 'vue'; const ok = ref(false); const value = ok || 'default';",
+        "import { ref } from 'vue'; const ok = ref(false); while (ok) {}",
+        "import { ref } from 'vue'; const kind = ref('a'); switch (kind) {}",
+        "import { ref } from 'vue'; const name = ref(''); const message = `Hello ${name}`;",
+        "import { ref as r } from 'vue'; let count = r(0); count + 1;",
+        "import { computed } from 'vue'; const doubled = computed(() => 2); doubled === 2;",
+        "import { shallowRef, toRef } from 'vue'; const a = shallowRef(0); const b = toRef(props, 'b'); a + b;",
+    ];
+
+    let fix = vec![
+        (
+            "import { ref } from 'vue'; const count = ref(0); count + 1;",
+            "import { ref } from 'vue'; const count = ref(0); count.value + 1;",
+            None,
+        ),
+        (
+            "import { ref } from 'vue'; const ok = ref(false); if (!ok) {}",
+            "import { ref } from 'vue'; const ok = ref(false); if (!ok.value) {}",
+            None,
+        ),
+    ];
+
+    Tester::new(NoRefAsOperand::NAME, NoRefAsOperand::CATEGORY, pass, fail)
+        .expect_fix(fix)
+        .with_vue_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,258 @@
+use oxc_ast::{
+    ast::{AssignmentTarget, BindingPattern, BindingPatternKind, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{ScopeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_define_props_call, get_vue_component_option, is_vue_component_object},
+    AstNode,
+};
+
+fn destructuring_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Destructuring the `props` will cause the value to lose reactivity.")
+        .with_help("Use `toRefs(props)` or `toRef(props, 'name')`, or access the props where the value is needed.")
+        .with_label(span)
+}
+
+fn getting_value_diagnostic(scope: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Getting a value from the `props` in root scope of `{scope}` will cause the value to lose reactivity."
+    ))
+    .with_help("Use `toRef(props, 'name')` or `computed(() => props.name)` to keep the value reactive.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSetupPropsReactivityLoss;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow destructuring the props, or reading a prop into a variable, in the root scope of
+    /// `setup()` or `<script setup>`.
+    ///
+    /// Destructuring `defineProps()` directly, e.g. `const { foo } = defineProps()`, is allowed,
+    /// since Vue 3.5 compiles it to reactive accesses.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The `props` object is reactive, but the values read from it in the root scope are plain
+    /// values which are read once when the component is set up, and are not updated when the
+    /// parent passes new props.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// export default {
+    ///   setup({ count }) {},
+    /// };
+    ///
+    /// const props = defineProps(['count']);
+    /// const { count } = props;
+    /// const doubled = props.count * 2;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// export default {
+    ///   setup(props) {
+    ///     const { count } = toRefs(props);
+    ///   },
+    /// };
+    ///
+    /// const props = defineProps(['count']);
+    /// const doubled = computed(() => props.count * 2);
+    /// ```
+    NoSetupPropsReactivityLoss,
+    suspicious
+);
+
+impl Rule for NoSetupPropsReactivityLoss {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(declarator) => {
+                let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                    return;
+                };
+                let root_scope_id = ctx.scopes().root_scope_id();
+                if node.scope_id() == root_scope_id
+                    && declarator
+                        .init
+                        .as_ref()
+                        .is_some_and(|init| get_define_props_call(init, ctx).is_some())
+                {
+                    check_props(ident.symbol_id(), root_scope_id, "<script setup>", ctx);
+                }
+            }
+            AstKind::ObjectExpression(object) if is_vue_component_object(node, ctx) => {
+                let (params, scope_id) = match get_vue_component_option(object, "setup") {
+                    Some(Expression::FunctionExpression(setup)) => {
+                        (&setup.params, setup.scope_id.get())
+                    }
+                    Some(Expression::ArrowFunctionExpression(setup)) => {
+                        (&setup.params, setup.scope_id.get())
+                    }
+                    _ => return,
+                };
+                let (Some(props), Some(scope_id)) = (params.items.first(), scope_id) else {
+                    return;
+                };
+                check_setup_param(&props.pattern, scope_id, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_setup_param(pattern: &BindingPattern, scope_id: ScopeId, ctx: &LintContext) {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => {
+            check_props(ident.symbol_id(), scope_id, "setup()", ctx);
+        }
+        BindingPatternKind::ObjectPattern(_) | BindingPatternKind::ArrayPattern(_) => {
+            ctx.diagnostic(destructuring_diagnostic(pattern.span()));
+        }
+        BindingPatternKind::AssignmentPattern(assignment) => {
+            check_setup_param(&assignment.left, scope_id, ctx);
+        }
+    }
+}
+
+/// Check the references to the props object `symbol_id` which are in `scope_id`, but not in
+/// nested functions.
+fn check_props(symbol_id: SymbolId, scope_id: ScopeId, scope: &str, ctx: &LintContext) {
+    for reference in ctx.symbols().get_resolved_references(symbol_id) {
+        let node = ctx.nodes().get_node(reference.node_id());
+        if get_function_scope(node.scope_id(), ctx) != scope_id {
+            continue;
+        }
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+            continue;
+        };
+        let span = node.kind().span();
+        match parent.kind() {
+            // `const { foo } = props`
+            AstKind::VariableDeclarator(declarator)
+                if declarator.init.as_ref().is_some_and(|init| init.span() == span)
+                    && declarator.id.kind.is_destructuring_pattern() =>
+            {
+                ctx.diagnostic(destructuring_diagnostic(declarator.id.span()));
+            }
+            // `({ foo } = props)`
+            AstKind::AssignmentExpression(assignment)
+                if assignment.right.span() == span
+                    && matches!(
+                        assignment.left,
+                        AssignmentTarget::ObjectAssignmentTarget(_)
+                            | AssignmentTarget::ArrayAssignmentTarget(_)
+                    ) =>
+            {
+                ctx.diagnostic(destructuring_diagnostic(assignment.left.span()));
+            }
+            // `const foo = props.foo`, `foo = props.foo`
+            AstKind::MemberExpression(member) if member.object().span() == span => {
+                let value_span = member.span();
+                let is_read_into_variable = match ctx.nodes().parent_kind(parent.id()) {
+                    Some(AstKind::VariableDeclarator(declarator)) => {
+                        declarator.init.as_ref().is_some_and(|init| init.span() == value_span)
+                    }
+                    Some(AstKind::AssignmentExpression(assignment)) => {
+                        assignment.right.span() == value_span
+                    }
+                    _ => false,
+                };
+                if is_read_into_variable {
+                    ctx.diagnostic(getting_value_diagnostic(scope, value_span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the scope of the function (or the program) which `scope_id` is in.
+fn get_function_scope(scope_id: ScopeId, ctx: &LintContext) -> ScopeId {
+    ctx.scopes()
+        .ancestors(scope_id)
+        .find(|&scope_id| {
+            let flags = ctx.scopes().get_flags(scope_id);
+            flags.is_function() || flags.is_top()
+        })
+        .unwrap_or(scope_id)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "
+        export default {
+          setup(props) {
+            const { count } = toRefs(props);
+            const doubled = computed(() => props.count * 2);
+            watch(() => props.count, () => {});
+          },
+        };
+        ",
+        "
+        export default {
+          setup(props) {
+            const update = () => {
+              const { count } = props;
+              const value = props.count;
+            };
+          },
+        };
+        ",
+        "const props = defineProps(['count']); const doubled = computed(() => props.count * 2);",
+        "const props = defineProps(['count']); const count = toRef(props, 'count');",
+        "const { count } = defineProps(['count']);",
+        "const props = defineProps(['count']); function update() { const { count } = props; }",
+        // not a component
+        "const options = { setup({ count }) {} };",
+        // not the props
+        "const props = useProps(); const { count } = props;",
+        "function setup(props) { const { count } = props; }",
+    ];
+
+    let fail = vec![
+        "export default { setup({ count }) {} };",
+        "export default { setup: ({ count }) => {} };",
+        "export default defineComponent({ setup([count]) {} });",
+        "
+        export default {
+          setup(props) {
+            const { count } = props;
+          },
+        };
+        ",
+        "
+        export default {
+          setup(props) {
+            const count = props.count;
+            let doubled;
+            doubled = props.count;
+            if (props.count) {
+              const value = props.value;
+            }
+          },
+        };
+        ",
+        "const props = defineProps(['count']); const { count } = props;",
+        "const props = defineProps(['count']); let count; ({ count } = props);",
+        "const props = defineProps(['count']); const count = props.count;",
+        "const props = withDefaults(defineProps<{ count?: number }>(), { count: 0 }); const count = props.count;",
+    ];
+
+    Tester::new(NoSetupPropsReactivityLoss::NAME, NoSetupPropsReactivityLoss::CATEGORY, pass, fail)
+        .with_vue_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,259 @@
+use oxc_ast::{
+    ast::{Argument, BindingPatternKind, Expression, ObjectExpression, TSLiteral, TSType},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_vue_component_option, get_vue_runtime_props, get_vue_type_props,
+        is_vue_component_object, is_vue_macro_call, VueProp, VuePropKind,
+    },
+    AstNode,
+};
+
+fn require_default_prop_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Prop '{name}' requires default value to be set."))
+        .with_help("Set a `default` for the prop, or mark it as `required: true`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireDefaultProp;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require a default value for props which are not required, in the `props` option and in
+    /// `defineProps()`. Boolean props are skipped, since they default to `false`.
+    ///
+    /// For type-based declarations, e.g. `defineProps<{ foo?: string }>()`, optional props need
+    /// a default in `withDefaults()` or in the destructuring of the props.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An optional prop without a default is `undefined` when the parent does not pass it, which
+    /// the component has to handle everywhere the prop is used.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// export default {
+    ///   props: {
+    ///     a: Number,
+    ///     b: { type: String, required: false },
+    ///   },
+    /// };
+    ///
+    /// defineProps<{ c?: string }>();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// export default {
+    ///   props: {
+    ///     a: { type: Number, required: true },
+    ///     b: { type: String, default: '' },
+    ///     c: Boolean,
+    ///   },
+    /// };
+    ///
+    /// withDefaults(defineProps<{ d?: string }>(), { d: '' });
+    /// ```
+    RequireDefaultProp,
+    style
+);
+
+impl Rule for RequireDefaultProp {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::CallExpression(call) if is_vue_macro_call(call, "defineProps", ctx) => {
+                if let Some(props) = call.arguments.first().and_then(Argument::as_expression) {
+                    check_runtime_props(&get_vue_runtime_props(props), ctx);
+                } else {
+                    check_type_props(node, &get_vue_type_props(call, ctx), ctx);
+                }
+            }
+            AstKind::ObjectExpression(object) if is_vue_component_object(node, ctx) => {
+                if let Some(props) = get_vue_component_option(object, "props") {
+                    check_runtime_props(&get_vue_runtime_props(props), ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_runtime_props(props: &[VueProp], ctx: &LintContext) {
+    for prop in props {
+        let VuePropKind::Runtime(value) = prop.kind else {
+            continue;
+        };
+        let has_default = match value.get_inner_expression() {
+            Expression::ObjectExpression(options) => {
+                is_required(options)
+                    || get_vue_component_option(options, "default").is_some()
+                    || get_vue_component_option(options, "type").is_some_and(is_boolean_constructor)
+            }
+            value => is_boolean_constructor(value),
+        };
+        if !has_default {
+            ctx.diagnostic(require_default_prop_diagnostic(&prop.name, prop.span));
+        }
+    }
+}
+
+/// `{ required: true }`
+fn is_required(options: &ObjectExpression) -> bool {
+    matches!(
+        get_vue_component_option(options, "required").map(Expression::get_inner_expression),
+        Some(Expression::BooleanLiteral(required)) if required.value
+    )
+}
+
+/// `Boolean` or `[Boolean]`
+fn is_boolean_constructor(ty: &Expression) -> bool {
+    match ty.get_inner_expression() {
+        Expression::Identifier(ident) => ident.name == "Boolean",
+        Expression::ArrayExpression(array) => {
+            array.elements.len() == 1
+                && array.elements[0]
+                    .as_expression()
+                    .is_some_and(|element| element.is_specific_id("Boolean"))
+        }
+        _ => false,
+    }
+}
+
+/// Check the props of `defineProps<Props>()` at `node`, whose defaults are given by
+/// `withDefaults()` or by destructuring the props.
+fn check_type_props(node: &AstNode, props: &[VueProp], ctx: &LintContext) {
+    let mut outer = node;
+    let mut defaults = None;
+    if let Some(AstKind::Argument(_)) = ctx.nodes().parent_kind(node.id()) {
+        if let Some(with_defaults) = ctx.nodes().parent_node(node.id()).and_then(|argument| {
+            ctx.nodes().parent_node(argument.id()).filter(|call| {
+                matches!(call.kind(), AstKind::CallExpression(call) if is_vue_macro_call(call, "withDefaults", ctx))
+            })
+        }) {
+            if let AstKind::CallExpression(call) = with_defaults.kind() {
+                defaults = match call.arguments.get(1) {
+                    Some(Argument::ObjectExpression(defaults)) => Some(&**defaults),
+                    _ => None,
+                };
+            }
+            outer = with_defaults;
+        }
+    }
+    let destructured = match ctx.nodes().parent_kind(outer.id()) {
+        Some(AstKind::VariableDeclarator(declarator)) => match &declarator.id.kind {
+            BindingPatternKind::ObjectPattern(pattern) => Some(pattern),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    for prop in props {
+        let VuePropKind::Type { optional, ty } = prop.kind else {
+            continue;
+        };
+        if !optional || ty.is_some_and(is_boolean_type) {
+            continue;
+        }
+        if defaults.is_some_and(|defaults| get_vue_component_option(defaults, &prop.name).is_some())
+        {
+            continue;
+        }
+        if destructured.is_some_and(|pattern| {
+            pattern.properties.iter().any(|property| {
+                property.key.is_specific_static_name(&prop.name)
+                    && matches!(property.value.kind, BindingPatternKind::AssignmentPattern(_))
+            })
+        }) {
+            continue;
+        }
+        ctx.diagnostic(require_default_prop_diagnostic(&prop.name, prop.span));
+    }
+}
+
+/// `boolean`, or a union of `boolean` with `true`, `false`, `undefined` and `null`.
+fn is_boolean_type(ty: &TSType) -> bool {
+    match ty {
+        TSType::TSBooleanKeyword(_) => true,
+        TSType::TSParenthesizedType(ty) => is_boolean_type(&ty.type_annotation),
+        TSType::TSUnionType(union) => {
+            union.types.iter().any(is_boolean_type)
+                && union.types.iter().all(|ty| {
+                    is_boolean_type(ty)
+                        || matches!(
+                            ty,
+                            TSType::TSUndefinedKeyword(_) | TSType::TSNullKeyword(_)
+                        )
+                        || matches!(ty, TSType::TSLiteralType(literal) if matches!(literal.literal, TSLiteral::BooleanLiteral(_)))
+                })
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "
+        export default {
+          props: {
+            a: { type: Number, required: true },
+            b: { type: Number, default: 0 },
+            c: { type: Object, default() { return {}; } },
+            d: Boolean,
+            e: { type: Boolean },
+            f: [Boolean],
+            g: { type: [Boolean] },
+          },
+        };
+        ",
+        // array syntax has no defaults
+        "export default { props: ['a', 'b'] };",
+        // not a component
+        "const options = { props: { a: Number } };",
+        "defineProps({ a: { type: String, default: '' }, b: { type: String, required: true } });",
+        "defineProps<{ a: string; b?: boolean; c?: boolean | undefined }>();",
+        "withDefaults(defineProps<{ a?: string; b?: number }>(), { a: '', b: 0 });",
+        "const { a = '' } = defineProps<{ a?: string }>();",
+        "interface Props { a?: string } withDefaults(defineProps<Props>(), { a: '' });",
+        "export default defineComponent({ props: { a: { type: String, required: true } } });",
+    ];
+
+    let fail = vec![
+        "
+        export default {
+          props: {
+            a: Number,
+            b: [Number, String],
+            c: { type: Number },
+            d: { type: Number, required: false },
+            e: { type: [Boolean, String] },
+          },
+        };
+        ",
+        "export default defineComponent({ props: { a: String } });",
+        "Vue.component('my-component', { props: { a: String } });",
+        "defineProps({ a: String, b: { type: String, required: false } });",
+        "defineProps<{ a?: string; b?: number | undefined }>();",
+        "withDefaults(defineProps<{ a?: string; b?: number }>(), { a: '' });",
+        "const { a = '', b } = defineProps<{ a?: string; b?: string }>();",
+        "interface Props { a?: string } defineProps<Props>();",
+        "export type Props = { a?: () => void }; defineProps<Props>();",
+    ];
+
+    Tester::new(RequireDefaultProp::NAME, RequireDefaultProp::CATEGORY, pass, fail)
+        .with_vue_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,191 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolFlags;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_vue_component_option, is_vue_component_object, is_vue_macro_call},
+};
+
+fn multiple_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`defineEmits` has been called multiple times.")
+        .with_help("Declare all custom events in a single `defineEmits` call.")
+        .with_label(span)
+}
+
+fn has_type_and_arg_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`defineEmits` has both a type-only emit and an argument.")
+        .with_help(
+            "Declare the custom events either with the type argument or with the runtime argument.",
+        )
+        .with_label(span)
+}
+
+fn referencing_locally_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`defineEmits` are referencing locally declared variables.")
+        .with_help("The argument of `defineEmits` is hoisted out of `setup()`, so it can only reference imports and globals.")
+        .with_label(span)
+}
+
+fn defined_in_both_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Custom events are defined in both `defineEmits` and `export default {}`.")
+        .with_help("Remove the `emits` option, or the declaration in `defineEmits`.")
+        .with_label(span)
+}
+
+fn not_defined_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Custom events are not defined.")
+        .with_help(
+            "Declare the custom events with an argument or a type argument of `defineEmits`.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ValidDefineEmits;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce valid `defineEmits()` compiler macros in `<script setup>`:
+    /// * `defineEmits` is called at most once
+    /// * the events are declared with either a type argument or a runtime argument, not both
+    /// * the runtime argument does not reference variables declared in `<script setup>`
+    /// * the events are not also declared in the `emits` option of `export default {}`
+    /// * the events are declared at all
+    ///
+    /// The `emits` option is only found in the same script, since each `<script>` block of a
+    /// single-file component is linted separately.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The Vue compiler rejects these declarations, or silently uses only one of them.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const events = ['change'];
+    /// defineEmits(events);
+    ///
+    /// defineEmits<{ (e: 'change'): void }>(['change']);
+    ///
+    /// defineEmits();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// defineEmits(['change']);
+    ///
+    /// defineEmits<{ (e: 'change', id: number): void }>();
+    /// ```
+    ValidDefineEmits,
+    correctness
+);
+
+impl Rule for ValidDefineEmits {
+    fn run_once(&self, ctx: &LintContext) {
+        let calls = ctx
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::CallExpression(call) if is_vue_macro_call(call, "defineEmits", ctx) => {
+                    Some(call)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if calls.is_empty() {
+            return;
+        }
+
+        if calls.len() > 1 {
+            for call in &calls {
+                ctx.diagnostic(multiple_diagnostic(call.span));
+            }
+            return;
+        }
+
+        let call = calls[0];
+        let has_emits_option = ctx.nodes().iter().any(|node| {
+            matches!(node.kind(), AstKind::ObjectExpression(object)
+                if is_vue_component_object(node, ctx)
+                    && get_vue_component_option(object, "emits").is_some())
+        });
+
+        let Some(argument) = call.arguments.first() else {
+            if call.type_parameters.is_some() {
+                if has_emits_option {
+                    ctx.diagnostic(defined_in_both_diagnostic(call.span));
+                }
+            } else if !has_emits_option {
+                ctx.diagnostic(not_defined_diagnostic(call.span));
+            }
+            return;
+        };
+
+        if call.type_parameters.is_some() {
+            ctx.diagnostic(has_type_and_arg_diagnostic(call.span));
+            return;
+        }
+        if has_emits_option {
+            ctx.diagnostic(defined_in_both_diagnostic(call.span));
+            return;
+        }
+
+        let argument_span = argument.span();
+        let root_scope_id = ctx.scopes().root_scope_id();
+        for node in ctx.nodes().iter() {
+            let AstKind::IdentifierReference(ident) = node.kind() else {
+                continue;
+            };
+            if !argument_span.contains_inclusive(ident.span) {
+                continue;
+            }
+            let Some(symbol_id) = ctx.symbols().get_reference(ident.reference_id()).symbol_id()
+            else {
+                continue;
+            };
+            if ctx.symbols().get_scope_id(symbol_id) == root_scope_id
+                && !ctx.symbols().get_flags(symbol_id).intersects(SymbolFlags::Import)
+            {
+                ctx.diagnostic(referencing_locally_diagnostic(ident.span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "defineEmits(['change', 'update']);",
+        "defineEmits({ submit: (payload) => typeof payload === 'string' });",
+        "defineEmits<{ (e: 'change', id: number): void }>();",
+        "defineEmits<{ change: [id: number] }>();",
+        "import { EVENTS } from './events'; defineEmits(EVENTS);",
+        "const emit = defineEmits(['change']); emit('change');",
+        // the events are declared by the `emits` option
+        "export default { emits: ['change'] }; defineEmits();",
+        // not the compiler macro
+        "import { defineEmits } from './macros'; defineEmits(); defineEmits();",
+    ];
+
+    let fail = vec![
+        "defineEmits(['change']); defineEmits(['update']);",
+        "defineEmits<{ (e: 'change'): void }>(['change']);",
+        "const events = ['change']; defineEmits(events);",
+        "const validate = (payload) => true; defineEmits({ submit: validate });",
+        "export default { emits: ['change'] }; defineEmits(['change']);",
+        "export default { emits: ['change'] }; defineEmits<{ (e: 'change'): void }>();",
+        "defineEmits();",
+    ];
+
+    Tester::new(ValidDefineEmits::NAME, ValidDefineEmits::CATEGORY, pass, fail)
+        .with_vue_plugin(true)
+        .test_and_snapshot();
+}
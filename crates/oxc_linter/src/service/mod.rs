@@ -7,6 +7,8 @@ use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
 use runtime::Runtime;
 
 use oxc_diagnostics::DiagnosticSender;
+use oxc_resolver::Resolver;
+use oxc_span::VALID_EXTENSIONS;
 
 use crate::Linter;
 
@@ -94,32 +96,77 @@ impl LintService {
             .iter_paths()
             .par_bridge()
             .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        if self.runtime.preloads_modules() {
+            self.runtime.link_importers();
+            self.runtime.iter_paths().par_bridge().for_each_with(&self.runtime, |runtime, path| {
+                runtime.process_preloaded_path(path, tx_error);
+            });
+        }
         tx_error.send(None).unwrap();
     }
 
-    /// For tests
+    /// For tests. Lints `source_text` as the module at `path`; the other paths of the service
+    /// are only loaded from disk as part of the module graph.
     #[cfg(test)]
     pub(crate) fn run_source<'a>(
         &self,
         allocator: &'a oxc_allocator::Allocator,
+        path: &Path,
         source_text: &'a str,
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<crate::Message<'a>> {
-        self.runtime
-            .iter_paths()
-            .flat_map(|path| {
-                let source_type = oxc_span::SourceType::from_path(path).unwrap();
-                self.runtime.init_cache_state(path);
-                self.runtime.process_source(
-                    path,
-                    allocator,
-                    source_text,
-                    source_type,
-                    check_syntax_errors,
-                    tx_error,
-                )
-            })
-            .collect::<Vec<_>>()
+        let source_type = oxc_span::SourceType::from_path(path).unwrap();
+        self.runtime.init_cache_state(path);
+        let messages = self.runtime.process_source(
+            path,
+            allocator,
+            source_text,
+            source_type,
+            check_syntax_errors,
+            tx_error,
+        );
+        if !self.runtime.preloads_modules() {
+            return messages;
+        }
+        for other in self.runtime.iter_paths().filter(|other| ***other != *path) {
+            self.runtime.process_path(other, tx_error);
+        }
+        self.runtime.link_importers();
+        self.runtime.process_source(
+            path,
+            allocator,
+            source_text,
+            source_type,
+            check_syntax_errors,
+            tx_error,
+        )
     }
 }
+
+/// Create the module resolver used for cross-module analysis, e.g. by the import plugin.
+///
+/// When a `tsconfig.json` is given, its path aliases and project references are respected and
+/// `.js` specifiers are also resolved to `.ts` files.
+pub fn create_resolver(tsconfig_path: Option<PathBuf>) -> Resolver {
+    use oxc_resolver::{ResolveOptions, TsconfigOptions, TsconfigReferences};
+    let tsconfig = tsconfig_path.and_then(|path| {
+        path.is_file()
+            .then_some(TsconfigOptions { config_file: path, references: TsconfigReferences::Auto })
+    });
+    let extension_alias = tsconfig.as_ref().map_or_else(Vec::new, |_| {
+        vec![
+            (".js".into(), vec![".js".into(), ".ts".into()]),
+            (".mjs".into(), vec![".mjs".into(), ".mts".into()]),
+            (".cjs".into(), vec![".cjs".into(), ".cts".into()]),
+        ]
+    });
+    Resolver::new(ResolveOptions {
+        extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+        main_fields: vec!["module".into(), "main".into()],
+        condition_names: vec!["module".into(), "import".into()],
+        extension_alias,
+        tsconfig,
+        ..ResolveOptions::default()
+    })
+}
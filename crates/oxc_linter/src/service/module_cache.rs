@@ -56,6 +56,17 @@ impl ModuleCache {
         self.modules.len()
     }
 
+    /// All module records which are resolved so far.
+    pub(super) fn module_records(&self) -> Vec<Arc<ModuleRecord>> {
+        self.modules
+            .iter()
+            .filter_map(|entry| match entry.value() {
+                ModuleState::Resolved(module_record) => Some(Arc::clone(module_record)),
+                ModuleState::Ignored => None,
+            })
+            .collect()
+    }
+
     pub(super) fn init_cache_state(&self, path: &Path) -> bool {
         let (lock, cvar) = {
             let mut state_map = self.cache_state.lock().expect("Failed to lock cache state");
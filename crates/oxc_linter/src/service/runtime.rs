@@ -2,9 +2,12 @@ use std::{
     borrow::Cow,
     ffi::OsStr,
     fs,
-    path::{Path, PathBuf},
+    path::Path,
     rc::Rc,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 
 use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
@@ -15,16 +18,18 @@ use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic}
 use oxc_parser::{ParseOptions, Parser};
 use oxc_resolver::Resolver;
 use oxc_semantic::SemanticBuilder;
-use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_span::SourceType;
 
 use crate::{
     loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
     module_record::ModuleRecord,
+    package_json::PackageJsonCache,
     utils::read_to_string,
     Fixer, Linter, Message,
 };
 
 use super::{
+    create_resolver,
     module_cache::{ModuleCache, ModuleState},
     LintServiceOptions,
 };
@@ -36,47 +41,33 @@ pub struct Runtime {
     pub(super) linter: Linter,
     resolver: Option<Resolver>,
     modules: ModuleCache,
+    /// Load the modules of all paths before linting any of them, so that rules can see which
+    /// modules import the module they lint.
+    preload_modules: bool,
+    /// Set once all modules are preloaded and linked to their importers.
+    modules_linked: AtomicBool,
+    /// The `package.json` files read by rules, which are only cached for this run.
+    package_jsons: Arc<PackageJsonCache>,
 }
 
 impl Runtime {
     pub(super) fn new(linter: Linter, options: LintServiceOptions) -> Self {
         let resolver = options.cross_module.then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
+            create_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
         });
+        let preload_modules = resolver.is_some() && linter.needs_module_graph();
         Self {
             cwd: options.cwd,
             paths: options.paths.iter().cloned().collect(),
             linter,
             resolver,
             modules: ModuleCache::default(),
+            preload_modules,
+            modules_linked: AtomicBool::new(false),
+            package_jsons: Arc::default(),
         }
     }
 
-    fn get_resolver(tsconfig_path: Option<PathBuf>) -> Resolver {
-        use oxc_resolver::{ResolveOptions, TsconfigOptions, TsconfigReferences};
-        let tsconfig = tsconfig_path.and_then(|path| {
-            path.is_file().then_some(TsconfigOptions {
-                config_file: path,
-                references: TsconfigReferences::Auto,
-            })
-        });
-        let extension_alias = tsconfig.as_ref().map_or_else(Vec::new, |_| {
-            vec![
-                (".js".into(), vec![".js".into(), ".ts".into()]),
-                (".mjs".into(), vec![".mjs".into(), ".mts".into()]),
-                (".cjs".into(), vec![".cjs".into(), ".cts".into()]),
-            ]
-        });
-        Resolver::new(ResolveOptions {
-            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
-            main_fields: vec!["module".into(), "main".into()],
-            condition_names: vec!["module".into(), "import".into()],
-            extension_alias,
-            tsconfig,
-            ..ResolveOptions::default()
-        })
-    }
-
     fn get_source_type_and_text(
         path: &Path,
         ext: &str,
@@ -99,14 +90,24 @@ impl Runtime {
         })
     }
 
-    // clippy: the source field is checked and assumed to be less than 4GB, and
-    // we assume that the fix offset will not exceed 2GB in either direction
-    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
     pub(super) fn process_path(&self, path: &Path, tx_error: &DiagnosticSender) {
         if self.init_cache_state(path) {
             return;
         }
+        self.process_uncached_path(path, tx_error);
+    }
 
+    /// Lint a path after all modules were preloaded with [`Runtime::process_path`] and linked
+    /// with [`Runtime::link_importers`].
+    pub(super) fn process_preloaded_path(&self, path: &Path, tx_error: &DiagnosticSender) {
+        debug_assert!(self.modules_linked.load(Ordering::Acquire));
+        self.process_uncached_path(path, tx_error);
+    }
+
+    // clippy: the source field is checked and assumed to be less than 4GB, and
+    // we assume that the fix offset will not exceed 2GB in either direction
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    fn process_uncached_path(&self, path: &Path, tx_error: &DiagnosticSender) {
         let Some(ext) = path.extension().and_then(OsStr::to_str) else {
             self.ignore_path(path);
             return;
@@ -157,6 +158,11 @@ impl Runtime {
                 tx_error,
             );
 
+            // Paths to lint are reported once all modules are preloaded.
+            if self.is_preloading() && self.paths.contains(path) {
+                continue;
+            }
+
             if self.linter.options().fix.is_some() {
                 let fix_result = Fixer::new(source.source_text, messages).fix();
                 if fix_result.fixed {
@@ -173,7 +179,11 @@ impl Runtime {
             }
 
             if !messages.is_empty() {
-                self.ignore_path(path);
+                // Preloaded module records must outlive linting, since other modules refer to
+                // them as their importers.
+                if !self.preload_modules {
+                    self.ignore_path(path);
+                }
                 let errors = messages.into_iter().map(Into::into).collect();
                 let path = path.strip_prefix(&self.cwd).unwrap_or(path);
                 let diagnostics =
@@ -208,7 +218,7 @@ impl Runtime {
             .parse();
 
         if !ret.errors.is_empty() {
-            if self.resolver.is_some() {
+            if self.resolver.is_some() && !self.is_linked() {
                 self.modules.add_resolved_module(path, Arc::new(ModuleRecord::default()));
             }
             return if ret.is_flow_language {
@@ -232,16 +242,41 @@ impl Runtime {
         let mut semantic = semantic_ret.semantic;
         semantic.set_irregular_whitespaces(ret.irregular_whitespaces);
 
-        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        // Preloaded modules are already resolved and linked to their importers, so only the
+        // semantic is rebuilt for linting.
+        if self.is_linked() {
+            if let Some(ModuleState::Resolved(module_record)) =
+                self.modules.get(path).as_deref().cloned()
+            {
+                return self.linter.run_with_package_jsons(
+                    path,
+                    Rc::new(semantic),
+                    module_record,
+                    &self.package_jsons,
+                );
+            }
+        }
+
+        let mut module_record = ModuleRecord::new(path, &ret.module_record, &semantic);
+        module_record.resolved = self.resolver.is_some();
+        // Dynamic imports are only needed to find the importers of a module.
+        if self.preload_modules {
+            module_record.dynamic_imports = ModuleRecord::collect_dynamic_imports(&semantic);
+        }
+        let module_record = Arc::new(module_record);
 
         // If import plugin is enabled.
         if self.resolver.is_some() {
-            self.modules.add_resolved_module(path, Arc::clone(&module_record));
+            if !self.is_linked() {
+                self.modules.add_resolved_module(path, Arc::clone(&module_record));
+            }
+            let dynamic_imports = module_record.dynamic_imports.iter().map(|request| &request.name);
             // Retrieve all dependent modules from this module.
             let dir = path.parent().unwrap();
             module_record
                 .requested_modules
                 .keys()
+                .chain(dynamic_imports)
                 .par_bridge()
                 .map_with(self.resolver.as_ref().unwrap(), |resolver, specifier| {
                     resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
@@ -249,7 +284,11 @@ impl Runtime {
                 .flatten()
                 .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
                     let path = resolution.path();
+                    module_record.resolved_modules.insert(specifier.clone(), path.to_path_buf());
                     self.process_path(path, tx_error);
+                    if !module_record.requested_modules.contains_key(specifier) {
+                        return;
+                    }
                     // Append target_module to loaded_modules
                     if let Some(target_ref) = self.modules.get(path) {
                         if let ModuleState::Resolved(target_module_record) = target_ref.value() {
@@ -291,13 +330,18 @@ impl Runtime {
                     .extend(remote_bindings);
             }
 
-            // Stop if the current module is not marked for lint.
-            if !self.paths.contains(path) {
+            // Stop if the current module is not marked for lint, or is only being preloaded.
+            if !self.paths.contains(path) || self.is_preloading() {
                 return vec![];
             }
         }
 
-        self.linter.run(path, Rc::new(semantic), Arc::clone(&module_record))
+        self.linter.run_with_package_jsons(
+            path,
+            Rc::new(semantic),
+            Arc::clone(&module_record),
+            &self.package_jsons,
+        )
     }
 
     pub(super) fn init_cache_state(&self, path: &Path) -> bool {
@@ -307,6 +351,35 @@ impl Runtime {
         self.modules.init_cache_state(path)
     }
 
+    pub(super) fn preloads_modules(&self) -> bool {
+        self.preload_modules
+    }
+
+    fn is_preloading(&self) -> bool {
+        self.preload_modules && !self.is_linked()
+    }
+
+    fn is_linked(&self) -> bool {
+        self.modules_linked.load(Ordering::Acquire)
+    }
+
+    /// Record each preloaded module in the `importers` of the modules it requests.
+    pub(super) fn link_importers(&self) {
+        for importer in self.modules.module_records() {
+            for entry in &importer.resolved_modules {
+                let Some(ModuleState::Resolved(module)) =
+                    self.modules.get(entry.value()).as_deref().cloned()
+                else {
+                    continue;
+                };
+                module
+                    .importers
+                    .insert(importer.resolved_absolute_path.clone(), Arc::downgrade(&importer));
+            }
+        }
+        self.modules_linked.store(true, Ordering::Release);
+    }
+
     fn ignore_path(&self, path: &Path) {
         self.resolver.is_some().then(|| self.modules.ignore_path(path));
     }
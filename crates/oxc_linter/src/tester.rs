@@ -1,5 +1,6 @@
 use std::{
     env,
+    panic::Location,
     path::{Path, PathBuf},
 };
 
@@ -162,6 +163,9 @@ where
 pub struct Tester {
     rule_name: &'static str,
     rule_category: RuleCategory,
+    /// The plugin of the rule, from the `rules/<plugin>/` directory of the test, to tell apart
+    /// rules of the same name and category in different plugins.
+    plugin_name: Option<&'static str>,
     rule_path: PathBuf,
     expect_pass: Vec<TestCase>,
     expect_fail: Vec<TestCase>,
@@ -178,10 +182,13 @@ pub struct Tester {
     /// See: [insta::Settings::set_snapshot_suffix]
     snapshot_suffix: Option<&'static str>,
     current_working_directory: Box<Path>,
+    /// Other modules linted alongside each test case, e.g. to build the module graph.
+    linted_paths: Vec<PathBuf>,
     plugins: LintPlugins,
 }
 
 impl Tester {
+    #[track_caller]
     pub fn new<T: Into<TestCase>>(
         rule_name: &'static str,
         rule_category: RuleCategory,
@@ -194,9 +201,14 @@ impl Tester {
         let expect_fail = expect_fail.into_iter().map(Into::into).collect::<Vec<_>>();
         let current_working_directory =
             env::current_dir().unwrap().join("fixtures/import").into_boxed_path();
+        let plugin_name = Path::new(Location::caller().file())
+            .parent()
+            .filter(|dir| dir.parent().is_some_and(|rules| rules.ends_with("rules")))
+            .and_then(|dir| dir.file_name()?.to_str());
         Self {
             rule_name,
             rule_category,
+            plugin_name,
             rule_path,
             expect_pass,
             expect_fail,
@@ -204,6 +216,7 @@ impl Tester {
             snapshot: String::new(),
             snapshot_suffix: None,
             current_working_directory,
+            linted_paths: vec![],
             plugins: LintPlugins::default(),
         }
     }
@@ -243,6 +256,14 @@ impl Tester {
         self
     }
 
+    /// Lint these paths (relative to the current working directory) together with each test
+    /// case, so that they are part of the module graph.
+    pub fn with_linted_paths(mut self, paths: &[&str]) -> Self {
+        self.linted_paths =
+            paths.iter().map(|path| self.current_working_directory.join(path)).collect();
+        self
+    }
+
     pub fn with_snapshot_suffix(mut self, suffix: &'static str) -> Self {
         self.snapshot_suffix = Some(suffix);
         self
@@ -283,6 +304,26 @@ impl Tester {
         self
     }
 
+    pub fn with_regexp_plugin(mut self, yes: bool) -> Self {
+        self.plugins.set(LintPlugins::REGEXP, yes);
+        self
+    }
+
+    pub fn with_vue_plugin(mut self, yes: bool) -> Self {
+        self.plugins.set(LintPlugins::VUE, yes);
+        self
+    }
+
+    pub fn with_testing_library_plugin(mut self, yes: bool) -> Self {
+        self.plugins.set(LintPlugins::TESTING_LIBRARY, yes);
+        self
+    }
+
+    pub fn with_security_plugin(mut self, yes: bool) -> Self {
+        self.plugins.set(LintPlugins::SECURITY, yes);
+        self
+    }
+
     /// Add cases that should fix problems found in the source code.
     ///
     /// These cases will fail if no fixes are produced or if the fixed source
@@ -452,8 +493,7 @@ impl Tester {
             .build();
 
         let path_to_lint = if self.plugins.has_import() {
-            assert!(path.is_none(), "import plugin does not support path");
-            self.current_working_directory.join(&self.rule_path)
+            self.current_working_directory.join(path.as_ref().unwrap_or(&self.rule_path))
         } else if let Some(path) = path {
             self.current_working_directory.join(path)
         } else if self.plugins.has_test() {
@@ -463,13 +503,17 @@ impl Tester {
         };
 
         let cwd = self.current_working_directory.clone();
-        let paths = vec![path_to_lint.into_boxed_path()];
+        let paths = std::iter::once(&path_to_lint)
+            .chain(&self.linted_paths)
+            .map(|path| path.clone().into_boxed_path())
+            .collect();
         let options =
             LintServiceOptions::new(cwd, paths).with_cross_module(self.plugins.has_import());
         let lint_service = LintService::from_linter(linter, options);
         let diagnostic_service = DiagnosticService::default();
         let tx_error = diagnostic_service.sender();
-        let result = lint_service.run_source(&allocator, source_text, false, tx_error);
+        let result =
+            lint_service.run_source(&allocator, &path_to_lint, source_text, false, tx_error);
 
         if result.is_empty() {
             return TestResult::Passed;
@@ -481,7 +525,7 @@ impl Tester {
         }
 
         let diagnostic_path = if self.plugins.has_import() {
-            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
+            self.rule_path.strip_prefix(&self.current_working_directory).unwrap_or(&self.rule_path)
         } else {
             &self.rule_path
         }
@@ -501,9 +545,13 @@ impl Tester {
     }
 
     fn find_rule(&self) -> &RuleEnum {
-        RULES
+        let mut rules = RULES
             .iter()
-            .find(|rule| rule.category() == self.rule_category && rule.name() == self.rule_name)
+            .filter(|rule| rule.category() == self.rule_category && rule.name() == self.rule_name);
+        let first = rules.clone().next();
+        rules
+            .find(|rule| self.plugin_name == Some(rule.plugin_name()))
+            .or(first)
             .unwrap_or_else(|| panic!("Rule not found: {}", &self.rule_name))
     }
 }
@@ -0,0 +1,143 @@
+use std::borrow::Cow;
+
+use oxc_resolver::NODEJS_BUILTINS;
+use oxc_span::Span;
+
+use crate::context::LintContext;
+
+/// Builtin modules which can only be imported with the `node:` protocol.
+const NODE_PROTOCOL_ONLY_BUILTINS: &[&str] = &["sea", "sqlite", "test", "test/reporters"];
+
+/// Builtin modules which are missing from [`NODEJS_BUILTINS`].
+const ADDITIONAL_NODE_BUILTINS: &[&str] =
+    &["inspector/promises", "path/win32", "readline/promises", "v8", "wasi"];
+
+/// Check if `specifier` is a Node.js builtin module, e.g. `fs`, `fs/promises` or `node:test`.
+pub fn is_node_builtin_module(specifier: &str) -> bool {
+    let (name, has_protocol) = match specifier.strip_prefix("node:") {
+        Some(name) => (name, true),
+        None => (specifier, false),
+    };
+    NODEJS_BUILTINS.binary_search(&name).is_ok()
+        || ADDITIONAL_NODE_BUILTINS.contains(&name)
+        || (has_protocol && NODE_PROTOCOL_ONLY_BUILTINS.contains(&name))
+}
+
+/// The name of the package imported by a bare module specifier, e.g. `lodash` for `lodash/fp`,
+/// or `@babel/core` for `@babel/core/lib/index.js`.
+///
+/// Returns `None` for relative and absolute paths, and for specifiers with a protocol, e.g.
+/// `node:fs` or `virtual:module`.
+pub fn get_package_name(specifier: &str) -> Option<&str> {
+    if specifier.starts_with(['.', '/']) || specifier.contains(':') {
+        return None;
+    }
+    let mut segments = specifier.splitn(3, '/');
+    let first = segments.next()?;
+    if let Some(scope) = first.strip_prefix('@') {
+        let name = segments.next()?;
+        if scope.is_empty() || name.is_empty() {
+            return None;
+        }
+        return Some(&specifier[..first.len() + 1 + name.len()]);
+    }
+    (!first.is_empty()).then_some(first)
+}
+
+/// Adjacent top-level import statements, each extended to the comments attached to it, which
+/// a fix can reorder by rearranging the source text.
+///
+/// Comments on the lines directly above a statement, and comments after it on the same line,
+/// move together with the statement.
+pub struct ImportBlock<'a> {
+    source_text: &'a str,
+    ranges: Vec<Span>,
+    fixable: bool,
+}
+
+impl<'a> ImportBlock<'a> {
+    pub fn new(statements: &[Span], ctx: &LintContext<'a>) -> Self {
+        let source_text = ctx.source_text();
+        let semantic = ctx.semantic();
+        let mut ranges: Vec<Span> = Vec::with_capacity(statements.len());
+
+        for span in statements {
+            let lower_bound = ranges.last().map_or(0, |range| range.end);
+
+            let mut start = span.start;
+            for comment in semantic.comments_range(lower_bound..span.start).rev() {
+                let between = &source_text[comment.span.end as usize..start as usize];
+                let line_start = source_text[..comment.span.start as usize]
+                    .rfind('\n')
+                    .map_or(0, |index| index + 1);
+                if !between.trim().is_empty()
+                    || between.matches('\n').count() > 1
+                    || !source_text[line_start..comment.span.start as usize].trim().is_empty()
+                {
+                    break;
+                }
+                start = comment.span.start;
+            }
+
+            let mut end = span.end;
+            for comment in semantic.comments_range(span.end..) {
+                let between = &source_text[end as usize..comment.span.start as usize];
+                if between.contains('\n') || !between.trim().is_empty() {
+                    break;
+                }
+                end = comment.span.end;
+            }
+
+            ranges.push(Span::new(start, end));
+        }
+
+        // A comment which is attached to neither of its neighbours can not be moved safely.
+        let fixable = ranges
+            .windows(2)
+            .all(|pair| !semantic.has_comments_between(Span::new(pair[0].end, pair[1].start)));
+
+        Self { source_text, ranges, fixable }
+    }
+
+    /// `false` if there is a comment between two statements which is attached to neither.
+    pub fn is_fixable(&self) -> bool {
+        self.fixable
+    }
+
+    /// The span of all statements and their attached comments.
+    pub fn span(&self) -> Span {
+        Span::new(self.ranges[0].start, self.ranges[self.ranges.len() - 1].end)
+    }
+
+    /// The whitespace between the statements at `index` and `index + 1`.
+    pub fn separator_span(&self, index: usize) -> Span {
+        Span::new(self.ranges[index].end, self.ranges[index + 1].start)
+    }
+
+    /// The number of empty lines between the statements at `index` and `index + 1`.
+    pub fn empty_lines_after(&self, index: usize) -> usize {
+        let separator = self.separator_span(index).source_text(self.source_text);
+        separator.matches('\n').count().saturating_sub(1)
+    }
+
+    /// The source text of the statements in `order`, which contains the index of every
+    /// statement exactly once.
+    ///
+    /// `separator` is called with the indices of two statements which end up next to each
+    /// other, and the original separator at that position.
+    pub fn reorder<F>(&self, order: &[usize], mut separator: F) -> String
+    where
+        F: FnMut(usize, usize, &'a str) -> Cow<'a, str>,
+    {
+        debug_assert_eq!(order.len(), self.ranges.len());
+        let mut text = String::with_capacity(self.span().size() as usize);
+        for (position, &index) in order.iter().enumerate() {
+            text.push_str(self.ranges[index].source_text(self.source_text));
+            if let Some(&next) = order.get(position + 1) {
+                let original = self.separator_span(position).source_text(self.source_text);
+                text.push_str(&separator(index, next, original));
+            }
+        }
+        text
+    }
+}
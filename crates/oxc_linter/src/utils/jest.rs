@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, path::Path};
 
 use oxc_ast::{
     ast::{
@@ -83,14 +83,20 @@ pub enum JestGeneralFnKind {
 
 /// <https://jestjs.io/docs/configuration#testmatch-arraystring>
 pub fn is_jest_file(ctx: &LintContext) -> bool {
-    if ctx.file_path().components().any(|c| match c {
+    is_test_file_path(ctx.file_path())
+}
+
+/// Whether `path` matches the default test file patterns of Jest, i.e. it is in a `__tests__`
+/// directory or ends with `.test.js` or `.spec.js` (or the `.jsx`, `.ts` and `.tsx` equivalents).
+pub fn is_test_file_path(path: &Path) -> bool {
+    if path.components().any(|c| match c {
         std::path::Component::Normal(p) => p == std::ffi::OsStr::new("__tests__"),
         _ => false,
     }) {
         return true;
     }
 
-    let file_path = ctx.file_path().to_string_lossy();
+    let file_path = path.to_string_lossy();
     ["spec.js", "spec.jsx", "spec.ts", "spec.tsx", "test.js", "test.jsx", "test.ts", "test.tsx"]
         .iter()
         .any(|ext| file_path.ends_with(ext))
@@ -2,7 +2,7 @@ use oxc_ast::{
     ast::{BindingPattern, BindingPatternKind, Expression, FormalParameters},
     AstKind,
 };
-use oxc_semantic::JSDoc;
+use oxc_semantic::{JSDoc, JSDocTag, JSDocTagTypePart};
 use oxc_span::Span;
 use rustc_hash::FxHashSet;
 
@@ -119,6 +119,66 @@ pub fn should_ignore_as_avoid(
     jsdoc.tags().iter().any(|tag| ignore_tag_names.contains(tag.kind.parsed()))
 }
 
+/// Tags whose `{...}` is a type expression.
+/// Braces of other tags are not types, e.g. `@see {@link foo}`.
+const TYPE_TAGS: [&str; 32] = [
+    "arg",
+    "argument",
+    "augments",
+    "const",
+    "constant",
+    "define",
+    "enum",
+    "exception",
+    "extends",
+    "implements",
+    "member",
+    "modifies",
+    "next",
+    "package",
+    "param",
+    "private",
+    "prop",
+    "property",
+    "protected",
+    "public",
+    "return",
+    "returns",
+    "satisfies",
+    "static",
+    "template",
+    "this",
+    "throws",
+    "type",
+    "typedef",
+    "var",
+    "yield",
+    "yields",
+];
+
+/// Returns the type of the tag, e.g. `{string}` of `@param {string} foo`.
+/// The type must be the first part of the tag body, and the tag must be known to have one,
+/// also with the user preferred name.
+pub fn get_tag_type<'a>(
+    tag: &JSDocTag<'a>,
+    settings: &JSDocPluginSettings,
+) -> Option<JSDocTagTypePart<'a>> {
+    let tag_name = tag.kind.parsed();
+    if !TYPE_TAGS
+        .iter()
+        .any(|name| *name == tag_name || settings.resolve_tag_name(name) == tag_name)
+    {
+        return None;
+    }
+
+    let type_part = tag.r#type()?;
+    // e.g. `@param foo - like {a: 1}` has no type
+    if type_part.span.start != tag.comment().span_trimmed_first_line().start {
+        return None;
+    }
+    Some(type_part)
+}
+
 #[derive(Debug, Clone)]
 pub struct Param {
     pub span: Span,
@@ -1,19 +1,25 @@
 mod config;
 mod express;
+mod import;
 mod jest;
 mod jsdoc;
 mod nextjs;
+mod node;
 mod promise;
 mod react;
 mod react_perf;
+mod regexp;
+mod security;
+mod testing_library;
 mod unicorn;
 mod vitest;
+mod vue;
 
 use std::{io, path::Path};
 
 pub use self::{
-    config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*, react_perf::*,
-    unicorn::*, vitest::*,
+    config::*, express::*, import::*, jest::*, jsdoc::*, nextjs::*, node::*, promise::*, react::*,
+    react_perf::*, regexp::*, security::*, testing_library::*, unicorn::*, vitest::*, vue::*,
 };
 
 /// List of Jest rules that have Vitest equivalents.
@@ -0,0 +1,393 @@
+use std::fmt;
+
+use oxc_ast::{
+    ast::{
+        Argument, BindingPatternKind, CallExpression, Expression, ImportDeclarationSpecifier,
+        StringLiteral,
+    },
+    AstKind,
+};
+use oxc_semantic::{NodeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::{context::LintContext, utils::is_node_builtin_module, AstNode};
+
+/// The Node.js version range which is assumed when neither the rule options nor the
+/// `engines` field of `package.json` configure one.
+pub const DEFAULT_NODE_VERSION_RANGE: &str = ">=16.0.0";
+
+/// A Node.js version, e.g. `14.18.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NodeVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl NodeVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Parse a possibly partial version, e.g. `14`, `14.x`, `v14.18.0` or `16.0.0-pre`.
+    /// Missing and wildcard components are `0`.
+    fn parse_partial(version: &str) -> Option<Self> {
+        let version = version.strip_prefix('v').unwrap_or(version);
+        let version = version.split(['-', '+']).next()?;
+        let mut components = [0; 3];
+        for (i, component) in version.split('.').enumerate() {
+            if i >= components.len() {
+                return None;
+            }
+            if matches!(component, "x" | "X" | "*") {
+                break;
+            }
+            components[i] = component.parse().ok()?;
+        }
+        let [major, minor, patch] = components;
+        Some(Self { major, minor, patch })
+    }
+
+    /// The smallest version above all versions matching the partial `version`, e.g. `15.0.0`
+    /// for `14` or `14.x`, and `14.1.3` for `14.1.2`.
+    fn parse_partial_exclusive(version: &str) -> Option<Self> {
+        let parsed = Self::parse_partial(version)?;
+        // Wildcards match any version, e.g. `14.x` is the same as `14`.
+        let components = version
+            .strip_prefix('v')
+            .unwrap_or(version)
+            .split('.')
+            .take_while(|component| !matches!(*component, "x" | "X" | "*"))
+            .count();
+        Some(match components {
+            0 => return None,
+            1 => Self::new(parsed.major + 1, 0, 0),
+            2 => Self::new(parsed.major, parsed.minor + 1, 0),
+            _ => Self::new(parsed.major, parsed.minor, parsed.patch + 1),
+        })
+    }
+}
+
+impl fmt::Display for NodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The lowest version matching the npm version `range`, e.g. `14.18.0` for
+/// `^14.18.0 || >=16.0.0`, or `None` if the range cannot be parsed.
+pub fn min_node_version(range: &str) -> Option<NodeVersion> {
+    range.split("||").map(min_version_of_comparator_set).try_fold(None, |min, version| {
+        let version = version?;
+        Some(Some(min.map_or(version, |min: NodeVersion| min.min(version))))
+    })?
+}
+
+/// The lowest version matching a set of comparators which are all required, e.g.
+/// `>=14.0.0 <16` or `14.0.0 - 16.0.0`.
+fn min_version_of_comparator_set(set: &str) -> Option<NodeVersion> {
+    let set = set.trim();
+    if let Some((lower, _)) = set.split_once(" - ") {
+        return NodeVersion::parse_partial(lower.trim());
+    }
+    let mut min = NodeVersion::new(0, 0, 0);
+    let mut operator = "";
+    for token in set.split_whitespace() {
+        // An operator separated from its version, e.g. `>= 14`.
+        if matches!(token, ">=" | ">" | "<=" | "<" | "=" | "^" | "~") {
+            operator = token;
+            continue;
+        }
+        let (op, version) = split_operator(token);
+        let op = if op.is_empty() { std::mem::take(&mut operator) } else { op };
+        let lower = match op {
+            "<" | "<=" => continue,
+            ">" => NodeVersion::parse_partial_exclusive(version)?,
+            _ if matches!(version, "*" | "x" | "X") => NodeVersion::new(0, 0, 0),
+            _ => NodeVersion::parse_partial(version)?,
+        };
+        min = min.max(lower);
+    }
+    Some(min)
+}
+
+fn split_operator(comparator: &str) -> (&str, &str) {
+    let version_start = comparator.find(|c: char| c.is_ascii_alphanumeric() || c == '*');
+    let (op, version) = comparator.split_at(version_start.unwrap_or(comparator.len()));
+    (op.trim_start_matches('v'), version)
+}
+
+/// The Node.js version range which a file supports: `configured` if set, otherwise the
+/// `engines.node` field of the nearest `package.json` of the linted file, otherwise
+/// [`DEFAULT_NODE_VERSION_RANGE`].
+pub fn get_node_version_range(configured: Option<&str>, ctx: &LintContext) -> String {
+    if let Some(range) = configured {
+        return range.to_string();
+    }
+    read_engines_node(ctx).unwrap_or_else(|| DEFAULT_NODE_VERSION_RANGE.to_string())
+}
+
+fn read_engines_node(ctx: &LintContext) -> Option<String> {
+    let package_json = ctx.package_jsons().nearest(ctx.file_path())?;
+    package_json.value.get("engines")?.get("node")?.as_str().map(ToString::to_string)
+}
+
+/// How a Node.js API is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeApiUsage {
+    /// A builtin module is imported or required, e.g. `'fs'` in `import fs from 'fs'`.
+    Module,
+    /// A member of a builtin module, or a global variable, is read where it appears in the
+    /// source text, e.g. `fs.exists` in `fs.exists(path)`, or `exists` in
+    /// `import { exists } from 'fs'`. Later reads of a variable holding the value are not
+    /// recorded.
+    Read,
+    /// The value is called, directly or through a variable, e.g. `Buffer(10)`, by the call
+    /// expression with the node id.
+    Call(NodeId),
+    /// The value is constructed, directly or through a variable, e.g. `new Buffer(10)`, by the
+    /// `new` expression with the node id.
+    New(NodeId),
+}
+
+/// A use of a Node.js API, e.g. `fs.promises.rm` for `require('fs').promises.rm()`.
+#[derive(Debug)]
+pub struct NodeApiReference {
+    /// The builtin module, followed by the names of the members which are accessed, e.g.
+    /// `fs.exists`. The `node:` protocol is not part of the path.
+    pub path: String,
+    pub span: Span,
+    pub usage: NodeApiUsage,
+}
+
+/// Collect the uses of the builtin modules of Node.js, and of their members, through `import`
+/// declarations, `require()` calls and the variables which they are assigned to.
+///
+/// `globals` are the global variables to track as well, with the path that they refer to, e.g.
+/// `("Buffer", "buffer.Buffer")`.
+///
+/// A value is recorded as [`NodeApiUsage::Read`] once, where it appears in the source text, and
+/// additionally as [`NodeApiUsage::Call`] or [`NodeApiUsage::New`] each time it is called or
+/// constructed.
+pub fn collect_node_api_references(
+    ctx: &LintContext,
+    globals: &[(&str, &str)],
+) -> Vec<NodeApiReference> {
+    let mut tracker = NodeApiTracker { ctx, references: vec![], visited: FxHashSet::default() };
+    for node in ctx.nodes().iter() {
+        match node.kind() {
+            AstKind::ImportDeclaration(decl) if !decl.import_kind.is_type() => {
+                let Some(module) = get_builtin_module_name(&decl.source.value) else {
+                    continue;
+                };
+                tracker.push(module.to_string(), decl.source.span, NodeApiUsage::Module);
+                for specifier in decl.specifiers.iter().flatten() {
+                    match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                            if specifier.import_kind.is_type() {
+                                continue;
+                            }
+                            let path = format!("{module}.{}", specifier.imported.name());
+                            tracker.push(path.clone(), specifier.span, NodeApiUsage::Read);
+                            tracker.track_symbol(specifier.local.symbol_id(), &path);
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                            tracker.track_symbol(specifier.local.symbol_id(), module);
+                        }
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                            tracker.track_symbol(specifier.local.symbol_id(), module);
+                        }
+                    }
+                }
+            }
+            AstKind::ExportNamedDeclaration(decl) => {
+                if let Some(source) = &decl.source {
+                    tracker.push_module(&source.value, source.span);
+                }
+            }
+            AstKind::ExportAllDeclaration(decl) => {
+                tracker.push_module(&decl.source.value, decl.source.span);
+            }
+            AstKind::ImportExpression(import) => {
+                if let Expression::StringLiteral(source) = &import.source {
+                    tracker.push_module(&source.value, source.span);
+                }
+            }
+            AstKind::CallExpression(call) => {
+                let Some(source) = get_global_require_source(call, ctx) else {
+                    continue;
+                };
+                let Some(module) = get_builtin_module_name(&source.value) else {
+                    continue;
+                };
+                tracker.push(module.to_string(), source.span, NodeApiUsage::Module);
+                tracker.track_value(node, module, false);
+            }
+            AstKind::IdentifierReference(ident) => {
+                let Some((_, path)) = globals.iter().find(|(name, _)| ident.name == *name) else {
+                    continue;
+                };
+                if ctx.semantic().is_reference_to_global_variable(ident) {
+                    tracker.track_value(node, path, true);
+                }
+            }
+            _ => {}
+        }
+    }
+    tracker.references
+}
+
+/// The name of a builtin module without the `node:` protocol, e.g. `fs` for `node:fs`.
+fn get_builtin_module_name(specifier: &str) -> Option<&str> {
+    is_node_builtin_module(specifier).then(|| specifier.strip_prefix("node:").unwrap_or(specifier))
+}
+
+/// The source of a call of the global `require`, e.g. `'fs'` for `require('fs')`.
+pub fn get_global_require_source<'a, 'b>(
+    call: &'b CallExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<&'b StringLiteral<'a>> {
+    let Expression::Identifier(callee) = &call.callee else {
+        return None;
+    };
+    if callee.name != "require" || !ctx.semantic().is_reference_to_global_variable(callee) {
+        return None;
+    }
+    match call.arguments.as_slice() {
+        [Argument::StringLiteral(source)] => Some(source),
+        _ => None,
+    }
+}
+
+struct NodeApiTracker<'c, 'a> {
+    ctx: &'c LintContext<'a>,
+    references: Vec<NodeApiReference>,
+    /// Variables which are already tracked, e.g. `a` and `b` in `const a = b, b = a`.
+    visited: FxHashSet<SymbolId>,
+}
+
+impl<'a> NodeApiTracker<'_, 'a> {
+    fn push(&mut self, path: String, span: Span, usage: NodeApiUsage) {
+        self.references.push(NodeApiReference { path, span, usage });
+    }
+
+    fn push_module(&mut self, specifier: &str, span: Span) {
+        if let Some(module) = get_builtin_module_name(specifier) {
+            self.push(module.to_string(), span, NodeApiUsage::Module);
+        }
+    }
+
+    /// Track the references of a variable which holds the value at `path`.
+    fn track_symbol(&mut self, symbol_id: SymbolId, path: &str) {
+        if !self.visited.insert(symbol_id) {
+            return;
+        }
+        let ctx = self.ctx;
+        for reference in ctx.symbols().get_resolved_references(symbol_id) {
+            if reference.is_read() {
+                self.track_value(ctx.nodes().get_node(reference.node_id()), path, false);
+            }
+        }
+    }
+
+    /// Track the value at `path` which `node` evaluates to. With `is_origin`, `node` is where
+    /// the value appears in the source text, e.g. a member expression, rather than a reference
+    /// of a variable holding the value.
+    fn track_value(&mut self, node: &AstNode<'a>, path: &str, is_origin: bool) {
+        let ctx = self.ctx;
+        let value_span = node.kind().span();
+        if is_origin {
+            self.push(path.to_string(), value_span, NodeApiUsage::Read);
+        }
+
+        let mut current = node;
+        let mut parent = ctx.nodes().parent_node(current.id());
+        while let Some(wrapper) = parent.filter(|parent| {
+            matches!(
+                parent.kind(),
+                AstKind::ParenthesizedExpression(_)
+                    | AstKind::TSAsExpression(_)
+                    | AstKind::TSSatisfiesExpression(_)
+                    | AstKind::TSNonNullExpression(_)
+            )
+        }) {
+            current = wrapper;
+            parent = ctx.nodes().parent_node(current.id());
+        }
+        let Some(parent) = parent else {
+            return;
+        };
+        let span = current.kind().span();
+
+        match parent.kind() {
+            AstKind::CallExpression(call) if call.callee.span() == span => {
+                self.push(path.to_string(), value_span, NodeApiUsage::Call(parent.id()));
+            }
+            AstKind::NewExpression(new_expr) if new_expr.callee.span() == span => {
+                self.push(path.to_string(), value_span, NodeApiUsage::New(parent.id()));
+            }
+            AstKind::MemberExpression(member) if member.object().span() == span => {
+                if let Some(name) = member.static_property_name() {
+                    self.track_value(parent, &format!("{path}.{name}"), true);
+                }
+            }
+            AstKind::VariableDeclarator(declarator)
+                if declarator.init.as_ref().is_some_and(|init| init.span() == span) =>
+            {
+                match &declarator.id.kind {
+                    BindingPatternKind::BindingIdentifier(ident) => {
+                        self.track_symbol(ident.symbol_id(), path);
+                    }
+                    // `const { exists } = require('fs')`
+                    BindingPatternKind::ObjectPattern(pattern) => {
+                        for property in &pattern.properties {
+                            let Some(name) = property.key.static_name() else {
+                                continue;
+                            };
+                            let member_path = format!("{path}.{name}");
+                            self.push(member_path.clone(), property.span, NodeApiUsage::Read);
+                            if let BindingPatternKind::BindingIdentifier(ident) =
+                                &property.value.kind
+                            {
+                                self.track_symbol(ident.symbol_id(), &member_path);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{min_node_version, NodeVersion};
+
+    #[test]
+    fn test_min_node_version() {
+        let cases = [
+            (">=16.0.0", Some(NodeVersion::new(16, 0, 0))),
+            (">= 14", Some(NodeVersion::new(14, 0, 0))),
+            ("^14.18.0 || >=16.0.0", Some(NodeVersion::new(14, 18, 0))),
+            (">=16 || 14.x", Some(NodeVersion::new(14, 0, 0))),
+            (">=12.22.0 <13 || >=14.17.0", Some(NodeVersion::new(12, 22, 0))),
+            ("~18.12", Some(NodeVersion::new(18, 12, 0))),
+            (">14", Some(NodeVersion::new(15, 0, 0))),
+            (">14.1.2", Some(NodeVersion::new(14, 1, 3))),
+            (">14.x", Some(NodeVersion::new(15, 0, 0))),
+            (">14.*.*", Some(NodeVersion::new(15, 0, 0))),
+            (">14.1.X", Some(NodeVersion::new(14, 2, 0))),
+            ("v20.1.0", Some(NodeVersion::new(20, 1, 0))),
+            ("18.0.0 - 20.0.0", Some(NodeVersion::new(18, 0, 0))),
+            ("<20", Some(NodeVersion::new(0, 0, 0))),
+            ("*", Some(NodeVersion::new(0, 0, 0))),
+            ("lts", None),
+        ];
+        for (range, expected) in cases {
+            assert_eq!(min_node_version(range), expected, "{range}");
+        }
+    }
+}
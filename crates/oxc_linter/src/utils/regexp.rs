@@ -0,0 +1,220 @@
+use oxc_allocator::{Allocator, CloneIn};
+use oxc_ast::{
+    ast::{Argument, RegExpFlags},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_regular_expression::{
+    analysis::{CharSet, MatchFlags},
+    ast::{Alternative, Pattern, Quantifier, Term},
+    visit::{walk, Visit},
+    ConstructorParser, Options,
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::extract_regex_flags, context::LintContext, AstNode};
+
+/// A regular expression pattern in the source code, from a literal or a `RegExp` call.
+pub struct RegExpPattern<'p, 'a> {
+    pub pattern: &'p Pattern<'a>,
+    pub flags: RegExpFlags,
+    /// `true` for regular expression literals.
+    ///
+    /// Fixes are only offered for literals. The source text of a pattern passed to `RegExp` is a
+    /// string literal, so reprinted pattern nodes can not be inserted into it as is.
+    pub is_literal: bool,
+}
+
+impl RegExpPattern<'_, '_> {
+    /// `u` or `v` flag
+    pub fn unicode_mode(&self) -> bool {
+        self.flags.intersects(RegExpFlags::U | RegExpFlags::V)
+    }
+
+    /// `v` flag
+    pub fn unicode_sets(&self) -> bool {
+        self.flags.contains(RegExpFlags::V)
+    }
+
+    /// The flags which change the characters matched by the pattern.
+    pub fn match_flags(&self) -> MatchFlags {
+        MatchFlags {
+            ignore_case: self.flags.contains(RegExpFlags::I),
+            dot_all: self.flags.contains(RegExpFlags::S),
+            unicode_mode: self.unicode_mode(),
+        }
+    }
+
+    /// Report `diagnostic`, and replace `span` with `replacement` as the fix if the pattern is a
+    /// literal.
+    pub fn report(
+        &self,
+        ctx: &LintContext,
+        diagnostic: OxcDiagnostic,
+        fix: Option<(Span, String)>,
+    ) {
+        match fix {
+            Some((span, replacement)) if self.is_literal => {
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, replacement));
+            }
+            _ => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// Run `check` on the pattern of a regular expression literal, or of a `new RegExp("...")` or
+/// `RegExp("...")` call with string literal arguments.
+pub fn run_on_regex_node<'a, F>(node: &AstNode<'a>, ctx: &LintContext<'a>, check: F)
+where
+    F: FnOnce(&RegExpPattern<'_, '_>),
+{
+    let arguments = match node.kind() {
+        AstKind::RegExpLiteral(literal) => {
+            if let Some(pattern) = literal.regex.pattern.as_pattern() {
+                check(&RegExpPattern { pattern, flags: literal.regex.flags, is_literal: true });
+            }
+            return;
+        }
+        AstKind::NewExpression(expr) if expr.callee.is_specific_id("RegExp") => &expr.arguments,
+        AstKind::CallExpression(expr) if expr.callee.is_specific_id("RegExp") => &expr.arguments,
+        _ => return,
+    };
+
+    let Some(Argument::StringLiteral(pattern)) = arguments.first() else {
+        return;
+    };
+    let (flags, flags_span) = match arguments.get(1) {
+        None => (RegExpFlags::empty(), None),
+        Some(Argument::StringLiteral(flags)) => {
+            let Some(parsed) = extract_regex_flags(arguments) else {
+                return;
+            };
+            (parsed, Some(flags.span))
+        }
+        // The flags are unknown, so the pattern can not be parsed reliably.
+        Some(_) => return,
+    };
+
+    let allocator = Allocator::default();
+    let parser = ConstructorParser::new(
+        &allocator,
+        pattern.span.source_text(ctx.source_text()),
+        flags_span.map(|span| span.source_text(ctx.source_text())),
+        Options {
+            pattern_span_offset: pattern.span.start,
+            flags_span_offset: flags_span.map_or(0, |span| span.start),
+        },
+    );
+    let Ok(pattern) = parser.parse() else {
+        return;
+    };
+    check(&RegExpPattern { pattern: &pattern, flags, is_literal: false });
+}
+
+/// All alternatives of `pattern`, including the alternatives of groups and lookarounds.
+pub fn collect_alternatives<'a>(pattern: &Pattern<'a>) -> Vec<&'a Alternative<'a>> {
+    #[derive(Default)]
+    struct AlternativeCollector<'a> {
+        alternatives: Vec<&'a Alternative<'a>>,
+    }
+
+    impl<'a> Visit<'a> for AlternativeCollector<'a> {
+        fn visit_alternative(&mut self, alternative: &Alternative<'a>) {
+            self.alternatives.push(self.alloc(alternative));
+            walk::walk_alternative(self, alternative);
+        }
+    }
+
+    let mut collector = AlternativeCollector::default();
+    collector.visit_pattern(pattern);
+    collector.alternatives
+}
+
+/// A single-character element of an alternative and its repetition count, e.g. `\d+`, `[a-z]?` or
+/// `a`, which is repeated exactly once.
+pub struct QuantifiedChars<'t, 'a> {
+    pub span: Span,
+    /// The repeated element, e.g. `\d` of `\d+`.
+    pub element: &'t Term<'a>,
+    pub min: u64,
+    /// `None` means no upper bound.
+    pub max: Option<u64>,
+    pub greedy: bool,
+    pub chars: CharSet,
+}
+
+impl<'t, 'a> QuantifiedChars<'t, 'a> {
+    pub fn from_term(term: &'t Term<'a>, regexp: &RegExpPattern) -> Option<Self> {
+        match term {
+            Term::Quantifier(quantifier) => Some(Self {
+                span: quantifier.span,
+                element: &quantifier.body,
+                min: quantifier.min,
+                max: quantifier.max,
+                greedy: quantifier.greedy,
+                chars: CharSet::from_term(&quantifier.body, regexp.match_flags())?,
+            }),
+            term => Some(Self {
+                span: term.span(),
+                element: term,
+                min: 1,
+                max: Some(1),
+                greedy: true,
+                chars: CharSet::from_term(term, regexp.match_flags())?,
+            }),
+        }
+    }
+
+    pub fn is_quantified(&self) -> bool {
+        self.min != 1 || self.max != Some(1)
+    }
+
+    /// Reprint `element` with the given repetition count, e.g. `\d{2,}`.
+    fn print(element: &Term, min: u64, max: Option<u64>) -> String {
+        match (min, max) {
+            (_, Some(0)) => String::new(),
+            (1, Some(1)) => element.to_string(),
+            _ => {
+                let allocator = Allocator::default();
+                let quantifier = Quantifier {
+                    span: Span::default(),
+                    min,
+                    max,
+                    greedy: true,
+                    body: element.clone_in(&allocator),
+                };
+                quantifier.to_string()
+            }
+        }
+    }
+}
+
+/// Simplify two adjacent elements `a` and `b` of an alternative into an equivalent element, e.g.
+/// `a*a+` into `a+`, or `\w+\d*` into `\w+`.
+///
+/// Returns the reprinted replacement for `a` and `b`, or `None` if they can not be simplified.
+pub fn simplify_quantified_chars(a: &QuantifiedChars, b: &QuantifiedChars) -> Option<String> {
+    if !a.greedy || !b.greedy {
+        return None;
+    }
+
+    // `a*a+` -> `a+`
+    if a.chars == b.chars {
+        let max = a.max.zip(b.max).map(|(a_max, b_max)| a_max + b_max);
+        return Some(QuantifiedChars::print(a.element, a.min + b.min, max));
+    }
+
+    // `\w+\d*` -> `\w+`, `\w+\d+` -> `\w+\d`
+    if a.max.is_none() && b.max != Some(b.min) && b.chars.is_subset_of(&a.chars) {
+        let b = QuantifiedChars::print(b.element, b.min, Some(b.min));
+        return Some(format!("{}{b}", QuantifiedChars::print(a.element, a.min, a.max)));
+    }
+
+    // `\d*\w+` -> `\w+`, `\d+\w+` -> `\d\w+`
+    if b.max.is_none() && a.max != Some(a.min) && a.chars.is_subset_of(&b.chars) {
+        let a = QuantifiedChars::print(a.element, a.min, Some(a.min));
+        return Some(format!("{a}{}", QuantifiedChars::print(b.element, b.min, b.max)));
+    }
+
+    None
+}
@@ -0,0 +1,176 @@
+use oxc_ast::{
+    ast::{
+        Argument, AssignmentOperator, BindingPatternKind, CallExpression, Expression,
+        IdentifierReference, UnaryOperator,
+    },
+    AstKind,
+};
+use oxc_semantic::SymbolId;
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, utils::get_global_require_source, AstNode};
+
+/// Methods of the `path` module which return a static value for static arguments.
+const PATH_METHODS: [&str; 7] =
+    ["basename", "dirname", "extname", "join", "normalize", "relative", "resolve"];
+
+/// Whether `expr` evaluates to a value which is known from the source code alone, rather than
+/// a value which may be tainted by data from outside of it, e.g. a function parameter, the
+/// result of a function call or a property of an object.
+///
+/// Besides literals, these expressions are static when their operands are:
+/// - template literals, and unary, binary, logical and conditional expressions
+/// - variables, when their initial value and all values assigned to them are static
+/// - calls of the `path` module, e.g. `path.join(__dirname, 'foo')`, and `new URL()`
+///
+/// `undefined`, `__dirname`, `__filename` and `import.meta` are static as well.
+pub fn is_static_expression<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    StaticChecker { ctx, symbols: FxHashMap::default() }.is_static(expr)
+}
+
+struct StaticChecker<'c, 'a> {
+    ctx: &'c LintContext<'a>,
+    /// The variables which are checked, or being checked, and whether they are static. A
+    /// variable which is being checked is assumed to be static, e.g. `a` in `a = a + 'b'`.
+    symbols: FxHashMap<SymbolId, bool>,
+}
+
+impl<'a> StaticChecker<'_, 'a> {
+    fn is_static(&mut self, expr: &Expression<'a>) -> bool {
+        match expr.get_inner_expression() {
+            Expression::StringLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::MetaProperty(_) => true,
+            Expression::TemplateLiteral(template) => {
+                template.expressions.iter().all(|expr| self.is_static(expr))
+            }
+            Expression::UnaryExpression(unary) => {
+                unary.operator == UnaryOperator::Typeof || self.is_static(&unary.argument)
+            }
+            Expression::BinaryExpression(binary) => {
+                self.is_static(&binary.left) && self.is_static(&binary.right)
+            }
+            Expression::LogicalExpression(logical) => {
+                self.is_static(&logical.left) && self.is_static(&logical.right)
+            }
+            Expression::ConditionalExpression(conditional) => {
+                self.is_static(&conditional.consequent) && self.is_static(&conditional.alternate)
+            }
+            Expression::Identifier(ident) => self.is_static_identifier(ident),
+            // `import.meta.url`
+            Expression::StaticMemberExpression(member) => {
+                matches!(member.object.get_inner_expression(), Expression::MetaProperty(_))
+            }
+            Expression::CallExpression(call) => self.is_static_path_call(call),
+            // `new URL('./file.txt', import.meta.url)`
+            Expression::NewExpression(new_expr) => {
+                let is_url = matches!(&new_expr.callee, Expression::Identifier(ident)
+                    if ident.name == "URL"
+                        && self.ctx.semantic().is_reference_to_global_variable(ident));
+                is_url && self.are_static_arguments(&new_expr.arguments)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_static_identifier(&mut self, ident: &IdentifierReference<'a>) -> bool {
+        let ctx = self.ctx;
+        let Some(symbol_id) = ctx.symbols().get_reference(ident.reference_id()).symbol_id() else {
+            return matches!(ident.name.as_str(), "undefined" | "__dirname" | "__filename");
+        };
+        if let Some(&is_static) = self.symbols.get(&symbol_id) {
+            return is_static;
+        }
+        self.symbols.insert(symbol_id, true);
+
+        let declaration = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+        let is_static = match declaration.kind() {
+            AstKind::VariableDeclarator(declarator) => {
+                matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_))
+                    && match &declarator.init {
+                        Some(init) => self.is_static(init),
+                        // `undefined`, unless it is the variable of a `for...in` or `for...of`
+                        None => !matches!(
+                            ctx.nodes().ancestors(declaration.id()).nth(2).map(AstNode::kind),
+                            Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))
+                        ),
+                    }
+                    && ctx.symbols().get_resolved_references(symbol_id).all(|reference| {
+                        !reference.is_write()
+                            || self.is_static_write(ctx.nodes().get_node(reference.node_id()))
+                    })
+            }
+            _ => false,
+        };
+        self.symbols.insert(symbol_id, is_static);
+        is_static
+    }
+
+    /// Whether the value assigned by a write reference is static, e.g. `x = 'foo'` or
+    /// `x += 'foo'`, but not `x++` or `[x] = foo`.
+    fn is_static_write(&mut self, reference: &AstNode<'a>) -> bool {
+        for ancestor in self.ctx.nodes().ancestors(reference.id()).skip(1) {
+            match ancestor.kind() {
+                AstKind::SimpleAssignmentTarget(_) | AstKind::AssignmentTarget(_) => {}
+                AstKind::AssignmentExpression(assign) => {
+                    return matches!(
+                        assign.operator,
+                        AssignmentOperator::Assign
+                            | AssignmentOperator::Addition
+                            | AssignmentOperator::LogicalOr
+                            | AssignmentOperator::LogicalAnd
+                            | AssignmentOperator::LogicalNullish
+                    ) && self.is_static(&assign.right);
+                }
+                _ => return false,
+            }
+        }
+        false
+    }
+
+    /// `path.join(__dirname, 'foo')`, where `path` is the `path` module.
+    fn is_static_path_call(&mut self, call: &CallExpression<'a>) -> bool {
+        let Some(member) = call.callee.get_inner_expression().as_member_expression() else {
+            return false;
+        };
+        let Expression::Identifier(object) = member.object().get_inner_expression() else {
+            return false;
+        };
+        member.static_property_name().is_some_and(|name| PATH_METHODS.contains(&name))
+            && is_path_module(object, self.ctx)
+            && self.are_static_arguments(&call.arguments)
+    }
+
+    fn are_static_arguments(&mut self, arguments: &[Argument<'a>]) -> bool {
+        arguments.iter().all(|arg| arg.as_expression().is_some_and(|arg| self.is_static(arg)))
+    }
+}
+
+/// Whether `ident` is a variable holding the `path` module, e.g. `path` in
+/// `const path = require('path')` or `import * as path from 'node:path'`.
+fn is_path_module(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    let Some(symbol_id) = ctx.symbols().get_reference(ident.reference_id()).symbol_id() else {
+        return false;
+    };
+    let declaration = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+    let source = match declaration.kind() {
+        AstKind::VariableDeclarator(declarator) => {
+            match declarator.init.as_ref().map(Expression::get_inner_expression) {
+                Some(Expression::CallExpression(call)) => get_global_require_source(call, ctx),
+                _ => None,
+            }
+        }
+        AstKind::ImportDefaultSpecifier(_) | AstKind::ImportNamespaceSpecifier(_) => {
+            ctx.nodes().ancestors(declaration.id()).find_map(|ancestor| match ancestor.kind() {
+                AstKind::ImportDeclaration(decl) => Some(&decl.source),
+                _ => None,
+            })
+        }
+        _ => None,
+    };
+    source.is_some_and(|source| matches!(source.value.as_str(), "path" | "node:path"))
+}
@@ -0,0 +1,94 @@
+use oxc_ast::ast::{CallExpression, Expression};
+
+use crate::{context::ContextHost, utils::is_test_file_path};
+
+/// Whether the Testing Library rules apply to the file: a [test file](is_test_file_path), or a
+/// file which imports a Testing Library package, e.g. a shared `test-utils.tsx`.
+pub fn is_testing_library_file(ctx: &ContextHost) -> bool {
+    is_test_file_path(ctx.file_path()) || is_testing_library_imported(ctx)
+}
+
+/// Whether the file imports a Testing Library package, e.g. `@testing-library/react`.
+pub fn is_testing_library_imported(ctx: &ContextHost) -> bool {
+    ctx.module_record()
+        .import_entries
+        .iter()
+        .any(|entry| entry.module_request.name().starts_with("@testing-library/"))
+}
+
+/// The variant of a query, e.g. `getAllBy` in `getAllByRole`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryVariant {
+    Get,
+    GetAll,
+    Query,
+    QueryAll,
+    Find,
+    FindAll,
+}
+
+impl QueryVariant {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "getBy",
+            Self::GetAll => "getAllBy",
+            Self::Query => "queryBy",
+            Self::QueryAll => "queryAllBy",
+            Self::Find => "findBy",
+            Self::FindAll => "findAllBy",
+        }
+    }
+
+    /// `findBy` and `findAllBy` queries return a promise.
+    pub fn is_async(self) -> bool {
+        matches!(self, Self::Find | Self::FindAll)
+    }
+}
+
+/// A Testing Library query, e.g. `getByRole`.
+#[derive(Debug, Clone, Copy)]
+pub struct TestingLibraryQuery<'a> {
+    pub variant: QueryVariant,
+    /// The queried attribute, e.g. `Role` in `getByRole`.
+    pub method: &'a str,
+}
+
+impl TestingLibraryQuery<'_> {
+    /// The name of the query with `variant` instead, e.g. `findByRole` for `getByRole`.
+    pub fn name_with_variant(&self, variant: QueryVariant) -> String {
+        format!("{}{}", variant.as_str(), self.method)
+    }
+}
+
+const QUERY_VARIANTS: [QueryVariant; 6] = [
+    QueryVariant::GetAll,
+    QueryVariant::Get,
+    QueryVariant::QueryAll,
+    QueryVariant::Query,
+    QueryVariant::FindAll,
+    QueryVariant::Find,
+];
+
+const QUERY_METHODS: [&str; 8] =
+    ["AltText", "DisplayValue", "LabelText", "PlaceholderText", "Role", "TestId", "Text", "Title"];
+
+/// Parses the name of a Testing Library query, e.g. `getByRole` or `findAllByText`.
+pub fn parse_testing_library_query(name: &str) -> Option<TestingLibraryQuery<'_>> {
+    QUERY_VARIANTS.iter().find_map(|&variant| {
+        let method = name.strip_prefix(variant.as_str())?;
+        QUERY_METHODS.contains(&method).then_some(TestingLibraryQuery { variant, method })
+    })
+}
+
+/// Returns the query called by `call`, e.g. `getByRole` in `screen.getByRole('button')` or in
+/// `getByRole('button')`.
+pub fn get_testing_library_query_call<'a>(
+    call: &CallExpression<'a>,
+) -> Option<TestingLibraryQuery<'a>> {
+    let name = match call.callee.get_inner_expression() {
+        Expression::Identifier(ident) => ident.name.as_str(),
+        Expression::StaticMemberExpression(member) => member.property.name.as_str(),
+        _ => return None,
+    };
+    parse_testing_library_query(name)
+}
@@ -0,0 +1,257 @@
+use std::{borrow::Cow, path::Path};
+
+use oxc_ast::{
+    ast::{
+        Argument, ArrayExpressionElement, CallExpression, Declaration, Expression,
+        IdentifierReference, ObjectExpression, ObjectPropertyKind, Statement, TSSignature, TSType,
+        TSTypeName,
+    },
+    AstKind,
+};
+use oxc_semantic::{AstNode, SymbolFlags};
+use oxc_span::{GetSpan, Span};
+
+use crate::{module_record::ImportImportName, LintContext};
+
+/// Whether `export default {}` in the file at `path` is a Vue component: single-file components
+/// and JSX files.
+pub fn is_vue_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext, "vue" | "jsx" | "tsx"))
+}
+
+/// Whether `call` is a call to the `<script setup>` compiler macro `name`, e.g. `defineProps()`.
+///
+/// Compiler macros are not imported, so a local binding with the same name is not a macro.
+pub fn is_vue_macro_call(call: &CallExpression, name: &str, ctx: &LintContext) -> bool {
+    matches!(&call.callee, Expression::Identifier(ident)
+        if ident.name == name && ctx.semantic().is_reference_to_global_variable(ident))
+}
+
+/// Returns the name under which `ident` is imported from `vue`, e.g. `ref` for `r` in
+/// `import { ref as r } from 'vue'`.
+pub fn get_vue_import_name<'c>(
+    ident: &IdentifierReference,
+    ctx: &'c LintContext,
+) -> Option<&'c str> {
+    let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id()?;
+    if !ctx.symbols().get_flags(symbol_id).contains(SymbolFlags::Import) {
+        return None;
+    }
+    ctx.module_record().import_entries.iter().find_map(|entry| {
+        if entry.module_request.name() != "vue" || entry.local_name.name() != ident.name {
+            return None;
+        }
+        match &entry.import_name {
+            ImportImportName::Name(name) => Some(name.name()),
+            _ => None,
+        }
+    })
+}
+
+/// Returns the `defineProps()` call of `expr`, and the defaults of
+/// `withDefaults(defineProps(), defaults)`.
+pub fn get_define_props_call<'a, 'b>(
+    expr: &'b Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(&'b CallExpression<'a>, Option<&'b ObjectExpression<'a>>)> {
+    let Expression::CallExpression(call) = expr.get_inner_expression() else {
+        return None;
+    };
+    if is_vue_macro_call(call, "defineProps", ctx) {
+        return Some((call, None));
+    }
+    if !is_vue_macro_call(call, "withDefaults", ctx) {
+        return None;
+    }
+    let Some(Argument::CallExpression(define_props)) = call.arguments.first() else {
+        return None;
+    };
+    if !is_vue_macro_call(define_props, "defineProps", ctx) {
+        return None;
+    }
+    let defaults = match call.arguments.get(1) {
+        Some(Argument::ObjectExpression(defaults)) => Some(&**defaults),
+        _ => None,
+    };
+    Some((define_props, defaults))
+}
+
+/// Whether the object expression `node` is the options object of a Vue component:
+/// * `export default {}` in a [Vue file](is_vue_file)
+/// * `defineComponent({})`
+/// * `Vue.component('name', {})` or `Vue.extend({})`
+pub fn is_vue_component_object(node: &AstNode, ctx: &LintContext) -> bool {
+    let AstKind::ObjectExpression(object) = node.kind() else {
+        return false;
+    };
+    let mut parents = ctx.nodes().ancestors(node.id()).skip(1).filter(|parent| {
+        !matches!(
+            parent.kind(),
+            AstKind::ParenthesizedExpression(_)
+                | AstKind::TSAsExpression(_)
+                | AstKind::TSSatisfiesExpression(_)
+        )
+    });
+    match parents.next().map(AstNode::kind) {
+        Some(AstKind::ExportDefaultDeclaration(_)) => is_vue_file(ctx.file_path()),
+        Some(AstKind::Argument(_)) => {
+            let Some(AstKind::CallExpression(call)) = parents.next().map(AstNode::kind) else {
+                return false;
+            };
+            let is_last_argument =
+                call.arguments.last().is_some_and(|arg| arg.span() == object.span);
+            is_last_argument
+                && match &call.callee {
+                    Expression::Identifier(ident) => ident.name == "defineComponent",
+                    Expression::StaticMemberExpression(member) => {
+                        member.object.is_specific_id("Vue")
+                            && matches!(member.property.name.as_str(), "component" | "extend")
+                    }
+                    _ => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+/// Returns the value of the component option `name`, e.g. `['foo']` for `props` in
+/// `{ props: ['foo'] }`.
+pub fn get_vue_component_option<'a, 'b>(
+    object: &'b ObjectExpression<'a>,
+    name: &str,
+) -> Option<&'b Expression<'a>> {
+    object.properties.iter().find_map(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property)
+            if !property.computed && property.key.is_specific_static_name(name) =>
+        {
+            Some(&property.value)
+        }
+        _ => None,
+    })
+}
+
+/// A prop declared by a Vue component.
+#[derive(Debug)]
+pub struct VueProp<'a, 'b> {
+    pub name: Cow<'a, str>,
+    /// The span of the prop name.
+    pub span: Span,
+    pub kind: VuePropKind<'a, 'b>,
+}
+
+#[derive(Debug)]
+pub enum VuePropKind<'a, 'b> {
+    /// `props: ['foo']`
+    Array,
+    /// `props: { foo: String }` or `defineProps({ foo: { type: String, default: '' } })`
+    Runtime(&'b Expression<'a>),
+    /// `defineProps<{ foo?: string }>()`
+    Type { optional: bool, ty: Option<&'b TSType<'a>> },
+}
+
+/// Returns the props declared by the `props` option, or by the runtime argument of
+/// `defineProps()`.
+pub fn get_vue_runtime_props<'a, 'b>(props: &'b Expression<'a>) -> Vec<VueProp<'a, 'b>> {
+    match props.get_inner_expression() {
+        Expression::ArrayExpression(array) => array
+            .elements
+            .iter()
+            .filter_map(|element| match element {
+                ArrayExpressionElement::StringLiteral(name) => Some(VueProp {
+                    name: Cow::Borrowed(name.value.as_str()),
+                    span: name.span,
+                    kind: VuePropKind::Array,
+                }),
+                _ => None,
+            })
+            .collect(),
+        Expression::ObjectExpression(object) => object
+            .properties
+            .iter()
+            .filter_map(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property) if !property.computed => {
+                    Some(VueProp {
+                        name: property.key.static_name()?,
+                        span: property.key.span(),
+                        kind: VuePropKind::Runtime(&property.value),
+                    })
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Returns the props declared by the type argument of `defineProps<Props>()`. Type references
+/// are resolved to interfaces and type aliases declared in the same file.
+pub fn get_vue_type_props<'a, 'b>(
+    call: &'b CallExpression<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Vec<VueProp<'a, 'b>> {
+    let Some(ty) = call.type_parameters.as_ref().and_then(|params| params.params.first()) else {
+        return vec![];
+    };
+    let members = match ty {
+        TSType::TSTypeLiteral(literal) => &literal.members,
+        TSType::TSTypeReference(reference) => {
+            let TSTypeName::IdentifierReference(name) = &reference.type_name else {
+                return vec![];
+            };
+            match find_local_type_members(name.name.as_str(), ctx) {
+                Some(members) => members,
+                None => return vec![],
+            }
+        }
+        _ => return vec![],
+    };
+    members
+        .iter()
+        .filter_map(|member| match member {
+            TSSignature::TSPropertySignature(property) if !property.computed => Some(VueProp {
+                name: property.key.static_name()?,
+                span: property.key.span(),
+                kind: VuePropKind::Type {
+                    optional: property.optional,
+                    ty: property.type_annotation.as_ref().map(|ty| &ty.type_annotation),
+                },
+            }),
+            TSSignature::TSMethodSignature(method) if !method.computed => Some(VueProp {
+                name: method.key.static_name()?,
+                span: method.key.span(),
+                kind: VuePropKind::Type { optional: method.optional, ty: None },
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the members of the top level interface or object type alias `name`.
+fn find_local_type_members<'a>(
+    name: &str,
+    ctx: &LintContext<'a>,
+) -> Option<&'a oxc_allocator::Vec<'a, TSSignature<'a>>> {
+    let AstKind::Program(program) = ctx.nodes().root_node()?.kind() else {
+        return None;
+    };
+    program.body.iter().find_map(|statement| {
+        let declaration = match statement {
+            Statement::ExportNamedDeclaration(export) => export.declaration.as_ref()?,
+            statement => statement.as_declaration()?,
+        };
+        match declaration {
+            Declaration::TSInterfaceDeclaration(interface) if interface.id.name == name => {
+                Some(&interface.body.body)
+            }
+            Declaration::TSTypeAliasDeclaration(alias) if alias.id.name == name => {
+                match &alias.type_annotation {
+                    TSType::TSTypeLiteral(literal) => Some(&literal.members),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    })
+}
@@ -1,6 +1,7 @@
 use napi_derive::napi;
 
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use oxc_sourcemap::{napi::SourceMap, JSONSourceMap};
 
 #[napi(object)]
 pub struct OxcError {
@@ -66,3 +67,27 @@ impl From<oxc_diagnostics::Severity> for Severity {
         }
     }
 }
+
+/// Decode a source map passed from JavaScript, e.g. an `inputMap` option.
+///
+/// `x_google_ignoreList` is not decoded. Maps composed with this one keep the order of its
+/// sources, so copy the list over to the composed map instead.
+///
+/// # Errors
+///
+/// Returns an error if the mappings cannot be decoded.
+pub fn decode_source_map(map: &SourceMap) -> Result<oxc_sourcemap::SourceMap, OxcDiagnostic> {
+    oxc_sourcemap::SourceMap::from_json(JSONSourceMap {
+        file: map.file.clone(),
+        mappings: map.mappings.clone(),
+        source_root: map.source_root.clone(),
+        sources: map.sources.clone(),
+        sources_content: map
+            .sources_content
+            .as_ref()
+            .map(|contents| contents.iter().cloned().map(Some).collect()),
+        names: map.names.clone(),
+        debug_id: None,
+    })
+    .map_err(|err| OxcDiagnostic::error(format!("Invalid input source map: {err}")))
+}
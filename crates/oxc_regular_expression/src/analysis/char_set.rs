@@ -0,0 +1,196 @@
+use crate::ast::{
+    CharacterClass, CharacterClassContents, CharacterClassContentsKind, CharacterClassEscapeKind,
+    Term,
+};
+
+use super::MatchFlags;
+
+/// The set of characters matched by a single-character element of a pattern, as sorted,
+/// non-overlapping and non-adjacent ranges of code points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharSet(Vec<(u32, u32)>);
+
+const DIGIT_RANGES: &[(u32, u32)] = &[(0x30, 0x39)];
+const WORD_RANGES: &[(u32, u32)] = &[(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A)];
+const SPACE_RANGES: &[(u32, u32)] = &[
+    (0x09, 0x0D),
+    (0x20, 0x20),
+    (0xA0, 0xA0),
+    (0x1680, 0x1680),
+    (0x2000, 0x200A),
+    (0x2028, 0x2029),
+    (0x202F, 0x202F),
+    (0x205F, 0x205F),
+    (0x3000, 0x3000),
+    (0xFEFF, 0xFEFF),
+];
+const LINE_TERMINATOR_RANGES: &[(u32, u32)] = &[(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)];
+
+/// Characters which are preferred by [`CharSet::sample`], so that attack strings are readable.
+const SAMPLE_CANDIDATES: &[char] = &['a', 'A', '0', ' ', '_', '-', '.', '!', '\n', '\t'];
+
+impl CharSet {
+    fn new(mut ranges: Vec<(u32, u32)>) -> Self {
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (min, max) in ranges {
+            match merged.last_mut() {
+                Some(last) if min <= last.1.saturating_add(1) => last.1 = last.1.max(max),
+                _ => merged.push((min, max)),
+            }
+        }
+        Self(merged)
+    }
+
+    /// Characters matched by `term`, or `None` if it is not a single-character element, or
+    /// contains escapes which are not supported, e.g. `\p{L}`.
+    pub fn from_term(term: &Term, flags: MatchFlags) -> Option<Self> {
+        match term {
+            Term::Character(ch) => Some(Self::from_range(ch.value, ch.value, flags)),
+            Term::Dot(_) => {
+                let all = Self(vec![(0, flags.max_code_point())]);
+                if flags.dot_all {
+                    Some(all)
+                } else {
+                    Some(Self(LINE_TERMINATOR_RANGES.to_vec()).complement(flags.max_code_point()))
+                }
+            }
+            Term::CharacterClassEscape(escape) => Some(Self::from_escape(escape.kind, flags)),
+            Term::CharacterClass(class) => Self::from_class(class, flags),
+            _ => None,
+        }
+    }
+
+    /// Characters matched by an element of a character class.
+    pub fn from_class_contents(
+        contents: &CharacterClassContents,
+        flags: MatchFlags,
+    ) -> Option<Self> {
+        match contents {
+            CharacterClassContents::Character(ch) => {
+                Some(Self::from_range(ch.value, ch.value, flags))
+            }
+            CharacterClassContents::CharacterClassRange(range) => {
+                Some(Self::from_range(range.min.value, range.max.value, flags))
+            }
+            CharacterClassContents::CharacterClassEscape(escape) => {
+                Some(Self::from_escape(escape.kind, flags))
+            }
+            _ => None,
+        }
+    }
+
+    fn from_class(class: &CharacterClass, flags: MatchFlags) -> Option<Self> {
+        if class.kind != CharacterClassContentsKind::Union || class.strings {
+            return None;
+        }
+        let mut ranges = vec![];
+        for contents in &class.body {
+            ranges.extend(Self::from_class_contents(contents, flags)?.0);
+        }
+        let set = Self::new(ranges);
+        Some(if class.negative { set.complement(flags.max_code_point()) } else { set })
+    }
+
+    fn from_escape(kind: CharacterClassEscapeKind, flags: MatchFlags) -> Self {
+        let (ranges, negative) = match kind {
+            CharacterClassEscapeKind::D => (DIGIT_RANGES, false),
+            CharacterClassEscapeKind::NegativeD => (DIGIT_RANGES, true),
+            CharacterClassEscapeKind::W => (WORD_RANGES, false),
+            CharacterClassEscapeKind::NegativeW => (WORD_RANGES, true),
+            CharacterClassEscapeKind::S => (SPACE_RANGES, false),
+            CharacterClassEscapeKind::NegativeS => (SPACE_RANGES, true),
+        };
+        let set = Self(ranges.to_vec());
+        if negative {
+            set.complement(flags.max_code_point())
+        } else {
+            set
+        }
+    }
+
+    /// `min-max`, plus the other case of ASCII letters with the `i` flag.
+    fn from_range(min: u32, max: u32, flags: MatchFlags) -> Self {
+        let mut ranges = vec![(min, max)];
+        if flags.ignore_case {
+            // `A-Z` <-> `a-z`
+            for (lower, upper, other) in [(0x41, 0x5A, 0x61), (0x61, 0x7A, 0x41)] {
+                let (start, end) = (min.max(lower), max.min(upper));
+                if start <= end {
+                    ranges.push((start - lower + other, end - lower + other));
+                }
+            }
+        }
+        Self::new(ranges)
+    }
+
+    fn complement(&self, max_code_point: u32) -> Self {
+        let mut ranges = vec![];
+        let mut next = 0;
+        for &(min, max) in &self.0 {
+            if min > next {
+                ranges.push((next, min - 1));
+            }
+            next = max + 1;
+        }
+        if next <= max_code_point {
+            ranges.push((next, max_code_point));
+        }
+        Self(ranges)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, code_point: u32) -> bool {
+        self.0.iter().any(|&(min, max)| min <= code_point && code_point <= max)
+    }
+
+    /// `true` if every character in `self` is also in `other`.
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .all(|&(min, max)| other.0.iter().any(|&(o_min, o_max)| o_min <= min && max <= o_max))
+    }
+
+    /// `true` if some character is in both `self` and `other`.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .any(|&(min, max)| other.0.iter().any(|&(o_min, o_max)| min <= o_max && o_min <= max))
+    }
+
+    /// Characters which are in both `self` and `other`.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        for &(min, max) in &self.0 {
+            for &(o_min, o_max) in &other.0 {
+                let (start, end) = (min.max(o_min), max.min(o_max));
+                if start <= end {
+                    ranges.push((start, end));
+                }
+            }
+        }
+        Self::new(ranges)
+    }
+
+    /// Characters which are in `self` or `other`.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(self.0.iter().chain(&other.0).copied().collect())
+    }
+
+    /// A character in the set, preferring printable ASCII characters.
+    ///
+    /// Returns `None` if the set is empty, or only contains lone surrogates.
+    pub fn sample(&self) -> Option<char> {
+        SAMPLE_CANDIDATES.iter().copied().find(|&c| self.contains(u32::from(c))).or_else(|| {
+            self.0.iter().find_map(|&(min, max)| {
+                // Skip the surrogate range, which has no `char`s.
+                char::from_u32(min).or_else(|| char::from_u32(max.min(0xE000)))
+            })
+        })
+    }
+}
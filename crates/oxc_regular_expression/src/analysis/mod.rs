@@ -0,0 +1,38 @@
+//! Static analysis of parsed patterns.
+
+mod char_set;
+mod redos;
+
+pub use char_set::CharSet;
+pub use redos::{analyze_backtracking, AttackString, Backtracking, BacktrackingKind};
+
+/// Flags which change the characters matched by a pattern.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MatchFlags {
+    /// `i` flag
+    pub ignore_case: bool,
+    /// `s` flag
+    pub dot_all: bool,
+    /// `u` or `v` flag
+    pub unicode_mode: bool,
+}
+
+impl MatchFlags {
+    /// Parse the flags text of a regular expression, e.g. `gis`. Unknown flags are ignored.
+    pub fn from_flags_text(flags: &str) -> Self {
+        Self {
+            ignore_case: flags.contains('i'),
+            dot_all: flags.contains('s'),
+            unicode_mode: flags.contains('u') || flags.contains('v'),
+        }
+    }
+
+    /// The largest code point, or code unit outside of unicode mode, matched by a pattern.
+    pub fn max_code_point(self) -> u32 {
+        if self.unicode_mode {
+            0x10_FFFF
+        } else {
+            0xFFFF
+        }
+    }
+}
@@ -0,0 +1,532 @@
+//! Detection of patterns which can backtrack catastrophically, also known as ReDoS.
+//!
+//! The analysis is a heuristic over the structure of the pattern, not a full simulation of the
+//! regex engine. It finds:
+//!
+//! - Exponential backtracking: an unbounded quantifier whose body can match the same string in
+//!   more than one way, either through a nested unbounded quantifier, e.g. `(a+)+` or
+//!   `(\w+\s?)*`, or through alternatives which match a common string, e.g. `(a|a)*` or
+//!   `(\d|\w)+`.
+//! - Polynomial backtracking: two unbounded quantifiers in sequence which can match the same
+//!   characters, and are only separated by elements which can match the empty string, e.g.
+//!   `\s*\s*` or `\d+\.?\d+`.
+//!
+//! Only elements which match single characters, e.g. `a`, `\d`, `.` or `[a-z]`, are compared.
+//! Patterns with backreferences, lookarounds or unicode property escapes in the ambiguous part
+//! are not reported.
+
+use std::fmt;
+
+use oxc_span::{GetSpan, Span};
+
+use crate::ast::{Alternative, Disjunction, Modifiers, Pattern, Quantifier, Term};
+
+use super::{CharSet, MatchFlags};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BacktrackingKind {
+    /// The matching time grows exponentially with the length of the input, e.g. `(a+)+$`.
+    Exponential,
+    /// The matching time grows polynomially with the length of the input, e.g. `a*a*$`.
+    Polynomial,
+}
+
+/// An input which makes a pattern backtrack: `prefix`, followed by `pump` repeated many times,
+/// followed by `suffix`, which is meant to make the match fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttackString {
+    pub prefix: String,
+    pub pump: String,
+    pub suffix: String,
+}
+
+impl AttackString {
+    /// The number of repetitions of `pump` used by the [`fmt::Display`] implementation.
+    pub const DEFAULT_REPEAT: usize = 30;
+
+    /// The attack string with `pump` repeated `count` times.
+    pub fn build(&self, count: usize) -> String {
+        format!("{}{}{}", self.prefix, self.pump.repeat(count), self.suffix)
+    }
+}
+
+/// Print the attack string as a JavaScript expression, e.g. `"x" + "a".repeat(30) + "!"`.
+impl fmt::Display for AttackString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.prefix.is_empty() {
+            write!(f, "\"{}\" + ", self.prefix.escape_default())?;
+        }
+        write!(f, "\"{}\".repeat({})", self.pump.escape_default(), Self::DEFAULT_REPEAT)?;
+        if !self.suffix.is_empty() {
+            write!(f, " + \"{}\"", self.suffix.escape_default())?;
+        }
+        Ok(())
+    }
+}
+
+/// A sub-pattern which can backtrack catastrophically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backtracking {
+    pub kind: BacktrackingKind,
+    /// The offending sub-pattern, e.g. `(a+)+` of `^(a+)+$`, or `\s*\s*` of `\s*\s*$`.
+    pub span: Span,
+    /// An input which triggers the backtracking.
+    pub attack: AttackString,
+}
+
+/// Find sub-patterns of `pattern` which can backtrack catastrophically.
+///
+/// Sub-patterns nested inside a reported exponential quantifier are not reported again.
+pub fn analyze_backtracking(pattern: &Pattern, flags: MatchFlags) -> Vec<Backtracking> {
+    let mut analyzer = Analyzer { results: vec![] };
+    analyzer.check_disjunction(&pattern.body, "", flags);
+    analyzer.results
+}
+
+/// Characters which are tried as the suffix of an attack string, in order.
+const SUFFIX_CANDIDATES: &[char] = &['!', '\n', '_', '=', ' ', 'a', '0', '\0'];
+
+/// Upper bound for the repetition of an element in the prefix of an attack string, e.g. for
+/// `a{1000}`.
+const MAX_PREFIX_REPEAT: u64 = 100;
+
+struct Analyzer {
+    results: Vec<Backtracking>,
+}
+
+impl Analyzer {
+    fn check_disjunction(&mut self, disjunction: &Disjunction, prefix: &str, flags: MatchFlags) {
+        for alternative in &disjunction.body {
+            self.check_alternative(alternative, prefix, flags);
+        }
+    }
+
+    fn check_alternative(&mut self, alternative: &Alternative, prefix: &str, flags: MatchFlags) {
+        let mut prefix = prefix.to_string();
+        // Index of the last quantifier which was reported as polynomial, so that `a*a*a*` is
+        // reported once.
+        let mut next_polynomial_start = 0;
+        for (i, term) in alternative.body.iter().enumerate() {
+            if i >= next_polynomial_start {
+                if let Some(end) =
+                    self.check_polynomial(&alternative.body.as_slice()[i..], &prefix, flags)
+                {
+                    next_polynomial_start = i + end + 1;
+                }
+            }
+            self.check_term(term, &prefix, flags);
+            prefix.push_str(&sample_term(term, flags));
+        }
+    }
+
+    fn check_term(&mut self, term: &Term, prefix: &str, flags: MatchFlags) {
+        match term {
+            Term::Quantifier(quantifier) => {
+                if let Some((pump, pump_chars)) = exponential_pump(quantifier, flags) {
+                    self.results.push(Backtracking {
+                        kind: BacktrackingKind::Exponential,
+                        span: quantifier.span,
+                        attack: AttackString {
+                            prefix: prefix.to_string(),
+                            pump,
+                            suffix: suffix_for(&pump_chars),
+                        },
+                    });
+                    return;
+                }
+                self.check_term(&quantifier.body, prefix, flags);
+            }
+            Term::CapturingGroup(group) => self.check_disjunction(&group.body, prefix, flags),
+            Term::IgnoreGroup(group) => self.check_disjunction(
+                &group.body,
+                prefix,
+                group_flags(group.modifiers.as_ref(), flags),
+            ),
+            Term::LookAroundAssertion(assertion) => {
+                self.check_disjunction(&assertion.body, prefix, flags);
+            }
+            _ => {}
+        }
+    }
+
+    /// Check whether `terms[0]` is an unbounded quantifier which can exchange characters with a
+    /// later unbounded quantifier, e.g. `\d+\.?\d+`.
+    ///
+    /// Returns the index of the later quantifier in `terms`, if reported.
+    fn check_polynomial(
+        &mut self,
+        terms: &[Term],
+        prefix: &str,
+        flags: MatchFlags,
+    ) -> Option<usize> {
+        let (first, rest) = terms.split_first()?;
+        let first_chars = unbounded_chars(first, flags)?;
+        for (i, term) in rest.iter().enumerate() {
+            if let Some(chars) = unbounded_chars(term, flags) {
+                let common = first_chars.intersection(&chars);
+                if let Some(pump) = common.sample() {
+                    self.results.push(Backtracking {
+                        kind: BacktrackingKind::Polynomial,
+                        span: Span::new(first.span().start, term.span().end),
+                        attack: AttackString {
+                            prefix: prefix.to_string(),
+                            pump: pump.to_string(),
+                            suffix: suffix_for(&common),
+                        },
+                    });
+                    return Some(i + 1);
+                }
+            }
+            if !is_nullable(term) {
+                return None;
+            }
+        }
+        None
+    }
+}
+
+/// The string pumped by an unbounded `quantifier` whose body can match it in more than one way,
+/// and the characters of the string.
+fn exponential_pump(quantifier: &Quantifier, flags: MatchFlags) -> Option<(String, CharSet)> {
+    if quantifier.max.is_some() {
+        return None;
+    }
+    let (alternatives, flags) = body_alternatives(&quantifier.body, flags);
+
+    // `(a+)+`, `(\w+\s?)*`
+    for terms in &alternatives {
+        if let Some(chars) = pumpable_chars(terms, flags) {
+            let pump = chars.sample()?;
+            return Some((pump.to_string(), chars));
+        }
+    }
+
+    // `(a|a)*`, `(\d|\w+)*`
+    for (i, a) in alternatives.iter().enumerate() {
+        for b in &alternatives[i + 1..] {
+            if let Some(common) = common_string(a, b, flags) {
+                return Some(common);
+            }
+        }
+    }
+
+    None
+}
+
+/// The alternatives of the body of a quantifier, e.g. `a` and `b` of `(?:a|b)*`, or `a` of `a*`.
+fn body_alternatives<'t, 'a>(
+    body: &'t Term<'a>,
+    flags: MatchFlags,
+) -> (Vec<&'t [Term<'a>]>, MatchFlags) {
+    match body {
+        Term::CapturingGroup(group) => (disjunction_terms(&group.body), flags),
+        Term::IgnoreGroup(group) => {
+            (disjunction_terms(&group.body), group_flags(group.modifiers.as_ref(), flags))
+        }
+        term => (vec![std::slice::from_ref(term)], flags),
+    }
+}
+
+fn disjunction_terms<'t, 'a>(disjunction: &'t Disjunction<'a>) -> Vec<&'t [Term<'a>]> {
+    disjunction.body.iter().map(|alternative| alternative.body.as_slice()).collect()
+}
+
+/// `flags` inside of a group with `modifiers`, e.g. `(?i:...)`.
+fn group_flags(modifiers: Option<&Modifiers>, flags: MatchFlags) -> MatchFlags {
+    let Some(modifiers) = modifiers else { return flags };
+    let mut flags = flags;
+    if modifiers.enabling.as_ref().is_some_and(|modifier| modifier.ignore_case) {
+        flags.ignore_case = true;
+    }
+    if modifiers.disabling.as_ref().is_some_and(|modifier| modifier.ignore_case) {
+        flags.ignore_case = false;
+    }
+    flags
+}
+
+/// The characters which can be repeated any number of times by a sequence of terms, if it
+/// contains a repeatable term and all other terms can match the empty string, e.g. `a` of
+/// `a+b?`.
+fn pumpable_chars(terms: &[Term], flags: MatchFlags) -> Option<CharSet> {
+    terms.iter().enumerate().find_map(|(i, term)| {
+        let others_nullable =
+            terms.iter().enumerate().all(|(j, other)| i == j || is_nullable(other));
+        if others_nullable {
+            repeatable_chars(term, flags)
+        } else {
+            None
+        }
+    })
+}
+
+/// The characters which can be repeated by `term`, e.g. `a` of `a+`, `(a|b)*` or `(?:a+b?)`.
+fn repeatable_chars(term: &Term, flags: MatchFlags) -> Option<CharSet> {
+    match term {
+        Term::Quantifier(quantifier) if quantifier.max.map_or(true, |max| max > 1) => {
+            single_chars(&quantifier.body, flags)
+                .or_else(|| repeatable_chars(&quantifier.body, flags))
+        }
+        Term::CapturingGroup(_) | Term::IgnoreGroup(_) => {
+            let (alternatives, flags) = body_alternatives(term, flags);
+            alternatives.iter().find_map(|terms| pumpable_chars(terms, flags))
+        }
+        _ => None,
+    }
+}
+
+/// Characters matched by an element which matches a single character, e.g. `a`, `[a-z]`, or a
+/// group of such elements, e.g. `(?:a|\d)`.
+fn single_chars(term: &Term, flags: MatchFlags) -> Option<CharSet> {
+    match term {
+        Term::CapturingGroup(_) | Term::IgnoreGroup(_) => {
+            let (alternatives, flags) = body_alternatives(term, flags);
+            let mut union: Option<CharSet> = None;
+            for terms in alternatives {
+                let [term] = terms else { return None };
+                let chars = single_chars(term, flags)?;
+                union = Some(match union {
+                    Some(union) => union.union(&chars),
+                    None => chars,
+                });
+            }
+            union
+        }
+        term => CharSet::from_term(term, flags),
+    }
+}
+
+/// Characters matched by an unbounded quantifier of single characters, e.g. `\d` of `\d+`.
+fn unbounded_chars(term: &Term, flags: MatchFlags) -> Option<CharSet> {
+    match term {
+        Term::Quantifier(quantifier) if quantifier.max.is_none() => {
+            single_chars(&quantifier.body, flags)
+        }
+        _ => None,
+    }
+}
+
+/// A quantified single-character element of an alternative, e.g. `\d{1,3}`.
+struct Repetition {
+    chars: CharSet,
+    min: u64,
+    max: Option<u64>,
+}
+
+impl Repetition {
+    fn from_terms(terms: &[Term], flags: MatchFlags) -> Option<Vec<Self>> {
+        terms
+            .iter()
+            .map(|term| match term {
+                Term::Quantifier(quantifier) => Some(Self {
+                    chars: single_chars(&quantifier.body, flags)?,
+                    min: quantifier.min,
+                    max: quantifier.max,
+                }),
+                term => Some(Self { chars: single_chars(term, flags)?, min: 1, max: Some(1) }),
+            })
+            .collect()
+    }
+
+    fn is_single(&self) -> bool {
+        self.min == 1 && self.max == Some(1)
+    }
+
+    fn allows(&self, count: u64) -> bool {
+        self.min <= count && self.max.map_or(true, |max| count <= max)
+    }
+}
+
+/// A non-empty string which is matched by both alternatives `a` and `b`, and its characters.
+fn common_string(a: &[Term], b: &[Term], flags: MatchFlags) -> Option<(String, CharSet)> {
+    let a = Repetition::from_terms(a, flags)?;
+    let b = Repetition::from_terms(b, flags)?;
+
+    // Character sets of each position of the common string.
+    let positions: Vec<CharSet> = match (a.as_slice(), b.as_slice()) {
+        // `(\d+|\w+)*`
+        ([a], [b]) => {
+            let len = a.min.max(b.min).max(1);
+            if !a.allows(len) || !b.allows(len) {
+                return None;
+            }
+            let common = a.chars.intersection(&b.chars);
+            (0..len.min(MAX_PREFIX_REPEAT)).map(|_| common.clone()).collect()
+        }
+        // `(ab|\w+)*`
+        (sequence, [repetition]) | ([repetition], sequence)
+            if sequence.iter().all(Repetition::is_single) =>
+        {
+            if !repetition.allows(sequence.len() as u64) {
+                return None;
+            }
+            sequence.iter().map(|element| element.chars.intersection(&repetition.chars)).collect()
+        }
+        // `(ab|a\w)*`
+        (a, b) if a.len() == b.len() && a.iter().chain(b).all(Repetition::is_single) => {
+            a.iter().zip(b).map(|(a, b)| a.chars.intersection(&b.chars)).collect()
+        }
+        _ => return None,
+    };
+
+    let mut pump = String::new();
+    let mut chars: Option<CharSet> = None;
+    for position in positions {
+        pump.push(position.sample()?);
+        chars = Some(match chars {
+            Some(chars) => chars.union(&position),
+            None => position,
+        });
+    }
+    chars.map(|chars| (pump, chars))
+}
+
+/// `true` if `term` can match the empty string.
+fn is_nullable(term: &Term) -> bool {
+    match term {
+        Term::BoundaryAssertion(_) | Term::LookAroundAssertion(_) => true,
+        Term::Quantifier(quantifier) => quantifier.min == 0 || is_nullable(&quantifier.body),
+        Term::CapturingGroup(group) => is_nullable_disjunction(&group.body),
+        Term::IgnoreGroup(group) => is_nullable_disjunction(&group.body),
+        _ => false,
+    }
+}
+
+fn is_nullable_disjunction(disjunction: &Disjunction) -> bool {
+    disjunction.body.iter().any(|alternative| alternative.body.iter().all(is_nullable))
+}
+
+/// A string matched by `term`, used to reach the offending sub-pattern in an attack string.
+fn sample_term(term: &Term, flags: MatchFlags) -> String {
+    match term {
+        Term::Quantifier(quantifier) => {
+            let count = quantifier.min.min(MAX_PREFIX_REPEAT);
+            sample_term(&quantifier.body, flags).repeat(usize::try_from(count).unwrap_or(0))
+        }
+        Term::CapturingGroup(_) | Term::IgnoreGroup(_) => {
+            let (alternatives, flags) = body_alternatives(term, flags);
+            alternatives
+                .first()
+                .map(|terms| terms.iter().map(|term| sample_term(term, flags)).collect())
+                .unwrap_or_default()
+        }
+        term => CharSet::from_term(term, flags)
+            .and_then(|chars| chars.sample())
+            .map(String::from)
+            .unwrap_or_default(),
+    }
+}
+
+/// A character which is not in `chars`, to make the match fail after the pumped string.
+fn suffix_for(chars: &CharSet) -> String {
+    SUFFIX_CANDIDATES
+        .iter()
+        .find(|&&c| !chars.contains(u32::from(c)))
+        .map(char::to_string)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use super::{analyze_backtracking, BacktrackingKind};
+    use crate::{analysis::MatchFlags, LiteralParser, Options};
+
+    fn analyze(source_text: &str, flags: &str) -> Vec<(BacktrackingKind, String, String)> {
+        let allocator = Allocator::default();
+        let pattern = LiteralParser::new(&allocator, source_text, Some(flags), Options::default())
+            .parse()
+            .unwrap_or_else(|err| panic!("Failed to parse {source_text}: {err}"));
+        analyze_backtracking(&pattern, MatchFlags::from_flags_text(flags))
+            .into_iter()
+            .map(|result| {
+                let span = result.span;
+                let sub_pattern = &source_text[span.start as usize..span.end as usize];
+                (result.kind, sub_pattern.to_string(), result.attack.to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn safe() {
+        for (source_text, flags) in [
+            ("abc", ""),
+            ("a+", ""),
+            ("a+b+", ""),
+            (r"\d+\.\d+", ""),
+            (r"(\w+\s)+", ""),
+            ("(a|b)*", ""),
+            ("(ab|cd)+", ""),
+            ("(a|ab)*", ""),
+            ("(a+){2}", ""),
+            (r"^[a-z]+(?:-[a-z]+)*$", ""),
+            (r"(a+)\1", ""),
+            ("a*?b*", ""),
+            (r"(\p{L}+)+", "u"),
+            ("a*A*", ""),
+        ] {
+            assert_eq!(analyze(source_text, flags), vec![], "{source_text}");
+        }
+    }
+
+    #[test]
+    fn exponential() {
+        use BacktrackingKind::Exponential;
+
+        for (source_text, flags, expected_span, expected_attack) in [
+            ("(a+)+", "", "(a+)+", r#""a".repeat(30) + "!""#),
+            ("^(a+)+$", "", "(a+)+", r#""a".repeat(30) + "!""#),
+            ("x(a*)*y", "", "(a*)*", r#""x" + "a".repeat(30) + "!""#),
+            (r"(\w+\s?)*$", "", r"(\w+\s?)*", r#""a".repeat(30) + "!""#),
+            ("(?:a|a)*b", "", "(?:a|a)*", r#""a".repeat(30) + "!""#),
+            (r"(\d|\w)+$", "", r"(\d|\w)+", r#""0".repeat(30) + "!""#),
+            (r"(.|\s)*$", "", r"(.|\s)*", r#"" ".repeat(30) + "!""#),
+            (r"(\d+|\w+)*!", "", r"(\d+|\w+)*", r#""0".repeat(30) + "!""#),
+            ("(ab|a[a-z])*$", "", "(ab|a[a-z])*", r#""ab".repeat(30) + "!""#),
+            ("(?:(?:a|b)+)+$", "", "(?:(?:a|b)+)+", r#""a".repeat(30) + "!""#),
+            ("((a+))+$", "", "((a+))+", r#""a".repeat(30) + "!""#),
+            ("(?:a{1,2})+$", "u", "(?:a{1,2})+", r#""a".repeat(30) + "!""#),
+            ("(?:a+|A)+$", "i", "(?:a+|A)+", r#""a".repeat(30) + "!""#),
+            ("(?i:a|A)+$", "", "(?i:a|A)+", r#""a".repeat(30) + "!""#),
+            ("(!+)+$", "", "(!+)+", r#""!".repeat(30) + "\n""#),
+            ("((a+)+)+$", "", "((a+)+)+", r#""a".repeat(30) + "!""#),
+            ("(?=(a+)+)", "", "(a+)+", r#""a".repeat(30) + "!""#),
+        ] {
+            assert_eq!(
+                analyze(source_text, flags),
+                vec![(Exponential, expected_span.to_string(), expected_attack.to_string())],
+                "{source_text}"
+            );
+        }
+    }
+
+    #[test]
+    fn polynomial() {
+        use BacktrackingKind::Polynomial;
+
+        for (source_text, flags, expected_span, expected_attack) in [
+            (r"\s*\s*$", "", r"\s*\s*", r#"" ".repeat(30) + "!""#),
+            (r"\d+\.?\d+$", "", r"\d+\.?\d+", r#""0".repeat(30) + "!""#),
+            (r"x\w+\d+$", "", r"\w+\d+", r#""x" + "0".repeat(30) + "!""#),
+            ("a*A*$", "i", "a*A*", r#""a".repeat(30) + "!""#),
+            (".*a+", "", ".*a+", r#""a".repeat(30) + "!""#),
+        ] {
+            assert_eq!(
+                analyze(source_text, flags),
+                vec![(Polynomial, expected_span.to_string(), expected_attack.to_string())],
+                "{source_text}"
+            );
+        }
+
+        assert_eq!(analyze("a*a*a*$", "").len(), 1);
+    }
+
+    #[test]
+    fn attack_string() {
+        let allocator = Allocator::default();
+        let pattern =
+            LiteralParser::new(&allocator, "x(a+)+$", None, Options::default()).parse().unwrap();
+        let results = analyze_backtracking(&pattern, MatchFlags::default());
+        assert_eq!(results[0].attack.build(3), "xaaa!");
+    }
+}
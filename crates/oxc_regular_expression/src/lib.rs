@@ -14,6 +14,7 @@ mod generated {
     mod derive_estree;
 }
 
+pub mod analysis;
 pub mod ast;
 pub use crate::{
     ast_impl::visit,
@@ -504,7 +504,9 @@ pub fn check_break_statement<'a>(
                     |label| ctx.error(invalid_label_target(label.span)),
                 );
             }
-            AstKind::Function(_) | AstKind::StaticBlock(_) => {
+            AstKind::Function(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::StaticBlock(_) => {
                 return stmt.label.as_ref().map_or_else(
                     || ctx.error(invalid_break(stmt.span)),
                     |label| ctx.error(invalid_label_jump_target(label.span)),
@@ -550,7 +552,9 @@ pub fn check_continue_statement<'a>(
                     |label| ctx.error(invalid_label_target(label.span)),
                 );
             }
-            AstKind::Function(_) | AstKind::StaticBlock(_) => {
+            AstKind::Function(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::StaticBlock(_) => {
                 return stmt.label.as_ref().map_or_else(
                     || ctx.error(invalid_continue(stmt.span)),
                     |label| ctx.error(invalid_label_jump_target(label.span)),
@@ -598,7 +602,10 @@ pub fn check_labeled_statement<'a>(
     for node_id in ctx.nodes.ancestor_ids(node.id()).skip(1) {
         match ctx.nodes.kind(node_id) {
             // label cannot cross boundary on function or static block
-            AstKind::Function(_) | AstKind::StaticBlock(_) | AstKind::Program(_) => break,
+            AstKind::Function(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::StaticBlock(_)
+            | AstKind::Program(_) => break,
             // check label name redeclaration
             AstKind::LabeledStatement(label_stmt) if stmt.label.name == label_stmt.label.name => {
                 return ctx.error(label_redeclaration(
@@ -794,6 +801,23 @@ pub fn check_super<'a>(sup: &Super, node: &AstNode<'a>, ctx: &SemanticBuilder<'a
     // skip(2) is the parent `CallExpression` or `NewExpression`
     for node_id in ctx.nodes.ancestor_ids(node.id()).skip(2) {
         match ctx.nodes.kind(node_id) {
+            // Arrow functions inherit `super` from the enclosing scope, but plain
+            // functions do not, e.g. `class A { x = function() { super.x } }`
+            AstKind::Function(_) => match ctx.nodes.parent_kind(node_id) {
+                Some(AstKind::MethodDefinition(_)) => {}
+                Some(AstKind::ObjectProperty(prop)) if prop.method || prop.kind.is_accessor() => {
+                    if let Some(super_call_span) = super_call_span {
+                        ctx.error(unexpected_super_call(super_call_span));
+                    }
+                    break;
+                }
+                _ => {
+                    return super_call_span.map_or_else(
+                        || ctx.error(unexpected_super_reference(sup.span)),
+                        |super_call_span| ctx.error(unexpected_super_call(super_call_span)),
+                    );
+                }
+            },
             AstKind::MethodDefinition(def) => {
                 // ClassElement : MethodDefinition
                 // It is a Syntax Error if PropName of MethodDefinition is not "constructor" and HasDirectSuper of MethodDefinition is true.
@@ -1034,6 +1058,33 @@ fn await_or_yield_in_parameter(x0: &str, span1: Span) -> OxcDiagnostic {
         .with_label(span1.label(format!("{x0} expression not allowed in formal parameter")))
 }
 
+fn await_or_yield_in_class_field_initializer(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("{x0} expression not allowed in class field initializer"))
+        .with_label(span1)
+}
+
+/// Class field initializers are evaluated as if they were methods, so they
+/// cannot contain `await` or `yield` of an enclosing function.
+fn is_in_class_field_initializer<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) -> bool {
+    let span = node.kind().span();
+    for node_id in ctx.nodes.ancestor_ids(node.id()).skip(1) {
+        match ctx.nodes.kind(node_id) {
+            AstKind::PropertyDefinition(prop) => {
+                return prop
+                    .value
+                    .as_ref()
+                    .is_some_and(|value| value.span().contains_inclusive(span));
+            }
+            AstKind::Program(_)
+            | AstKind::Function(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::StaticBlock(_) => break,
+            _ => {}
+        }
+    }
+    false
+}
+
 pub fn check_await_expression<'a>(
     expr: &AwaitExpression,
     node: &AstNode<'a>,
@@ -1042,6 +1093,9 @@ pub fn check_await_expression<'a>(
     if is_in_formal_parameters(node, ctx) {
         ctx.error(await_or_yield_in_parameter("await", expr.span));
     }
+    if is_in_class_field_initializer(node, ctx) {
+        ctx.error(await_or_yield_in_class_field_initializer("await", expr.span));
+    }
     // It is a Syntax Error if ClassStaticBlockStatementList Contains await is true.
     if ctx.scope.get_flags(node.scope_id()).is_class_static_block() {
         let start = expr.span.start;
@@ -1057,4 +1111,7 @@ pub fn check_yield_expression<'a>(
     if is_in_formal_parameters(node, ctx) {
         ctx.error(await_or_yield_in_parameter("yield", expr.span));
     }
+    if is_in_class_field_initializer(node, ctx) {
+        ctx.error(await_or_yield_in_class_field_initializer("yield", expr.span));
+    }
 }
@@ -37,6 +37,7 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
         AstKind::Directive(dir) => js::check_directive(dir, ctx),
         AstKind::ModuleDeclaration(decl) => {
             js::check_module_declaration(decl, node, ctx);
+            ts::check_module_declaration(decl, ctx);
         }
         AstKind::MetaProperty(prop) => js::check_meta_property(prop, node, ctx),
 
@@ -81,6 +82,8 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
             ts::check_method_definition(method, ctx);
         }
         AstKind::PropertyDefinition(prop) => ts::check_property_definition(prop, ctx),
+        AstKind::Function(func) => ts::check_function(func, ctx),
+        AstKind::TSMethodSignature(sig) => ts::check_ts_method_signature(sig, ctx),
         AstKind::ObjectProperty(prop) => {
             js::check_object_property(prop, ctx);
             ts::check_object_property(prop, ctx);
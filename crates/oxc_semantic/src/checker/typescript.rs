@@ -436,7 +436,13 @@ pub fn check_method_definition<'a>(method: &MethodDefinition<'a>, ctx: &Semantic
                 // have abstract modifiers, but this gets checked during parsing
                 MethodDefinitionKind::Constructor => {}
             }
-            ctx.error(abstract_method_cannot_have_implementation(method_name, span));
+        }
+    }
+
+    // Illegal to have `constructor<T>() {}`
+    if method.kind.is_constructor() {
+        if let Some(type_parameters) = &method.value.type_parameters {
+            ctx.error(type_parameters_on_constructor(type_parameters.span));
         }
     }
 
@@ -456,6 +462,11 @@ pub fn check_method_definition<'a>(method: &MethodDefinition<'a>, ctx: &Semantic
     }
 }
 
+/// Type parameters cannot appear on a constructor declaration. (1092)
+fn type_parameters_on_constructor(span: Span) -> OxcDiagnostic {
+    ts_error("1092", "Type parameters cannot appear on a constructor declaration.").with_label(span)
+}
+
 pub fn check_property_definition<'a>(prop: &PropertyDefinition<'a>, ctx: &SemanticBuilder<'a>) {
     if prop.r#type.is_abstract() && prop.value.is_some() {
         let (prop_name, span) = prop.key.prop_name().unwrap_or_else(|| {
@@ -463,6 +474,101 @@ pub fn check_property_definition<'a>(prop: &PropertyDefinition<'a>, ctx: &Semant
             (&ctx.source_text[key_span], key_span)
         });
         ctx.error(abstract_property_cannot_have_initializer(prop_name, span));
+    } else if let Some(value) = &prop.value {
+        // `class A { declare x = 1 }`, `declare class A { x = 1 }`
+        // Readonly properties may have an initializer in ambient classes, e.g. `readonly x = 1`.
+        if prop.declare || (!prop.readonly && is_in_ambient_context(ctx)) {
+            ctx.error(initializer_in_ambient_context(value.span()));
+        }
+    }
+}
+
+/// An implementation cannot be declared in ambient contexts. (1183)
+fn implementation_in_ambient_context(span: Span) -> OxcDiagnostic {
+    ts_error("1183", "An implementation cannot be declared in ambient contexts.").with_label(span)
+}
+
+/// Whether the current node is inside a `declare` class or namespace, or a
+/// `.d.ts` file.
+fn is_in_ambient_context(ctx: &SemanticBuilder<'_>) -> bool {
+    ctx.source_type.is_typescript_definition()
+        || ctx.nodes.ancestor_kinds(ctx.current_node_id).any(|kind| match kind {
+            AstKind::Class(class) => class.declare,
+            AstKind::TSModuleDeclaration(decl) => decl.declare,
+            _ => false,
+        })
+}
+
+pub fn check_function<'a>(func: &Function<'a>, ctx: &SemanticBuilder<'a>) {
+    // `declare function f() {}`, `declare class A { m() {} }`
+    if let Some(body) = &func.body {
+        if func.declare || is_in_ambient_context(ctx) {
+            ctx.error(implementation_in_ambient_context(Span::new(
+                body.span.start,
+                body.span.start + 1,
+            )));
+        }
+    }
+}
+
+/// A 'get' accessor cannot have parameters. (1054)
+fn getter_signature_with_parameters(span: Span) -> OxcDiagnostic {
+    ts_error("1054", "A 'get' accessor cannot have parameters.").with_label(span)
+}
+
+/// A 'set' accessor must have exactly one parameter. (1049)
+fn setter_signature_with_parameters(span: Span) -> OxcDiagnostic {
+    ts_error("1049", "A 'set' accessor must have exactly one parameter.").with_label(span)
+}
+
+pub fn check_ts_method_signature<'a>(sig: &TSMethodSignature<'a>, ctx: &SemanticBuilder<'a>) {
+    match sig.kind {
+        // `interface I { get x(a): number }`
+        TSMethodSignatureKind::Get if !sig.params.is_empty() => {
+            ctx.error(getter_signature_with_parameters(sig.params.span));
+        }
+        // `interface I { set x(): void }`
+        TSMethodSignatureKind::Set if sig.params.rest.is_some() || sig.params.items.len() != 1 => {
+            ctx.error(setter_signature_with_parameters(sig.params.span));
+        }
+        _ => {}
+    }
+}
+
+/// The 'type' modifier cannot be used on a named import when 'import type' is used on its import statement. (2206)
+/// The 'type' modifier cannot be used on a named export when 'export type' is used on its export statement. (2207)
+fn type_modifier_on_type_only_declaration(is_import: bool, span: Span) -> OxcDiagnostic {
+    let (code, kind) = if is_import { ("2206", "import") } else { ("2207", "export") };
+    ts_error(
+        code,
+        format!(
+            "The 'type' modifier cannot be used on a named {kind} when '{kind} type' is used on its {kind} statement."
+        ),
+    )
+    .with_label(span)
+}
+
+pub fn check_module_declaration<'a>(decl: &ModuleDeclaration<'a>, ctx: &SemanticBuilder<'a>) {
+    match decl {
+        // `import type { type A } from "mod"`
+        ModuleDeclaration::ImportDeclaration(decl) if decl.import_kind.is_type() => {
+            for specifier in decl.specifiers.iter().flatten() {
+                if let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier {
+                    if specifier.import_kind.is_type() {
+                        ctx.error(type_modifier_on_type_only_declaration(true, specifier.span));
+                    }
+                }
+            }
+        }
+        // `export type { type A }`
+        ModuleDeclaration::ExportNamedDeclaration(decl) if decl.export_kind.is_type() => {
+            for specifier in &decl.specifiers {
+                if specifier.export_kind.is_type() {
+                    ctx.error(type_modifier_on_type_only_declaration(false, specifier.span));
+                }
+            }
+        }
+        _ => {}
     }
 }
 
@@ -0,0 +1,435 @@
+//! Reaching definitions and liveness of variables, computed with the data-flow
+//! framework in [`oxc_cfg::dataflow`].
+//!
+//! Both analyses need [`Semantic`] to be built with a control flow graph
+//! (see [`SemanticBuilder::with_cfg`]). Only variables, parameters and `catch`
+//! bindings are tracked.
+//!
+//! Variables whose uses cannot be ordered within a single function body are
+//! handled conservatively: every definition reaches every read, and no
+//! definition is dead. This applies to variables that are referenced from
+//! nested functions or class elements, from `catch` clauses or `finally`
+//! blocks, and to exported variables.
+//!
+//! [`SemanticBuilder::with_cfg`]: crate::SemanticBuilder::with_cfg
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_ast::AstKind;
+use oxc_cfg::{
+    dataflow::{solve, Analysis, Direction},
+    BasicBlock, BlockNodeId, Instruction,
+};
+use oxc_span::GetSpan;
+use oxc_syntax::{
+    node::NodeId,
+    reference::ReferenceId,
+    symbol::{SymbolFlags, SymbolId},
+};
+
+use crate::{AstNodes, Semantic};
+
+/// A point where a variable is given a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Definition {
+    /// A binding that is initialized where it is declared, e.g. `let a = 1`,
+    /// `for (const a of b)`, a parameter or a `catch` parameter.
+    ///
+    /// Holds the [`NodeId`] of the `BindingIdentifier`.
+    Declaration(NodeId),
+    /// A write reference, e.g. `a = 1`, `a += 1` or `a++`.
+    Write(ReferenceId),
+}
+
+/// The definitions that may reach each read of a variable.
+#[derive(Debug)]
+pub struct ReachingDefinitions {
+    reaching: FxHashMap<ReferenceId, Vec<Definition>>,
+}
+
+impl ReachingDefinitions {
+    /// Returns [`None`] if `semantic` was built without a control flow graph.
+    pub fn new(semantic: &Semantic) -> Option<Self> {
+        let cfg = semantic.cfg()?;
+        let def_use = DefUse::new(semantic);
+        let results = solve(cfg, &ReachingDefinitionsAnalysis { def_use: &def_use });
+
+        let mut reaching = FxHashMap::default();
+        for (&block_id, events) in &def_use.events {
+            let mut state = results.entry_state(block_id).clone();
+            for event in events {
+                if let EventKind::Use(reference_id) = event.kind {
+                    let definitions = state
+                        .iter()
+                        .map(|&index| def_use.definitions[index])
+                        .filter(|(symbol_id, _)| *symbol_id == event.symbol_id)
+                        .map(|(_, definition)| definition)
+                        .collect();
+                    reaching.insert(reference_id, definitions);
+                }
+                def_use.apply_forward(&mut state, event);
+            }
+        }
+
+        // Any definition of an escaping variable may reach any of its reads.
+        for &symbol_id in &def_use.escaping {
+            let definitions = def_use.definitions_of(symbol_id).collect::<Vec<_>>();
+            for &reference_id in semantic.symbols().get_resolved_reference_ids(symbol_id) {
+                if semantic.symbols().get_reference(reference_id).is_read() {
+                    reaching.insert(reference_id, definitions.clone());
+                }
+            }
+        }
+
+        Some(Self { reaching })
+    }
+
+    /// The definitions that may provide the value read by `reference_id`.
+    ///
+    /// An empty slice means the variable is read before it is assigned, or
+    /// that the reference is not a read of a tracked variable.
+    pub fn reaching(&self, reference_id: ReferenceId) -> &[Definition] {
+        self.reaching.get(&reference_id).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Which definitions of variables are read afterwards.
+#[derive(Debug)]
+pub struct Liveness {
+    dead: Vec<(SymbolId, Definition)>,
+}
+
+impl Liveness {
+    /// Returns [`None`] if `semantic` was built without a control flow graph.
+    pub fn new(semantic: &Semantic) -> Option<Self> {
+        let cfg = semantic.cfg()?;
+        let def_use = DefUse::new(semantic);
+        let results = solve(cfg, &LivenessAnalysis { def_use: &def_use });
+
+        let mut live = FxHashSet::default();
+        for (&block_id, events) in &def_use.events {
+            let mut state = results.exit_state(block_id).clone();
+            for event in events.iter().rev() {
+                if let EventKind::Def(index) = event.kind {
+                    if state.contains(&event.symbol_id) {
+                        live.insert(index);
+                    }
+                }
+                DefUse::apply_backward(&mut state, event);
+            }
+        }
+
+        let dead = def_use
+            .definitions
+            .iter()
+            .enumerate()
+            .filter(|(index, (symbol_id, _))| {
+                !live.contains(index) && !def_use.escaping.contains(symbol_id)
+            })
+            .map(|(_, definition)| *definition)
+            .collect();
+        Some(Self { dead })
+    }
+
+    /// Whether the value given by `definition` may be read afterwards.
+    pub fn is_live(&self, definition: Definition) -> bool {
+        !self.dead.iter().any(|(_, dead)| *dead == definition)
+    }
+
+    /// Definitions whose value is never read, e.g. `a = 1` in `let a; a = 1; a = 2; f(a);`.
+    pub fn dead_definitions(&self) -> impl Iterator<Item = (SymbolId, Definition)> + '_ {
+        self.dead.iter().copied()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum EventKind {
+    /// A read of the variable.
+    Use(ReferenceId),
+    /// A definition, as an index into [`DefUse::definitions`].
+    Def(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Event {
+    /// Source position at which the event takes effect, used to order events within a block.
+    position: u32,
+    symbol_id: SymbolId,
+    kind: EventKind,
+}
+
+/// The definitions and uses of tracked variables in each basic block.
+struct DefUse {
+    definitions: Vec<(SymbolId, Definition)>,
+    /// Events of each block, in evaluation order.
+    events: FxHashMap<BlockNodeId, Vec<Event>>,
+    /// Variables whose uses cannot be ordered by the control flow graph of a single function.
+    escaping: FxHashSet<SymbolId>,
+}
+
+impl DefUse {
+    fn new(semantic: &Semantic) -> Self {
+        let nodes = semantic.nodes();
+        let symbols = semantic.symbols();
+        let mut def_use = Self {
+            definitions: vec![],
+            events: FxHashMap::default(),
+            escaping: FxHashSet::default(),
+        };
+
+        let is_tracked = |symbol_id: SymbolId| {
+            let flags = symbols.get_flags(symbol_id);
+            flags.intersects(SymbolFlags::Variable | SymbolFlags::CatchVariable)
+                && !flags.intersects(SymbolFlags::Function | SymbolFlags::Class)
+        };
+
+        // Declarations, and where in the program each variable is declared.
+        let mut boundaries = FxHashMap::<SymbolId, NodeId>::default();
+        for node in nodes.iter() {
+            let AstKind::BindingIdentifier(ident) = node.kind() else { continue };
+            let Some(symbol_id) = ident.symbol_id.get() else { continue };
+            if !is_tracked(symbol_id) {
+                continue;
+            }
+            let boundary = boundary(nodes, node.id());
+            if *boundaries.entry(symbol_id).or_insert(boundary) != boundary {
+                def_use.escaping.insert(symbol_id);
+            }
+            match declaration_position(nodes, node.id()) {
+                Declared::Initialized(position) => {
+                    def_use.push_definition(
+                        node.cfg_id(),
+                        position,
+                        symbol_id,
+                        Definition::Declaration(node.id()),
+                    );
+                }
+                Declared::Uninitialized => {}
+                Declared::Exported => {
+                    def_use.escaping.insert(symbol_id);
+                }
+            }
+        }
+
+        for (&symbol_id, &boundary_id) in &boundaries {
+            for &reference_id in symbols.get_resolved_reference_ids(symbol_id) {
+                let reference = symbols.get_reference(reference_id);
+                if !reference.is_value() || reference.flags().is_value_as_type() {
+                    continue;
+                }
+                let node = nodes.get_node(reference.node_id());
+                if node.flags().has_export_specifier()
+                    || boundary(nodes, reference.node_id()) != boundary_id
+                {
+                    def_use.escaping.insert(symbol_id);
+                }
+                let span = node.kind().span();
+                if reference.is_read() {
+                    def_use.push_event(
+                        node.cfg_id(),
+                        Event {
+                            position: span.start,
+                            symbol_id,
+                            kind: EventKind::Use(reference_id),
+                        },
+                    );
+                }
+                if reference.is_write() {
+                    let position = write_position(nodes, reference.node_id()).unwrap_or(span.end);
+                    def_use.push_definition(
+                        node.cfg_id(),
+                        position,
+                        symbol_id,
+                        Definition::Write(reference_id),
+                    );
+                }
+            }
+        }
+
+        // Events of escaping variables are not needed by the analyses.
+        let escaping = &def_use.escaping;
+        for events in def_use.events.values_mut() {
+            events.retain(|event| !escaping.contains(&event.symbol_id));
+            // Reads take effect before definitions at the same position, e.g. `a++`.
+            events.sort_unstable_by_key(|event| {
+                (event.position, matches!(event.kind, EventKind::Def(_)))
+            });
+        }
+
+        def_use
+    }
+
+    fn push_event(&mut self, block_id: BlockNodeId, event: Event) {
+        self.events.entry(block_id).or_default().push(event);
+    }
+
+    fn push_definition(
+        &mut self,
+        block_id: BlockNodeId,
+        position: u32,
+        symbol_id: SymbolId,
+        definition: Definition,
+    ) {
+        let kind = EventKind::Def(self.definitions.len());
+        self.definitions.push((symbol_id, definition));
+        self.push_event(block_id, Event { position, symbol_id, kind });
+    }
+
+    fn events(&self, block_id: BlockNodeId) -> &[Event] {
+        self.events.get(&block_id).map_or(&[], Vec::as_slice)
+    }
+
+    fn definitions_of(&self, symbol_id: SymbolId) -> impl Iterator<Item = Definition> + '_ {
+        self.definitions
+            .iter()
+            .filter(move |(id, _)| *id == symbol_id)
+            .map(|(_, definition)| *definition)
+    }
+
+    /// Transfer function of reaching definitions.
+    fn apply_forward(&self, state: &mut FxHashSet<usize>, event: &Event) {
+        if let EventKind::Def(index) = event.kind {
+            state.retain(|&other| self.definitions[other].0 != event.symbol_id);
+            state.insert(index);
+        }
+    }
+
+    /// Transfer function of liveness.
+    fn apply_backward(state: &mut FxHashSet<SymbolId>, event: &Event) {
+        match event.kind {
+            EventKind::Use(_) => {
+                state.insert(event.symbol_id);
+            }
+            EventKind::Def(_) => {
+                state.remove(&event.symbol_id);
+            }
+        }
+    }
+}
+
+struct ReachingDefinitionsAnalysis<'d> {
+    def_use: &'d DefUse,
+}
+
+impl Analysis for ReachingDefinitionsAnalysis<'_> {
+    type Domain = FxHashSet<usize>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn bottom(&self) -> Self::Domain {
+        FxHashSet::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other.iter().copied());
+    }
+
+    fn apply_instruction(&self, _state: &mut Self::Domain, _instruction: &Instruction) {}
+
+    fn apply_block(&self, state: &mut Self::Domain, block_id: BlockNodeId, _block: &BasicBlock) {
+        for event in self.def_use.events(block_id) {
+            self.def_use.apply_forward(state, event);
+        }
+    }
+}
+
+struct LivenessAnalysis<'d> {
+    def_use: &'d DefUse,
+}
+
+impl Analysis for LivenessAnalysis<'_> {
+    type Domain = FxHashSet<SymbolId>;
+
+    const DIRECTION: Direction = Direction::Backward;
+
+    fn bottom(&self) -> Self::Domain {
+        FxHashSet::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other.iter().copied());
+    }
+
+    fn apply_instruction(&self, _state: &mut Self::Domain, _instruction: &Instruction) {}
+
+    fn apply_block(&self, state: &mut Self::Domain, block_id: BlockNodeId, _block: &BasicBlock) {
+        for event in self.def_use.events(block_id).iter().rev() {
+            DefUse::apply_backward(state, event);
+        }
+    }
+}
+
+enum Declared {
+    /// The binding is given a value at this position.
+    Initialized(u32),
+    /// `let a;`
+    Uninitialized,
+    /// `export let a = 1`
+    Exported,
+}
+
+fn declaration_position(nodes: &AstNodes, node_id: NodeId) -> Declared {
+    let span = nodes.kind(node_id).span();
+    for ancestor_id in nodes.ancestor_ids(node_id).skip(1) {
+        match nodes.kind(ancestor_id) {
+            AstKind::ObjectPattern(_)
+            | AstKind::ArrayPattern(_)
+            | AstKind::AssignmentPattern(_)
+            | AstKind::BindingRestElement(_) => {}
+            AstKind::VariableDeclarator(decl) => {
+                // VariableDeclarator -> VariableDeclaration -> parent
+                return match nodes.ancestor_kinds(ancestor_id).nth(2) {
+                    Some(AstKind::ExportNamedDeclaration(_)) => Declared::Exported,
+                    Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)) => {
+                        Declared::Initialized(span.start)
+                    }
+                    _ if decl.init.is_some() => Declared::Initialized(decl.span.end),
+                    _ => Declared::Uninitialized,
+                };
+            }
+            _ => break,
+        }
+    }
+    Declared::Initialized(span.start)
+}
+
+/// Writes take effect once the whole assignment has been evaluated, e.g. the
+/// read of `a` in `a = a + 1` happens before `a` is written.
+fn write_position(nodes: &AstNodes, node_id: NodeId) -> Option<u32> {
+    for kind in nodes.ancestor_kinds(node_id).skip(1) {
+        match kind {
+            AstKind::AssignmentExpression(expr) => return Some(expr.span.end),
+            AstKind::UpdateExpression(expr) => return Some(expr.span.end),
+            kind if kind.is_statement() || kind.is_function_like() => break,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The innermost node that separates `node_id` from the straight-line code
+/// around it: a function, a class element, a `catch` clause, a `finally`
+/// block or the program.
+fn boundary(nodes: &AstNodes, node_id: NodeId) -> NodeId {
+    let span = nodes.kind(node_id).span();
+    for ancestor_id in nodes.ancestor_ids(node_id).skip(1) {
+        match nodes.kind(ancestor_id) {
+            AstKind::Program(_)
+            | AstKind::Function(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::StaticBlock(_)
+            | AstKind::PropertyDefinition(_)
+            | AstKind::CatchClause(_) => return ancestor_id,
+            AstKind::TryStatement(stmt)
+                if stmt
+                    .finalizer
+                    .as_ref()
+                    .is_some_and(|block| block.span.contains_inclusive(span)) =>
+            {
+                return ancestor_id;
+            }
+            _ => {}
+        }
+    }
+    node_id
+}
@@ -1,5 +1,7 @@
 use oxc_span::Span;
 
+use super::jsdoc_type::{parse_jsdoc_type, JSDocType, JSDocTypeParseError};
+
 /// Used for `JSDoc.comment` and `JSDocTag.comment`
 #[derive(Debug, Clone, Copy)]
 pub struct JSDocCommentPart<'a> {
@@ -125,6 +127,17 @@ impl<'a> JSDocTagTypePart<'a> {
         // +1 for `{`, -1 for `}`
         self.raw[1..self.raw.len() - 1].trim()
     }
+
+    /// Parses the type content into a type expression, e.g. `Array<string>|null`.
+    /// Spans of the type expression and the error point into the source text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the type content is not a valid type expression, e.g. `Array<`.
+    pub fn parse_type(&self) -> Result<JSDocType<'a>, JSDocTypeParseError> {
+        // +1 for `{`, -1 for `}`
+        parse_jsdoc_type(&self.raw[1..self.raw.len() - 1], self.span.start + 1)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
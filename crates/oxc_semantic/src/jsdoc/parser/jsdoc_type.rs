@@ -0,0 +1,1189 @@
+use oxc_span::{GetSpan, Span};
+
+// The syntax of type expressions is a mix of JSDoc, Closure Compiler and TypeScript, like
+// `jsdoc-type-pratt-parser` which `eslint-plugin-jsdoc` uses. e.g.
+// - Closure: `?string`, `!Object`, `string=`, `...number`, `function(this:Foo, string): number`
+// - JSDoc: `Array.<string>`, `module:foo/bar~Baz`, `Foo#bar`, `*`, `?`
+// - TypeScript: `string[]`, `(a: string) => void`, `{ [key: string]: number }`, `keyof T`
+//
+// Conditional, mapped and template literal types of TypeScript are not supported.
+
+/// A type expression of a JSDoc tag, e.g. `Array<string>|null` in `@type {Array<string>|null}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JSDocType<'a> {
+    /// `*`
+    Any(Span),
+    /// `?`
+    Unknown(Span),
+    /// `string`, `foo.Bar`, `Foo#bar`, `module:foo/bar`
+    Name(JSDocTypeName<'a>),
+    /// `'foo'`, `1`
+    Literal(JSDocTypeLiteral<'a>),
+    /// `string|number`
+    Union(Box<JSDocCompositeType<'a>>),
+    /// `Foo & Bar`
+    Intersection(Box<JSDocCompositeType<'a>>),
+    /// `Array<string>`, `Array.<string>`
+    Generic(Box<JSDocGenericType<'a>>),
+    /// `string[]`
+    Array(Box<JSDocArrayType<'a>>),
+    /// `function(string): number`, `(a: string) => number`
+    Function(Box<JSDocFunctionType<'a>>),
+    /// `{ a: string, b?: number }`
+    Record(Box<JSDocRecordType<'a>>),
+    /// `[string, number]`
+    Tuple(Box<JSDocTupleType<'a>>),
+    /// `?string`, `!Object`, `string=`, `...number`
+    Modified(Box<JSDocModifiedType<'a>>),
+    /// `(string|number)`
+    Parenthesized(Box<JSDocParenthesizedType<'a>>),
+    /// `typeof foo`, `keyof T`
+    Operator(Box<JSDocOperatorType<'a>>),
+    /// `import('./foo').Bar`
+    Import(Box<JSDocImportType<'a>>),
+    /// `value is string`, `asserts value`
+    Predicate(Box<JSDocPredicateType<'a>>),
+}
+
+/// A name path, e.g. `foo.Bar`, `Foo#bar`, `Foo~bar` or `module:foo/bar`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JSDocTypeName<'a> {
+    pub span: Span,
+    pub name: &'a str,
+}
+
+impl<'a> JSDocTypeName<'a> {
+    /// The first name of the name path, e.g. `foo` for `foo.Bar`, with its span. Name paths of
+    /// modules, externals and events, e.g. `module:foo/bar`, have no root.
+    pub fn root(&self) -> Option<JSDocTypeName<'a>> {
+        if ["module:", "external:", "event:"].iter().any(|prefix| self.name.starts_with(prefix)) {
+            return None;
+        }
+        let end = self.name.find(['.', '#', '~']).unwrap_or(self.name.len());
+        Some(JSDocTypeName {
+            span: Span::new(
+                self.span.start,
+                self.span.start + u32::try_from(end).unwrap_or_default(),
+            ),
+            name: &self.name[..end],
+        })
+    }
+}
+
+/// A string or number literal, e.g. `'foo'` or `-1.5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JSDocTypeLiteral<'a> {
+    pub span: Span,
+    /// The literal as written, including the quotes of a string.
+    pub raw: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocCompositeType<'a> {
+    pub span: Span,
+    pub types: Vec<JSDocType<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocGenericType<'a> {
+    pub span: Span,
+    pub base: JSDocType<'a>,
+    pub arguments: Vec<JSDocType<'a>>,
+    /// Whether the arguments are written as `.<>`, e.g. `Array.<string>`.
+    pub dot: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocArrayType<'a> {
+    pub span: Span,
+    pub element: JSDocType<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocFunctionType<'a> {
+    pub span: Span,
+    /// Whether the function is written as an arrow function, e.g. `(a: string) => void`,
+    /// rather than as `function(string): void`.
+    pub arrow: bool,
+    /// Whether the function is a constructor, e.g. `new (a: string) => Foo`.
+    pub constructor: bool,
+    /// `Foo` in `function(this:Foo)`
+    pub this_type: Option<JSDocType<'a>>,
+    /// `Foo` in `function(new:Foo)`
+    pub new_type: Option<JSDocType<'a>>,
+    pub params: Vec<JSDocFunctionParam<'a>>,
+    pub return_type: Option<JSDocType<'a>>,
+}
+
+/// A parameter of a function type. Parameters of `function()` have a type only, e.g. `string`
+/// in `function(string)`, while parameters of arrow functions have a name and an optional
+/// type, e.g. `a: string` in `(a: string) => void`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocFunctionParam<'a> {
+    pub span: Span,
+    pub name: Option<JSDocTypeName<'a>>,
+    /// `...a: string[]`
+    pub rest: bool,
+    /// `a?: string`
+    pub optional: bool,
+    pub r#type: Option<JSDocType<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocRecordType<'a> {
+    pub span: Span,
+    pub fields: Vec<JSDocRecordField<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocRecordField<'a> {
+    pub span: Span,
+    pub key: JSDocRecordKey<'a>,
+    /// `a?: string`
+    pub optional: bool,
+    /// The type of the value, which is omitted in `{ a }`.
+    pub value: Option<JSDocType<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JSDocRecordKey<'a> {
+    /// `a`, `'a'` or `1` in `{ a: string }`, `{ 'a': string }` or `{ 1: string }`
+    Name(JSDocTypeName<'a>),
+    /// `[key: string]` in `{ [key: string]: number }`
+    Index(JSDocTypeName<'a>, Box<JSDocType<'a>>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocTupleType<'a> {
+    pub span: Span,
+    pub elements: Vec<JSDocType<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocModifiedType<'a> {
+    pub span: Span,
+    pub modifier: JSDocTypeModifier,
+    /// Whether the modifier is written after the type, e.g. `string?` rather than `?string`.
+    pub postfix: bool,
+    pub element: JSDocType<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JSDocTypeModifier {
+    /// `?string`, `string?`
+    Nullable,
+    /// `!Object`, `Object!`
+    NonNullable,
+    /// `string=`
+    Optional,
+    /// `...number`
+    Variadic,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocParenthesizedType<'a> {
+    pub span: Span,
+    pub element: JSDocType<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocOperatorType<'a> {
+    pub span: Span,
+    pub operator: JSDocTypeOperator,
+    pub element: JSDocType<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JSDocTypeOperator {
+    Typeof,
+    Keyof,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocImportType<'a> {
+    pub span: Span,
+    /// `'./foo'` in `import('./foo').Bar`
+    pub source: JSDocTypeLiteral<'a>,
+    /// `Bar` in `import('./foo').Bar`
+    pub qualifier: Option<JSDocTypeName<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocPredicateType<'a> {
+    pub span: Span,
+    /// `asserts value is string`
+    pub asserts: bool,
+    pub parameter: JSDocTypeName<'a>,
+    /// `string` in `value is string`, which is omitted in `asserts value`.
+    pub r#type: Option<JSDocType<'a>>,
+}
+
+impl GetSpan for JSDocType<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Any(span) | Self::Unknown(span) => *span,
+            Self::Name(name) => name.span,
+            Self::Literal(literal) => literal.span,
+            Self::Union(ty) | Self::Intersection(ty) => ty.span,
+            Self::Generic(ty) => ty.span,
+            Self::Array(ty) => ty.span,
+            Self::Function(ty) => ty.span,
+            Self::Record(ty) => ty.span,
+            Self::Tuple(ty) => ty.span,
+            Self::Modified(ty) => ty.span,
+            Self::Parenthesized(ty) => ty.span,
+            Self::Operator(ty) => ty.span,
+            Self::Import(ty) => ty.span,
+            Self::Predicate(ty) => ty.span,
+        }
+    }
+}
+
+impl<'a> JSDocType<'a> {
+    /// Call `f` with this type and all types nested in it, parents before their children.
+    pub fn walk<F: FnMut(&JSDocType<'a>)>(&self, f: &mut F) {
+        f(self);
+        match self {
+            Self::Any(_)
+            | Self::Unknown(_)
+            | Self::Name(_)
+            | Self::Literal(_)
+            | Self::Import(_) => {}
+            Self::Union(ty) | Self::Intersection(ty) => ty.types.iter().for_each(|ty| ty.walk(f)),
+            Self::Generic(ty) => {
+                ty.base.walk(f);
+                ty.arguments.iter().for_each(|ty| ty.walk(f));
+            }
+            Self::Array(ty) => ty.element.walk(f),
+            Self::Function(ty) => {
+                let params = ty.params.iter().filter_map(|param| param.r#type.as_ref());
+                ty.this_type
+                    .iter()
+                    .chain(&ty.new_type)
+                    .chain(params)
+                    .chain(&ty.return_type)
+                    .for_each(|ty| ty.walk(f));
+            }
+            Self::Record(ty) => {
+                for field in &ty.fields {
+                    if let JSDocRecordKey::Index(_, key_type) = &field.key {
+                        key_type.walk(f);
+                    }
+                    if let Some(value) = &field.value {
+                        value.walk(f);
+                    }
+                }
+            }
+            Self::Tuple(ty) => ty.elements.iter().for_each(|ty| ty.walk(f)),
+            Self::Modified(ty) => ty.element.walk(f),
+            Self::Parenthesized(ty) => ty.element.walk(f),
+            Self::Operator(ty) => ty.element.walk(f),
+            Self::Predicate(ty) => {
+                if let Some(ty) = &ty.r#type {
+                    ty.walk(f);
+                }
+            }
+        }
+    }
+}
+
+/// An error of a type expression which can not be parsed, e.g. `Array<string` without `>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocTypeParseError {
+    pub message: String,
+    /// Where the error is found, which is empty at the end of the type expression.
+    pub span: Span,
+}
+
+/// Parse the type expression `source`, which starts at `offset` in the source text.
+pub fn parse_jsdoc_type(source: &str, offset: u32) -> Result<JSDocType<'_>, JSDocTypeParseError> {
+    let mut parser = TypeParser { source, offset, pos: 0 };
+    let ty = parser.parse_union()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(ty),
+        Some(c) => Err(parser.error(format!("Unexpected `{c}`."))),
+    }
+}
+
+type ParseResult<T> = Result<T, JSDocTypeParseError>;
+
+/// The characters which end a type, e.g. `,` in `Object<string, number>`.
+const TYPE_TERMINATORS: [char; 10] = [',', ')', '>', '|', '&', ']', '}', '=', ';', ':'];
+
+struct TypeParser<'a> {
+    source: &'a str,
+    offset: u32,
+    /// The byte offset of the next character in `source`.
+    pos: usize,
+}
+
+impl<'a> TypeParser<'a> {
+    fn span(&self, start: usize, end: usize) -> Span {
+        Span::new(
+            self.offset + u32::try_from(start).unwrap_or_default(),
+            self.offset + u32::try_from(end).unwrap_or_default(),
+        )
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn error(&self, message: String) -> JSDocTypeParseError {
+        let len = self.peek().map_or(0, char::len_utf8);
+        JSDocTypeParseError { message, span: self.span(self.pos, self.pos + len) }
+    }
+
+    /// Skip whitespace, and the leading `*` of each line of a multiline type.
+    fn skip_whitespace(&mut self) {
+        let mut line_start = false;
+        while let Some(c) = self.peek() {
+            if c == '\n' {
+                line_start = true;
+            } else if c == '*' && line_start {
+                line_start = false;
+            } else if !c.is_whitespace() {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    /// Skip whitespace, then skip `token` if it is next.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> ParseResult<()> {
+        if self.eat(token) {
+            return Ok(());
+        }
+        Err(match self.peek() {
+            Some(c) => self.error(format!("Expected `{token}` but found `{c}`.")),
+            None => self.error(format!("Expected `{token}` but found the end of the type.")),
+        })
+    }
+
+    /// Whether the type ends after whitespace, e.g. `?` in `function(?)` is the unknown type.
+    fn at_type_end(&mut self) -> bool {
+        let pos = self.pos;
+        self.skip_whitespace();
+        let at_end = self.peek().map_or(true, |c| TYPE_TERMINATORS.contains(&c));
+        self.pos = pos;
+        at_end
+    }
+
+    /// The identifier which starts at the next character, without skipping it.
+    fn peek_identifier(&self) -> Option<&'a str> {
+        let rest = self.rest();
+        let mut chars = rest.char_indices();
+        let (_, first) = chars.next()?;
+        if !is_identifier_start(first) {
+            return None;
+        }
+        let end = chars.find(|(_, c)| !is_identifier_part(*c)).map_or(rest.len(), |(i, _)| i);
+        Some(&rest[..end])
+    }
+
+    fn parse_identifier(&mut self) -> ParseResult<JSDocTypeName<'a>> {
+        self.skip_whitespace();
+        let Some(name) = self.peek_identifier() else {
+            return Err(match self.peek() {
+                Some(c) => self.error(format!("Expected a name but found `{c}`.")),
+                None => self.error("Expected a name but found the end of the type.".to_string()),
+            });
+        };
+        let start = self.pos;
+        self.pos += name.len();
+        Ok(JSDocTypeName { span: self.span(start, self.pos), name })
+    }
+
+    /// Whether `keyword` is next, followed by whitespace and then a type, e.g. `typeof foo`.
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        self.peek_identifier() == Some(keyword)
+            && self.rest()[keyword.len()..].starts_with(char::is_whitespace)
+    }
+
+    /// Whether `(` follows the identifier `word` which is next, e.g. `function(`.
+    fn is_call_like(&self, word: &str) -> bool {
+        self.rest()[word.len()..].trim_start().starts_with('(')
+    }
+
+    /// `string|number`
+    fn parse_union(&mut self) -> ParseResult<JSDocType<'a>> {
+        let first = self.parse_intersection()?;
+        let mut types = vec![first];
+        while self.eat("|") {
+            types.push(self.parse_intersection()?);
+        }
+        Ok(composite(types, JSDocType::Union))
+    }
+
+    /// `Foo & Bar`
+    fn parse_intersection(&mut self) -> ParseResult<JSDocType<'a>> {
+        let first = self.parse_optional()?;
+        let mut types = vec![first];
+        while self.eat("&") {
+            types.push(self.parse_optional()?);
+        }
+        Ok(composite(types, JSDocType::Intersection))
+    }
+
+    /// `string=`, which applies to prefixed types too, e.g. `?string=` is an optional nullable.
+    fn parse_optional(&mut self) -> ParseResult<JSDocType<'a>> {
+        let element = self.parse_prefix()?;
+        let pos = self.pos;
+        self.skip_whitespace();
+        if !self.rest().starts_with('=') || self.rest().starts_with("=>") {
+            self.pos = pos;
+            return Ok(element);
+        }
+        self.pos += 1;
+        let span = Span::new(element.span().start, self.span(self.pos, self.pos).end);
+        Ok(JSDocType::Modified(Box::new(JSDocModifiedType {
+            span,
+            modifier: JSDocTypeModifier::Optional,
+            postfix: true,
+            element,
+        })))
+    }
+
+    /// `?string`, `!Object`, `...number`, `typeof foo`, `keyof T`
+    fn parse_prefix(&mut self) -> ParseResult<JSDocType<'a>> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let modifier = if self.rest().starts_with("...") {
+            self.pos += 3;
+            JSDocTypeModifier::Variadic
+        } else if self.rest().starts_with('?') {
+            self.pos += 1;
+            if self.at_type_end() {
+                return Ok(JSDocType::Unknown(self.span(start, self.pos)));
+            }
+            JSDocTypeModifier::Nullable
+        } else if self.rest().starts_with('!') {
+            self.pos += 1;
+            JSDocTypeModifier::NonNullable
+        } else {
+            let operator = if self.peek_keyword("typeof") {
+                JSDocTypeOperator::Typeof
+            } else if self.peek_keyword("keyof") {
+                JSDocTypeOperator::Keyof
+            } else {
+                return self.parse_postfix();
+            };
+            self.pos += if operator == JSDocTypeOperator::Typeof { 6 } else { 5 };
+            let element = self.parse_prefix()?;
+            let span = Span::new(self.span(start, start).start, element.span().end);
+            return Ok(JSDocType::Operator(Box::new(JSDocOperatorType {
+                span,
+                operator,
+                element,
+            })));
+        };
+        let element = self.parse_prefix()?;
+        let span = Span::new(self.span(start, start).start, element.span().end);
+        Ok(JSDocType::Modified(Box::new(JSDocModifiedType {
+            span,
+            modifier,
+            postfix: false,
+            element,
+        })))
+    }
+
+    /// `string[]`, `Array<string>`, `Array.<string>`, `string?`, `Object!`
+    fn parse_postfix(&mut self) -> ParseResult<JSDocType<'a>> {
+        let mut ty = self.parse_primary()?;
+        loop {
+            let pos = self.pos;
+            self.skip_whitespace();
+            let rest = self.rest();
+            let start = ty.span().start;
+            if rest.starts_with("[]") {
+                self.pos += 2;
+                let span = Span::new(start, self.span(self.pos, self.pos).end);
+                ty = JSDocType::Array(Box::new(JSDocArrayType { span, element: ty }));
+            } else if rest.starts_with(".<") || rest.starts_with('<') {
+                let dot = rest.starts_with('.');
+                self.pos += if dot { 2 } else { 1 };
+                let arguments = self.parse_type_list(">")?;
+                let span = Span::new(start, self.span(self.pos, self.pos).end);
+                ty = JSDocType::Generic(Box::new(JSDocGenericType {
+                    span,
+                    base: ty,
+                    arguments,
+                    dot,
+                }));
+            } else {
+                let modifier = if rest.starts_with('?') {
+                    self.pos += 1;
+                    if !self.at_type_end() {
+                        self.pos = pos;
+                        return Ok(ty);
+                    }
+                    JSDocTypeModifier::Nullable
+                } else if rest.starts_with('!') {
+                    self.pos += 1;
+                    JSDocTypeModifier::NonNullable
+                } else {
+                    self.pos = pos;
+                    return Ok(ty);
+                };
+                let span = Span::new(start, self.span(self.pos, self.pos).end);
+                ty = JSDocType::Modified(Box::new(JSDocModifiedType {
+                    span,
+                    modifier,
+                    postfix: true,
+                    element: ty,
+                }));
+            }
+        }
+    }
+
+    /// Types separated by `,`, up to `close`, e.g. `string, number>`.
+    fn parse_type_list(&mut self, close: &str) -> ParseResult<Vec<JSDocType<'a>>> {
+        let mut types = vec![];
+        loop {
+            types.push(self.parse_union()?);
+            if !self.eat(",") {
+                self.expect(close)?;
+                return Ok(types);
+            }
+        }
+    }
+
+    fn parse_primary(&mut self) -> ParseResult<JSDocType<'a>> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let Some(c) = self.peek() else {
+            return Err(self.error("Expected a type but found the end of the type.".to_string()));
+        };
+        match c {
+            '*' => {
+                self.pos += 1;
+                Ok(JSDocType::Any(self.span(start, self.pos)))
+            }
+            '(' => {
+                if let Some(function) = self.try_parse_arrow_function(start, false) {
+                    return Ok(function);
+                }
+                self.pos += 1;
+                let element = self.parse_union()?;
+                self.expect(")")?;
+                let span = self.span(start, self.pos);
+                Ok(JSDocType::Parenthesized(Box::new(JSDocParenthesizedType { span, element })))
+            }
+            '{' => self.parse_record(),
+            '[' => {
+                self.pos += 1;
+                let elements = if self.eat("]") { vec![] } else { self.parse_tuple_elements()? };
+                let span = self.span(start, self.pos);
+                Ok(JSDocType::Tuple(Box::new(JSDocTupleType { span, elements })))
+            }
+            '\'' | '"' => Ok(JSDocType::Literal(self.parse_string_literal()?)),
+            '-' | '0'..='9' => Ok(JSDocType::Literal(self.parse_number_literal()?)),
+            c if is_identifier_start(c) => self.parse_identifier_type(),
+            c => Err(self.error(format!("Expected a type but found `{c}`."))),
+        }
+    }
+
+    /// Types which start with an identifier, e.g. `foo.Bar`, `function(string)` or
+    /// `import('./foo')`.
+    fn parse_identifier_type(&mut self) -> ParseResult<JSDocType<'a>> {
+        let start = self.pos;
+        let word = self.peek_identifier().unwrap_or_default();
+        match word {
+            "function" if self.is_call_like(word) => {
+                self.pos += word.len();
+                self.parse_function(start)
+            }
+            "new" if self.is_call_like(word) => {
+                self.pos += word.len();
+                self.skip_whitespace();
+                let pos = self.pos;
+                self.try_parse_arrow_function(start, true).ok_or_else(|| {
+                    self.pos = pos;
+                    self.error("Expected the parameters of a constructor type.".to_string())
+                })
+            }
+            "import" if self.is_call_like(word) => {
+                self.pos += word.len();
+                self.expect("(")?;
+                self.skip_whitespace();
+                let source = self.parse_string_literal()?;
+                self.expect(")")?;
+                let qualifier = if self.rest().starts_with('.') && !self.rest().starts_with(".<") {
+                    self.pos += 1;
+                    Some(self.parse_name_path()?)
+                } else {
+                    None
+                };
+                let span = self.span(start, self.pos);
+                Ok(JSDocType::Import(Box::new(JSDocImportType { span, source, qualifier })))
+            }
+            "asserts"
+                if self.peek_keyword(word) && {
+                    let rest = self.rest()[word.len()..].trim_start();
+                    rest.starts_with(is_identifier_start)
+                } =>
+            {
+                self.pos += word.len();
+                let parameter = self.parse_identifier()?;
+                let r#type = if self.eat_predicate_is() { Some(self.parse_union()?) } else { None };
+                let end = r#type.as_ref().map_or(parameter.span.end, |ty| ty.span().end);
+                let span = Span::new(self.span(start, start).start, end);
+                Ok(JSDocType::Predicate(Box::new(JSDocPredicateType {
+                    span,
+                    asserts: true,
+                    parameter,
+                    r#type,
+                })))
+            }
+            _ => {
+                let name = self.parse_name_path()?;
+                if !name.name.contains(['.', '#', '~', ':']) && self.eat_predicate_is() {
+                    let r#type = self.parse_union()?;
+                    let span = Span::new(name.span.start, r#type.span().end);
+                    return Ok(JSDocType::Predicate(Box::new(JSDocPredicateType {
+                        span,
+                        asserts: false,
+                        parameter: name,
+                        r#type: Some(r#type),
+                    })));
+                }
+                Ok(JSDocType::Name(name))
+            }
+        }
+    }
+
+    /// Skip ` is ` of a type predicate, e.g. `value is string`.
+    fn eat_predicate_is(&mut self) -> bool {
+        let pos = self.pos;
+        self.skip_whitespace();
+        if self.pos > pos && self.peek_keyword("is") {
+            self.pos += 2;
+            return true;
+        }
+        self.pos = pos;
+        false
+    }
+
+    /// `foo.Bar`, `Foo#bar`, `Foo~bar`, `foo."bar"`, `module:foo/bar`
+    fn parse_name_path(&mut self) -> ParseResult<JSDocTypeName<'a>> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let word = self.peek_identifier().unwrap_or_default();
+        if matches!(word, "module" | "external" | "event")
+            && self.rest()[word.len()..].starts_with(':')
+        {
+            self.pos += word.len() + 1;
+            self.skip_module_path()?;
+        } else {
+            self.parse_identifier()?;
+        }
+        loop {
+            let rest = self.rest();
+            if rest.starts_with('.') && !rest.starts_with(".<") {
+                self.pos += 1;
+                if matches!(self.peek(), Some('\'' | '"')) {
+                    self.parse_string_literal()?;
+                } else {
+                    self.parse_identifier()?;
+                }
+            } else if rest.starts_with(['#', '~']) {
+                self.pos += 1;
+                if self.rest().starts_with("event:") {
+                    self.pos += "event:".len();
+                }
+                self.parse_identifier()?;
+            } else {
+                break;
+            }
+        }
+        Ok(JSDocTypeName { span: self.span(start, self.pos), name: &self.source[start..self.pos] })
+    }
+
+    /// The path of `module:foo/bar`, up to the members which follow it, e.g. `#baz`.
+    fn skip_module_path(&mut self) -> ParseResult<()> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c == '\'' || c == '"' {
+                self.parse_string_literal()?;
+                continue;
+            }
+            if c.is_whitespace() || TYPE_TERMINATORS.contains(&c) || "(<{[.#~!?".contains(c) {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+        if self.pos == start {
+            return Err(self.error("Expected a path after `:`.".to_string()));
+        }
+        Ok(())
+    }
+
+    fn parse_string_literal(&mut self) -> ParseResult<JSDocTypeLiteral<'a>> {
+        let start = self.pos;
+        let Some(quote) = self.peek().filter(|c| matches!(c, '\'' | '"')) else {
+            return Err(self.error("Expected a string.".to_string()));
+        };
+        let mut escaped = false;
+        for (i, c) in self.rest().char_indices().skip(1) {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == quote {
+                self.pos += i + 1;
+                return Ok(JSDocTypeLiteral {
+                    span: self.span(start, self.pos),
+                    raw: &self.source[start..self.pos],
+                });
+            }
+        }
+        Err(self.error("Unterminated string.".to_string()))
+    }
+
+    /// `1`, `-1.5`, `1e3`
+    fn parse_number_literal(&mut self) -> ParseResult<JSDocTypeLiteral<'a>> {
+        let start = self.pos;
+        let rest = self.rest();
+        let digits_start = usize::from(rest.starts_with('-'));
+        let end = rest[digits_start..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '_'))
+            .map_or(rest.len(), |end| end + digits_start);
+        let raw = &rest[..end];
+        let digits = &raw[digits_start..];
+        let is_valid = digits.starts_with(|c: char| c.is_ascii_digit())
+            && (digits.parse::<f64>().is_ok() || is_radix_literal(digits));
+        if !is_valid {
+            return Err(self.error("Expected a type but found an invalid number.".to_string()));
+        }
+        self.pos += end;
+        Ok(JSDocTypeLiteral { span: self.span(start, self.pos), raw })
+    }
+
+    /// `function(string, number=): boolean`, `function(this:Foo)`, `function(new:Foo)`
+    fn parse_function(&mut self, start: usize) -> ParseResult<JSDocType<'a>> {
+        self.expect("(")?;
+        let mut this_type = None;
+        let mut new_type = None;
+        let mut params = vec![];
+        if !self.eat(")") {
+            loop {
+                self.skip_whitespace();
+                let param_start = self.pos;
+                let word = self.peek_identifier().unwrap_or_default();
+                if matches!(word, "this" | "new")
+                    && self.rest()[word.len()..].trim_start().starts_with(':')
+                {
+                    self.pos += word.len();
+                    self.expect(":")?;
+                    let ty = self.parse_union()?;
+                    if word == "this" {
+                        this_type = Some(ty);
+                    } else {
+                        new_type = Some(ty);
+                    }
+                } else {
+                    let ty = self.parse_union()?;
+                    params.push(JSDocFunctionParam {
+                        span: Span::new(self.span(param_start, param_start).start, ty.span().end),
+                        name: None,
+                        rest: false,
+                        optional: false,
+                        r#type: Some(ty),
+                    });
+                }
+                if !self.eat(",") {
+                    self.expect(")")?;
+                    break;
+                }
+            }
+        }
+        let return_type = if self.eat(":") { Some(self.parse_prefix()?) } else { None };
+        let span = self.span(start, self.pos);
+        Ok(JSDocType::Function(Box::new(JSDocFunctionType {
+            span,
+            arrow: false,
+            constructor: false,
+            this_type,
+            new_type,
+            params,
+            return_type,
+        })))
+    }
+
+    /// `(a: string, b?: number) => void`, which is parenthesized type if it is not followed by
+    /// `=>`. Returns `None` and restores the position when it is not an arrow function.
+    fn try_parse_arrow_function(
+        &mut self,
+        start: usize,
+        constructor: bool,
+    ) -> Option<JSDocType<'a>> {
+        let pos = self.pos;
+        let result = self.parse_arrow_params().and_then(|params| {
+            if !self.eat("=>") {
+                return Err(self.error("Expected `=>`.".to_string()));
+            }
+            let return_type = self.parse_union()?;
+            Ok((params, return_type))
+        });
+        let Ok((params, return_type)) = result else {
+            self.pos = pos;
+            return None;
+        };
+        Some(JSDocType::Function(Box::new(JSDocFunctionType {
+            span: self.span(start, self.pos),
+            arrow: true,
+            constructor,
+            this_type: None,
+            new_type: None,
+            params,
+            return_type: Some(return_type),
+        })))
+    }
+
+    fn parse_arrow_params(&mut self) -> ParseResult<Vec<JSDocFunctionParam<'a>>> {
+        self.expect("(")?;
+        let mut params = vec![];
+        if self.eat(")") {
+            return Ok(params);
+        }
+        loop {
+            self.skip_whitespace();
+            let param_start = self.pos;
+            let rest = self.eat("...");
+            let name = self.parse_identifier()?;
+            let optional = self.eat("?");
+            let r#type = if self.eat(":") { Some(self.parse_union()?) } else { None };
+            params.push(JSDocFunctionParam {
+                span: self.span(param_start, self.pos),
+                name: Some(name),
+                rest,
+                optional,
+                r#type,
+            });
+            if !self.eat(",") {
+                self.expect(")")?;
+                return Ok(params);
+            }
+        }
+    }
+
+    /// `{ a: string, 'b'?: number; [key: string]: any }`
+    fn parse_record(&mut self) -> ParseResult<JSDocType<'a>> {
+        let start = self.pos;
+        self.expect("{")?;
+        let mut fields = vec![];
+        while !self.eat("}") {
+            self.skip_whitespace();
+            let field_start = self.pos;
+            let key = match self.peek() {
+                Some('[') => {
+                    self.pos += 1;
+                    let name = self.parse_identifier()?;
+                    self.expect(":")?;
+                    let key_type = self.parse_union()?;
+                    self.expect("]")?;
+                    JSDocRecordKey::Index(name, Box::new(key_type))
+                }
+                Some('\'' | '"') => {
+                    let literal = self.parse_string_literal()?;
+                    JSDocRecordKey::Name(JSDocTypeName { span: literal.span, name: literal.raw })
+                }
+                Some('0'..='9') => {
+                    let literal = self.parse_number_literal()?;
+                    JSDocRecordKey::Name(JSDocTypeName { span: literal.span, name: literal.raw })
+                }
+                _ => JSDocRecordKey::Name(self.parse_identifier()?),
+            };
+            let optional = self.eat("?");
+            let value = if self.eat(":") {
+                Some(self.parse_union()?)
+            } else if matches!(key, JSDocRecordKey::Index(..)) {
+                return Err(self.error("Expected `:` after an index signature.".to_string()));
+            } else {
+                None
+            };
+            fields.push(JSDocRecordField {
+                span: self.span(field_start, self.pos),
+                key,
+                optional,
+                value,
+            });
+            if !self.eat(",") && !self.eat(";") {
+                self.expect("}")?;
+                break;
+            }
+        }
+        let span = self.span(start, self.pos);
+        Ok(JSDocType::Record(Box::new(JSDocRecordType { span, fields })))
+    }
+
+    /// The elements of a tuple after `[`, e.g. `string, number]`.
+    fn parse_tuple_elements(&mut self) -> ParseResult<Vec<JSDocType<'a>>> {
+        let mut elements = vec![];
+        loop {
+            elements.push(self.parse_union()?);
+            if !self.eat(",") {
+                self.expect("]")?;
+                return Ok(elements);
+            }
+            // trailing comma
+            if self.eat("]") {
+                return Ok(elements);
+            }
+        }
+    }
+}
+
+fn composite<'a>(
+    mut types: Vec<JSDocType<'a>>,
+    kind: fn(Box<JSDocCompositeType<'a>>) -> JSDocType<'a>,
+) -> JSDocType<'a> {
+    if types.len() == 1 {
+        return types.pop().unwrap();
+    }
+    let span = Span::new(types[0].span().start, types[types.len() - 1].span().end);
+    kind(Box::new(JSDocCompositeType { span, types }))
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// `0x1F`, `0o17`, `0b11`
+fn is_radix_literal(digits: &str) -> bool {
+    let (prefix, digits) = digits.split_at(digits.len().min(2));
+    let radix = match prefix {
+        "0x" | "0X" => 16,
+        "0o" | "0O" => 8,
+        "0b" | "0B" => 2,
+        _ => return false,
+    };
+    !digits.is_empty() && digits.chars().all(|c| c == '_' || c.is_digit(radix))
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::{GetSpan, Span};
+
+    use super::{parse_jsdoc_type, JSDocType, JSDocTypeModifier, JSDocTypeName};
+
+    fn names<'a>(ty: &JSDocType<'a>) -> Vec<&'a str> {
+        let mut names = vec![];
+        ty.walk(&mut |ty| {
+            if let JSDocType::Name(name) = ty {
+                names.push(name.name);
+            }
+        });
+        names
+    }
+
+    #[test]
+    fn parses_valid_types() {
+        for source in [
+            "string",
+            "*",
+            "?",
+            "foo.Bar",
+            "Foo#bar",
+            "Foo~bar",
+            "module:foo/bar",
+            "module:foo/bar~Baz",
+            "module:'foo/bar'.Baz",
+            "external:jQuery",
+            "foo.\"bar baz\"",
+            "'foo'",
+            "\"foo\" | 'bar'",
+            "1",
+            "-1.5",
+            "0x1F",
+            "string|number",
+            "(string|number)",
+            "string | number | null",
+            "Foo & Bar",
+            "Array<string>",
+            "Array.<string>",
+            "Object.<string, number>",
+            "Promise<Array<string>>",
+            "string[]",
+            "string[][]",
+            "(string|number)[]",
+            "?string",
+            "string?",
+            "!Object",
+            "Object!",
+            "string=",
+            "...number",
+            "...?number",
+            "function()",
+            "function(string, number=): boolean",
+            "function(this:Foo, ...string)",
+            "function(new:Foo): Foo",
+            "function(?): ?",
+            "function(): function(): string",
+            "() => void",
+            "(a: string, b?: number) => boolean",
+            "(...args: string[]) => void",
+            "(a) => void",
+            "new (a: string) => Foo",
+            "{}",
+            "{ a: string, b?: number }",
+            "{ a: string; b: number; }",
+            "{ a, b: string }",
+            "{ 'a-b': string, 1: number }",
+            "{ [key: string]: number }",
+            "{ a: { b: string } }",
+            "[]",
+            "[string, number]",
+            "typeof foo",
+            "keyof typeof foo",
+            "keyof T",
+            "import('./foo')",
+            "import('./foo').Bar",
+            "import(\"./foo\").Bar.Baz<string>",
+            "value is string",
+            "asserts value",
+            "asserts value is string",
+            "\n * string |\n * number\n ",
+        ] {
+            if let Err(error) = parse_jsdoc_type(source, 0) {
+                panic!("{source:?} should be valid, but got {error:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn reports_invalid_types() {
+        for (source, message) in [
+            ("", "Expected a type but found the end of the type."),
+            ("   ", "Expected a type but found the end of the type."),
+            ("Array<string", "Expected `>` but found the end of the type."),
+            ("Array<>", "Expected a type but found `>`."),
+            ("string|", "Expected a type but found the end of the type."),
+            ("string number", "Unexpected `n`."),
+            ("(string", "Expected `)` but found the end of the type."),
+            ("function(string", "Expected `)` but found the end of the type."),
+            ("{ a: string", "Expected `}` but found the end of the type."),
+            ("{ [key: string] }", "Expected `:` after an index signature."),
+            ("[string", "Expected `]` but found the end of the type."),
+            ("'foo", "Unterminated string."),
+            ("1abc", "Expected a type but found an invalid number."),
+            ("string]", "Unexpected `]`."),
+            ("Foo.", "Expected a name but found the end of the type."),
+            ("module:", "Expected a path after `:`."),
+            ("new (a: string)", "Expected the parameters of a constructor type."),
+            ("import(foo)", "Expected a string."),
+            ("@foo", "Expected a type but found `@`."),
+        ] {
+            match parse_jsdoc_type(source, 0) {
+                Ok(ty) => panic!("{source:?} should be invalid, but got {ty:?}"),
+                Err(error) => assert_eq!(error.message, message, "{source:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn error_span() {
+        let error = parse_jsdoc_type("Array<string", 10).unwrap_err();
+        assert_eq!(error.span, Span::new(22, 22));
+        let error = parse_jsdoc_type("string number", 10).unwrap_err();
+        assert_eq!(error.span, Span::new(17, 18));
+    }
+
+    #[test]
+    fn collects_names() {
+        for (source, expected) in [
+            ("string", vec!["string"]),
+            ("Array.<Foo|Bar>", vec!["Array", "Foo", "Bar"]),
+            ("function(this:Foo, Bar=): Baz", vec!["Foo", "Bar", "Baz"]),
+            ("(a: Foo, b) => Bar", vec!["Foo", "Bar"]),
+            ("{ a: Foo, [key: Bar]: Baz, b }", vec!["Foo", "Bar", "Baz"]),
+            ("[Foo, ...Bar[]]", vec!["Foo", "Bar"]),
+            ("typeof foo | keyof Foo", vec!["foo", "Foo"]),
+            ("value is Foo", vec!["Foo"]),
+            ("import('./foo').Bar<Baz>", vec!["Baz"]),
+            ("'Foo' | 1 | * | ?", vec![]),
+        ] {
+            let ty = parse_jsdoc_type(source, 0).unwrap();
+            assert_eq!(names(&ty), expected, "{source:?}");
+        }
+    }
+
+    #[test]
+    fn structure() {
+        let ty = parse_jsdoc_type("?Array.<string>=", 0).unwrap();
+        let JSDocType::Modified(optional) = &ty else { panic!("{ty:?}") };
+        assert_eq!(optional.modifier, JSDocTypeModifier::Optional);
+        assert!(optional.postfix);
+        let JSDocType::Modified(nullable) = &optional.element else { panic!("{ty:?}") };
+        assert_eq!(nullable.modifier, JSDocTypeModifier::Nullable);
+        assert!(!nullable.postfix);
+        let JSDocType::Generic(generic) = &nullable.element else { panic!("{ty:?}") };
+        assert!(generic.dot);
+        assert_eq!(generic.arguments.len(), 1);
+
+        let ty = parse_jsdoc_type("string|number&boolean", 0).unwrap();
+        let JSDocType::Union(union) = &ty else { panic!("{ty:?}") };
+        assert_eq!(union.types.len(), 2);
+        assert!(matches!(union.types[1], JSDocType::Intersection(_)));
+
+        let ty = parse_jsdoc_type("function(this:Foo, string, number=): boolean", 0).unwrap();
+        let JSDocType::Function(function) = &ty else { panic!("{ty:?}") };
+        assert!(!function.arrow);
+        assert!(function.this_type.is_some());
+        assert_eq!(function.params.len(), 2);
+        assert!(function.return_type.is_some());
+
+        let ty = parse_jsdoc_type("(a: string, b?: number) => void", 0).unwrap();
+        let JSDocType::Function(function) = &ty else { panic!("{ty:?}") };
+        assert!(function.arrow);
+        assert_eq!(function.params[0].name.map(|name| name.name), Some("a"));
+        assert!(function.params[1].optional);
+
+        let ty = parse_jsdoc_type("(string)", 0).unwrap();
+        assert!(matches!(ty, JSDocType::Parenthesized(_)));
+    }
+
+    #[test]
+    fn spans() {
+        let ty = parse_jsdoc_type(" Array<foo.Bar> ", 10).unwrap();
+        assert_eq!(ty.span(), Span::new(11, 25));
+        let JSDocType::Generic(generic) = &ty else { panic!("{ty:?}") };
+        let JSDocType::Name(name) = &generic.arguments[0] else { panic!("{ty:?}") };
+        assert_eq!(*name, JSDocTypeName { span: Span::new(17, 24), name: "foo.Bar" });
+        assert_eq!(name.root(), Some(JSDocTypeName { span: Span::new(17, 20), name: "foo" }));
+    }
+
+    #[test]
+    fn name_root() {
+        for (name, expected) in [
+            ("Foo", Some("Foo")),
+            ("foo.Bar", Some("foo")),
+            ("Foo#bar", Some("Foo")),
+            ("Foo~bar", Some("Foo")),
+            ("module:foo/bar", None),
+            ("external:jQuery", None),
+        ] {
+            let name = JSDocTypeName { span: Span::new(0, 0), name };
+            assert_eq!(name.root().map(|root| root.name), expected);
+        }
+    }
+}
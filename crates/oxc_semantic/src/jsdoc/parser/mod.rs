@@ -1,8 +1,17 @@
 mod jsdoc;
 mod jsdoc_parts;
 mod jsdoc_tag;
+mod jsdoc_type;
 mod parse;
 mod utils;
 
 pub use jsdoc::JSDoc;
+pub use jsdoc_parts::JSDocTagTypePart;
 pub use jsdoc_tag::JSDocTag;
+pub use jsdoc_type::{
+    JSDocArrayType, JSDocCompositeType, JSDocFunctionParam, JSDocFunctionType, JSDocGenericType,
+    JSDocImportType, JSDocModifiedType, JSDocOperatorType, JSDocParenthesizedType,
+    JSDocPredicateType, JSDocRecordField, JSDocRecordKey, JSDocRecordType, JSDocTupleType,
+    JSDocType, JSDocTypeLiteral, JSDocTypeModifier, JSDocTypeName, JSDocTypeOperator,
+    JSDocTypeParseError,
+};
@@ -20,6 +20,7 @@ pub use oxc_syntax::{
     symbol::{SymbolFlags, SymbolId},
 };
 
+pub mod dataflow;
 pub mod dot;
 
 mod binder;
@@ -31,15 +32,23 @@ mod jsdoc;
 mod label;
 mod node;
 mod reference;
+mod rename;
 mod scope;
 mod stats;
 mod symbol;
 mod unresolved_stack;
 
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
-pub use jsdoc::{JSDoc, JSDocFinder, JSDocTag};
+pub use jsdoc::{
+    JSDoc, JSDocArrayType, JSDocCompositeType, JSDocFinder, JSDocFunctionParam, JSDocFunctionType,
+    JSDocGenericType, JSDocImportType, JSDocModifiedType, JSDocOperatorType,
+    JSDocParenthesizedType, JSDocPredicateType, JSDocRecordField, JSDocRecordKey, JSDocRecordType,
+    JSDocTag, JSDocTagTypePart, JSDocTupleType, JSDocType, JSDocTypeLiteral, JSDocTypeModifier,
+    JSDocTypeName, JSDocTypeOperator, JSDocTypeParseError,
+};
 pub use node::{AstNode, AstNodes};
 pub use reference::Reference;
+pub use rename::{OccurrenceKind, RenameEdit, RenameError, SymbolOccurrence};
 pub use scope::ScopeTree;
 pub use stats::Stats;
 pub use symbol::{IsGlobalReference, SymbolTable};
@@ -0,0 +1,326 @@
+//! Symbol queries for editor tooling: finding all occurrences of a symbol and
+//! computing the text edits needed to rename it.
+
+use std::fmt;
+
+use oxc_ast::{
+    ast::{
+        AssignmentTargetProperty, BindingIdentifier, Expression, IdentifierReference, Statement,
+    },
+    visit::walk,
+    AstKind, Visit,
+};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    identifier::is_identifier_name, keyword::is_reserved_keyword, reference::ReferenceId,
+    scope::ScopeId, symbol::SymbolId,
+};
+use rustc_hash::FxHashMap;
+
+use crate::Semantic;
+
+/// How a symbol is used at a [`SymbolOccurrence`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OccurrenceKind {
+    /// The symbol is declared (or redeclared) here.
+    Declaration,
+    /// The symbol is only read here.
+    Read,
+    /// The symbol is written to here. Includes read-write references such as `a += 1`.
+    Write,
+}
+
+/// A place in the source text where a symbol is declared or referenced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolOccurrence {
+    /// Span of the identifier.
+    pub span: Span,
+    pub kind: OccurrenceKind,
+    /// The reference at this occurrence. [`None`] for declarations.
+    pub reference_id: Option<ReferenceId>,
+}
+
+/// A single text edit produced by [`Semantic::rename_symbol`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameEdit {
+    pub span: Span,
+    pub replacement: String,
+}
+
+/// Reasons a symbol cannot be renamed safely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenameError {
+    /// The new name is not a valid identifier, or is a reserved word.
+    InvalidName(String),
+    /// The symbol is not declared by a plain identifier (e.g. a TS enum member
+    /// with a string key) and cannot be renamed by replacing identifiers.
+    NotRenamable,
+    /// Another symbol with the new name is already declared in the same scope.
+    Collision { span: Span },
+    /// After renaming, the reference at `span` would resolve to a different symbol.
+    Shadowed { span: Span },
+}
+
+impl fmt::Display for RenameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(name) => write!(f, "`{name}` is not a valid identifier"),
+            Self::NotRenamable => f.write_str("This symbol cannot be renamed"),
+            Self::Collision { .. } => {
+                f.write_str("A symbol with this name is already declared in the same scope")
+            }
+            Self::Shadowed { .. } => {
+                f.write_str("Renaming would change which symbol a reference resolves to")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RenameError {}
+
+impl Semantic<'_> {
+    /// Find the symbol declared or referenced by the identifier at `offset`.
+    ///
+    /// An offset at the end of an identifier (e.g. the cursor position right
+    /// after typing it) is considered to be within it.
+    ///
+    /// Returns [`None`] if there is no identifier at `offset`, or it refers
+    /// to an unresolved (global) reference.
+    pub fn symbol_at_offset(&self, offset: u32) -> Option<SymbolId> {
+        let AstKind::Program(program) = self.nodes.root_node()?.kind() else {
+            return None;
+        };
+        let mut finder = SymbolAtOffset { semantic: self, offset, symbol_id: None };
+        finder.visit_program(program);
+        finder.symbol_id
+    }
+
+    /// Get every declaration, redeclaration and resolved reference of a
+    /// symbol, sorted by position in the source text.
+    pub fn symbol_occurrences(&self, symbol_id: SymbolId) -> Vec<SymbolOccurrence> {
+        let declaration =
+            |span| SymbolOccurrence { span, kind: OccurrenceKind::Declaration, reference_id: None };
+        let mut occurrences = std::iter::once(self.symbols.get_span(symbol_id))
+            .chain(self.symbols.get_redeclarations(symbol_id).iter().copied())
+            .map(declaration)
+            .collect::<Vec<_>>();
+        for &reference_id in self.symbols.get_resolved_reference_ids(symbol_id) {
+            let reference = self.symbols.get_reference(reference_id);
+            let kind =
+                if reference.is_write() { OccurrenceKind::Write } else { OccurrenceKind::Read };
+            let span = self.reference_span(reference);
+            occurrences.push(SymbolOccurrence { span, kind, reference_id: Some(reference_id) });
+        }
+        occurrences.sort_unstable_by_key(|occurrence| occurrence.span.start);
+        occurrences.dedup_by_key(|occurrence| occurrence.span);
+        occurrences
+    }
+
+    /// Compute the edits needed to rename a symbol to `new_name`.
+    ///
+    /// Shorthand properties, imports and exports are expanded so that the
+    /// program keeps its behavior, e.g. renaming `a` to `b` turns `({ a })`
+    /// into `({ a: b })` and `export { a }` into `export { b as a }`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RenameError`] if `new_name` is not a valid identifier, or if
+    /// the rename would collide with or be shadowed by another binding, or
+    /// capture references that currently resolve elsewhere.
+    pub fn rename_symbol(
+        &self,
+        symbol_id: SymbolId,
+        new_name: &str,
+    ) -> Result<Vec<RenameEdit>, RenameError> {
+        if !is_identifier_name(new_name) || is_reserved_keyword(new_name) {
+            return Err(RenameError::InvalidName(new_name.to_string()));
+        }
+        let old_name = self.symbols.get_name(symbol_id);
+        let occurrences = self.symbol_occurrences(symbol_id);
+        if occurrences
+            .iter()
+            .any(|occurrence| occurrence.span.source_text(self.source_text) != old_name)
+        {
+            return Err(RenameError::NotRenamable);
+        }
+        if old_name == new_name {
+            return Ok(vec![]);
+        }
+
+        self.check_rename_conflicts(symbol_id, new_name)?;
+
+        let shorthands = self.collect_shorthands();
+        let edits = occurrences
+            .into_iter()
+            .map(|occurrence| {
+                let replacement = match shorthands.get(&occurrence.span) {
+                    Some(Shorthand::Property) => format!("{old_name}: {new_name}"),
+                    Some(Shorthand::Import) => format!("{old_name} as {new_name}"),
+                    Some(Shorthand::Export) => format!("{new_name} as {old_name}"),
+                    None => new_name.to_string(),
+                };
+                RenameEdit { span: occurrence.span, replacement }
+            })
+            .collect();
+        Ok(edits)
+    }
+
+    fn check_rename_conflicts(
+        &self,
+        symbol_id: SymbolId,
+        new_name: &str,
+    ) -> Result<(), RenameError> {
+        let symbol_scope_id = self.symbols.get_scope_id(symbol_id);
+
+        // `let a; let b;` -> renaming `a` to `b` redeclares `b`.
+        if let Some(existing) = self.scopes.get_binding(symbol_scope_id, new_name) {
+            return Err(RenameError::Collision { span: self.symbols.get_span(existing) });
+        }
+
+        // `let a; { let b; a }` -> renaming `a` to `b` makes `a` refer to the inner `b`.
+        for reference in self.symbols.get_resolved_references(symbol_id) {
+            let reference_scope_id = self.nodes.get_node(reference.node_id()).scope_id();
+            let shadowed = self
+                .scopes
+                .ancestors(reference_scope_id)
+                .take_while(|&scope_id| scope_id != symbol_scope_id)
+                .any(|scope_id| self.scopes.has_binding(scope_id, new_name));
+            if shadowed {
+                return Err(RenameError::Shadowed { span: self.reference_span(reference) });
+            }
+        }
+
+        // `let b; { let a; b }` -> renaming `a` to `b` captures the reference to the outer `b`.
+        let resolved_elsewhere = self
+            .symbols
+            .symbol_ids()
+            .filter(|&other| self.symbols.get_name(other) == new_name)
+            .flat_map(|other| self.symbols.get_resolved_reference_ids(other).iter().copied());
+        let unresolved = self
+            .scopes
+            .root_unresolved_references()
+            .get(new_name)
+            .into_iter()
+            .flat_map(|reference_ids| reference_ids.iter().copied());
+        for reference_id in resolved_elsewhere.chain(unresolved) {
+            let reference = self.symbols.get_reference(reference_id);
+            let reference_scope_id = self.nodes.get_node(reference.node_id()).scope_id();
+            if self.would_capture(reference_scope_id, symbol_scope_id, new_name) {
+                return Err(RenameError::Shadowed { span: self.reference_span(reference) });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether a reference to `name` in `reference_scope_id` reaches
+    /// `symbol_scope_id` before finding its current binding.
+    fn would_capture(
+        &self,
+        reference_scope_id: ScopeId,
+        symbol_scope_id: ScopeId,
+        name: &str,
+    ) -> bool {
+        for scope_id in self.scopes.ancestors(reference_scope_id) {
+            if scope_id == symbol_scope_id {
+                return true;
+            }
+            if self.scopes.has_binding(scope_id, name) {
+                return false;
+            }
+        }
+        false
+    }
+
+    /// Spans of identifiers that stand for both a key (or module export name)
+    /// and a local binding, and need to be expanded when renamed.
+    fn collect_shorthands(&self) -> FxHashMap<Span, Shorthand> {
+        let mut shorthands = FxHashMap::default();
+        for node in self.nodes.iter() {
+            match node.kind() {
+                AstKind::ObjectProperty(prop) if prop.shorthand => {
+                    shorthands.insert(prop.key.span(), Shorthand::Property);
+                }
+                AstKind::ObjectPattern(pattern) => {
+                    for prop in pattern.properties.iter().filter(|prop| prop.shorthand) {
+                        shorthands.insert(prop.key.span(), Shorthand::Property);
+                    }
+                }
+                AstKind::ObjectAssignmentTarget(target) => {
+                    for prop in &target.properties {
+                        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) =
+                            prop
+                        {
+                            shorthands.insert(prop.binding.span, Shorthand::Property);
+                        }
+                    }
+                }
+                AstKind::ImportSpecifier(specifier)
+                    if specifier.imported.span() == specifier.local.span =>
+                {
+                    shorthands.insert(specifier.local.span, Shorthand::Import);
+                }
+                AstKind::ExportSpecifier(specifier)
+                    if specifier.local.span() == specifier.exported.span() =>
+                {
+                    shorthands.insert(specifier.local.span(), Shorthand::Export);
+                }
+                _ => {}
+            }
+        }
+        shorthands
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Shorthand {
+    /// `{ a }`, `const { a } = obj`, `({ a } = obj)`
+    Property,
+    /// `import { a } from "mod"`
+    Import,
+    /// `export { a }`
+    Export,
+}
+
+/// Finds the symbol of the identifier at an offset, only descending into
+/// statements and expressions which contain it.
+struct SymbolAtOffset<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    offset: u32,
+    symbol_id: Option<SymbolId>,
+}
+
+impl SymbolAtOffset<'_, '_> {
+    fn contains(&self, span: Span) -> bool {
+        self.symbol_id.is_none() && span.start <= self.offset && self.offset <= span.end
+    }
+}
+
+impl<'a> Visit<'a> for SymbolAtOffset<'_, 'a> {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if self.contains(stmt.span()) {
+            walk::walk_statement(self, stmt);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if self.contains(expr.span()) {
+            walk::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        if self.contains(ident.span) {
+            self.symbol_id = ident.symbol_id.get();
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if self.contains(ident.span) {
+            // Unresolved references have no symbol, e.g. `console`
+            self.symbol_id = ident.reference_id.get().and_then(|reference_id| {
+                self.semantic.symbols.get_reference(reference_id).symbol_id()
+            });
+        }
+    }
+}
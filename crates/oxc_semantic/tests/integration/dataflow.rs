@@ -0,0 +1,94 @@
+use oxc_semantic::{
+    dataflow::{Definition, Liveness, ReachingDefinitions},
+    Semantic,
+};
+use oxc_span::GetSpan;
+
+use crate::util::SemanticTester;
+
+/// Source offset of a definition, for readable assertions.
+fn definition_start(semantic: &Semantic, definition: Definition) -> u32 {
+    match definition {
+        Definition::Declaration(node_id) => semantic.nodes().kind(node_id).span().start,
+        Definition::Write(reference_id) => {
+            let reference = semantic.symbols().get_reference(reference_id);
+            semantic.nodes().kind(reference.node_id()).span().start
+        }
+    }
+}
+
+/// Offsets of all definitions whose value is never read.
+fn dead_definitions(source_text: &'static str) -> Vec<u32> {
+    let tester = SemanticTester::js(source_text).with_cfg(true);
+    let semantic = tester.build();
+    let liveness = Liveness::new(&semantic).unwrap();
+    let mut dead = liveness
+        .dead_definitions()
+        .map(|(_, definition)| definition_start(&semantic, definition))
+        .collect::<Vec<_>>();
+    dead.sort_unstable();
+    dead
+}
+
+/// Offsets of the definitions reaching the last read of `a`.
+fn reaching_last_read(source_text: &'static str) -> Vec<u32> {
+    let tester = SemanticTester::js(source_text).with_cfg(true);
+    let semantic = tester.build();
+    let reaching_definitions = ReachingDefinitions::new(&semantic).unwrap();
+    let symbol_id = semantic.scopes().get_root_binding("a").unwrap();
+    let reference_id = semantic
+        .symbols()
+        .get_resolved_reference_ids(symbol_id)
+        .iter()
+        .copied()
+        .filter(|&reference_id| semantic.symbols().get_reference(reference_id).is_read())
+        .last()
+        .unwrap();
+    let mut reaching = reaching_definitions
+        .reaching(reference_id)
+        .iter()
+        .map(|&definition| definition_start(&semantic, definition))
+        .collect::<Vec<_>>();
+    reaching.sort_unstable();
+    reaching
+}
+
+#[test]
+fn test_liveness() {
+    let cases: [(&str, &[u32]); 12] = [
+        ("let a = 1; a = 2; f(a);", &[4]),
+        ("let a = 1; if (c) { a = 2 } f(a);", &[]),
+        ("let a = 0; a = a + 1;", &[11]),
+        ("let a = 1; a++;", &[11]),
+        ("let i = 0; while (i < 10) { i++ }", &[]),
+        ("function f(x) { x = 1 }", &[11, 16]),
+        ("for (const x of xs) {}", &[11]),
+        ("let a; a = 1; a = 2; f(a);", &[7]),
+        ("let { a, b } = o; f(a);", &[9]),
+        // a closure may read `a` at any time
+        ("let a = 1; function g() { return a } a = 2;", &[]),
+        // `f` may throw after the first write
+        ("let a; try { a = 1; f(); a = 2 } catch { g(a) }", &[]),
+        ("let a = 1; export { a }; a = 2;", &[]),
+    ];
+
+    for (source_text, expected) in cases {
+        assert_eq!(dead_definitions(source_text), expected, "{source_text}");
+    }
+}
+
+#[test]
+fn test_reaching_definitions() {
+    let cases: [(&str, &[u32]); 6] = [
+        ("let a = 1; a = 2; f(a);", &[11]),
+        ("let a = 1; if (c) { a = 2 } f(a);", &[4, 20]),
+        ("let a = 1; while (c) { a = 2 } f(a);", &[4, 23]),
+        ("let a = 1; while (c) { f(a); a = 2 }", &[4, 29]),
+        ("let a; f(a);", &[]),
+        ("let a = 1; function g() { a = 2 } f(a);", &[4, 26]),
+    ];
+
+    for (source_text, expected) in cases {
+        assert_eq!(reaching_last_read(source_text), expected, "{source_text}");
+    }
+}
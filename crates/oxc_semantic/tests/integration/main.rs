@@ -2,7 +2,9 @@
 
 pub mod cfg;
 pub mod classes;
+pub mod dataflow;
 pub mod modules;
+pub mod rename;
 pub mod scopes;
 pub mod symbols;
 pub mod util;
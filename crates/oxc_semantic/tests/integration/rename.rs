@@ -0,0 +1,136 @@
+use oxc_semantic::{OccurrenceKind, RenameError, Semantic};
+
+use crate::util::SemanticTester;
+
+/// Apply the edits from renaming the symbol at the first occurrence of `target` to `new_name`.
+fn rename(source_text: &'static str, target: &str, new_name: &str) -> Result<String, RenameError> {
+    let tester = SemanticTester::ts(source_text);
+    let semantic = tester.build();
+    let symbol_id = symbol_at(&semantic, target);
+    let mut edits = semantic.rename_symbol(symbol_id, new_name)?;
+    edits.sort_unstable_by_key(|edit| std::cmp::Reverse(edit.span.start));
+    let mut output = source_text.to_string();
+    for edit in edits {
+        output.replace_range(edit.span.start as usize..edit.span.end as usize, &edit.replacement);
+    }
+    Ok(output)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn symbol_at(semantic: &Semantic, target: &str) -> oxc_semantic::SymbolId {
+    let offset = semantic.source_text().find(target).expect("target should be in source") as u32;
+    semantic.symbol_at_offset(offset).expect("should find a symbol at offset")
+}
+
+#[test]
+fn test_symbol_at_offset() {
+    let tester = SemanticTester::js("let a = 1; console.log(a);");
+    let semantic = tester.build();
+    let a = semantic.scopes().get_root_binding("a").unwrap();
+    assert_eq!(semantic.symbol_at_offset(4), Some(a));
+    // end of the identifier
+    assert_eq!(semantic.symbol_at_offset(5), Some(a));
+    assert_eq!(semantic.symbol_at_offset(23), Some(a));
+    // `console` is unresolved
+    assert_eq!(semantic.symbol_at_offset(12), None);
+    assert_eq!(semantic.symbol_at_offset(8), None);
+
+    // nested in functions, classes and parameters
+    let tester = SemanticTester::js(
+        "function f(x) { class C { m() { return x; } } return C; }\nconst y = f;",
+    );
+    let semantic = tester.build();
+    let source_text = semantic.source_text();
+    let find = |target: &str| {
+        #[allow(clippy::cast_possible_truncation)]
+        let offset = source_text.find(target).unwrap() as u32;
+        semantic.symbol_at_offset(offset).map(|symbol_id| semantic.symbols().get_name(symbol_id))
+    };
+    assert_eq!(find("x)"), Some("x"));
+    assert_eq!(find("x; }"), Some("x"));
+    assert_eq!(find("C {"), Some("C"));
+    assert_eq!(find("C; }"), Some("C"));
+    assert_eq!(find("m()"), None);
+    assert_eq!(find("f;"), Some("f"));
+}
+
+#[test]
+fn test_symbol_occurrences() {
+    let tester = SemanticTester::js("var a = 1; var a; a += 1; foo(a);");
+    let semantic = tester.build();
+    let a = semantic.scopes().get_root_binding("a").unwrap();
+    let kinds = semantic
+        .symbol_occurrences(a)
+        .into_iter()
+        .map(|o| (o.span.start, o.kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            (4, OccurrenceKind::Declaration),
+            (15, OccurrenceKind::Declaration),
+            (18, OccurrenceKind::Write),
+            (30, OccurrenceKind::Read),
+        ]
+    );
+}
+
+#[test]
+fn test_rename() {
+    let pass = [
+        (
+            "let a = 1; a++; function f() { return a }",
+            "a",
+            "b",
+            "let b = 1; b++; function f() { return b }",
+        ),
+        (
+            "function foo(a) { return a } foo(1)",
+            "foo",
+            "bar",
+            "function bar(a) { return a } bar(1)",
+        ),
+        ("let a; const o = { a };", "a", "b", "let b; const o = { a: b };"),
+        ("const { a } = o; a;", "a", "b", "const { a: b } = o; b;"),
+        ("const { a = 1 } = o; a;", "a", "b", "const { a: b = 1 } = o; b;"),
+        ("let a; ({ a } = o);", "a", "b", "let b; ({ a: b } = o);"),
+        ("import { a } from 'mod'; a;", "a", "b", "import { a as b } from 'mod'; b;"),
+        ("import { a as c } from 'mod'; c;", "c", "b", "import { a as b } from 'mod'; b;"),
+        ("let a; export { a };", "a", "b", "let b; export { b as a };"),
+        ("let a; export { a as c };", "a", "b", "let b; export { b as c };"),
+        ("type A = string; let x: A;", "A", "B", "type B = string; let x: B;"),
+        // a binding with the same name in an unrelated scope is fine
+        ("let a; function f() { let b; b }", "a", "b", "let b; function f() { let b; b }"),
+        // renaming to the same name is a no-op
+        ("let a; a;", "a", "a", "let a; a;"),
+    ];
+
+    for (source, target, new_name, expected) in pass {
+        let output = rename(source, target, new_name)
+            .unwrap_or_else(|err| panic!("failed to rename `{target}` in {source:?}: {err}"));
+        assert_eq!(output, expected, "renaming `{target}` to `{new_name}`");
+    }
+}
+
+#[test]
+fn test_rename_errors() {
+    let fail = [
+        ("let a; a;", "a", "1b"),
+        ("let a; a;", "a", "class"),
+        // collision in the same scope
+        ("let a; let b;", "a", "b"),
+        // a reference to `a` would resolve to the inner `b`
+        ("let a; function f() { let b; a }", "a", "b"),
+        // the reference to the outer `b` would resolve to the renamed symbol
+        ("let b; function f() { let a; b }", "a", "b"),
+        // the reference to the global `b` would resolve to the renamed symbol
+        ("let a; b;", "a", "b"),
+    ];
+
+    for (source, target, new_name) in fail {
+        assert!(
+            rename(source, target, new_name).is_err(),
+            "expected renaming `{target}` to `{new_name}` in {source:?} to fail"
+        );
+    }
+}
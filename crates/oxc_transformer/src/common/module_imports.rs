@@ -115,6 +115,22 @@ impl<'a> ModuleImportsStore<'a> {
         self.add_import(source, Import::Named(NamedImport { imported, local }), front);
     }
 
+    /// Add `import` or `require` for side effects only to top of program.
+    ///
+    /// * `import 'source';` or
+    /// * `require('source');`
+    ///
+    /// If `front` is `true`, `import`/`require` is added to front of the `import`s/`require`s.
+    pub fn add_side_effect_import(&self, source: Atom<'a>, front: bool) {
+        let mut imports = self.imports.borrow_mut();
+        let entry = imports.entry(source);
+        let index = entry.index();
+        entry.or_default();
+        if front && index != 0 {
+            imports.move_index(index, 0);
+        }
+    }
+
     /// Returns `true` if no imports have been scheduled for insertion.
     pub fn is_empty(&self) -> bool {
         self.imports.borrow().is_empty()
@@ -211,9 +227,11 @@ impl<'a> ModuleImportsStore<'a> {
             ),
         }));
 
+        // `import 'source'` for side effects only
+        let specifiers = (!specifiers.is_empty()).then_some(specifiers);
         Statement::from(ctx.ast.module_declaration_import_declaration(
             SPAN,
-            Some(specifiers),
+            specifiers,
             ctx.ast.string_literal(SPAN, source, None),
             None,
             NONE,
@@ -238,7 +256,12 @@ impl<'a> ModuleImportsStore<'a> {
             let arg = Argument::from(ctx.ast.expression_string_literal(SPAN, source, None));
             ctx.ast.vec1(arg)
         };
-        let Some(Import::Default(local)) = names.into_iter().next() else { unreachable!() };
+        let Some(import) = names.into_iter().next() else {
+            // `require('source')` for side effects only
+            let call = ctx.ast.expression_call(SPAN, callee, NONE, args, false);
+            return ctx.ast.statement_expression(SPAN, call);
+        };
+        let Import::Default(local) = import else { unreachable!() };
         let id = local.create_binding_pattern(ctx);
         let var_kind = VariableDeclarationKind::Var;
         let decl = {
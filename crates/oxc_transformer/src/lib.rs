@@ -6,7 +6,10 @@
 //! * <https://babel.dev/docs/presets>
 //! * <https://github.com/microsoft/TypeScript/blob/v5.6.3/src/compiler/transformer.ts>
 
-use std::path::Path;
+use std::{
+    mem,
+    path::{Path, PathBuf},
+};
 
 use oxc_allocator::{Allocator, Vec as ArenaVec};
 use oxc_ast::{ast::*, AstBuilder};
@@ -31,10 +34,12 @@ mod es2020;
 mod es2021;
 mod es2022;
 mod jsx;
+mod polyfills;
 mod regexp;
 mod typescript;
 
 mod plugins;
+mod rewrite_imports;
 
 use common::Common;
 use context::TransformCtx;
@@ -47,7 +52,10 @@ use es2020::ES2020;
 use es2021::ES2021;
 use es2022::ES2022;
 use jsx::Jsx;
+use plugins::Plugins;
+use polyfills::Polyfills;
 use regexp::RegExp;
+use rewrite_imports::RewriteImports;
 use rustc_hash::FxHashMap;
 use typescript::TypeScript;
 
@@ -61,6 +69,7 @@ pub use crate::{
         ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
     },
     plugins::*,
+    rewrite_imports::RewriteImportsOptions,
     typescript::{RewriteExtensionsMode, TypeScriptOptions},
 };
 
@@ -74,6 +83,20 @@ pub struct TransformerReturn {
     pub helpers_used: FxHashMap<Helper, String>,
 }
 
+/// Where a plugin added with [`Transformer::with_plugin`] runs, relative to the built-in
+/// transforms.
+///
+/// Plugins run in the same traversal as the built-in transforms. For each node, the `enter_*`
+/// and `exit_*` methods of [`PluginPosition::Pre`] plugins are called before those of the
+/// built-in transforms, and those of [`PluginPosition::Post`] plugins after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginPosition {
+    /// Before the built-in transforms, e.g. `enter_*` methods see TypeScript and JSX syntax.
+    Pre,
+    /// After the built-in transforms.
+    Post,
+}
+
 pub struct Transformer<'a> {
     ctx: TransformCtx<'a>,
     // options: TransformOptions,
@@ -82,6 +105,12 @@ pub struct Transformer<'a> {
     typescript: TypeScriptOptions,
     jsx: JsxOptions,
     env: EnvOptions,
+    plugins: PluginsOptions,
+    rewrite_imports: Option<RewriteImportsOptions>,
+    cwd: PathBuf,
+
+    pre_plugins: Vec<Box<dyn Traverse<'a> + 'a>>,
+    post_plugins: Vec<Box<dyn Traverse<'a> + 'a>>,
 }
 
 impl<'a> Transformer<'a> {
@@ -92,10 +121,33 @@ impl<'a> Transformer<'a> {
             allocator,
             typescript: options.typescript.clone(),
             jsx: options.jsx.clone(),
-            env: options.env,
+            env: options.env.clone(),
+            plugins: options.plugins.clone(),
+            rewrite_imports: options.rewrite_imports.clone(),
+            cwd: options.cwd.clone(),
+            pre_plugins: vec![],
+            post_plugins: vec![],
         }
     }
 
+    /// Run `plugin` in the same traversal as the built-in transforms.
+    ///
+    /// Plugins with the same [`PluginPosition`] run in the order they were added.
+    /// Pass `&mut plugin` to read its state after the transform.
+    #[must_use]
+    pub fn with_plugin<P: Traverse<'a> + 'a>(
+        mut self,
+        position: PluginPosition,
+        plugin: P,
+    ) -> Self {
+        let plugins = match position {
+            PluginPosition::Pre => &mut self.pre_plugins,
+            PluginPosition::Post => &mut self.post_plugins,
+        };
+        plugins.push(Box::new(plugin));
+        self
+    }
+
     pub fn build_with_symbols_and_scopes(
         mut self,
         symbols: SymbolTable,
@@ -114,8 +166,19 @@ impl<'a> Transformer<'a> {
             &self.ctx,
         );
 
+        let pre_plugins = mem::take(&mut self.pre_plugins);
+        let post_plugins = mem::take(&mut self.post_plugins);
+
         let mut transformer = TransformerImpl {
             common: Common::new(&self.env, &self.ctx),
+            x0_plugins: Plugins::new(self.plugins, &self.cwd, &self.ctx),
+            x0_rewrite_imports: self
+                .rewrite_imports
+                .map(|options| RewriteImports::new(options, &self.ctx)),
+            x0_polyfills: self
+                .env
+                .use_built_ins
+                .then(|| Polyfills::new(self.env.targets.clone(), &self.ctx)),
             x0_typescript: program
                 .source_type
                 .is_typescript()
@@ -132,7 +195,14 @@ impl<'a> Transformer<'a> {
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
         };
 
-        let (symbols, scopes) = traverse_mut(&mut transformer, allocator, program, symbols, scopes);
+        let (symbols, scopes) = if pre_plugins.is_empty() && post_plugins.is_empty() {
+            traverse_mut(&mut transformer, allocator, program, symbols, scopes)
+        } else {
+            let mut traversers: Vec<Box<dyn Traverse<'a> + '_>> = pre_plugins;
+            traversers.push(Box::new(transformer));
+            traversers.extend(post_plugins);
+            traverse_mut(&mut traversers, allocator, program, symbols, scopes)
+        };
         let helpers_used = self.ctx.helper_loader.used_helpers.borrow_mut().drain().collect();
         #[allow(deprecated)]
         TransformerReturn { errors: self.ctx.take_errors(), symbols, scopes, helpers_used }
@@ -141,6 +211,9 @@ impl<'a> Transformer<'a> {
 
 struct TransformerImpl<'a, 'ctx> {
     // NOTE: all callbacks must run in order.
+    x0_plugins: Plugins<'a, 'ctx>,
+    x0_rewrite_imports: Option<RewriteImports<'a, 'ctx>>,
+    x0_polyfills: Option<Polyfills<'a, 'ctx>>,
     x0_typescript: Option<TypeScript<'a, 'ctx>>,
     x1_jsx: Jsx<'a, 'ctx>,
     x2_es2022: ES2022<'a, 'ctx>,
@@ -158,6 +231,10 @@ struct TransformerImpl<'a, 'ctx> {
 
 impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x0_plugins.enter_program(program, ctx);
+        if let Some(rewrite_imports) = self.x0_rewrite_imports.as_mut() {
+            rewrite_imports.enter_program(program, ctx);
+        }
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_program(program, ctx);
         }
@@ -165,11 +242,15 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x0_plugins.exit_program(program, ctx);
         self.x1_jsx.exit_program(program, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.exit_program(program, ctx);
         }
         self.x2_es2018.exit_program(program, ctx);
+        if let Some(polyfills) = self.x0_polyfills.as_mut() {
+            polyfills.exit_program(program, ctx);
+        }
         self.common.exit_program(program, ctx);
     }
 
@@ -221,9 +302,32 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         node: &mut IdentifierReference<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        if let Some(polyfills) = self.x0_polyfills.as_mut() {
+            polyfills.enter_identifier_reference(node, ctx);
+        }
         self.common.enter_identifier_reference(node, ctx);
     }
 
+    fn enter_static_member_expression(
+        &mut self,
+        expr: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(polyfills) = self.x0_polyfills.as_mut() {
+            polyfills.enter_static_member_expression(expr, ctx);
+        }
+    }
+
+    fn enter_computed_member_expression(
+        &mut self,
+        expr: &mut ComputedMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(polyfills) = self.x0_polyfills.as_mut() {
+            polyfills.enter_computed_member_expression(expr, ctx);
+        }
+    }
+
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_binding_pattern(pat, ctx);
@@ -280,6 +384,10 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
 
     #[inline]
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x0_plugins.enter_expression(expr, ctx);
+        if let Some(rewrite_imports) = self.x0_rewrite_imports.as_mut() {
+            rewrite_imports.enter_expression(expr, ctx);
+        }
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_expression(expr, ctx);
         }
@@ -370,6 +478,7 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     }
 
     fn enter_jsx_element(&mut self, node: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x0_plugins.enter_jsx_element(node, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_jsx_element(node, ctx);
         }
@@ -580,6 +689,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         node: &mut ImportDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        if let Some(rewrite_imports) = self.x0_rewrite_imports.as_mut() {
+            rewrite_imports.enter_import_declaration(node, ctx);
+        }
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_import_declaration(node, ctx);
         }
@@ -590,6 +702,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         node: &mut ExportAllDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        if let Some(rewrite_imports) = self.x0_rewrite_imports.as_mut() {
+            rewrite_imports.enter_export_all_declaration(node, ctx);
+        }
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_export_all_declaration(node, ctx);
         }
@@ -600,6 +715,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         node: &mut ExportNamedDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        if let Some(rewrite_imports) = self.x0_rewrite_imports.as_mut() {
+            rewrite_imports.enter_export_named_declaration(node, ctx);
+        }
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_export_named_declaration(node, ctx);
         }
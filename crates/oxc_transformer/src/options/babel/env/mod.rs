@@ -36,10 +36,10 @@ pub struct BabelEnvOptions {
     #[deprecated = "Not Implemented"]
     pub exclude: Option<serde_json::Value>,
 
-    #[deprecated = "Not Implemented"]
+    /// Only `"usage"` is supported.
     pub use_built_ins: Option<serde_json::Value>,
 
-    #[deprecated = "Not Implemented"]
+    /// Only core-js 3 is supported.
     pub corejs: Option<serde_json::Value>,
 
     #[deprecated = "Not Implemented"]
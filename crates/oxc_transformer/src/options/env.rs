@@ -17,7 +17,7 @@ use crate::{
 
 use super::{babel::BabelEnvOptions, ESFeature, ESTarget, Engine, Module};
 
-#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize)]
 #[serde(try_from = "BabelEnvOptions")]
 pub struct EnvOptions {
     /// Specify what module code is generated.
@@ -40,6 +40,17 @@ pub struct EnvOptions {
     pub es2021: ES2021Options,
 
     pub es2022: ES2022Options,
+
+    /// Engine targets which the options were created from.
+    ///
+    /// Empty when no targets were given, which means all polyfills are needed.
+    pub targets: EngineTargets,
+
+    /// Inject `core-js` imports for built-ins which are used, and are not supported by
+    /// [`EnvOptions::targets`].
+    ///
+    /// Same as `useBuiltIns: "usage"` with `corejs: 3` in `@babel/preset-env`.
+    pub use_built_ins: bool,
 }
 
 impl EnvOptions {
@@ -90,6 +101,8 @@ impl EnvOptions {
                     None
                 },
             },
+            targets: EngineTargets::default(),
+            use_built_ins: false,
         }
     }
 
@@ -145,9 +158,37 @@ impl EnvOptions {
     }
 }
 
-impl From<BabelEnvOptions> for EnvOptions {
-    fn from(o: BabelEnvOptions) -> Self {
-        Self::from(o.targets)
+impl TryFrom<BabelEnvOptions> for EnvOptions {
+    type Error = String;
+
+    fn try_from(o: BabelEnvOptions) -> Result<Self, Self::Error> {
+        let use_built_ins = match &o.use_built_ins {
+            None | Some(serde_json::Value::Bool(false)) => false,
+            Some(serde_json::Value::String(s)) if s == "usage" => true,
+            Some(value) => {
+                return Err(format!(
+                    "`useBuiltIns: {value}` is not supported, only `\"usage\"` is supported."
+                ))
+            }
+        };
+        if use_built_ins {
+            // `3`, `"3.39"` or `{ "version": "3.39" }`
+            let core_js_version = match &o.corejs {
+                Some(serde_json::Value::Object(o)) => o.get("version").cloned(),
+                corejs => corejs.clone(),
+            };
+            let core_js_major = match core_js_version {
+                Some(serde_json::Value::Number(n)) => n.to_string(),
+                Some(serde_json::Value::String(s)) => s,
+                _ => String::new(),
+            };
+            if core_js_major.split('.').next() != Some("3") {
+                return Err("`useBuiltIns: \"usage\"` requires `corejs: 3`.".to_string());
+            }
+        }
+        let mut env = Self::from(o.targets);
+        env.use_built_ins = use_built_ins;
+        Ok(env)
     }
 }
 
@@ -193,6 +234,8 @@ impl From<EngineTargets> for EnvOptions {
                 class_static_block: o.has_feature(ES2022ClassStaticBlock),
                 class_properties: o.has_feature(ES2022ClassProperties).then(Default::default),
             },
+            targets: o,
+            use_built_ins: false,
         }
     }
 }
@@ -12,7 +12,9 @@ use crate::{
     es2021::ES2021Options,
     es2022::ES2022Options,
     jsx::JsxOptions,
+    plugins::PluginsOptions,
     regexp::RegExpOptions,
+    rewrite_imports::RewriteImportsOptions,
     typescript::TypeScriptOptions,
     ReactRefreshOptions,
 };
@@ -61,6 +63,15 @@ pub struct TransformOptions {
     /// ECMAScript Env Options
     pub env: EnvOptions,
 
+    /// Plugins which are not part of a preset, e.g. styled-components.
+    pub plugins: PluginsOptions,
+
+    /// Rewrites path aliases and named imports from barrel files in import and export
+    /// declarations, in JavaScript and TypeScript files.
+    ///
+    /// Defaults to `None` (do nothing).
+    pub rewrite_imports: Option<RewriteImportsOptions>,
+
     pub helper_loader: HelperLoaderOptions,
 }
 
@@ -80,6 +91,8 @@ impl TransformOptions {
                 ..JsxOptions::default()
             },
             env: EnvOptions::enable_all(/* include_unfinished_plugins */ false),
+            plugins: PluginsOptions::default(),
+            rewrite_imports: None,
             helper_loader: HelperLoaderOptions {
                 mode: HelperLoaderMode::Runtime,
                 ..Default::default()
@@ -163,7 +176,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             jsx_options
         };
 
-        let env = options.presets.env.unwrap_or_default();
+        let env = options.presets.env.clone().unwrap_or_default();
 
         let module = Module::try_from(&options.plugins).unwrap_or_else(|_| {
             options.presets.env.as_ref().map(|env| env.module).unwrap_or_default()
@@ -256,7 +269,11 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 es2020,
                 es2021,
                 es2022,
+                targets: env.targets,
+                use_built_ins: env.use_built_ins,
             },
+            plugins: PluginsOptions::default(),
+            rewrite_imports: None,
             helper_loader,
         })
     }
@@ -1,5 +1,57 @@
 mod inject_global_variables;
+mod options;
 mod replace_global_defines;
+mod styled_components;
+
+use std::path::Path;
+
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
 
 pub use inject_global_variables::*;
+pub use options::PluginsOptions;
 pub use replace_global_defines::*;
+use styled_components::StyledComponents;
+pub use styled_components::StyledComponentsOptions;
+
+pub(crate) struct Plugins<'a, 'ctx> {
+    styled_components: Option<StyledComponents<'a, 'ctx>>,
+}
+
+impl<'a, 'ctx> Plugins<'a, 'ctx> {
+    pub fn new(options: PluginsOptions, cwd: &Path, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            styled_components: options
+                .styled_components
+                .map(|options| StyledComponents::new(options, cwd, ctx)),
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Plugins<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(styled_components) = &mut self.styled_components {
+            styled_components.enter_program(program, ctx);
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(styled_components) = &mut self.styled_components {
+            styled_components.exit_program(program, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(styled_components) = &mut self.styled_components {
+            styled_components.enter_expression(expr, ctx);
+        }
+    }
+
+    fn enter_jsx_element(&mut self, element: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(styled_components) = &mut self.styled_components {
+            styled_components.enter_jsx_element(element, ctx);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+use super::StyledComponentsOptions;
+
+/// Options for plugins which are not part of a preset.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PluginsOptions {
+    /// [babel-plugin-styled-components](https://styled-components.com/docs/tooling#babel-plugin)
+    pub styled_components: Option<StyledComponentsOptions>,
+}
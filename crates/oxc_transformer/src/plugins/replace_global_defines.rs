@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, sync::Arc};
+use std::{cmp::Ordering, mem, sync::Arc};
 
 use lazy_static::lazy_static;
 use oxc_allocator::{Address, Allocator, GetAddress};
@@ -6,8 +6,8 @@ use oxc_ast::{ast::*, VisitMut};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_parser::Parser;
 use oxc_semantic::{IsGlobalReference, ScopeFlags, ScopeTree, SymbolTable};
-use oxc_span::{CompactStr, SourceType, SPAN};
-use oxc_syntax::identifier::is_identifier_name;
+use oxc_span::{CompactStr, GetSpan, SourceType, SPAN};
+use oxc_syntax::{identifier::is_identifier_name, operator::UnaryOperator};
 use oxc_traverse::{traverse_mut, Ancestor, Traverse, TraverseCtx};
 use rustc_hash::FxHashSet;
 
@@ -34,6 +34,8 @@ struct IdentifierDefine {
 struct ReplaceGlobalDefinesConfigImpl {
     identifier: IdentifierDefine,
     dot: Vec<DotDefine>,
+    /// `typeof x` and `typeof x.y` defines, with the parts after `typeof`
+    typeof_defines: Vec<DotDefine>,
     meta_property: Vec<MetaPropertyDefine>,
     /// extra field to avoid linear scan `meta_property` to check if it has `import.meta` every
     /// time
@@ -71,6 +73,8 @@ impl DotDefine {
 
 enum IdentifierType {
     Identifier,
+    // typeof a, typeof a.b
+    Typeof { parts: Vec<CompactStr> },
     DotDefines { parts: Vec<CompactStr> },
     // import.meta.a
     ImportMetaWithParts { parts: Vec<CompactStr>, postfix_wildcard: bool },
@@ -81,12 +85,13 @@ enum IdentifierType {
 impl ReplaceGlobalDefinesConfig {
     /// # Errors
     ///
-    /// * key is not an identifier
+    /// * key is not an identifier, a member expression of identifiers, or `typeof` of either
     /// * value has a syntax error
     pub fn new<S: AsRef<str>>(defines: &[(S, S)]) -> Result<Self, Vec<OxcDiagnostic>> {
         let allocator = Allocator::default();
         let mut identifier_defines = vec![];
         let mut dot_defines = vec![];
+        let mut typeof_defines = vec![];
         let mut meta_properties_defines = vec![];
         let mut import_meta = None;
         let mut has_this_expr_define = false;
@@ -104,6 +109,9 @@ impl ReplaceGlobalDefinesConfig {
                 IdentifierType::DotDefines { parts } => {
                     dot_defines.push(DotDefine::new(parts, CompactStr::new(value)));
                 }
+                IdentifierType::Typeof { parts } => {
+                    typeof_defines.push(DotDefine::new(parts, CompactStr::new(value)));
+                }
                 IdentifierType::ImportMetaWithParts { parts, postfix_wildcard } => {
                     meta_properties_defines.push(MetaPropertyDefine::new(
                         parts,
@@ -139,12 +147,25 @@ impl ReplaceGlobalDefinesConfig {
         Ok(Self(Arc::new(ReplaceGlobalDefinesConfigImpl {
             identifier: IdentifierDefine { identifier_defines, has_this_expr_define },
             dot: dot_defines,
+            typeof_defines,
             meta_property: meta_properties_defines,
             import_meta,
         })))
     }
 
     fn check_key(key: &str) -> Result<IdentifierType, Vec<OxcDiagnostic>> {
+        if let Some(argument) = key.strip_prefix("typeof ") {
+            return match Self::check_key(argument.trim_start())? {
+                IdentifierType::Identifier => {
+                    Ok(IdentifierType::Typeof { parts: vec![CompactStr::new(argument.trim_start())] })
+                }
+                IdentifierType::DotDefines { parts } => Ok(IdentifierType::Typeof { parts }),
+                _ => Err(vec![OxcDiagnostic::error(format!(
+                    "The define key `{key}` is not `typeof` of an identifier or a member expression."
+                ))]),
+            };
+        }
+
         let parts: Vec<&str> = key.split('.').collect();
 
         assert!(!parts.is_empty());
@@ -208,10 +229,21 @@ impl ReplaceGlobalDefinesConfig {
 pub struct ReplaceGlobalDefinesReturn {
     pub symbols: SymbolTable,
     pub scopes: ScopeTree,
+    /// Warnings for assignments to defined names which could not be replaced.
+    pub errors: Vec<OxcDiagnostic>,
 }
 
 /// Replace Global Defines.
 ///
+/// Follows the semantics of esbuild:
+///
+/// * Identifiers are only replaced when they are unbound globals, e.g. `process` is not replaced
+///   in `function f(process) { process }`.
+/// * `typeof x` can be defined separately from `x`, e.g. `typeof window` -> `"object"`.
+/// * Values can be any expression, including JSON objects and arrays.
+/// * Assignments to defined names are only replaced when the value is an identifier or a member
+///   expression, otherwise they are kept as is and a warning is reported.
+///
 /// References:
 ///
 /// * <https://esbuild.github.io/api/#define>
@@ -227,6 +259,7 @@ pub struct ReplaceGlobalDefines<'a> {
     /// When `exit` the node, reset the `Lock` to `None` to make sure not affect other
     /// transformation.
     ast_node_lock: Option<Address>,
+    errors: Vec<OxcDiagnostic>,
 }
 
 impl<'a> Traverse<'a> for ReplaceGlobalDefines<'a> {
@@ -234,8 +267,9 @@ impl<'a> Traverse<'a> for ReplaceGlobalDefines<'a> {
         if self.ast_node_lock.is_some() {
             return;
         }
-        let is_replaced =
-            self.replace_identifier_defines(expr, ctx) || self.replace_dot_defines(expr, ctx);
+        let is_replaced = self.replace_typeof_defines(expr, ctx)
+            || self.replace_identifier_defines(expr, ctx)
+            || self.replace_dot_defines(expr, ctx);
         if is_replaced {
             self.ast_node_lock = Some(expr.address());
         }
@@ -275,7 +309,7 @@ impl<'a> Traverse<'a> for ReplaceGlobalDefines<'a> {
 
 impl<'a> ReplaceGlobalDefines<'a> {
     pub fn new(allocator: &'a Allocator, config: ReplaceGlobalDefinesConfig) -> Self {
-        Self { allocator, config, ast_node_lock: None }
+        Self { allocator, config, ast_node_lock: None, errors: vec![] }
     }
 
     pub fn build(
@@ -285,7 +319,7 @@ impl<'a> ReplaceGlobalDefines<'a> {
         program: &mut Program<'a>,
     ) -> ReplaceGlobalDefinesReturn {
         let (symbols, scopes) = traverse_mut(self, self.allocator, program, symbols, scopes);
-        ReplaceGlobalDefinesReturn { symbols, scopes }
+        ReplaceGlobalDefinesReturn { symbols, scopes, errors: mem::take(&mut self.errors) }
     }
 
     // Construct a new expression because we don't have ast clone right now.
@@ -302,6 +336,45 @@ impl<'a> ReplaceGlobalDefines<'a> {
         expr
     }
 
+    /// `typeof window` -> `"object"`
+    fn replace_typeof_defines(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) -> bool {
+        let Expression::UnaryExpression(unary_expr) = expr else { return false };
+        if unary_expr.operator != UnaryOperator::Typeof {
+            return false;
+        }
+        for typeof_define in &self.config.0.typeof_defines {
+            let matched = match &unary_expr.argument {
+                Expression::Identifier(ident) => {
+                    typeof_define.parts.len() == 1
+                        && ident.name.as_str() == typeof_define.parts[0]
+                        && ident.is_global_reference(ctx.symbols())
+                }
+                Expression::StaticMemberExpression(member) => {
+                    typeof_define.parts.len() > 1
+                        && Self::is_dot_define(
+                            ctx,
+                            typeof_define,
+                            DotDefineMemberExpression::StaticMemberExpression(member),
+                        )
+                }
+                Expression::ComputedMemberExpression(member) => {
+                    typeof_define.parts.len() > 1
+                        && Self::is_dot_define(
+                            ctx,
+                            typeof_define,
+                            DotDefineMemberExpression::ComputedMemberExpression(member),
+                        )
+                }
+                _ => false,
+            };
+            if matched {
+                *expr = self.parse_value(&typeof_define.value);
+                return true;
+            }
+        }
+        false
+    }
+
     fn replace_identifier_defines(
         &self,
         expr: &mut Expression<'a>,
@@ -355,26 +428,35 @@ impl<'a> ReplaceGlobalDefines<'a> {
         node: &mut AssignmentExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> bool {
-        let new_left = node
-            .left
-            .as_simple_assignment_target_mut()
-            .and_then(|item| match item {
-                SimpleAssignmentTarget::ComputedMemberExpression(ref mut computed_member_expr) => {
-                    self.replace_dot_computed_member_expr(ctx, computed_member_expr)
-                }
-                SimpleAssignmentTarget::StaticMemberExpression(ref mut member) => {
-                    self.replace_dot_static_member_expr(ctx, member)
-                }
-                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
-                    self.replace_identifier_define_impl(ident, ctx)
-                }
-                _ => None,
-            })
-            .and_then(assignment_target_from_expr);
-        if let Some(new_left) = new_left {
+        let new_value = node.left.as_simple_assignment_target_mut().and_then(|item| match item {
+            SimpleAssignmentTarget::ComputedMemberExpression(ref mut computed_member_expr) => {
+                self.replace_dot_computed_member_expr(ctx, computed_member_expr)
+            }
+            SimpleAssignmentTarget::StaticMemberExpression(ref mut member) => {
+                self.replace_dot_static_member_expr(ctx, member)
+            }
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                self.replace_identifier_define_impl(ident, ctx)
+            }
+            _ => None,
+        });
+        let Some(new_value) = new_value else { return false };
+        if let Some(new_left) = assignment_target_from_expr(new_value) {
             node.left = new_left;
             return true;
         }
+        // Same as esbuild, keep assignments to defined values which are not assignable, e.g. `a = 0`
+        // with `a` defined as `null`.
+        let name = node.left.as_simple_assignment_target().and_then(define_key_name);
+        let message = match name {
+            Some(name) => format!("Suspicious assignment to defined constant `{name}`."),
+            None => "Suspicious assignment to defined constant.".to_string(),
+        };
+        self.errors.push(
+            OxcDiagnostic::warn(message)
+                .with_label(node.left.span())
+                .with_help("The defined value is not assignable, so the assignment is kept as is."),
+        );
         false
     }
 
@@ -700,6 +782,35 @@ const fn should_replace_this_expr(scope_flags: ScopeFlags) -> bool {
     !scope_flags.contains(ScopeFlags::Function) || scope_flags.contains(ScopeFlags::Arrow)
 }
 
+/// `a.b['c']` -> `a.b.c`
+fn define_key_name(target: &SimpleAssignmentTarget) -> Option<String> {
+    fn object_name(expr: &Expression) -> Option<String> {
+        match expr {
+            Expression::Identifier(ident) => Some(ident.name.to_string()),
+            Expression::ThisExpression(_) => Some("this".to_string()),
+            Expression::MetaProperty(meta) => {
+                Some(format!("{}.{}", meta.meta.name, meta.property.name))
+            }
+            expr => {
+                let member = expr.as_member_expression()?;
+                Some(format!(
+                    "{}.{}",
+                    object_name(member.object())?,
+                    member.static_property_name()?
+                ))
+            }
+        }
+    }
+
+    match target {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => Some(ident.name.to_string()),
+        target => {
+            let member = target.as_member_expression()?;
+            Some(format!("{}.{}", object_name(member.object())?, member.static_property_name()?))
+        }
+    }
+}
+
 fn assignment_target_from_expr(expr: Expression) -> Option<AssignmentTarget> {
     match expr {
         Expression::ComputedMemberExpression(expr) => {
@@ -0,0 +1,912 @@
+//! Styled Components
+//!
+//! Improves the debugging experience and server-side rendering support of
+//! [styled-components](https://styled-components.com).
+//!
+//! * `displayName`: Adds the name of the component to the class names of its elements.
+//! * `ssr`: Adds a deterministic `componentId`, so the class names generated on the server
+//!   match the ones generated on the client.
+//! * `minify`: Removes whitespace and comments from the CSS in templates.
+//! * `transpileTemplateLiterals`: Compiles tagged templates to calls, which are smaller.
+//! * `cssProp`: Compiles the `css` prop of JSX elements to styled components.
+//!
+//! The tagged templates of the `styled` default import and the `css`, `keyframes`,
+//! `createGlobalStyle` and `injectGlobal` helpers of `styled-components` are transformed.
+//! Libraries with the same API, e.g. `@emotion/styled`, can be added with `topLevelImportPaths`.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! // src/Button.js
+//! import styled from "styled-components";
+//! const Button = styled.button`
+//!   color: ${(props) => props.color};
+//!   padding: 4px;
+//! `;
+//! <div css="color: red;" />;
+//! ```
+//!
+//! Output:
+//! ```js
+//! import styled from "styled-components";
+//! const Button = styled.button.withConfig({
+//!   displayName: "Button",
+//!   componentId: "sc-1u82d5a-0",
+//! })(["color:", ";padding:4px;"], (props) => props.color);
+//! <_StyledDiv />;
+//! var _StyledDiv = styled("div").withConfig({
+//!   displayName: "Button___StyledDiv",
+//!   componentId: "sc-1u82d5a-1",
+//! })(["color:red;"]);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [babel-plugin-styled-components](https://github.com/styled-components/babel-plugin-styled-components).
+//!
+//! Babel hashes the package name and the path relative to the package root to create the
+//! `componentId`. We hash the path relative to [`TransformOptions::cwd`] instead, so no
+//! `package.json` has to be read.
+//!
+//! The `pure` option is not supported, because annotation comments can not be added to the AST.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/styled-components/babel-plugin-styled-components/tree/v2.1.4/src>
+//! * SWC plugin implementation: <https://github.com/swc-project/plugins/tree/main/packages/styled-components>
+//!
+//! [`TransformOptions::cwd`]: crate::TransformOptions::cwd
+
+use std::{iter, mem, path::Path};
+
+use cow_utils::CowUtils;
+use serde::Deserialize;
+
+use oxc_ast::{ast::*, NONE};
+use oxc_semantic::{ScopeFlags, SymbolFlags, SymbolId};
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct StyledComponentsOptions {
+    /// Add a `displayName` to styled components.
+    ///
+    /// Default: `true`
+    pub display_name: bool,
+
+    /// Add a deterministic `componentId` to styled components, for server-side rendering.
+    ///
+    /// Default: `true`
+    pub ssr: bool,
+
+    /// Prefix the `displayName` with the name of the file.
+    ///
+    /// Default: `true`
+    pub file_name: bool,
+
+    /// File names which are not descriptive, e.g. `index`. The directory name is used as the
+    /// prefix of the `displayName` instead.
+    ///
+    /// Default: `["index"]`
+    pub meaningless_file_names: Vec<String>,
+
+    /// Prefix the `componentId` with a namespace, e.g. to avoid class name conflicts between
+    /// libraries.
+    ///
+    /// Default: `""`
+    pub namespace: String,
+
+    /// Additional import paths to transform, e.g. `@emotion/styled` or a re-export of
+    /// `styled-components`.
+    ///
+    /// Default: `[]`
+    pub top_level_import_paths: Vec<String>,
+
+    /// Compile tagged templates to function calls.
+    ///
+    /// Default: `true`
+    pub transpile_template_literals: bool,
+
+    /// Remove whitespace and comments from the CSS in templates.
+    ///
+    /// Default: `true`
+    pub minify: bool,
+
+    /// Compile the `css` prop of JSX elements to styled components.
+    ///
+    /// Default: `true`
+    pub css_prop: bool,
+}
+
+impl Default for StyledComponentsOptions {
+    fn default() -> Self {
+        Self {
+            display_name: true,
+            ssr: true,
+            file_name: true,
+            meaningless_file_names: vec!["index".to_string()],
+            namespace: String::new(),
+            top_level_import_paths: vec![],
+            transpile_template_literals: true,
+            minify: true,
+            css_prop: true,
+        }
+    }
+}
+
+/// Helpers of `styled-components` which take a CSS template.
+const HELPERS: [&str; 4] = ["css", "keyframes", "createGlobalStyle", "injectGlobal"];
+
+pub struct StyledComponents<'a, 'ctx> {
+    options: StyledComponentsOptions,
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// Hash of the file path, used in the `componentId`.
+    file_hash: String,
+    /// File name used as the prefix of the `displayName`.
+    block_name: Option<String>,
+    /// Number of `componentId`s generated in this file.
+    component_count: usize,
+
+    /// Local names of the `styled` default import.
+    styled: Vec<SymbolId>,
+    /// Local names of `import * as styled`.
+    namespaces: Vec<SymbolId>,
+    /// Local names of [`HELPERS`].
+    helpers: Vec<SymbolId>,
+
+    /// `styled` binding used by the styled components created for `css` props.
+    css_prop_styled: Option<BoundIdentifier<'a>>,
+    /// Styled components created for `css` props, added to the end of the program.
+    css_prop_components: Vec<Statement<'a>>,
+}
+
+impl<'a, 'ctx> StyledComponents<'a, 'ctx> {
+    pub fn new(options: StyledComponentsOptions, cwd: &Path, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let source_path = &ctx.source_path;
+        let relative_path = source_path.strip_prefix(cwd).unwrap_or(source_path);
+        let file_hash = if relative_path.as_os_str().is_empty() {
+            hash(ctx.source_text)
+        } else {
+            hash(&relative_path.to_string_lossy().cow_replace('\\', "/"))
+        };
+        let block_name = source_path.file_stem().map(|stem| {
+            let stem = stem.to_string_lossy();
+            if options.meaningless_file_names.iter().any(|name| *name == stem) {
+                if let Some(dir) = source_path.parent().and_then(Path::file_name) {
+                    return dir.to_string_lossy().into_owned();
+                }
+            }
+            stem.into_owned()
+        });
+        Self {
+            options,
+            ctx,
+            file_hash,
+            block_name,
+            component_count: 0,
+            styled: vec![],
+            namespaces: vec![],
+            helpers: vec![],
+            css_prop_styled: None,
+            css_prop_components: vec![],
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for StyledComponents<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.collect_imports(program);
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        program.body.extend(self.css_prop_components.drain(..));
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::TaggedTemplateExpression(tagged) = expr else {
+            return;
+        };
+        let is_styled = self.is_styled(&tagged.tag, ctx);
+        if !is_styled && !self.is_helper(&tagged.tag, ctx) {
+            return;
+        }
+        let name = if is_styled { Self::get_name(ctx) } else { None };
+        self.transform_tagged_template(expr, is_styled, name.as_deref(), ctx);
+    }
+
+    fn enter_jsx_element(&mut self, element: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.css_prop {
+            self.transform_css_prop(element, ctx);
+        }
+    }
+}
+
+impl<'a, 'ctx> StyledComponents<'a, 'ctx> {
+    fn collect_imports(&mut self, program: &Program<'a>) {
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else {
+                continue;
+            };
+            if decl.import_kind.is_type() || !self.is_styled_import_source(&decl.source.value) {
+                continue;
+            }
+            for specifier in decl.specifiers.iter().flatten() {
+                match specifier {
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                        self.styled.push(specifier.local.symbol_id());
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                        self.namespaces.push(specifier.local.symbol_id());
+                    }
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        if specifier.import_kind.is_type() {
+                            continue;
+                        }
+                        let imported = specifier.imported.name();
+                        if imported == "default" {
+                            self.styled.push(specifier.local.symbol_id());
+                        } else if HELPERS.contains(&imported.as_str()) {
+                            self.helpers.push(specifier.local.symbol_id());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_styled_import_source(&self, source: &str) -> bool {
+        matches!(
+            source,
+            "styled-components"
+                | "styled-components/native"
+                | "styled-components/primitives"
+                | "styled-components/no-tags"
+                | "styled-components/macro"
+        ) || self.options.top_level_import_paths.iter().any(|path| path == source)
+    }
+
+    fn resolve(ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> Option<SymbolId> {
+        ctx.symbols().get_reference(ident.reference_id()).symbol_id()
+    }
+
+    /// `styled` or `ns.default`
+    fn is_styled_import(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => {
+                Self::resolve(ident, ctx).is_some_and(|symbol_id| self.styled.contains(&symbol_id))
+            }
+            Expression::StaticMemberExpression(member) => {
+                member.property.name == "default" && self.is_namespace(&member.object, ctx)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_namespace(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        matches!(expr, Expression::Identifier(ident)
+            if Self::resolve(ident, ctx).is_some_and(|symbol_id| self.namespaces.contains(&symbol_id)))
+    }
+
+    /// `styled.div`, `styled(Component)`, `styled.div.attrs({})`, `styled(Component).withConfig({})`
+    fn is_styled(&self, tag: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match tag {
+            Expression::StaticMemberExpression(member) => {
+                self.is_styled_import(&member.object, ctx)
+            }
+            Expression::CallExpression(call) => match &call.callee {
+                Expression::StaticMemberExpression(member)
+                    if matches!(member.property.name.as_str(), "attrs" | "withConfig") =>
+                {
+                    self.is_styled(&member.object, ctx)
+                }
+                callee => self.is_styled_import(callee, ctx),
+            },
+            _ => false,
+        }
+    }
+
+    /// `css`, `keyframes`, `createGlobalStyle`, `injectGlobal`
+    fn is_helper(&self, tag: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match tag {
+            Expression::Identifier(ident) => {
+                Self::resolve(ident, ctx).is_some_and(|symbol_id| self.helpers.contains(&symbol_id))
+            }
+            Expression::StaticMemberExpression(member) => {
+                HELPERS.contains(&member.property.name.as_str())
+                    && self.is_namespace(&member.object, ctx)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether `.withConfig()` was already called on the styled component.
+    fn has_config(tag: &Expression<'a>) -> bool {
+        match tag {
+            Expression::CallExpression(call) => match &call.callee {
+                Expression::StaticMemberExpression(member) => {
+                    member.property.name == "withConfig" || Self::has_config(&member.object)
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Get the name of the styled component from the variable, property or class field it is
+    /// assigned to.
+    fn get_name(ctx: &TraverseCtx<'a>) -> Option<String> {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                // `Button = styled.button```, `foo.Button = styled.button```
+                Ancestor::AssignmentExpressionRight(assign_expr) => {
+                    return match assign_expr.left() {
+                        AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                            Some(ident.name.to_string())
+                        }
+                        AssignmentTarget::StaticMemberExpression(expr) => {
+                            Some(expr.property.name.to_string())
+                        }
+                        _ => None,
+                    };
+                }
+                // `const Button = styled.button```
+                Ancestor::VariableDeclaratorInit(declarator) => {
+                    return declarator.id().get_identifier().map(|name| name.to_string());
+                }
+                // `{ Button: styled.button`` }`
+                Ancestor::ObjectPropertyValue(prop) => {
+                    return prop.key().static_name().map(|name| name.to_string());
+                }
+                // `class Foo { static Button = styled.button`` }`
+                Ancestor::PropertyDefinitionValue(prop) => {
+                    return prop.key().static_name().map(|name| name.to_string());
+                }
+                // Stop crawling up when hit a statement
+                _ if ancestor.is_via_statement() => return None,
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn display_name(&self, name: Option<&str>) -> Option<String> {
+        let block_name = self.block_name.as_deref().filter(|_| self.options.file_name);
+        match (block_name, name) {
+            (Some(block_name), Some(name)) if block_name == name => Some(name.to_string()),
+            (Some(block_name), Some(name)) => {
+                Some(format!("{}__{name}", prefix_leading_digit(block_name)))
+            }
+            (Some(block_name), None) => Some(prefix_leading_digit(block_name)),
+            (None, name) => name.map(ToString::to_string),
+        }
+    }
+
+    fn component_id(&mut self) -> String {
+        let namespace = if self.options.namespace.is_empty() {
+            String::new()
+        } else {
+            format!("{}__", self.options.namespace)
+        };
+        let id = format!("{namespace}sc-{}-{}", self.file_hash, self.component_count);
+        self.component_count += 1;
+        id
+    }
+
+    /// Add the config, minify and transpile the tagged template of a styled component or helper.
+    fn transform_tagged_template(
+        &mut self,
+        expr: &mut Expression<'a>,
+        is_styled: bool,
+        name: Option<&str>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::TaggedTemplateExpression(tagged) = expr else {
+            return;
+        };
+
+        if is_styled && !Self::has_config(&tagged.tag) {
+            self.add_config(tagged, name, ctx);
+        }
+
+        if self.options.minify {
+            minify_template(&mut tagged.quasi, ctx);
+        }
+
+        if self.options.transpile_template_literals
+            && tagged.quasi.quasis.iter().all(|quasi| quasi.value.cooked.is_some())
+        {
+            let Expression::TaggedTemplateExpression(tagged) = ctx.ast.move_expression(expr) else {
+                unreachable!()
+            };
+            let tagged = tagged.unbox();
+            *expr = transpile_template(tagged.span, tagged.tag, tagged.quasi, ctx);
+        }
+    }
+
+    /// `styled.div``` -> `styled.div.withConfig({ displayName: "Foo", componentId: "sc-hash-0" })```
+    fn add_config(
+        &mut self,
+        tagged: &mut TaggedTemplateExpression<'a>,
+        name: Option<&str>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut properties = ctx.ast.vec();
+        let mut add_property = |key: &'static str, value: &str| {
+            properties.push(ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                ctx.ast.property_key_identifier_name(SPAN, key),
+                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(value), None),
+                false,
+                false,
+                false,
+            ));
+        };
+        if self.options.display_name {
+            if let Some(display_name) = self.display_name(name) {
+                add_property("displayName", &display_name);
+            }
+        }
+        if self.options.ssr {
+            add_property("componentId", &self.component_id());
+        }
+        if properties.is_empty() {
+            return;
+        }
+
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            ctx.ast.move_expression(&mut tagged.tag),
+            ctx.ast.identifier_name(SPAN, "withConfig"),
+            false,
+        ));
+        let config = ctx.ast.expression_object(SPAN, properties, None);
+        tagged.tag = ctx.ast.expression_call(
+            SPAN,
+            callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(config)),
+            false,
+        );
+    }
+
+    /// `<div css="color: red;" />` -> `<_StyledDiv />` + `var _StyledDiv = _styled("div")`color: red;`;`
+    fn transform_css_prop(&mut self, element: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(index) = element.opening_element.attributes.iter().position(|attr| {
+            matches!(attr, JSXAttributeItem::Attribute(attr)
+                if attr.is_identifier("css") && attr.value.is_some())
+        }) else {
+            return;
+        };
+
+        // The styled component is declared at the top level, so the element must be accessible there.
+        let component_name = match &element.opening_element.name {
+            JSXElementName::Identifier(ident) => ident.name.clone(),
+            JSXElementName::IdentifierReference(ident) => {
+                let root_scope_id = ctx.scopes().root_scope_id();
+                if Self::resolve(ident, ctx)
+                    .is_some_and(|symbol_id| ctx.symbols().get_scope_id(symbol_id) != root_scope_id)
+                {
+                    return;
+                }
+                ident.name.clone()
+            }
+            _ => return,
+        };
+
+        let JSXAttributeItem::Attribute(attr) = element.opening_element.attributes.remove(index)
+        else {
+            unreachable!()
+        };
+        let (mut quasis, mut expressions) = match attr.unbox().value {
+            Some(JSXAttributeValue::StringLiteral(lit)) => {
+                (ctx.ast.vec1(template_element(&lit.value, true, ctx)), ctx.ast.vec())
+            }
+            Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                match container.unbox().expression {
+                    JSXExpression::TemplateLiteral(template) => {
+                        let template = template.unbox();
+                        (template.quasis, template.expressions)
+                    }
+                    JSXExpression::TaggedTemplateExpression(tagged)
+                        if self.is_helper(&tagged.tag, ctx) =>
+                    {
+                        let quasi = tagged.unbox().quasi;
+                        (quasi.quasis, quasi.expressions)
+                    }
+                    JSXExpression::EmptyExpression(_) => return,
+                    expression => {
+                        // `css={{ color: "red" }}` -> `${(p) => p.$_css}`
+                        let quasis = ctx.ast.vec_from_iter([
+                            template_element("", false, ctx),
+                            template_element("", true, ctx),
+                        ]);
+                        (quasis, ctx.ast.vec1(expression.into_expression()))
+                    }
+                }
+            }
+            _ => return,
+        };
+
+        // Interpolations which are not accessible at the top level are passed as props.
+        let mut prop_count = 0;
+        for expr in expressions.iter_mut() {
+            if Self::is_top_level_accessible(expr, ctx) {
+                continue;
+            }
+            prop_count += 1;
+            let prop_name =
+                if prop_count == 1 { "$_css".to_string() } else { format!("$_css{prop_count}") };
+            let prop_name = ctx.ast.atom(&prop_name);
+            let value = ctx.ast.move_expression(expr);
+            *expr = Self::create_prop_getter(prop_name.clone(), ctx);
+            element.opening_element.attributes.push(ctx.ast.jsx_attribute_item_jsx_attribute(
+                SPAN,
+                ctx.ast.jsx_attribute_name_jsx_identifier(SPAN, prop_name),
+                Some(ctx.ast.jsx_attribute_value_jsx_expression_container(
+                    SPAN,
+                    JSXExpression::from(value),
+                )),
+            ));
+        }
+        if let Some(last) = quasis.last_mut() {
+            last.tail = true;
+        }
+
+        // `<div>` -> `<_StyledDiv>`
+        let binding = ctx.generate_uid_in_root_scope(
+            &format!("Styled{}", capitalize(&component_name)),
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let name =
+            JSXElementName::IdentifierReference(ctx.ast.alloc(binding.create_read_reference(ctx)));
+        let target = match mem::replace(&mut element.opening_element.name, name) {
+            JSXElementName::IdentifierReference(ident) => Expression::Identifier(ident),
+            _ => ctx.ast.expression_string_literal(SPAN, component_name, None),
+        };
+        if let Some(closing_element) = &mut element.closing_element {
+            if let JSXElementName::IdentifierReference(ident) = &closing_element.name {
+                ctx.delete_reference_for_identifier(ident);
+            }
+            closing_element.name = JSXElementName::IdentifierReference(
+                ctx.ast.alloc(binding.create_read_reference(ctx)),
+            );
+        }
+
+        // `var _StyledDiv = _styled("div")`...`;`
+        let styled = self.css_prop_styled(ctx).create_read_expression(ctx);
+        let tag = ctx.ast.expression_call(
+            SPAN,
+            styled,
+            NONE,
+            ctx.ast.vec1(Argument::from(target)),
+            false,
+        );
+        let quasi = ctx.ast.template_literal(SPAN, quasis, expressions);
+        let mut init = ctx.ast.expression_tagged_template(SPAN, tag, quasi, NONE);
+        self.transform_tagged_template(&mut init, true, Some(&binding.name), ctx);
+
+        let kind = VariableDeclarationKind::Var;
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            binding.create_binding_pattern(ctx),
+            Some(init),
+            false,
+        );
+        self.css_prop_components.push(Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        )));
+    }
+
+    /// The `styled` default import, added if it is not imported yet.
+    fn css_prop_styled(&mut self, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        if let Some(binding) = &self.css_prop_styled {
+            return binding.clone();
+        }
+        let binding = if let Some(&symbol_id) = self.styled.first() {
+            BoundIdentifier::new(ctx.ast.atom(ctx.symbols().get_name(symbol_id)), symbol_id)
+        } else {
+            let binding = ctx.generate_uid_in_root_scope("styled", SymbolFlags::Import);
+            self.ctx.module_imports.add_default_import(
+                Atom::from("styled-components"),
+                binding.clone(),
+                false,
+            );
+            binding
+        };
+        self.css_prop_styled = Some(binding.clone());
+        binding
+    }
+
+    /// Whether `expr` can be moved to the top level of the program, i.e. it is a literal, or only
+    /// refers to top level or global variables.
+    fn is_top_level_accessible(expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::StringLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_) => true,
+            Expression::Identifier(ident) => Self::resolve(ident, ctx).map_or(true, |symbol_id| {
+                ctx.symbols().get_scope_id(symbol_id) == ctx.scopes().root_scope_id()
+            }),
+            Expression::StaticMemberExpression(member) => {
+                Self::is_top_level_accessible(&member.object, ctx)
+            }
+            _ => false,
+        }
+    }
+
+    /// `(p) => p.$_css`
+    fn create_prop_getter(prop_name: Atom<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let scope_id = ctx.create_child_scope(
+            ctx.scopes().root_scope_id(),
+            ScopeFlags::Arrow | ScopeFlags::Function,
+        );
+        let param =
+            ctx.generate_binding(Atom::from("p"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ctx.ast.vec1(ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                param.create_binding_pattern(ctx),
+                None,
+                false,
+                false,
+            )),
+            NONE,
+        );
+        let body = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            param.create_read_expression(ctx),
+            ctx.ast.identifier_name(SPAN, prop_name),
+            false,
+        ));
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body)),
+        );
+        Expression::ArrowFunctionExpression(ctx.ast.alloc_arrow_function_expression_with_scope_id(
+            SPAN, true, false, NONE, params, NONE, body, scope_id,
+        ))
+    }
+}
+
+/// A template element with the given cooked value.
+fn template_element<'a>(value: &str, tail: bool, ctx: &TraverseCtx<'a>) -> TemplateElement<'a> {
+    let raw = value.cow_replace('\\', "\\\\");
+    let raw = raw.cow_replace('`', "\\`");
+    let raw = raw.cow_replace("${", "\\${");
+    ctx.ast.template_element(
+        SPAN,
+        tail,
+        TemplateElementValue { raw: ctx.ast.atom(&raw), cooked: Some(ctx.ast.atom(value)) },
+    )
+}
+
+/// `` tag`a${b}c` `` -> `tag(["a", "c"], b)`
+fn transpile_template<'a>(
+    span: Span,
+    tag: Expression<'a>,
+    quasi: TemplateLiteral<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let strings = ctx.ast.vec_from_iter(quasi.quasis.iter().map(|quasi| {
+        let cooked = quasi.value.cooked.clone().unwrap_or(quasi.value.raw.clone());
+        ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, cooked, None))
+    }));
+    let arguments = ctx.ast.vec_from_iter(
+        iter::once(ctx.ast.expression_array(SPAN, strings, None))
+            .chain(quasi.expressions)
+            .map(Argument::from),
+    );
+    ctx.ast.expression_call(span, tag, NONE, arguments, false)
+}
+
+/// Minify the CSS in the quasis of `template`.
+fn minify_template<'a>(template: &mut TemplateLiteral<'a>, ctx: &TraverseCtx<'a>) {
+    let Some(raws) = minify_quasis(template.quasis.iter().map(|quasi| quasi.value.raw.as_str()))
+    else {
+        return;
+    };
+    let cooked = if template
+        .quasis
+        .iter()
+        .all(|quasi| quasi.value.cooked.as_ref() == Some(&quasi.value.raw))
+    {
+        Some(raws.clone())
+    } else if template.quasis.iter().all(|quasi| quasi.value.cooked.is_some()) {
+        minify_quasis(
+            template.quasis.iter().map(|quasi| quasi.value.cooked.as_ref().unwrap().as_str()),
+        )
+    } else {
+        None
+    };
+    let Some(cooked) = cooked else {
+        return;
+    };
+    for ((quasi, raw), cooked) in template.quasis.iter_mut().zip(raws).zip(cooked) {
+        quasi.value.raw = ctx.ast.atom(&raw);
+        quasi.value.cooked = Some(ctx.ast.atom(&cooked));
+    }
+}
+
+/// Minify CSS split into quasis, returning `None` if an interpolation is inside a comment.
+fn minify_quasis<'s, I: Iterator<Item = &'s str>>(quasis: I) -> Option<Vec<String>> {
+    let mut css = String::new();
+    let mut count = 0;
+    for (i, quasi) in quasis.enumerate() {
+        if i > 0 {
+            css.push_str(&placeholder(i - 1));
+        }
+        css.push_str(quasi);
+        count = i + 1;
+    }
+
+    let mut minified = minify_css(&css);
+    let mut quasis = Vec::with_capacity(count);
+    for i in 0..count - 1 {
+        let placeholder = placeholder(i);
+        let index = minified.find(&placeholder)?;
+        quasis.push(minified[..index].to_string());
+        minified = minified[index + placeholder.len()..].to_string();
+    }
+    quasis.push(minified);
+    Some(quasis)
+}
+
+fn placeholder(index: usize) -> String {
+    format!("__PLACEHOLDER_{index}__")
+}
+
+/// Remove comments and collapse whitespace, keeping strings intact.
+fn minify_css(css: &str) -> String {
+    /// Whitespace around these characters can be removed.
+    fn is_symbol(c: char) -> bool {
+        matches!(c, ';' | ':' | '{' | '}' | ',')
+    }
+
+    let mut out = String::with_capacity(css.len());
+    let mut chars = css.chars().peekable();
+    let mut pending_space = false;
+    let mut paren_depth = 0usize;
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let keep = chars.peek() == Some(&'!');
+                let mut comment = String::from("/*");
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    comment.push(c);
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+                if keep {
+                    out.push_str(&comment);
+                } else {
+                    pending_space = true;
+                }
+            }
+            // Line comments, but not `//` in urls, e.g. `url(http://example.com)`
+            '/' if chars.peek() == Some(&'/') && paren_depth == 0 && !out.ends_with(':') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+                pending_space = true;
+            }
+            c if c.is_whitespace() => pending_space = true,
+            '"' | '\'' => {
+                push_space(&mut out, &mut pending_space, c, is_symbol);
+                out.push(c);
+                let mut escaped = false;
+                for next in chars.by_ref() {
+                    out.push(next);
+                    if escaped {
+                        escaped = false;
+                    } else if next == '\\' {
+                        escaped = true;
+                    } else if next == c {
+                        break;
+                    }
+                }
+            }
+            c => {
+                push_space(&mut out, &mut pending_space, c, is_symbol);
+                match c {
+                    '(' => paren_depth += 1,
+                    ')' => paren_depth = paren_depth.saturating_sub(1),
+                    _ => {}
+                }
+                out.push(c);
+            }
+        }
+    }
+    out
+}
+
+fn push_space(out: &mut String, pending_space: &mut bool, next: char, is_symbol: fn(char) -> bool) {
+    if *pending_space && out.chars().last().is_some_and(|last| !is_symbol(last)) && !is_symbol(next)
+    {
+        out.push(' ');
+    }
+    *pending_space = false;
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars.next().map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+/// CSS class names can not start with a digit.
+fn prefix_leading_digit(s: &str) -> String {
+    if s.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("sc-{s}")
+    } else {
+        s.to_string()
+    }
+}
+
+/// MurmurHash2 of the UTF-16 code units of `text`, in base 36.
+fn hash(text: &str) -> String {
+    const M: u32 = 0x5bd1_e995;
+    let units = text.encode_utf16().map(u32::from).collect::<Vec<_>>();
+    #[expect(clippy::cast_possible_truncation)]
+    let mut hash = units.len() as u32;
+    let mut chunks = units.chunks_exact(4);
+    for chunk in chunks.by_ref() {
+        let mut value = chunk[0] | (chunk[1] << 8) | (chunk[2] << 16) | (chunk[3] << 24);
+        value = value.wrapping_mul(M);
+        value ^= value >> 24;
+        value = value.wrapping_mul(M);
+        hash = hash.wrapping_mul(M) ^ value;
+    }
+    match *chunks.remainder() {
+        [first, second, third] => {
+            hash ^= first | (second << 8) | (third << 16);
+            hash = hash.wrapping_mul(M);
+        }
+        [first, second] => {
+            hash ^= first | (second << 8);
+            hash = hash.wrapping_mul(M);
+        }
+        [first] => {
+            hash ^= first;
+            hash = hash.wrapping_mul(M);
+        }
+        _ => {}
+    }
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(M);
+    hash ^= hash >> 15;
+    to_base36(hash)
+}
+
+fn to_base36(mut n: u32) -> String {
+    const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+    let mut out = vec![];
+    loop {
+        out.push(DIGITS[(n % 36) as usize]);
+        n /= 36;
+        if n == 0 {
+            break;
+        }
+    }
+    out.reverse();
+    String::from_utf8(out).unwrap()
+}
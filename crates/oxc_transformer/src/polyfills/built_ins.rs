@@ -0,0 +1,69 @@
+//! Built-ins which can be polyfilled, and the core-js modules which polyfill them.
+//!
+//! Based on `babel-plugin-polyfill-corejs3`:
+//! <https://github.com/babel/babel-polyfills/blob/main/packages/babel-plugin-polyfill-corejs3/src/built-in-definitions.ts>
+
+/// Global built-ins, e.g. `structuredClone`.
+pub fn global(name: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match name {
+        "AggregateError" => &["es.aggregate-error"],
+        "globalThis" => &["es.global-this"],
+        "Map" => &["es.map"],
+        "Promise" => &["es.promise"],
+        "queueMicrotask" => &["web.queue-microtask"],
+        "Set" => &["es.set"],
+        "structuredClone" => &["web.structured-clone", "es.map", "es.set"],
+        "Symbol" => &["es.symbol"],
+        "WeakMap" => &["es.weak-map"],
+        "WeakSet" => &["es.weak-set"],
+        _ => return None,
+    };
+    Some(modules)
+}
+
+/// Static properties of global built-ins, e.g. `Promise.withResolvers`.
+pub fn static_property(object: &str, property: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match (object, property) {
+        ("Map", "groupBy") => &["es.map.group-by"],
+        ("Object", "entries") => &["es.object.entries"],
+        ("Object", "fromEntries") => &["es.object.from-entries"],
+        ("Object", "groupBy") => &["es.object.group-by"],
+        ("Object", "hasOwn") => &["es.object.has-own"],
+        ("Object", "values") => &["es.object.values"],
+        ("Promise", "allSettled") => &["es.promise.all-settled"],
+        ("Promise", "any") => &["es.promise.any", "es.aggregate-error"],
+        ("Promise", "withResolvers") => &["es.promise.with-resolvers"],
+        _ => return None,
+    };
+    Some(modules)
+}
+
+/// Instance properties, e.g. `[].at`.
+///
+/// The type of the object is unknown, so all modules which define the property are included,
+/// e.g. both `Array.prototype.at` and `String.prototype.at` for `x.at`.
+pub fn instance_property(property: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match property {
+        "at" => &["es.array.at", "es.string.at-alternative"],
+        "finally" => &["es.promise.finally"],
+        "findLast" => &["es.array.find-last"],
+        "findLastIndex" => &["es.array.find-last-index"],
+        "flat" => &["es.array.flat"],
+        "flatMap" => &["es.array.flat-map"],
+        "includes" => &["es.array.includes", "es.string.includes"],
+        "isWellFormed" => &["es.string.is-well-formed"],
+        "matchAll" => &["es.string.match-all"],
+        "padEnd" => &["es.string.pad-end"],
+        "padStart" => &["es.string.pad-start"],
+        "replaceAll" => &["es.string.replace-all"],
+        "toReversed" => &["es.array.to-reversed"],
+        "toSorted" => &["es.array.to-sorted"],
+        "toSpliced" => &["es.array.to-spliced"],
+        "toWellFormed" => &["es.string.to-well-formed"],
+        "trimEnd" => &["es.string.trim-end"],
+        "trimStart" => &["es.string.trim-start"],
+        "with" => &["es.array.with"],
+        _ => return None,
+    };
+    Some(modules)
+}
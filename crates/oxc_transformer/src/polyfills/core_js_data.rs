@@ -0,0 +1,693 @@
+// Auto generated by `tasks/compat_data/src/lib.rs`.
+#![allow(clippy::enum_glob_use)]
+use crate::{Engine, EngineTargets};
+use browserslist::Version;
+use rustc_hash::FxHashMap;
+use std::sync::OnceLock;
+/// Minimum engine versions which support each core-js module without a polyfill.
+pub fn core_js_modules() -> &'static FxHashMap<&'static str, EngineTargets> {
+    use Engine::*;
+    static MODULES: OnceLock<FxHashMap<&'static str, EngineTargets>> = OnceLock::new();
+    MODULES.get_or_init(|| {
+        FxHashMap::from_iter([
+            (
+                "es.aggregate-error",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(85u32, 0u32, 0u32)),
+                    (Electron, Version(10u32, 0u32, 0u32)),
+                    (Samsung, Version(14u32, 0u32, 0u32)),
+                    (Node, Version(15u32, 0u32, 0u32)),
+                    (Firefox, Version(79u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 2u32, 0u32)),
+                    (Android, Version(85u32, 0u32, 0u32)),
+                    (Safari, Version(14u32, 0u32, 0u32)),
+                    (Opera, Version(71u32, 0u32, 0u32)),
+                    (Ios, Version(14u32, 0u32, 0u32)),
+                    (Edge, Version(85u32, 0u32, 0u32)),
+                    (Es, Version(2021u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.at",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(92u32, 0u32, 0u32)),
+                    (Electron, Version(14u32, 0u32, 0u32)),
+                    (Samsung, Version(16u32, 0u32, 0u32)),
+                    (Node, Version(16u32, 6u32, 0u32)),
+                    (Firefox, Version(90u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 12u32, 0u32)),
+                    (Android, Version(92u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Opera, Version(78u32, 0u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(92u32, 0u32, 0u32)),
+                    (Es, Version(2022u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.find-last",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(97u32, 0u32, 0u32)),
+                    (Electron, Version(17u32, 0u32, 0u32)),
+                    (Samsung, Version(18u32, 0u32, 0u32)),
+                    (Node, Version(18u32, 0u32, 0u32)),
+                    (Firefox, Version(104u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 16u32, 0u32)),
+                    (Android, Version(97u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Opera, Version(83u32, 0u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(97u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.find-last-index",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(97u32, 0u32, 0u32)),
+                    (Electron, Version(17u32, 0u32, 0u32)),
+                    (Samsung, Version(18u32, 0u32, 0u32)),
+                    (Node, Version(18u32, 0u32, 0u32)),
+                    (Firefox, Version(104u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 16u32, 0u32)),
+                    (Android, Version(97u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Opera, Version(83u32, 0u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(97u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.flat",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(69u32, 0u32, 0u32)),
+                    (Electron, Version(4u32, 0u32, 0u32)),
+                    (Samsung, Version(10u32, 1u32, 0u32)),
+                    (Node, Version(11u32, 0u32, 0u32)),
+                    (Firefox, Version(62u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(69u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 0u32, 0u32)),
+                    (Opera, Version(55u32, 0u32, 0u32)),
+                    (Ios, Version(12u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.flat-map",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(69u32, 0u32, 0u32)),
+                    (Electron, Version(4u32, 0u32, 0u32)),
+                    (Samsung, Version(10u32, 1u32, 0u32)),
+                    (Node, Version(11u32, 0u32, 0u32)),
+                    (Firefox, Version(62u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(69u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 0u32, 0u32)),
+                    (Opera, Version(55u32, 0u32, 0u32)),
+                    (Ios, Version(12u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.includes",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(53u32, 0u32, 0u32)),
+                    (Electron, Version(2u32, 0u32, 0u32)),
+                    (Samsung, Version(6u32, 2u32, 0u32)),
+                    (Node, Version(7u32, 0u32, 0u32)),
+                    (Firefox, Version(102u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(53u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Opera, Version(39u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2016u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.to-reversed",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(110u32, 0u32, 0u32)),
+                    (Electron, Version(23u32, 0u32, 0u32)),
+                    (Samsung, Version(21u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(115u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 31u32, 0u32)),
+                    (Android, Version(110u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 0u32, 0u32)),
+                    (Opera, Version(96u32, 0u32, 0u32)),
+                    (Ios, Version(16u32, 0u32, 0u32)),
+                    (Edge, Version(110u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.to-sorted",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(110u32, 0u32, 0u32)),
+                    (Electron, Version(23u32, 0u32, 0u32)),
+                    (Samsung, Version(21u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(115u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 31u32, 0u32)),
+                    (Android, Version(110u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 0u32, 0u32)),
+                    (Opera, Version(96u32, 0u32, 0u32)),
+                    (Ios, Version(16u32, 0u32, 0u32)),
+                    (Edge, Version(110u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.to-spliced",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(110u32, 0u32, 0u32)),
+                    (Electron, Version(23u32, 0u32, 0u32)),
+                    (Samsung, Version(21u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(115u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 31u32, 0u32)),
+                    (Android, Version(110u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 0u32, 0u32)),
+                    (Opera, Version(96u32, 0u32, 0u32)),
+                    (Ios, Version(16u32, 0u32, 0u32)),
+                    (Edge, Version(110u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.with",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(110u32, 0u32, 0u32)),
+                    (Electron, Version(23u32, 0u32, 0u32)),
+                    (Samsung, Version(21u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(115u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 31u32, 0u32)),
+                    (Android, Version(110u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 0u32, 0u32)),
+                    (Opera, Version(96u32, 0u32, 0u32)),
+                    (Ios, Version(16u32, 0u32, 0u32)),
+                    (Edge, Version(110u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.global-this",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(71u32, 0u32, 0u32)),
+                    (Electron, Version(5u32, 0u32, 0u32)),
+                    (Samsung, Version(10u32, 1u32, 0u32)),
+                    (Node, Version(12u32, 0u32, 0u32)),
+                    (Firefox, Version(65u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(71u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 1u32, 0u32)),
+                    (Opera, Version(57u32, 0u32, 0u32)),
+                    (Ios, Version(12u32, 1u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2020u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.map",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Electron, Version(2u32, 0u32, 0u32)),
+                    (Samsung, Version(5u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 5u32, 0u32)),
+                    (Firefox, Version(53u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(51u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Opera, Version(37u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.map.group-by",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(117u32, 0u32, 0u32)),
+                    (Electron, Version(27u32, 0u32, 0u32)),
+                    (Samsung, Version(24u32, 0u32, 0u32)),
+                    (Node, Version(21u32, 0u32, 0u32)),
+                    (Firefox, Version(119u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 37u32, 0u32)),
+                    (Android, Version(117u32, 0u32, 0u32)),
+                    (Safari, Version(17u32, 4u32, 0u32)),
+                    (Opera, Version(103u32, 0u32, 0u32)),
+                    (Ios, Version(17u32, 4u32, 0u32)),
+                    (Edge, Version(117u32, 0u32, 0u32)),
+                    (Es, Version(2024u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.entries",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(54u32, 0u32, 0u32)),
+                    (Electron, Version(2u32, 0u32, 0u32)),
+                    (Samsung, Version(6u32, 2u32, 0u32)),
+                    (Node, Version(7u32, 0u32, 0u32)),
+                    (Firefox, Version(47u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(54u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 1u32, 0u32)),
+                    (Opera, Version(40u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 1u32, 0u32)),
+                    (Edge, Version(14u32, 0u32, 0u32)),
+                    (Es, Version(2017u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.from-entries",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(73u32, 0u32, 0u32)),
+                    (Electron, Version(5u32, 0u32, 0u32)),
+                    (Samsung, Version(11u32, 1u32, 0u32)),
+                    (Node, Version(12u32, 4u32, 0u32)),
+                    (Firefox, Version(63u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(73u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 1u32, 0u32)),
+                    (Opera, Version(59u32, 0u32, 0u32)),
+                    (Ios, Version(12u32, 1u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.group-by",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(117u32, 0u32, 0u32)),
+                    (Electron, Version(27u32, 0u32, 0u32)),
+                    (Samsung, Version(24u32, 0u32, 0u32)),
+                    (Node, Version(21u32, 0u32, 0u32)),
+                    (Firefox, Version(119u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 37u32, 0u32)),
+                    (Android, Version(117u32, 0u32, 0u32)),
+                    (Safari, Version(17u32, 4u32, 0u32)),
+                    (Opera, Version(103u32, 0u32, 0u32)),
+                    (Ios, Version(17u32, 4u32, 0u32)),
+                    (Edge, Version(117u32, 0u32, 0u32)),
+                    (Es, Version(2024u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.has-own",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(93u32, 0u32, 0u32)),
+                    (Electron, Version(14u32, 0u32, 0u32)),
+                    (Samsung, Version(17u32, 0u32, 0u32)),
+                    (Node, Version(16u32, 9u32, 0u32)),
+                    (Firefox, Version(92u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 13u32, 0u32)),
+                    (Android, Version(93u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Opera, Version(79u32, 0u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(93u32, 0u32, 0u32)),
+                    (Es, Version(2022u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.values",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(54u32, 0u32, 0u32)),
+                    (Electron, Version(2u32, 0u32, 0u32)),
+                    (Samsung, Version(6u32, 2u32, 0u32)),
+                    (Node, Version(7u32, 0u32, 0u32)),
+                    (Firefox, Version(47u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(54u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 1u32, 0u32)),
+                    (Opera, Version(40u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 1u32, 0u32)),
+                    (Edge, Version(14u32, 0u32, 0u32)),
+                    (Es, Version(2017u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.promise",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(67u32, 0u32, 0u32)),
+                    (Electron, Version(4u32, 0u32, 0u32)),
+                    (Samsung, Version(9u32, 2u32, 0u32)),
+                    (Node, Version(10u32, 4u32, 0u32)),
+                    (Firefox, Version(69u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(67u32, 0u32, 0u32)),
+                    (Safari, Version(11u32, 0u32, 0u32)),
+                    (Opera, Version(53u32, 0u32, 0u32)),
+                    (Ios, Version(11u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.promise.all-settled",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(76u32, 0u32, 0u32)),
+                    (Electron, Version(6u32, 0u32, 0u32)),
+                    (Samsung, Version(12u32, 0u32, 0u32)),
+                    (Node, Version(12u32, 9u32, 0u32)),
+                    (Firefox, Version(71u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(76u32, 0u32, 0u32)),
+                    (Safari, Version(13u32, 0u32, 0u32)),
+                    (Opera, Version(62u32, 0u32, 0u32)),
+                    (Ios, Version(13u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2020u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.promise.any",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(85u32, 0u32, 0u32)),
+                    (Electron, Version(10u32, 0u32, 0u32)),
+                    (Samsung, Version(14u32, 0u32, 0u32)),
+                    (Node, Version(15u32, 0u32, 0u32)),
+                    (Firefox, Version(79u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 2u32, 0u32)),
+                    (Android, Version(85u32, 0u32, 0u32)),
+                    (Safari, Version(14u32, 0u32, 0u32)),
+                    (Opera, Version(71u32, 0u32, 0u32)),
+                    (Ios, Version(14u32, 0u32, 0u32)),
+                    (Edge, Version(85u32, 0u32, 0u32)),
+                    (Es, Version(2021u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.promise.finally",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(67u32, 0u32, 0u32)),
+                    (Electron, Version(4u32, 0u32, 0u32)),
+                    (Samsung, Version(9u32, 2u32, 0u32)),
+                    (Node, Version(10u32, 4u32, 0u32)),
+                    (Firefox, Version(69u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(67u32, 0u32, 0u32)),
+                    (Safari, Version(13u32, 1u32, 0u32)),
+                    (Opera, Version(53u32, 0u32, 0u32)),
+                    (Ios, Version(13u32, 1u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2018u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.promise.with-resolvers",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(119u32, 0u32, 0u32)),
+                    (Electron, Version(28u32, 0u32, 0u32)),
+                    (Samsung, Version(25u32, 0u32, 0u32)),
+                    (Node, Version(22u32, 0u32, 0u32)),
+                    (Firefox, Version(121u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 38u32, 0u32)),
+                    (Android, Version(119u32, 0u32, 0u32)),
+                    (Safari, Version(17u32, 4u32, 0u32)),
+                    (Opera, Version(105u32, 0u32, 0u32)),
+                    (Ios, Version(17u32, 4u32, 0u32)),
+                    (Edge, Version(119u32, 0u32, 0u32)),
+                    (Es, Version(2024u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.set",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Electron, Version(2u32, 0u32, 0u32)),
+                    (Samsung, Version(5u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 5u32, 0u32)),
+                    (Firefox, Version(53u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(51u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Opera, Version(37u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.at-alternative",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(92u32, 0u32, 0u32)),
+                    (Electron, Version(14u32, 0u32, 0u32)),
+                    (Samsung, Version(16u32, 0u32, 0u32)),
+                    (Node, Version(16u32, 6u32, 0u32)),
+                    (Firefox, Version(90u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 12u32, 0u32)),
+                    (Android, Version(92u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Opera, Version(78u32, 0u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(92u32, 0u32, 0u32)),
+                    (Es, Version(2022u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.includes",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(41u32, 0u32, 0u32)),
+                    (Electron, Version(1u32, 0u32, 0u32)),
+                    (Samsung, Version(5u32, 0u32, 0u32)),
+                    (Node, Version(4u32, 0u32, 0u32)),
+                    (Firefox, Version(40u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(41u32, 0u32, 0u32)),
+                    (Safari, Version(9u32, 0u32, 0u32)),
+                    (Opera, Version(27u32, 0u32, 0u32)),
+                    (Ios, Version(9u32, 0u32, 0u32)),
+                    (Edge, Version(12u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.is-well-formed",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(111u32, 0u32, 0u32)),
+                    (Electron, Version(24u32, 0u32, 0u32)),
+                    (Samsung, Version(22u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(119u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 32u32, 0u32)),
+                    (Android, Version(111u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 4u32, 0u32)),
+                    (Opera, Version(97u32, 0u32, 0u32)),
+                    (Ios, Version(16u32, 4u32, 0u32)),
+                    (Edge, Version(111u32, 0u32, 0u32)),
+                    (Es, Version(2024u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.match-all",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(80u32, 0u32, 0u32)),
+                    (Electron, Version(8u32, 0u32, 0u32)),
+                    (Samsung, Version(13u32, 0u32, 0u32)),
+                    (Node, Version(14u32, 0u32, 0u32)),
+                    (Firefox, Version(73u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(80u32, 0u32, 0u32)),
+                    (Safari, Version(13u32, 1u32, 0u32)),
+                    (Opera, Version(66u32, 0u32, 0u32)),
+                    (Ios, Version(13u32, 1u32, 0u32)),
+                    (Edge, Version(80u32, 0u32, 0u32)),
+                    (Es, Version(2020u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.pad-end",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(57u32, 0u32, 0u32)),
+                    (Electron, Version(2u32, 0u32, 0u32)),
+                    (Samsung, Version(7u32, 2u32, 0u32)),
+                    (Node, Version(8u32, 0u32, 0u32)),
+                    (Firefox, Version(48u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(57u32, 0u32, 0u32)),
+                    (Safari, Version(11u32, 0u32, 0u32)),
+                    (Opera, Version(43u32, 0u32, 0u32)),
+                    (Ios, Version(11u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2017u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.pad-start",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(57u32, 0u32, 0u32)),
+                    (Electron, Version(2u32, 0u32, 0u32)),
+                    (Samsung, Version(7u32, 2u32, 0u32)),
+                    (Node, Version(8u32, 0u32, 0u32)),
+                    (Firefox, Version(48u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(57u32, 0u32, 0u32)),
+                    (Safari, Version(11u32, 0u32, 0u32)),
+                    (Opera, Version(43u32, 0u32, 0u32)),
+                    (Ios, Version(11u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2017u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.replace-all",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(85u32, 0u32, 0u32)),
+                    (Electron, Version(10u32, 0u32, 0u32)),
+                    (Samsung, Version(14u32, 0u32, 0u32)),
+                    (Node, Version(15u32, 0u32, 0u32)),
+                    (Firefox, Version(77u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 2u32, 0u32)),
+                    (Android, Version(85u32, 0u32, 0u32)),
+                    (Safari, Version(13u32, 1u32, 0u32)),
+                    (Opera, Version(71u32, 0u32, 0u32)),
+                    (Ios, Version(13u32, 1u32, 0u32)),
+                    (Edge, Version(85u32, 0u32, 0u32)),
+                    (Es, Version(2021u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.to-well-formed",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(111u32, 0u32, 0u32)),
+                    (Electron, Version(24u32, 0u32, 0u32)),
+                    (Samsung, Version(22u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(119u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 32u32, 0u32)),
+                    (Android, Version(111u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 4u32, 0u32)),
+                    (Opera, Version(97u32, 0u32, 0u32)),
+                    (Ios, Version(16u32, 4u32, 0u32)),
+                    (Edge, Version(111u32, 0u32, 0u32)),
+                    (Es, Version(2024u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.trim-end",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(66u32, 0u32, 0u32)),
+                    (Electron, Version(3u32, 0u32, 0u32)),
+                    (Samsung, Version(9u32, 2u32, 0u32)),
+                    (Node, Version(10u32, 0u32, 0u32)),
+                    (Firefox, Version(61u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(66u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 0u32, 0u32)),
+                    (Opera, Version(52u32, 0u32, 0u32)),
+                    (Ios, Version(12u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.trim-start",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(66u32, 0u32, 0u32)),
+                    (Electron, Version(3u32, 0u32, 0u32)),
+                    (Samsung, Version(9u32, 2u32, 0u32)),
+                    (Node, Version(10u32, 0u32, 0u32)),
+                    (Firefox, Version(61u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(66u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 0u32, 0u32)),
+                    (Opera, Version(52u32, 0u32, 0u32)),
+                    (Ios, Version(12u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.symbol",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(49u32, 0u32, 0u32)),
+                    (Electron, Version(1u32, 0u32, 0u32)),
+                    (Samsung, Version(5u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 0u32, 0u32)),
+                    (Firefox, Version(51u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(49u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Opera, Version(35u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.weak-map",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Electron, Version(2u32, 0u32, 0u32)),
+                    (Samsung, Version(5u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 5u32, 0u32)),
+                    (Firefox, Version(53u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(51u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Opera, Version(37u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.weak-set",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Electron, Version(2u32, 0u32, 0u32)),
+                    (Samsung, Version(5u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 5u32, 0u32)),
+                    (Firefox, Version(53u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(51u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Opera, Version(37u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "web.queue-microtask",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(71u32, 0u32, 0u32)),
+                    (Electron, Version(5u32, 0u32, 0u32)),
+                    (Samsung, Version(10u32, 1u32, 0u32)),
+                    (Node, Version(12u32, 0u32, 0u32)),
+                    (Firefox, Version(69u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 0u32, 0u32)),
+                    (Android, Version(71u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 1u32, 0u32)),
+                    (Opera, Version(57u32, 0u32, 0u32)),
+                    (Ios, Version(12u32, 1u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                ])),
+            ),
+            (
+                "web.structured-clone",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(98u32, 0u32, 0u32)),
+                    (Electron, Version(17u32, 0u32, 0u32)),
+                    (Samsung, Version(18u32, 0u32, 0u32)),
+                    (Node, Version(17u32, 0u32, 0u32)),
+                    (Firefox, Version(94u32, 0u32, 0u32)),
+                    (Deno, Version(1u32, 14u32, 0u32)),
+                    (Android, Version(98u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Opera, Version(84u32, 0u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(98u32, 0u32, 0u32)),
+                ])),
+            ),
+        ])
+    })
+}
@@ -0,0 +1,164 @@
+//! Polyfills
+//!
+//! This plugin injects `core-js` imports for the built-ins used in the code, which are not
+//! supported by the targets.
+//!
+//! > This is `useBuiltIns: "usage"` with `corejs: 3` of `@babel/preset-env`.
+//!
+//! ## Example
+//!
+//! Targets: `chrome 90`
+//!
+//! Input:
+//! ```js
+//! const { promise, resolve } = Promise.withResolvers();
+//! [1, 2, 3].at(-1);
+//! ```
+//!
+//! Output:
+//! ```js
+//! import "core-js/modules/es.promise.with-resolvers.js";
+//! import "core-js/modules/es.array.at.js";
+//! import "core-js/modules/es.string.at-alternative.js";
+//! const { promise, resolve } = Promise.withResolvers();
+//! [1, 2, 3].at(-1);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Usages are detected syntactically, the same as Babel:
+//!
+//! * Unbound global references, e.g. `structuredClone`.
+//! * Static properties of unbound globals, e.g. `Promise.withResolvers`.
+//! * Any property access with the name of an instance method, e.g. `x.at`, because the type
+//!   of `x` is unknown.
+//!
+//! Built-ins used by the code which other transforms insert are not detected.
+//!
+//! The engine versions which support each module are in `core_js_data.rs`, generated from
+//! `core-js-compat` by `tasks/compat_data`.
+//!
+//! ## References:
+//!
+//! * Babel preset-env: <https://babeljs.io/docs/babel-preset-env#usebuiltins-usage>
+//! * Babel plugin implementation: <https://github.com/babel/babel-polyfills/tree/main/packages/babel-plugin-polyfill-corejs3>
+//! * core-js-compat: <https://github.com/zloirock/core-js/tree/master/packages/core-js-compat>
+
+use oxc_ast::ast::*;
+use oxc_semantic::IsGlobalReference;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::{Engine, EngineTargets, TransformCtx};
+
+mod built_ins;
+mod core_js_data;
+
+use core_js_data::core_js_modules;
+
+pub struct Polyfills<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    targets: EngineTargets,
+    /// core-js modules to import, in order of first usage.
+    modules: Vec<&'static str>,
+}
+
+impl<'a, 'ctx> Polyfills<'a, 'ctx> {
+    pub fn new(targets: EngineTargets, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, targets, modules: vec![] }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Polyfills<'a, 'ctx> {
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Insert in reverse order, so the polyfills are in order of usage, and before other imports.
+        for module in self.modules.iter().rev() {
+            let source = ctx.ast.atom(&format!("core-js/modules/{module}.js"));
+            self.ctx.module_imports.add_side_effect_import(source, true);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `structuredClone`
+        if !Self::is_global_value_reference(ident, ctx) {
+            return;
+        }
+        if let Some(modules) = built_ins::global(&ident.name) {
+            self.add_modules(modules);
+        }
+    }
+
+    fn enter_static_member_expression(
+        &mut self,
+        expr: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.add_member_modules(&expr.object, &expr.property.name, ctx);
+    }
+
+    fn enter_computed_member_expression(
+        &mut self,
+        expr: &mut ComputedMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Expression::StringLiteral(property) = &expr.expression {
+            self.add_member_modules(&expr.object, &property.value, ctx);
+        }
+    }
+}
+
+impl<'a, 'ctx> Polyfills<'a, 'ctx> {
+    fn is_global_value_reference(ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        ident.is_global_reference(ctx.symbols())
+            && ctx.symbols().get_reference(ident.reference_id()).is_value()
+    }
+
+    /// `Promise.withResolvers` or `x.at`
+    fn add_member_modules(
+        &mut self,
+        object: &Expression<'a>,
+        property: &str,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if let Expression::Identifier(ident) = object {
+            if Self::is_global_value_reference(ident, ctx) {
+                if let Some(modules) = built_ins::static_property(&ident.name, property) {
+                    self.add_modules(modules);
+                    return;
+                }
+            }
+        }
+        if let Some(modules) = built_ins::instance_property(property) {
+            self.add_modules(modules);
+        }
+    }
+
+    fn add_modules(&mut self, modules: &[&'static str]) {
+        for module in modules {
+            if !self.modules.contains(module) && self.is_needed(module) {
+                self.modules.push(module);
+            }
+        }
+    }
+
+    /// Whether any of the targets does not support `module` without a polyfill.
+    fn is_needed(&self, module: &str) -> bool {
+        // Same as Babel, polyfill everything when there are no targets.
+        if self.targets.is_any_target() {
+            return true;
+        }
+        let Some(supported) = core_js_modules().get(module) else {
+            return true;
+        };
+        self.targets.iter().any(|(engine, version)| match supported.get(engine) {
+            Some(supported_version) if *engine == Engine::Es => version.0 < supported_version.0,
+            Some(supported_version) => version < supported_version,
+            // Engines without data do not support the module, except for `esXXXX` targets with
+            // web built-ins, which are not part of ECMAScript.
+            None => *engine != Engine::Es,
+        })
+    }
+}
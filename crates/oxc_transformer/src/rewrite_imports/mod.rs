@@ -0,0 +1,371 @@
+//! Rewrite import sources
+//!
+//! This plugin rewrites the sources of imports and re-exports:
+//!
+//! * Path aliases, e.g. from `compilerOptions.paths` of `tsconfig.json`, are rewritten to
+//!   relative paths, so the output can run without a bundler which understands them.
+//! * Named imports from barrel files are rewritten to imports of the individual modules, so
+//!   bundlers and runtimes do not have to load every module re-exported by the barrel file.
+//!
+//! The sources of `import` and `export ... from` declarations, and of `import()` expressions
+//! with a string literal, are rewritten, in JavaScript and TypeScript files.
+//!
+//! ## Example
+//!
+//! Options:
+//! ```json
+//! {
+//!   "baseUrl": "/project",
+//!   "paths": { "@/*": ["./src/*"] },
+//!   "barrels": { "ui": "ui/{{member}}" }
+//! }
+//! ```
+//!
+//! Input:
+//! ```js
+//! // /project/src/pages/home.ts
+//! import { Header } from "@/components/header";
+//! import { Button, Card as C } from "ui";
+//! const utils = import("@/utils");
+//! ```
+//!
+//! Output:
+//! ```js
+//! import { Header } from "../components/header";
+//! import Button from "ui/Button";
+//! import C from "ui/Card";
+//! const utils = import("../utils");
+//! ```
+//!
+//! ## Implementation
+//!
+//! Like TypeScript, each target of a path alias is tried in order until one exists on disk, with
+//! any of the extensions of JavaScript and TypeScript modules, or as a directory with an index
+//! file. When none of them exist, e.g. because they are generated by a later build step, the
+//! first target is used.
+//!
+//! Barrel expansion is based on the `modularizeImports` option of Next.js, and assumes that
+//! each member of the barrel file is the default export of its own module.
+//!
+//! ## References:
+//!
+//! * TypeScript `paths`: <https://www.typescriptlang.org/tsconfig/#paths>
+//! * Next.js `modularizeImports`: <https://nextjs.org/docs/architecture/nextjs-compiler#modularize-imports>
+
+mod options;
+
+use std::path::{Component, Path, PathBuf};
+
+use cow_utils::CowUtils;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, NONE};
+use oxc_span::SPAN;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+pub use options::RewriteImportsOptions;
+
+/// Extensions tried, in order, when checking if the target of a path alias exists.
+const EXTENSIONS: [&str; 10] =
+    ["ts", "tsx", "d.ts", "mts", "cts", "js", "jsx", "mjs", "cjs", "json"];
+
+pub struct RewriteImports<'a, 'ctx> {
+    options: RewriteImportsOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> RewriteImports<'a, 'ctx> {
+    pub fn new(options: RewriteImportsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for RewriteImports<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.options.barrels.is_empty() {
+            self.expand_barrel_imports(&mut program.body, ctx);
+        }
+    }
+
+    fn enter_import_declaration(
+        &mut self,
+        node: &mut ImportDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !node.import_kind.is_type() {
+            self.rewrite_source(&mut node.source, ctx);
+        }
+    }
+
+    fn enter_export_all_declaration(
+        &mut self,
+        node: &mut ExportAllDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !node.export_kind.is_type() {
+            self.rewrite_source(&mut node.source, ctx);
+        }
+    }
+
+    fn enter_export_named_declaration(
+        &mut self,
+        node: &mut ExportNamedDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if node.export_kind.is_type() {
+            return;
+        }
+        if let Some(source) = &mut node.source {
+            self.rewrite_source(source, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `import("@/utils")`
+        if let Expression::ImportExpression(import_expr) = expr {
+            if let Expression::StringLiteral(source) = &mut import_expr.source {
+                self.rewrite_source(source, ctx);
+            }
+        }
+    }
+}
+
+impl<'a, 'ctx> RewriteImports<'a, 'ctx> {
+    fn rewrite_source(&self, source: &mut StringLiteral<'a>, ctx: &TraverseCtx<'a>) {
+        if let Some(value) = self.resolve_alias(&source.value) {
+            source.value = ctx.ast.atom(&value);
+        }
+    }
+
+    /// Resolve `specifier` with the path alias with the longest matching prefix, and return the
+    /// path relative to the transformed file.
+    fn resolve_alias(&self, specifier: &str) -> Option<String> {
+        let mut best: Option<(usize, &[String], &str)> = None;
+        for (pattern, targets) in &self.options.paths {
+            if targets.is_empty() {
+                continue;
+            }
+            let (matched, prefix_len) = if let Some((prefix, suffix)) = pattern.split_once('*') {
+                if specifier.len() < prefix.len() + suffix.len()
+                    || !specifier.starts_with(prefix)
+                    || !specifier.ends_with(suffix)
+                {
+                    continue;
+                }
+                (&specifier[prefix.len()..specifier.len() - suffix.len()], prefix.len())
+            } else if pattern == specifier {
+                // Exact matches take precedence over wildcards.
+                ("", usize::MAX)
+            } else {
+                continue;
+            };
+            if best.map_or(true, |(len, _, _)| prefix_len > len) {
+                best = Some((prefix_len, targets, matched));
+            }
+        }
+
+        let (_, targets, matched) = best?;
+        let target_path = |target: &String| {
+            normalize(&self.options.base_url.join(target.cow_replacen('*', matched, 1).as_ref()))
+        };
+        let target = targets
+            .iter()
+            .map(target_path)
+            .find(|path| module_exists(path))
+            .unwrap_or_else(|| target_path(&targets[0]));
+        let dir = normalize(self.ctx.source_path.parent().unwrap_or(Path::new("")));
+        Some(relative_specifier(&dir, &target))
+    }
+
+    /// `import { Button, Card as C } from "ui"` -> `import Button from "ui/Button"; import C from "ui/Card";`
+    /// `export { Button } from "ui"` -> `export { default as Button } from "ui/Button";`
+    fn expand_barrel_imports(
+        &self,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let needs_expansion = body.iter().any(|stmt| match stmt {
+            Statement::ImportDeclaration(decl) => self.barrel_template(&decl.source).is_some(),
+            Statement::ExportNamedDeclaration(decl) => {
+                decl.source.as_ref().is_some_and(|source| self.barrel_template(source).is_some())
+            }
+            _ => false,
+        });
+        if !needs_expansion {
+            return;
+        }
+
+        let stmts = ctx.ast.move_vec(body);
+        for stmt in stmts {
+            match stmt {
+                Statement::ImportDeclaration(mut decl) if !decl.import_kind.is_type() => {
+                    let Some(template) = self.barrel_template(&decl.source) else {
+                        body.push(Statement::ImportDeclaration(decl));
+                        continue;
+                    };
+                    let Some(specifiers) = &mut decl.specifiers else {
+                        body.push(Statement::ImportDeclaration(decl));
+                        continue;
+                    };
+                    let mut expanded = vec![];
+                    specifiers.retain_mut(|specifier| match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier)
+                            if !specifier.import_kind.is_type()
+                                && specifier.imported.name() != "default" =>
+                        {
+                            let source = barrel_source(template, &specifier.imported.name());
+                            let local = specifier.local.clone();
+                            expanded.push((source, local));
+                            false
+                        }
+                        _ => true,
+                    });
+                    // Keep default, namespace and type imports of the barrel file.
+                    if !specifiers.is_empty() {
+                        body.push(Statement::ImportDeclaration(decl));
+                    }
+                    for (source, local) in expanded {
+                        let specifier = ctx
+                            .ast
+                            .import_declaration_specifier_import_default_specifier(SPAN, local);
+                        body.push(Statement::from(ctx.ast.module_declaration_import_declaration(
+                            SPAN,
+                            Some(ctx.ast.vec1(specifier)),
+                            ctx.ast.string_literal(SPAN, ctx.ast.atom(&source), None),
+                            None,
+                            NONE,
+                            ImportOrExportKind::Value,
+                        )));
+                    }
+                }
+                Statement::ExportNamedDeclaration(mut decl) if !decl.export_kind.is_type() => {
+                    let Some(template) =
+                        decl.source.as_ref().and_then(|source| self.barrel_template(source))
+                    else {
+                        body.push(Statement::ExportNamedDeclaration(decl));
+                        continue;
+                    };
+                    let mut expanded = vec![];
+                    decl.specifiers.retain_mut(|specifier| {
+                        if specifier.export_kind.is_type() || specifier.local.name() == "default" {
+                            return true;
+                        }
+                        let source = barrel_source(template, &specifier.local.name());
+                        expanded.push((source, specifier.exported.clone()));
+                        false
+                    });
+                    if !decl.specifiers.is_empty() {
+                        body.push(Statement::ExportNamedDeclaration(decl));
+                    }
+                    for (source, exported) in expanded {
+                        let local = ModuleExportName::IdentifierName(
+                            ctx.ast.identifier_name(SPAN, "default"),
+                        );
+                        let specifier = ctx.ast.export_specifier(
+                            SPAN,
+                            local,
+                            exported,
+                            ImportOrExportKind::Value,
+                        );
+                        body.push(Statement::from(
+                            ctx.ast.module_declaration_export_named_declaration(
+                                SPAN,
+                                None,
+                                ctx.ast.vec1(specifier),
+                                Some(ctx.ast.string_literal(SPAN, ctx.ast.atom(&source), None)),
+                                ImportOrExportKind::Value,
+                                NONE,
+                            ),
+                        ));
+                    }
+                }
+                stmt => body.push(stmt),
+            }
+        }
+    }
+
+    fn barrel_template(&self, source: &StringLiteral<'a>) -> Option<&str> {
+        self.options.barrels.get(source.value.as_str()).map(String::as_str)
+    }
+}
+
+/// Fill in the `{{member}}` and `{{kebabCase member}}` placeholders of a barrel template.
+fn barrel_source(template: &str, member: &str) -> String {
+    template
+        .cow_replace("{{member}}", member)
+        .cow_replace("{{kebabCase member}}", &kebab_case(member))
+        .into_owned()
+}
+
+/// `ButtonGroup` -> `button-group`, `XMLParser` -> `xml-parser`
+fn kebab_case(s: &str) -> String {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut out = String::with_capacity(s.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                out.push('-');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+/// Whether `path` is a module, as written in an import specifier without an extension.
+fn module_exists(path: &Path) -> bool {
+    let with_extension = |path: &Path, extension: &str| {
+        let mut path = path.as_os_str().to_owned();
+        path.push(".");
+        path.push(extension);
+        PathBuf::from(path)
+    };
+    path.is_file()
+        || EXTENSIONS.iter().any(|extension| with_extension(path, extension).is_file())
+        || (path.is_dir()
+            && EXTENSIONS
+                .iter()
+                .any(|extension| with_extension(&path.join("index"), extension).is_file()))
+}
+
+/// Remove `.` and resolve `..` components, without accessing the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(normalized.components().next_back(), Some(Component::Normal(_))) {
+                    normalized.pop();
+                } else {
+                    normalized.push("..");
+                }
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// The import specifier of `to` relative to the directory `from`, e.g. `./utils` or `../utils`.
+fn relative_specifier(from: &Path, to: &Path) -> String {
+    let from = from.components().collect::<Vec<_>>();
+    let to = to.components().collect::<Vec<_>>();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let parts = std::iter::repeat("..".to_string())
+        .take(from.len() - common)
+        .chain(to[common..].iter().map(|c| c.as_os_str().to_string_lossy().into_owned()))
+        .collect::<Vec<_>>();
+    if parts.first().is_some_and(|part| part == "..") {
+        parts.join("/")
+    } else {
+        format!("./{}", parts.join("/"))
+    }
+}
@@ -0,0 +1,67 @@
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct RewriteImportsOptions {
+    /// The directory which the targets of [`RewriteImportsOptions::paths`] are relative to,
+    /// like `compilerOptions.baseUrl` of `tsconfig.json`.
+    ///
+    /// Must be in the same form as the source path of the transformed file, i.e. both absolute
+    /// or both relative to the same directory.
+    pub base_url: PathBuf,
+
+    /// Path aliases, like `compilerOptions.paths` of `tsconfig.json`.
+    ///
+    /// e.g. `{ "@/*": ["./src/*"] }` rewrites `@/utils` to the relative path of `src/utils`.
+    pub paths: FxHashMap<String, Vec<String>>,
+
+    /// Barrel files whose named imports are rewritten to default imports of the modules of the
+    /// members. `{{member}}` is replaced with the imported name, and `{{kebabCase member}}` with
+    /// the imported name in kebab case.
+    ///
+    /// e.g. `{ "ui": "ui/{{member}}" }` rewrites `import { Button } from "ui"` to
+    /// `import Button from "ui/Button"`.
+    pub barrels: FxHashMap<String, String>,
+}
+
+impl RewriteImportsOptions {
+    /// Read [`RewriteImportsOptions::base_url`] and [`RewriteImportsOptions::paths`] from the
+    /// `compilerOptions` of a `tsconfig.json`.
+    ///
+    /// `extends` is not followed.
+    ///
+    /// # Errors
+    ///
+    /// * The file can not be read, or is not valid JSON.
+    pub fn from_tsconfig(path: &Path) -> Result<Self, String> {
+        #[derive(Deserialize)]
+        struct TsConfig {
+            #[serde(default, rename = "compilerOptions")]
+            compiler_options: CompilerOptions,
+        }
+
+        #[derive(Default, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CompilerOptions {
+            base_url: Option<PathBuf>,
+            #[serde(default)]
+            paths: FxHashMap<String, Vec<String>>,
+        }
+
+        let mut json = std::fs::read_to_string(path).map_err(|err| format!("{path:?}: {err}"))?;
+        json_strip_comments::strip(&mut json).map_err(|err| format!("{path:?}: {err}"))?;
+        let tsconfig: TsConfig =
+            serde_json::from_str(&json).map_err(|err| format!("{path:?}: {err}"))?;
+
+        // `paths` are relative to the directory of `tsconfig.json` when `baseUrl` is not set.
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let base_url = match tsconfig.compiler_options.base_url {
+            Some(base_url) => dir.join(base_url),
+            None => dir.to_path_buf(),
+        };
+        Ok(Self { base_url, paths: tsconfig.compiler_options.paths, barrels: FxHashMap::default() })
+    }
+}
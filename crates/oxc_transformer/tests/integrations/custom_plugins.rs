@@ -0,0 +1,136 @@
+use std::{cell::RefCell, path::Path, rc::Rc};
+
+use cow_utils::CowUtils;
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, Program, StringLiteral};
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{PluginPosition, TransformOptions, Transformer};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::codegen;
+
+/// Records the kind of every expression it enters and exits.
+#[derive(Default)]
+struct ExpressionLog(Vec<String>);
+
+impl ExpressionLog {
+    fn log(&mut self, event: &str, expr: &Expression<'_>) {
+        let kind = match expr {
+            Expression::JSXElement(_) => "jsx",
+            Expression::CallExpression(_) => "call",
+            Expression::TSAsExpression(_) => "as",
+            _ => return,
+        };
+        self.0.push(format!("{event} {kind}"));
+    }
+}
+
+impl<'a> Traverse<'a> for ExpressionLog {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.log("enter", expr);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.log("exit", expr);
+    }
+}
+
+/// Uppercases string literals, recording the original strings.
+#[derive(Default)]
+struct Extract(Vec<String>);
+
+impl<'a> Traverse<'a> for Extract {
+    fn enter_string_literal(&mut self, lit: &mut StringLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.0.push(lit.value.to_string());
+        lit.value = ctx.ast.atom(&lit.value.cow_to_uppercase());
+    }
+}
+
+fn transform(source_text: &str, plugins: Vec<(PluginPosition, &mut ExpressionLog)>) -> String {
+    let source_type = SourceType::tsx();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let mut transformer = Transformer::new(&allocator, Path::new(""), &TransformOptions::default());
+    for (position, plugin) in plugins {
+        transformer = transformer.with_plugin(position, plugin);
+    }
+    let ret = transformer.build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty());
+    CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+#[test]
+fn position() {
+    let source_text = "<div /> as any";
+    let mut pre = ExpressionLog::default();
+    let mut post = ExpressionLog::default();
+    transform(
+        source_text,
+        vec![(PluginPosition::Post, &mut post), (PluginPosition::Pre, &mut pre)],
+    );
+    // `Pre` plugins see the source, `Post` plugins see the transformed AST.
+    assert_eq!(pre.0, ["enter as", "exit jsx"]);
+    assert_eq!(post.0, ["enter jsx", "exit call"]);
+}
+
+/// Records its name in a shared log when entering the program.
+struct Named(&'static str, Rc<RefCell<Vec<&'static str>>>);
+
+impl<'a> Traverse<'a> for Named {
+    fn enter_program(&mut self, _program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+#[test]
+fn order() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, "", SourceType::mjs()).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    Transformer::new(&allocator, Path::new(""), &TransformOptions::default())
+        .with_plugin(PluginPosition::Post, Named("post 1", Rc::clone(&log)))
+        .with_plugin(PluginPosition::Pre, Named("pre 1", Rc::clone(&log)))
+        .with_plugin(PluginPosition::Post, Named("post 2", Rc::clone(&log)))
+        .with_plugin(PluginPosition::Pre, Named("pre 2", Rc::clone(&log)))
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert_eq!(*log.borrow(), ["pre 1", "pre 2", "post 1", "post 2"]);
+}
+
+#[test]
+fn state() {
+    let source_type = SourceType::tsx();
+    let allocator = Allocator::default();
+    let mut extract = Extract::default();
+    let ret = Parser::new(&allocator, "let x: string = 'hello'; <p title='world' />", source_type)
+        .parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let ret = Transformer::new(&allocator, Path::new(""), &TransformOptions::default())
+        .with_plugin(PluginPosition::Pre, &mut extract)
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty());
+    let code = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    assert_eq!(extract.0, ["hello", "world"]);
+    assert_eq!(
+        code,
+        codegen(
+            "import { jsx as _jsx } from 'react/jsx-runtime';\nlet x = 'HELLO';\n_jsx('p', { title: 'WORLD' });",
+            SourceType::mjs()
+        )
+    );
+}
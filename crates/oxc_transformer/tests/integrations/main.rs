@@ -1,5 +1,8 @@
+mod custom_plugins;
 mod es_target;
 mod plugins;
+mod polyfills;
+mod rewrite_imports;
 mod targets;
 
 use std::path::Path;
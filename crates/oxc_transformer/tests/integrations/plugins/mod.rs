@@ -1,2 +1,3 @@
 mod inject_global_variables;
 mod replace_global_defines;
+mod styled_components;
@@ -28,6 +28,17 @@ fn test_same(source_text: &str, config: ReplaceGlobalDefinesConfig) {
     test(source_text, source_text, config);
 }
 
+fn warnings(source_text: &str, config: ReplaceGlobalDefinesConfig) -> Vec<String> {
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let ret = ReplaceGlobalDefines::new(&allocator, config).build(symbols, scopes, &mut program);
+    ret.errors.into_iter().map(|error| error.message.to_string()).collect()
+}
+
 #[test]
 fn simple() {
     let config = ReplaceGlobalDefinesConfig::new(&[("id", "text"), ("str", "'text'")]).unwrap();
@@ -47,6 +58,93 @@ fn shadowed() {
     test_same("(function (process) { let x = process.env.NODE_ENV })()", config.clone());
 }
 
+#[test]
+fn bound() {
+    let config =
+        ReplaceGlobalDefinesConfig::new(&[("id", "text"), ("process.env.NODE_ENV", "'test'")])
+            .unwrap();
+    test_same("var id; id", config.clone());
+    test_same("import id from 'mod'; id", config.clone());
+    test_same("let process = {}; process.env.NODE_ENV", config.clone());
+    test("{ let id } id", "{ let id } text", config.clone());
+    // Not identifier references
+    test_same("var o = { id: 1 }; o.id; class C { id() {} }", config);
+}
+
+#[test]
+fn typeof_define() {
+    let config = ReplaceGlobalDefinesConfig::new(&[
+        ("typeof window", "'object'"),
+        ("typeof process.env", "'undefined'"),
+        ("typeof  require", "'function'"),
+        ("window", "globalThis"),
+    ])
+    .unwrap();
+    test("typeof window", "'object'", config.clone());
+    test("typeof process.env", "'undefined'", config.clone());
+    test("typeof process['env']", "'undefined'", config.clone());
+    test("typeof require", "'function'", config.clone());
+    test("window", "globalThis", config.clone());
+    test("typeof window.document", "typeof globalThis.document", config.clone());
+    test_same("typeof process", config.clone());
+    test_same("(function (window) { typeof window })()", config);
+
+    assert!(ReplaceGlobalDefinesConfig::new(&[("typeof import.meta", "'object'")]).is_err());
+    assert!(ReplaceGlobalDefinesConfig::new(&[("typeof 1", "'object'")]).is_err());
+}
+
+#[test]
+fn json_values() {
+    let config = ReplaceGlobalDefinesConfig::new(&[
+        ("OBJECT", r#"{"a": [1, "2", null], "b": {"c": true}}"#),
+        ("ARRAY", r#"[{"a": 1}, false]"#),
+        ("process.env", r#"{"NODE_ENV": "production"}"#),
+    ])
+    .unwrap();
+    test("x = OBJECT", "x = { 'a': [1, '2', null], 'b': { 'c': true } }", config.clone());
+    test("x = ARRAY", "x = [{ 'a': 1 }, false]", config.clone());
+    test("x = ARRAY[0].a", "x = [{ 'a': 1 }, false][0].a", config.clone());
+    test("x = process.env", "x = { 'NODE_ENV': 'production' }", config.clone());
+    test(
+        "const { NODE_ENV } = process.env",
+        "const { NODE_ENV } = { 'NODE_ENV': 'production' }",
+        config,
+    );
+}
+
+#[test]
+fn assignment_warning() {
+    let config = ReplaceGlobalDefinesConfig::new(&[
+        ("a", "null"),
+        ("b.c", "null"),
+        ("d", "ident"),
+        ("e.f", "ident"),
+        ("g", "dot.chain"),
+        ("h.i", "dot.chain"),
+    ])
+    .unwrap();
+    let source_text = "
+console.log(
+	[a = 0, b.c = 0, b['c'] = 0],
+	[d = 0, e.f = 0, e['f'] = 0],
+	[g = 0, h.i = 0, h['i'] = 0],
+)
+";
+    test(
+        source_text,
+        "console.log([a = 0, b.c = 0, b['c'] = 0], [ident = 0, ident = 0, ident = 0], [dot.chain = 0, dot.chain = 0, dot.chain = 0]);",
+        config.clone(),
+    );
+    assert_eq!(
+        warnings(source_text, config),
+        vec![
+            "Suspicious assignment to defined constant `a`.",
+            "Suspicious assignment to defined constant `b.c`.",
+            "Suspicious assignment to defined constant `b.c`.",
+        ]
+    );
+}
+
 #[test]
 fn dot() {
     let config =
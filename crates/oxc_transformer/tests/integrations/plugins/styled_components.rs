@@ -0,0 +1,319 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{
+    JsxOptions, PluginsOptions, StyledComponentsOptions, TransformOptions, Transformer,
+};
+
+use crate::codegen;
+
+fn transform(source_text: &str, source_path: &str, options: StyledComponentsOptions) -> String {
+    let source_type = SourceType::jsx();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let options = TransformOptions {
+        jsx: JsxOptions { jsx_plugin: false, display_name_plugin: false, ..JsxOptions::default() },
+        plugins: PluginsOptions { styled_components: Some(options) },
+        ..TransformOptions::default()
+    };
+    let ret = Transformer::new(&allocator, Path::new(source_path), &options)
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty());
+    CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+fn test_with(source_text: &str, expected: &str, options: StyledComponentsOptions) {
+    let result = transform(source_text, "src/Button.js", options);
+    let expected = codegen(expected, SourceType::jsx());
+    assert_eq!(result, expected, "for source {source_text}");
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with(source_text, expected, StyledComponentsOptions::default());
+}
+
+/// Only add the `displayName`, to keep the expected output readable.
+fn display_name_only() -> StyledComponentsOptions {
+    StyledComponentsOptions {
+        ssr: false,
+        minify: false,
+        transpile_template_literals: false,
+        css_prop: false,
+        ..StyledComponentsOptions::default()
+    }
+}
+
+#[test]
+fn default() {
+    test(
+        "
+        import styled from 'styled-components';
+        const Button = styled.button`
+          color: ${(props) => props.color};
+          padding: 4px;
+        `;
+        ",
+        "
+        import styled from 'styled-components';
+        const Button = styled.button.withConfig({ displayName: 'Button', componentId: 'sc-1u82d5a-0' })(['color:', ';padding:4px;'], (props) => props.color);
+        ",
+    );
+}
+
+#[test]
+fn display_name() {
+    let options = display_name_only;
+    test_with(
+        "
+        import styled from 'styled-components';
+        const Title = styled.h1``;
+        const Wrapper = styled(Title)``;
+        const Input = styled.input.attrs({ type: 'text' })``;
+        const Link = styled(Title).attrs({})``;
+        obj.Item = styled.li``;
+        const components = { Header: styled.header`` };
+        class List { static Item = styled.li`` }
+        export default styled.div``;
+        ",
+        "
+        import styled from 'styled-components';
+        const Title = styled.h1.withConfig({ displayName: 'Button__Title' })``;
+        const Wrapper = styled(Title).withConfig({ displayName: 'Button__Wrapper' })``;
+        const Input = styled.input.attrs({ type: 'text' }).withConfig({ displayName: 'Button__Input' })``;
+        const Link = styled(Title).attrs({}).withConfig({ displayName: 'Button__Link' })``;
+        obj.Item = styled.li.withConfig({ displayName: 'Button__Item' })``;
+        const components = { Header: styled.header.withConfig({ displayName: 'Button__Header' })`` };
+        class List { static Item = styled.li.withConfig({ displayName: 'Button__Item' })`` }
+        export default styled.div.withConfig({ displayName: 'Button' })``;
+        ",
+        options(),
+    );
+
+    // Same name as the file
+    test_with(
+        "import styled from 'styled-components'; const Button = styled.button``;",
+        "import styled from 'styled-components'; const Button = styled.button.withConfig({ displayName: 'Button' })``;",
+        options(),
+    );
+
+    // `fileName: false`
+    test_with(
+        "import styled from 'styled-components'; const Title = styled.h1``; export default styled.div``;",
+        "import styled from 'styled-components'; const Title = styled.h1.withConfig({ displayName: 'Title' })``; export default styled.div``;",
+        StyledComponentsOptions { file_name: false, ..options() },
+    );
+}
+
+#[test]
+fn meaningless_file_names() {
+    let result = transform(
+        "import styled from 'styled-components'; const Title = styled.h1``;",
+        "src/components/Header/index.js",
+        display_name_only(),
+    );
+    assert_eq!(
+        result,
+        codegen(
+            "import styled from 'styled-components'; const Title = styled.h1.withConfig({ displayName: 'Header__Title' })``;",
+            SourceType::jsx()
+        )
+    );
+}
+
+#[test]
+fn component_id() {
+    let options = StyledComponentsOptions {
+        display_name: false,
+        minify: false,
+        transpile_template_literals: false,
+        ..StyledComponentsOptions::default()
+    };
+    test_with(
+        "
+        import styled from 'styled-components';
+        const A = styled.div``;
+        const B = styled.div``;
+        ",
+        "
+        import styled from 'styled-components';
+        const A = styled.div.withConfig({ componentId: 'sc-1u82d5a-0' })``;
+        const B = styled.div.withConfig({ componentId: 'sc-1u82d5a-1' })``;
+        ",
+        options.clone(),
+    );
+    test_with(
+        "import styled from 'styled-components'; const A = styled.div``;",
+        "import styled from 'styled-components'; const A = styled.div.withConfig({ componentId: 'lib__sc-1u82d5a-0' })``;",
+        StyledComponentsOptions { namespace: "lib".to_string(), ..options },
+    );
+}
+
+#[test]
+fn existing_config() {
+    test_with(
+        "import styled from 'styled-components'; const A = styled.div.withConfig({ shouldForwardProp })``;",
+        "import styled from 'styled-components'; const A = styled.div.withConfig({ shouldForwardProp })``;",
+        display_name_only(),
+    );
+}
+
+#[test]
+fn imports() {
+    let options = display_name_only;
+    // Named and namespace imports
+    test_with(
+        "
+        import { default as s } from 'styled-components';
+        import * as sc from 'styled-components/native';
+        const A = s.div``;
+        const B = sc.default.div``;
+        ",
+        "
+        import { default as s } from 'styled-components';
+        import * as sc from 'styled-components/native';
+        const A = s.div.withConfig({ displayName: 'Button__A' })``;
+        const B = sc.default.div.withConfig({ displayName: 'Button__B' })``;
+        ",
+        options(),
+    );
+    // Not imported from styled-components
+    test_with(
+        "import styled from 'other'; const A = styled.div``; const B = css``;",
+        "import styled from 'other'; const A = styled.div``; const B = css``;",
+        options(),
+    );
+    // Shadowed
+    test_with(
+        "import styled from 'styled-components'; function f(styled) { const A = styled.div``; }",
+        "import styled from 'styled-components'; function f(styled) { const A = styled.div``; }",
+        options(),
+    );
+    // `topLevelImportPaths`
+    test_with(
+        "import styled from '@emotion/styled'; const A = styled.div``;",
+        "import styled from '@emotion/styled'; const A = styled.div.withConfig({ displayName: 'Button__A' })``;",
+        StyledComponentsOptions {
+            top_level_import_paths: vec!["@emotion/styled".to_string()],
+            ..options()
+        },
+    );
+}
+
+#[test]
+fn helpers() {
+    test(
+        "
+        import { css, keyframes, createGlobalStyle } from 'styled-components';
+        const a = css`
+          color: red;
+        `;
+        const b = keyframes`
+          from { opacity: 0; }
+        `;
+        const C = createGlobalStyle`
+          body { margin: 0; }
+        `;
+        ",
+        "
+        import { css, keyframes, createGlobalStyle } from 'styled-components';
+        const a = css(['color:red;']);
+        const b = keyframes(['from{opacity:0;}']);
+        const C = createGlobalStyle(['body{margin:0;}']);
+        ",
+    );
+}
+
+#[test]
+fn minify() {
+    let options = StyledComponentsOptions {
+        display_name: false,
+        ssr: false,
+        transpile_template_literals: false,
+        ..StyledComponentsOptions::default()
+    };
+    test_with(
+        r#"
+        import { css } from 'styled-components';
+        const a = css`
+          /* comment */
+          color: red; // line comment
+          background: url(http://example.com/a.png);
+          content: "  a  b  ";
+          @media (min-width: 100px) and (max-width: 200px) {
+            margin: ${margin}px  ${margin}px;
+          }
+        `;
+        const b = css`/*! keep */ a  ${/* in comment ${x} */ y}`;
+        "#,
+        r#"
+        import { css } from 'styled-components';
+        const a = css`color:red;background:url(http://example.com/a.png);content:"  a  b  ";@media (min-width:100px) and (max-width:200px){margin:${margin}px ${margin}px;}`;
+        const b = css`/*! keep */ a ${y}`;
+        "#,
+        options,
+    );
+}
+
+#[test]
+fn css_prop() {
+    test(
+        "
+        import styled from 'styled-components';
+        import Button from './Button';
+        const color = 'red';
+        function App({ size }) {
+          return (
+            <div css='color: red;'>
+              <Button css={`color: ${color}; padding: ${size}px;`}>Click</Button>
+              <p css={{ margin: 0 }} />
+            </div>
+          );
+        }
+        ",
+        "
+        import styled from 'styled-components';
+        import Button from './Button';
+        const color = 'red';
+        function App({ size }) {
+          return (
+            <_StyledDiv>
+              <_StyledButton $_css={size}>Click</_StyledButton>
+              <_StyledP $_css={{ margin: 0 }} />
+            </_StyledDiv>
+          );
+        }
+        var _StyledDiv = styled('div').withConfig({ displayName: 'Button___StyledDiv', componentId: 'sc-1u82d5a-0' })(['color:red;']);
+        var _StyledButton = styled(Button).withConfig({ displayName: 'Button___StyledButton', componentId: 'sc-1u82d5a-1' })(['color:', ';padding:', 'px;'], color, (p) => p.$_css);
+        var _StyledP = styled('p').withConfig({ displayName: 'Button___StyledP', componentId: 'sc-1u82d5a-2' })(['', ''], (p) => p.$_css);
+        ",
+    );
+
+    // Adds the `styled` import, and skips local components.
+    test_with(
+        "
+        function App() {
+          const Local = () => null;
+          return <><h1 css='margin: 0;'>Title</h1><Local css='margin: 0;' /></>;
+        }
+        ",
+        "
+        import _styled from 'styled-components';
+        function App() {
+          const Local = () => null;
+          return <><_StyledH>Title</_StyledH><Local css='margin: 0;' /></>;
+        }
+        var _StyledH = _styled('h1')`margin: 0;`;
+        ",
+        StyledComponentsOptions { display_name: false, css_prop: true, ..display_name_only() },
+    );
+}
@@ -0,0 +1,136 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{EnvOptions, TransformOptions, Transformer};
+
+use crate::{codegen, test};
+
+fn options(query: &str) -> TransformOptions {
+    let mut env = EnvOptions::from_browserslist_query(query).unwrap();
+    env.use_built_ins = true;
+    TransformOptions { env, ..TransformOptions::default() }
+}
+
+fn test_polyfills(source_text: &str, expected: &str, options: &TransformOptions) {
+    assert_eq!(test(source_text, options), Ok(codegen(expected, SourceType::mjs())));
+}
+
+#[test]
+fn usage() {
+    let options = options("chrome 90");
+    test_polyfills(
+        "
+        const { promise, resolve } = Promise.withResolvers();
+        [1, 2, 3].at(-1);
+        structuredClone(x);
+        x.flat();
+        ",
+        "
+        import 'core-js/modules/es.promise.with-resolvers.js';
+        import 'core-js/modules/es.array.at.js';
+        import 'core-js/modules/es.string.at-alternative.js';
+        import 'core-js/modules/web.structured-clone.js';
+        const { promise, resolve } = Promise.withResolvers();
+        [1, 2, 3].at(-1);
+        structuredClone(x);
+        x.flat();
+        ",
+        &options,
+    );
+    // Computed member with a string literal, and each module only once.
+    test_polyfills(
+        "Object['hasOwn'](a, b); Object.hasOwn(c, d);",
+        "import 'core-js/modules/es.object.has-own.js'; Object['hasOwn'](a, b); Object.hasOwn(c, d);",
+        &options,
+    );
+}
+
+#[test]
+fn supported_by_targets() {
+    let options = options("chrome 120");
+    for source_text in
+        ["Promise.withResolvers()", "[].at(-1)", "structuredClone(x)", "Object.hasOwn(a, b)"]
+    {
+        test_polyfills(source_text, source_text, &options);
+    }
+    // Any target without support needs the polyfill.
+    let options = self::options("chrome 120, safari 17");
+    test_polyfills(
+        "Promise.withResolvers()",
+        "import 'core-js/modules/es.promise.with-resolvers.js'; Promise.withResolvers()",
+        &options,
+    );
+}
+
+#[test]
+fn bound() {
+    let options = options("chrome 90");
+    test_polyfills(
+        "const Promise = P; Promise.withResolvers(); function f(structuredClone) { structuredClone(x) }",
+        "const Promise = P; Promise.withResolvers(); function f(structuredClone) { structuredClone(x) }",
+        &options,
+    );
+    // Not a static property of a global, but could be an instance method.
+    test_polyfills(
+        "const Object = O; Object.at(1)",
+        "import 'core-js/modules/es.array.at.js'; import 'core-js/modules/es.string.at-alternative.js'; const Object = O; Object.at(1)",
+        &options,
+    );
+}
+
+#[test]
+fn script() {
+    let source_type = SourceType::cjs();
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, "[].at(-1)", source_type).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let ret = Transformer::new(&allocator, Path::new(""), &options("chrome 90"))
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty());
+    let code = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    let expected = "require('core-js/modules/es.array.at.js'); require('core-js/modules/es.string.at-alternative.js'); [].at(-1)";
+    assert_eq!(code, codegen(expected, source_type));
+}
+
+#[test]
+fn disabled() {
+    let options = TransformOptions {
+        env: EnvOptions::from_browserslist_query("chrome 90").unwrap(),
+        ..TransformOptions::default()
+    };
+    test_polyfills("[].at(-1)", "[].at(-1)", &options);
+}
+
+#[test]
+fn babel_options() {
+    let env: EnvOptions = serde_json::from_value(serde_json::json!({
+        "targets": { "chrome": "90" },
+        "useBuiltIns": "usage",
+        "corejs": "3.39",
+    }))
+    .unwrap();
+    assert!(env.use_built_ins);
+
+    let env: EnvOptions = serde_json::from_value(serde_json::json!({
+        "useBuiltIns": "usage",
+        "corejs": { "version": 3 },
+    }))
+    .unwrap();
+    assert!(env.use_built_ins);
+
+    for options in [
+        serde_json::json!({ "useBuiltIns": "entry", "corejs": 3 }),
+        serde_json::json!({ "useBuiltIns": "usage", "corejs": 2 }),
+        serde_json::json!({ "useBuiltIns": "usage" }),
+    ] {
+        assert!(serde_json::from_value::<EnvOptions>(options).is_err());
+    }
+}
@@ -0,0 +1,212 @@
+use std::path::Path;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{RewriteImportsOptions, TransformOptions, Transformer};
+
+use crate::codegen;
+
+fn test(source_text: &str, expected: &str, options: RewriteImportsOptions) {
+    test_with_source_type(source_text, expected, options, SourceType::ts());
+}
+
+fn test_with_source_type(
+    source_text: &str,
+    expected: &str,
+    options: RewriteImportsOptions,
+    source_type: SourceType,
+) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let options =
+        TransformOptions { rewrite_imports: Some(options), ..TransformOptions::default() };
+    let ret = Transformer::new(&allocator, Path::new("/project/src/pages/home.ts"), &options)
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty());
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    let expected = codegen(expected, SourceType::mjs());
+    assert_eq!(result, expected, "for source {source_text}");
+}
+
+fn paths(paths: &[(&str, &str)]) -> RewriteImportsOptions {
+    RewriteImportsOptions {
+        base_url: "/project".into(),
+        paths: paths
+            .iter()
+            .map(|(pattern, target)| ((*pattern).to_string(), vec![(*target).to_string()]))
+            .collect(),
+        ..RewriteImportsOptions::default()
+    }
+}
+
+fn barrels(barrels: &[(&str, &str)]) -> RewriteImportsOptions {
+    RewriteImportsOptions {
+        barrels: barrels
+            .iter()
+            .map(|(source, template)| ((*source).to_string(), (*template).to_string()))
+            .collect(),
+        ..RewriteImportsOptions::default()
+    }
+}
+
+#[test]
+fn path_aliases() {
+    let options =
+        paths(&[("@/*", "./src/*"), ("@/components/*", "./src/ui/*"), ("~", "lib/index")]);
+    test(
+        "
+        import { a } from '@/utils';
+        import { b } from '@/components/button';
+        import c from '~';
+        export * from '@/pages/about';
+        export { d } from '@/pages/home/d';
+        import('@/lazy');
+        import e from 'react';
+        import f from './local';
+        console.log(a, b, c, e, f);
+        ",
+        "
+        import { a } from '../utils';
+        import { b } from '../ui/button';
+        import c from '../../lib/index';
+        export * from './about';
+        export { d } from './home/d';
+        import('../lazy');
+        import e from 'react';
+        import f from './local';
+        console.log(a, b, c, e, f);
+        ",
+        options,
+    );
+}
+
+#[test]
+fn path_alias_suffix() {
+    test(
+        "import a from 'assets:logo.svg?url'; console.log(a);",
+        "import a from '../../assets/logo.svg'; console.log(a);",
+        paths(&[("assets:*?url", "assets/*")]),
+    );
+}
+
+#[test]
+fn path_alias_targets() {
+    let dir = std::env::temp_dir().join("oxc_transformer_rewrite_imports_targets");
+    std::fs::create_dir_all(dir.join("generated/icons")).unwrap();
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(dir.join("src/utils.ts"), "").unwrap();
+    std::fs::write(dir.join("generated/icons/index.js"), "").unwrap();
+
+    let options = RewriteImportsOptions {
+        base_url: dir.clone(),
+        paths: FxHashMap::from_iter([(
+            "@/*".to_string(),
+            vec!["./missing/*".to_string(), "./generated/*".to_string(), "./src/*".to_string()],
+        )]),
+        ..RewriteImportsOptions::default()
+    };
+    let allocator = Allocator::default();
+    let source_text = "import a from '@/utils'; import b from '@/icons'; import c from '@/other';";
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let options =
+        TransformOptions { rewrite_imports: Some(options), ..TransformOptions::default() };
+    Transformer::new(&allocator, &dir.join("index.js"), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    // The first target which exists as a module, or the first target
+    assert_eq!(
+        result,
+        concat!(
+            "import a from './src/utils';\n",
+            "import b from './generated/icons';\n",
+            "import c from './missing/other';\n",
+        )
+    );
+}
+
+#[test]
+fn javascript() {
+    let mut options = paths(&[("@/*", "./src/*")]);
+    options.barrels = FxHashMap::from_iter([("ui".to_string(), "ui/{{member}}".to_string())]);
+    test_with_source_type(
+        "import { a } from '@/utils'; import { Button } from 'ui'; console.log(a, Button);",
+        "import { a } from '../utils'; import Button from 'ui/Button'; console.log(a, Button);",
+        options,
+        SourceType::mjs(),
+    );
+}
+
+#[test]
+fn barrel_imports() {
+    test(
+        "
+        import Ui, { Button, Card as C, type Props } from 'ui';
+        import { ButtonGroup } from 'icons';
+        export { Dialog, Menu as M } from 'ui';
+        console.log(Ui, Button, C, ButtonGroup);
+        ",
+        "
+        import Ui from 'ui';
+        import Button from 'ui/Button';
+        import C from 'ui/Card';
+        import ButtonGroup from 'icons/dist/button-group';
+        export { default as Dialog } from 'ui/Dialog';
+        export { default as M } from 'ui/Menu';
+        console.log(Ui, Button, C, ButtonGroup);
+        ",
+        barrels(&[("ui", "ui/{{member}}"), ("icons", "icons/dist/{{kebabCase member}}")]),
+    );
+}
+
+#[test]
+fn barrel_imports_with_path_aliases() {
+    let mut options = paths(&[("@/*", "./src/*")]);
+    options.barrels = FxHashMap::from_iter([("@/ui".to_string(), "@/ui/{{member}}".to_string())]);
+    test(
+        "import { Button } from '@/ui'; console.log(Button);",
+        "import Button from '../ui/Button'; console.log(Button);",
+        options,
+    );
+}
+
+#[test]
+fn from_tsconfig() {
+    let dir = std::env::temp_dir().join("oxc_transformer_rewrite_imports");
+    std::fs::create_dir_all(&dir).unwrap();
+    let tsconfig = dir.join("tsconfig.json");
+    std::fs::write(
+        &tsconfig,
+        r#"{
+            // comments are allowed
+            "compilerOptions": {
+                "baseUrl": "./src",
+                "paths": { "@/*": ["./*"] }
+            }
+        }"#,
+    )
+    .unwrap();
+    let options = RewriteImportsOptions::from_tsconfig(&tsconfig).unwrap();
+    assert_eq!(options.base_url, dir.join("./src"));
+    assert_eq!(options.paths["@/*"], vec!["./*".to_string()]);
+
+    assert!(RewriteImportsOptions::from_tsconfig(&dir.join("missing.json")).is_err());
+}
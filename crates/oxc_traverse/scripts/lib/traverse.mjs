@@ -7,7 +7,9 @@ export default function generateTraverseTraitCode(types) {
   const typesArr = Object.values(types);
   typesArr.push({ name: 'Statements', rawName: "Vec<'a, Statement<'a>>" });
 
-  let traverseMethods = '';
+  let traverseMethods = '',
+    forwardMethods = '',
+    sequenceMethods = '';
   for (const type of typesArr) {
     const snakeName = camelToSnake(type.name);
     traverseMethods += `
@@ -16,6 +18,23 @@ export default function generateTraverseTraitCode(types) {
       #[inline]
       fn exit_${snakeName}(&mut self, node: &mut ${type.rawName}, ctx: &mut TraverseCtx<'a>) {}
     `;
+
+    for (const method of [`enter_${snakeName}`, `exit_${snakeName}`]) {
+      forwardMethods += `
+        #[inline]
+        fn ${method}(&mut self, node: &mut ${type.rawName}, ctx: &mut TraverseCtx<'a>) {
+          (**self).${method}(node, ctx);
+        }
+      `;
+      sequenceMethods += `
+        #[inline]
+        fn ${method}(&mut self, node: &mut ${type.rawName}, ctx: &mut TraverseCtx<'a>) {
+          for traverse in self.iter_mut() {
+            traverse.${method}(node, ctx);
+          }
+        }
+      `;
+    }
   }
 
   return `
@@ -28,5 +47,20 @@ export default function generateTraverseTraitCode(types) {
     pub trait Traverse<'a> {
       ${traverseMethods}
     }
+
+    impl<'a, T: Traverse<'a> + ?Sized> Traverse<'a> for &mut T {
+      ${forwardMethods}
+    }
+
+    impl<'a, T: Traverse<'a> + ?Sized> Traverse<'a> for Box<T> {
+      ${forwardMethods}
+    }
+
+    /// Run each [\`Traverse\`] in turn on every node, in a single traversal.
+    ///
+    /// Both \`enter_*\` and \`exit_*\` methods are called in the order of the \`Vec\`.
+    impl<'a, T: Traverse<'a>> Traverse<'a> for std::vec::Vec<T> {
+      ${sequenceMethods}
+    }
   `;
 }
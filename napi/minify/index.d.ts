@@ -1,4 +1,160 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
-export declare function minify(filename: string, sourceText: string): string
+export interface CodegenOptions {
+  /**
+   * Remove whitespace.
+   *
+   * @default true
+   */
+  removeWhitespace?: boolean
+  /**
+   * What to do with legal comments, i.e. comments starting with `/*!` or
+   * containing `@license` or `@preserve`.
+   *
+   * * `none`: remove all legal comments.
+   * * `eof`: move all legal comments to the end of the file.
+   *
+   * @default 'none'
+   *
+   * @see [esbuild#legal-comments](https://esbuild.github.io/api/#legal-comments)
+   */
+  legalComments?: 'none' | 'eof'
+}
 
+export interface CompressOptions {
+  /**
+   * Remove `console.*` statements.
+   *
+   * @default false
+   */
+  dropConsole?: boolean
+  /**
+   * Remove `debugger;` statements.
+   *
+   * @default true
+   */
+  dropDebugger?: boolean
+}
+
+export interface ErrorLabel {
+  message?: string
+  start: number
+  end: number
+}
+
+export interface MangleOptions {
+  /**
+   * Also mangle names declared in the top level scope.
+   *
+   * @default false
+   */
+  toplevel?: boolean
+  /**
+   * Use more readable mangled names (e.g. `slot_0`, `slot_1`) for debugging.
+   *
+   * @default false
+   */
+  debug?: boolean
+}
+
+/**
+ * Minify a JavaScript or TypeScript file.
+ *
+ * @param filename The name of the file being minified.
+ * @param sourceText The source code itself.
+ * @param options The options for the minification. See {@link MinifyOptions}.
+ *
+ * @returns an object containing the minified code, source map, and any
+ * errors that occurred while parsing the code or reading the options.
+ */
+export declare function minify(filename: string, sourceText: string, options?: MinifyOptions | undefined | null): MinifyResult
+
+/**
+ * Minify asynchronously.
+ *
+ * Note: This function can be slower than `minify` due to the overhead of spawning a thread.
+ */
+export declare function minifyAsync(filename: string, sourceText: string, options?: MinifyOptions | undefined | null): Promise<MinifyResult>
+
+/**
+ * Options for minifying a JavaScript or TypeScript file.
+ *
+ * @see {@link minify}
+ */
+export interface MinifyOptions {
+  /**
+   * Fold constants, remove dead code and apply other size optimizations.
+   *
+   * Set to `false` to skip compression.
+   *
+   * @default true
+   */
+  compress?: boolean | CompressOptions
+  /**
+   * Shorten the names of local variables.
+   *
+   * Set to `false` to keep the original names.
+   *
+   * @default true
+   */
+  mangle?: boolean | MangleOptions
+  /** Configure how the minified code is printed. */
+  codegen?: CodegenOptions
+  /**
+   * Enable source map generation.
+   *
+   * When `true`, the `map` field of the minify result is populated.
+   *
+   * @default false
+   */
+  sourcemap?: boolean
+  /**
+   * The source map of the input code, e.g. produced by a previous
+   * transform.
+   *
+   * When set together with {@link sourcemap}, the returned map points back
+   * to the sources of this map instead of the input code.
+   */
+  inputMap?: SourceMap
+}
+
+export interface MinifyResult {
+  /**
+   * The minified code.
+   *
+   * Empty if the input could not be parsed.
+   */
+  code: string
+  /**
+   * The source map for the minified code.
+   *
+   * This will be set if {@link MinifyOptions#sourcemap} is `true`.
+   */
+  map?: SourceMap
+  /** Parse errors, and errors in the given options. */
+  errors: Array<OxcError>
+}
+
+export interface OxcError {
+  severity: Severity
+  message: string
+  labels: Array<ErrorLabel>
+  helpMessage?: string
+}
+
+export declare const enum Severity {
+  Error = 'Error',
+  Warning = 'Warning',
+  Advice = 'Advice'
+}
+
+export interface SourceMap {
+  file?: string
+  mappings: string
+  names: Array<string>
+  sourceRoot?: string
+  sources: Array<string>
+  sourcesContent?: Array<string>
+  version: number
+  x_google_ignoreList?: Array<number>
+}
@@ -362,3 +362,5 @@ if (!nativeBinding) {
 }
 
 module.exports.minify = nativeBinding.minify
+module.exports.minifyAsync = nativeBinding.minifyAsync
+module.exports.Severity = nativeBinding.Severity
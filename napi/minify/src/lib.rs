@@ -1,29 +1,150 @@
+// Napi value need to be passed as value
+#![expect(clippy::needless_pass_by_value)]
+
+mod options;
+
+use std::{mem, path::PathBuf};
+
+use napi::{bindgen_prelude::AsyncTask, Task};
 use napi_derive::napi;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_minifier::{CompressOptions, MangleOptions, Minifier, MinifierOptions};
+use oxc_codegen::Codegen;
+use oxc_mangler::Mangler;
+use oxc_minifier::Compressor;
+use oxc_napi::{decode_source_map, OxcError};
 use oxc_parser::Parser;
+use oxc_sourcemap::napi::SourceMap;
 use oxc_span::SourceType;
 
-#[allow(clippy::needless_pass_by_value)]
-#[napi]
-pub fn minify(filename: String, source_text: String) -> String {
-    let allocator = Allocator::default();
-    let source_type = SourceType::from_path(&filename).unwrap_or_default().with_typescript(true);
+use crate::options::ResolvedOptions;
+pub use crate::options::{CodegenOptions, CompressOptions, MangleOptions, MinifyOptions};
+
+#[napi(object)]
+#[derive(Default)]
+pub struct MinifyResult {
+    /// The minified code.
+    ///
+    /// Empty if the input could not be parsed.
+    pub code: String,
+
+    /// The source map for the minified code.
+    ///
+    /// This will be set if {@link MinifyOptions#sourcemap} is `true`.
+    pub map: Option<SourceMap>,
 
-    let mut program = Parser::new(&allocator, &source_text, source_type).parse().program;
+    /// Parse errors, and errors in the given options.
+    pub errors: Vec<OxcError>,
+}
+
+fn minify_with_options(filename: &str, source_text: &str, options: &MinifyOptions) -> MinifyResult {
+    let resolved = match ResolvedOptions::try_from(options) {
+        Ok(resolved) => resolved,
+        Err(err) => return MinifyResult { errors: vec![OxcError::new(err)], ..Default::default() },
+    };
+    let input_source_map = match options.input_map.as_ref().map(decode_source_map).transpose() {
+        Ok(input_source_map) => input_source_map,
+        Err(err) => return MinifyResult { errors: vec![err.into()], ..Default::default() },
+    };
+
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(filename).unwrap_or_default().with_typescript(true);
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if ret.panicked || !ret.errors.is_empty() {
+        return MinifyResult {
+            errors: ret.errors.into_iter().map(OxcError::from).collect(),
+            ..Default::default()
+        };
+    }
+    let mut program = ret.program;
 
-    let mangler = Minifier::new(MinifierOptions {
-        mangle: Some(MangleOptions::default()),
-        compress: CompressOptions::default(),
-    })
-    .build(&allocator, &mut program)
-    .mangler;
+    if let Some(compress) = resolved.compress {
+        Compressor::new(&allocator, compress).build(&mut program);
+    }
+    let mangler =
+        resolved.mangle.map(|options| Mangler::default().with_options(options).build(&program));
 
-    Codegen::new()
-        .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
+    let mut ret = Codegen::new()
+        .with_options(oxc_codegen::CodegenOptions {
+            minify: resolved.remove_whitespace,
+            comments: false,
+            source_map_path: resolved.sourcemap.then(|| PathBuf::from(filename)),
+            input_source_map,
+            ..oxc_codegen::CodegenOptions::default()
+        })
         .with_mangler(mangler)
-        .build(&program)
-        .code
+        .build(&program);
+
+    // Codegen only keeps legal comments when printing comments, which is never
+    // the case when removing whitespace.
+    if resolved.eof_legal_comments {
+        for comment in program.comments.iter().filter(|comment| comment.is_legal(source_text)) {
+            if !ret.code.is_empty() && !ret.code.ends_with('\n') {
+                ret.code.push('\n');
+            }
+            ret.code.push_str(comment.span.source_text(source_text));
+            ret.code.push('\n');
+        }
+    }
+
+    let map = ret.map.map(|map| {
+        let mut map = SourceMap::from(map);
+        map.x_google_ignorelist =
+            options.input_map.as_ref().and_then(|input_map| input_map.x_google_ignorelist.clone());
+        map
+    });
+
+    MinifyResult { code: ret.code, map, errors: vec![] }
+}
+
+/// Minify a JavaScript or TypeScript file.
+///
+/// @param filename The name of the file being minified.
+/// @param sourceText The source code itself.
+/// @param options The options for the minification. See {@link MinifyOptions}.
+///
+/// @returns an object containing the minified code, source map, and any
+/// errors that occurred while parsing the code or reading the options.
+#[napi]
+pub fn minify(
+    filename: String,
+    source_text: String,
+    options: Option<MinifyOptions>,
+) -> MinifyResult {
+    let options = options.unwrap_or_default();
+    minify_with_options(&filename, &source_text, &options)
+}
+
+pub struct MinifyTask {
+    filename: String,
+    source_text: String,
+    options: MinifyOptions,
+}
+
+#[napi]
+impl Task for MinifyTask {
+    type JsValue = MinifyResult;
+    type Output = MinifyResult;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let source_text = mem::take(&mut self.source_text);
+        Ok(minify_with_options(&self.filename, &source_text, &self.options))
+    }
+
+    fn resolve(&mut self, _: napi::Env, result: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(result)
+    }
+}
+
+/// Minify asynchronously.
+///
+/// Note: This function can be slower than `minify` due to the overhead of spawning a thread.
+#[napi]
+pub fn minify_async(
+    filename: String,
+    source_text: String,
+    options: Option<MinifyOptions>,
+) -> AsyncTask<MinifyTask> {
+    let options = options.unwrap_or_default();
+    AsyncTask::new(MinifyTask { filename, source_text, options })
 }
@@ -0,0 +1,152 @@
+use napi::Either;
+use napi_derive::napi;
+
+use oxc_sourcemap::napi::SourceMap;
+
+/// Options for minifying a JavaScript or TypeScript file.
+///
+/// @see {@link minify}
+#[napi(object)]
+#[derive(Default)]
+pub struct MinifyOptions {
+    /// Fold constants, remove dead code and apply other size optimizations.
+    ///
+    /// Set to `false` to skip compression.
+    ///
+    /// @default true
+    pub compress: Option<Either<bool, CompressOptions>>,
+
+    /// Shorten the names of local variables.
+    ///
+    /// Set to `false` to keep the original names.
+    ///
+    /// @default true
+    pub mangle: Option<Either<bool, MangleOptions>>,
+
+    /// Configure how the minified code is printed.
+    pub codegen: Option<CodegenOptions>,
+
+    /// Enable source map generation.
+    ///
+    /// When `true`, the `map` field of the minify result is populated.
+    ///
+    /// @default false
+    pub sourcemap: Option<bool>,
+
+    /// The source map of the input code, e.g. produced by a previous
+    /// transform.
+    ///
+    /// When set together with {@link sourcemap}, the returned map points back
+    /// to the sources of this map instead of the input code.
+    pub input_map: Option<SourceMap>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct CompressOptions {
+    /// Remove `console.*` statements.
+    ///
+    /// @default false
+    pub drop_console: Option<bool>,
+
+    /// Remove `debugger;` statements.
+    ///
+    /// @default true
+    pub drop_debugger: Option<bool>,
+}
+
+impl From<&CompressOptions> for oxc_minifier::CompressOptions {
+    fn from(options: &CompressOptions) -> Self {
+        let default = Self::default();
+        Self {
+            drop_console: options.drop_console.unwrap_or(default.drop_console),
+            drop_debugger: options.drop_debugger.unwrap_or(default.drop_debugger),
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct MangleOptions {
+    /// Also mangle names declared in the top level scope.
+    ///
+    /// @default false
+    pub toplevel: Option<bool>,
+
+    /// Use more readable mangled names (e.g. `slot_0`, `slot_1`) for debugging.
+    ///
+    /// @default false
+    pub debug: Option<bool>,
+}
+
+impl From<&MangleOptions> for oxc_minifier::MangleOptions {
+    fn from(options: &MangleOptions) -> Self {
+        Self {
+            top_level: options.toplevel.unwrap_or_default(),
+            debug: options.debug.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct CodegenOptions {
+    /// Remove whitespace.
+    ///
+    /// @default true
+    pub remove_whitespace: Option<bool>,
+
+    /// What to do with legal comments, i.e. comments starting with `/*!` or
+    /// containing `@license` or `@preserve`.
+    ///
+    /// * `none`: remove all legal comments.
+    /// * `eof`: move all legal comments to the end of the file.
+    ///
+    /// @default 'none'
+    ///
+    /// @see [esbuild#legal-comments](https://esbuild.github.io/api/#legal-comments)
+    #[napi(ts_type = "'none' | 'eof'")]
+    pub legal_comments: Option<String>,
+}
+
+/// Options resolved from the user-facing {@link MinifyOptions}.
+pub(crate) struct ResolvedOptions {
+    pub compress: Option<oxc_minifier::CompressOptions>,
+    pub mangle: Option<oxc_minifier::MangleOptions>,
+    pub remove_whitespace: bool,
+    pub eof_legal_comments: bool,
+    pub sourcemap: bool,
+}
+
+impl TryFrom<&MinifyOptions> for ResolvedOptions {
+    type Error = String;
+
+    fn try_from(options: &MinifyOptions) -> Result<Self, Self::Error> {
+        let compress = match &options.compress {
+            None | Some(Either::A(true)) => Some(oxc_minifier::CompressOptions::default()),
+            Some(Either::A(false)) => None,
+            Some(Either::B(options)) => Some(options.into()),
+        };
+        let mangle = match &options.mangle {
+            None | Some(Either::A(true)) => Some(oxc_minifier::MangleOptions::default()),
+            Some(Either::A(false)) => None,
+            Some(Either::B(options)) => Some(options.into()),
+        };
+        let codegen = options.codegen.as_ref();
+        let eof_legal_comments = match codegen.and_then(|codegen| codegen.legal_comments.as_deref())
+        {
+            None | Some("none") => false,
+            Some("eof") => true,
+            Some(value) => return Err(format!("Invalid legalComments option: '{value}'")),
+        };
+        Ok(Self {
+            compress,
+            mangle,
+            remove_whitespace: codegen
+                .and_then(|codegen| codegen.remove_whitespace)
+                .unwrap_or(true),
+            eof_legal_comments,
+            sourcemap: options.sourcemap.unwrap_or_default(),
+        })
+    }
+}
@@ -0,0 +1,85 @@
+import { describe, expect, it } from 'vitest';
+
+import { minify, minifyAsync } from '../index';
+
+const code = `/*! license */
+function foo(veryLongName) { debugger; return veryLongName + 1; }
+console.log(foo(1));
+`;
+
+describe('minify', () => {
+  it('compresses and mangles by default', () => {
+    const ret = minify('test.js', code);
+    expect(ret).toStrictEqual({
+      code: 'function foo(b){return b+1}console.log(foo(1));',
+      errors: [],
+    });
+  });
+
+  it('can skip compression and mangling', () => {
+    const ret = minify('test.js', code, { compress: false, mangle: false });
+    expect(ret.code).toContain('debugger');
+    expect(ret.code).toContain('veryLongName');
+  });
+
+  it('keeps whitespace', () => {
+    const ret = minify('test.js', code, { codegen: { removeWhitespace: false } });
+    expect(ret.code).toContain('\n');
+  });
+
+  it('moves legal comments to the end of the file', () => {
+    const ret = minify('test.js', code, { codegen: { legalComments: 'eof' } });
+    expect(ret.code).toBe('function foo(b){return b+1}console.log(foo(1));\n/*! license */\n');
+  });
+
+  it('returns parse errors', () => {
+    const ret = minify('test.js', 'const = 1');
+    expect(ret.code).toBe('');
+    expect(ret.errors.length).toBeGreaterThan(0);
+  });
+
+  it('returns errors for invalid options', () => {
+    // @ts-expect-error
+    const ret = minify('test.js', code, { codegen: { legalComments: 'linked' } });
+    expect(ret.errors.map((e) => e.message)).toStrictEqual(["Invalid legalComments option: 'linked'"]);
+  });
+});
+
+describe('sourcemap', () => {
+  it('maps to the input file', () => {
+    const ret = minify('test.js', code, { sourcemap: true });
+    expect(ret.map).toMatchObject({
+      names: ['veryLongName'],
+      sources: ['test.js'],
+      sourcesContent: [code],
+    });
+  });
+
+  it('composes with the input source map', () => {
+    const original = 'const a: number = 1;\nconsole.log(a);\n';
+    const ret = minify('test.js', 'const a = 1;\nconsole.log(a);\n', {
+      sourcemap: true,
+      mangle: false,
+      inputMap: {
+        mappings: 'AAAA;AACA',
+        names: [],
+        sources: ['test.ts'],
+        sourcesContent: [original],
+        version: 3,
+        x_google_ignoreList: [0],
+      },
+    });
+    expect(ret.map).toMatchObject({
+      sources: ['test.ts'],
+      sourcesContent: [original],
+      x_google_ignoreList: [0],
+    });
+  });
+});
+
+describe('async', () => {
+  it('matches the sync result', async () => {
+    const ret = await minifyAsync('test.js', code, { sourcemap: true });
+    expect(ret).toStrictEqual(minify('test.js', code, { sourcemap: true }));
+  });
+});
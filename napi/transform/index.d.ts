@@ -254,6 +254,14 @@ export interface TransformOptions {
    * @see {@link SourceMap}
    */
   sourcemap?: boolean
+  /**
+   * The source map of the source text, e.g. produced by an earlier build
+   * step.
+   *
+   * When set together with {@link sourcemap}, the returned source maps
+   * point back to the sources of this map instead of the source text.
+   */
+  inputMap?: SourceMap
   /** Set assumptions in order to produce smaller output. */
   assumptions?: CompilerAssumptions
   /** Configure how TypeScript is transformed. */
@@ -11,7 +11,7 @@ use napi_derive::napi;
 use rustc_hash::FxHashMap;
 
 use oxc::{
-    codegen::CodegenReturn,
+    codegen::{CodegenOptions, CodegenReturn},
     diagnostics::OxcDiagnostic,
     span::SourceType,
     transformer::{
@@ -20,7 +20,7 @@ use oxc::{
     },
     CompilerInterface,
 };
-use oxc_napi::OxcError;
+use oxc_napi::{decode_source_map, OxcError};
 use oxc_sourcemap::napi::SourceMap;
 
 use crate::IsolatedDeclarationsOptions;
@@ -99,6 +99,13 @@ pub struct TransformOptions {
     /// @see {@link SourceMap}
     pub sourcemap: Option<bool>,
 
+    /// The source map of the source text, e.g. produced by an earlier build
+    /// step.
+    ///
+    /// When set together with {@link sourcemap}, the returned source maps
+    /// point back to the sources of this map instead of the source text.
+    pub input_map: Option<SourceMap>,
+
     /// Set assumptions in order to produce smaller output.
     pub assumptions: Option<CompilerAssumptions>,
 
@@ -152,6 +159,8 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
                 .unwrap_or_default(),
             jsx: options.jsx.map(Into::into).unwrap_or_default(),
             env,
+            plugins: oxc::transformer::PluginsOptions::default(),
+            rewrite_imports: None,
             helper_loader: options
                 .helpers
                 .map_or_else(HelperLoaderOptions::default, HelperLoaderOptions::from),
@@ -466,6 +475,8 @@ struct Compiler {
     isolated_declaration_options: Option<oxc::isolated_declarations::IsolatedDeclarationsOptions>,
 
     sourcemap: bool,
+    input_source_map: Option<oxc_sourcemap::SourceMap>,
+    x_google_ignore_list: Option<Vec<u32>>,
 
     printed: String,
     printed_sourcemap: Option<SourceMap>,
@@ -492,6 +503,11 @@ impl Compiler {
 
         let sourcemap = options.as_ref().and_then(|o| o.sourcemap).unwrap_or_default();
 
+        let input_map = options.as_mut().and_then(|options| options.input_map.take());
+        let input_source_map =
+            input_map.as_ref().map(decode_source_map).transpose().map_err(|err| vec![err])?;
+        let x_google_ignore_list = input_map.and_then(|map| map.x_google_ignorelist);
+
         let define = options
             .as_mut()
             .and_then(|options| options.define.take())
@@ -537,6 +553,8 @@ impl Compiler {
             transform_options,
             isolated_declaration_options,
             sourcemap,
+            input_source_map,
+            x_google_ignore_list,
             printed: String::default(),
             printed_sourcemap: None,
             declaration: None,
@@ -549,6 +567,15 @@ impl Compiler {
     }
 }
 
+impl Compiler {
+    fn to_napi_source_map(&self, map: oxc_sourcemap::SourceMap) -> SourceMap {
+        let mut map = SourceMap::from(map);
+        // Composed maps keep the sources of the input map in order.
+        map.x_google_ignorelist.clone_from(&self.x_google_ignore_list);
+        map
+    }
+}
+
 impl CompilerInterface for Compiler {
     fn handle_errors(&mut self, errors: Vec<OxcDiagnostic>) {
         self.errors.extend(errors);
@@ -576,14 +603,21 @@ impl CompilerInterface for Compiler {
         self.inject.clone()
     }
 
+    fn codegen_options(&self) -> Option<CodegenOptions> {
+        Some(CodegenOptions {
+            input_source_map: self.input_source_map.clone(),
+            ..CodegenOptions::default()
+        })
+    }
+
     fn after_codegen(&mut self, ret: CodegenReturn) {
         self.printed = ret.code;
-        self.printed_sourcemap = ret.map.map(SourceMap::from);
+        self.printed_sourcemap = ret.map.map(|map| self.to_napi_source_map(map));
     }
 
     fn after_isolated_declarations(&mut self, ret: CodegenReturn) {
         self.declaration.replace(ret.code);
-        self.declaration_map = ret.map.map(SourceMap::from);
+        self.declaration_map = ret.map.map(|map| self.to_napi_source_map(map));
     }
 
     #[allow(deprecated)]
@@ -47,6 +47,26 @@ describe('simple', () => {
       },
     );
   });
+
+  it('uses the `inputMap` option', () => {
+    const original = '// generated\nexport class A<T> {}';
+    const ret = transform('test.ts', code, {
+      sourcemap: true,
+      inputMap: {
+        mappings: 'AACA',
+        names: [],
+        sources: ['original.ts', 'vendor.ts'],
+        sourcesContent: [original, ''],
+        version: 3,
+        x_google_ignoreList: [1],
+      },
+    });
+    expect(ret.map).toMatchObject({
+      sources: ['original.ts', 'vendor.ts'],
+      sourcesContent: [original, ''],
+      x_google_ignoreList: [1],
+    });
+  });
 });
 
 describe('transform', () => {
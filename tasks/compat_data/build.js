@@ -8,6 +8,8 @@ const interpolateAllResults = require('./compat-table/build-utils/interpolate-al
 const compareVersions = require('./compat-table/build-utils/compare-versions');
 const { addElectronSupportFromChromium } = require('./chromium-to-electron');
 const esFeatures = require(`./es-features`);
+const coreJsModules = require('./core-js-modules');
+const coreJsCompat = require('core-js-compat/data.json');
 
 const environments = [
   'chrome',
@@ -115,3 +117,21 @@ const generateData = (environments, items) => {
 const items = generateData(environments, esFeatures);
 
 fs.writeFileSync('./data.json', JSON.stringify(items, null, 2));
+
+// https://github.com/zloirock/core-js/tree/v3.39.0/packages/core-js-compat
+const coreJsEnvironments = {
+  ...Object.fromEntries(environments.map(env => [env, env])),
+  'opera-android': 'opera_mobile',
+  electron: 'electron',
+};
+
+const coreJsData = coreJsModules.map(({ name, es }) => {
+  const targets = {};
+  for (const [env, version] of Object.entries(coreJsCompat[name])) {
+    const target = coreJsEnvironments[env];
+    if (target) targets[target] = version;
+  }
+  return es ? { name, es, targets } : { name, targets };
+});
+
+fs.writeFileSync('./core-js-data.json', JSON.stringify(coreJsData, null, 2) + '\n');
@@ -0,0 +1,45 @@
+// core-js modules which can be injected by `useBuiltIns: "usage"`.
+// `es` is the ECMAScript version which includes the built-in, web built-ins have none.
+
+module.exports = [
+  { name: 'es.aggregate-error', es: 'ES2021' },
+  { name: 'es.array.at', es: 'ES2022' },
+  { name: 'es.array.find-last', es: 'ES2023' },
+  { name: 'es.array.find-last-index', es: 'ES2023' },
+  { name: 'es.array.flat', es: 'ES2019' },
+  { name: 'es.array.flat-map', es: 'ES2019' },
+  { name: 'es.array.includes', es: 'ES2016' },
+  { name: 'es.array.to-reversed', es: 'ES2023' },
+  { name: 'es.array.to-sorted', es: 'ES2023' },
+  { name: 'es.array.to-spliced', es: 'ES2023' },
+  { name: 'es.array.with', es: 'ES2023' },
+  { name: 'es.global-this', es: 'ES2020' },
+  { name: 'es.map', es: 'ES2015' },
+  { name: 'es.map.group-by', es: 'ES2024' },
+  { name: 'es.object.entries', es: 'ES2017' },
+  { name: 'es.object.from-entries', es: 'ES2019' },
+  { name: 'es.object.group-by', es: 'ES2024' },
+  { name: 'es.object.has-own', es: 'ES2022' },
+  { name: 'es.object.values', es: 'ES2017' },
+  { name: 'es.promise', es: 'ES2015' },
+  { name: 'es.promise.all-settled', es: 'ES2020' },
+  { name: 'es.promise.any', es: 'ES2021' },
+  { name: 'es.promise.finally', es: 'ES2018' },
+  { name: 'es.promise.with-resolvers', es: 'ES2024' },
+  { name: 'es.set', es: 'ES2015' },
+  { name: 'es.string.at-alternative', es: 'ES2022' },
+  { name: 'es.string.includes', es: 'ES2015' },
+  { name: 'es.string.is-well-formed', es: 'ES2024' },
+  { name: 'es.string.match-all', es: 'ES2020' },
+  { name: 'es.string.pad-end', es: 'ES2017' },
+  { name: 'es.string.pad-start', es: 'ES2017' },
+  { name: 'es.string.replace-all', es: 'ES2021' },
+  { name: 'es.string.to-well-formed', es: 'ES2024' },
+  { name: 'es.string.trim-end', es: 'ES2019' },
+  { name: 'es.string.trim-start', es: 'ES2019' },
+  { name: 'es.symbol', es: 'ES2015' },
+  { name: 'es.weak-map', es: 'ES2015' },
+  { name: 'es.weak-set', es: 'ES2015' },
+  { name: 'web.queue-microtask' },
+  { name: 'web.structured-clone' },
+];
@@ -15,6 +15,13 @@ struct Item {
     targets: EngineTargets,
 }
 
+#[derive(Debug, Deserialize)]
+struct CoreJsItem {
+    name: String,
+    es: Option<String>,
+    targets: EngineTargets,
+}
+
 impl Item {
     fn es_name(&self) -> Ident {
         quote::format_ident!("{}{}", self.es, self.name)
@@ -86,6 +93,57 @@ pub fn generate() {
     };
 
     generate_file("crates/oxc_transformer/src/options/es_features.rs", code);
+
+    generate_core_js_data();
+}
+
+fn generate_core_js_data() {
+    let path = project_root().join("tasks/compat_data/core-js-data.json");
+    let content = fs::read_to_string(path).unwrap();
+    let items = serde_json::from_str::<Vec<CoreJsItem>>(&content).unwrap();
+
+    let modules = items.iter().map(|item| {
+        let name = &item.name;
+        let targets = item
+            .targets
+            .iter()
+            .map(|(engine, version)| {
+                let engine = quote::format_ident!("{engine:?}");
+                let (a, b, c) = (version.0, version.1, version.2);
+                quote! {
+                    (#engine, Version(#a, #b, #c))
+                }
+            })
+            .chain(item.es.iter().map(|es| {
+                let es_version = u32::from_str(es.trim_start_matches("ES")).unwrap();
+                quote! { (Es, Version(#es_version, 0, 0)) }
+            }));
+        quote! {
+            (#name, EngineTargets::new(FxHashMap::from_iter([#(#targets),*])))
+        }
+    });
+
+    let code = quote! {
+        #![allow(clippy::enum_glob_use)]
+
+        use std::sync::OnceLock;
+
+        use browserslist::Version;
+        use rustc_hash::FxHashMap;
+
+        use crate::{Engine, EngineTargets};
+
+        /// Minimum engine versions which support each core-js module without a polyfill.
+        pub fn core_js_modules() -> &'static FxHashMap<&'static str, EngineTargets> {
+            use Engine::*;
+            static MODULES: OnceLock<FxHashMap<&'static str, EngineTargets>> = OnceLock::new();
+            MODULES.get_or_init(|| {
+                FxHashMap::from_iter([#(#modules),*])
+            })
+        }
+    };
+
+    generate_file("crates/oxc_transformer/src/polyfills/core_js_data.rs", code);
 }
 
 fn generate_file(file: &str, token_stream: proc_macro2::TokenStream) {
@@ -0,0 +1,11 @@
+async function f() {
+  class A {
+    x = await 1;
+  }
+}
+
+function* g() {
+  class A {
+    x = yield;
+  }
+}
@@ -0,0 +1,8 @@
+x: while (true) {
+  () => {
+    break x;
+  };
+  () => {
+    continue x;
+  };
+}
@@ -0,0 +1,15 @@
+class A extends B {
+  constructor() {
+    function f() {
+      super();
+    }
+  }
+  x = function () {
+    super.x;
+  };
+  m() {
+    function f() {
+      super.m();
+    }
+  }
+}
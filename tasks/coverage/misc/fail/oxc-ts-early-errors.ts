@@ -0,0 +1,30 @@
+abstract class A {
+  abstract foo() {}
+  abstract get x() {
+    return 1;
+  }
+}
+
+declare class B {
+  foo() {}
+  x = 1;
+}
+
+class C {
+  declare x = 1;
+  constructor<T>() {}
+}
+
+declare function f() {}
+
+declare namespace N {
+  function f() {}
+}
+
+interface I {
+  get x(a): number;
+  set x();
+}
+
+import type { type D } from "d";
+export type { type E } from "e";
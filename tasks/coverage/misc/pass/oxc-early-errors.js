@@ -0,0 +1,26 @@
+async function f() {
+  class A {
+    [await x] = 1;
+    y = async () => await 1;
+  }
+}
+
+class B extends C {
+  constructor() {
+    const f = () => super();
+  }
+  x = () => super.x;
+  m() {
+    ({
+      f() {
+        super.f();
+      },
+    });
+  }
+}
+
+a: {
+  () => {
+    a: ;
+  };
+}
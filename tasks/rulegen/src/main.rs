@@ -57,6 +57,18 @@ const REACT_PERF_TEST_PATH: &str =
 const NODE_TEST_PATH: &str =
     "https://raw.githubusercontent.com/eslint-community/eslint-plugin-n/master/tests/lib/rules";
 
+const REGEXP_TEST_PATH: &str =
+    "https://raw.githubusercontent.com/ota-meshi/eslint-plugin-regexp/master/tests/lib/rules";
+
+const VUE_TEST_PATH: &str =
+    "https://raw.githubusercontent.com/vuejs/eslint-plugin-vue/master/tests/lib/rules";
+
+const TESTING_LIBRARY_TEST_PATH: &str =
+    "https://raw.githubusercontent.com/testing-library/eslint-plugin-testing-library/main/tests/lib/rules";
+
+const SECURITY_TEST_PATH: &str =
+    "https://raw.githubusercontent.com/eslint-community/eslint-plugin-security/main/test/rules";
+
 const PROMISE_TEST_PATH: &str =
     "https://raw.githubusercontent.com/eslint-community/eslint-plugin-promise/main/__tests__";
 
@@ -597,6 +609,10 @@ pub enum RuleKind {
     Node,
     Promise,
     Vitest,
+    Regexp,
+    Vue,
+    TestingLibrary,
+    Security,
 }
 
 impl RuleKind {
@@ -615,6 +631,10 @@ impl RuleKind {
             "n" => Self::Node,
             "promise" => Self::Promise,
             "vitest" => Self::Vitest,
+            "regexp" => Self::Regexp,
+            "vue" => Self::Vue,
+            "testing-library" => Self::TestingLibrary,
+            "security" => Self::Security,
             _ => Self::ESLint,
         }
     }
@@ -637,6 +657,10 @@ impl Display for RuleKind {
             Self::Node => write!(f, "eslint-plugin-n"),
             Self::Promise => write!(f, "eslint-plugin-promise"),
             Self::Vitest => write!(f, "eslint-plugin-vitest"),
+            Self::Regexp => write!(f, "eslint-plugin-regexp"),
+            Self::Vue => write!(f, "eslint-plugin-vue"),
+            Self::TestingLibrary => write!(f, "eslint-plugin-testing-library"),
+            Self::Security => write!(f, "eslint-plugin-security"),
         }
     }
 }
@@ -665,6 +689,12 @@ fn main() {
         RuleKind::Node => format!("{NODE_TEST_PATH}/{kebab_rule_name}.js"),
         RuleKind::Promise => format!("{PROMISE_TEST_PATH}/{kebab_rule_name}.js"),
         RuleKind::Vitest => format!("{VITEST_TEST_PATH}/{kebab_rule_name}.test.ts"),
+        RuleKind::Regexp => format!("{REGEXP_TEST_PATH}/{kebab_rule_name}.ts"),
+        RuleKind::Vue => format!("{VUE_TEST_PATH}/{kebab_rule_name}.js"),
+        RuleKind::TestingLibrary => {
+            format!("{TESTING_LIBRARY_TEST_PATH}/{kebab_rule_name}.test.ts")
+        }
+        RuleKind::Security => format!("{SECURITY_TEST_PATH}/{kebab_rule_name}.js"),
         RuleKind::Oxc => String::new(),
     };
     let language = match rule_kind {
@@ -788,6 +818,10 @@ fn add_rules_entry(ctx: &Context, rule_kind: RuleKind) -> Result<(), Box<dyn std
         RuleKind::Promise => "promise",
         RuleKind::Vitest => "vitest",
         RuleKind::Node => "node",
+        RuleKind::Regexp => "regexp",
+        RuleKind::Vue => "vue",
+        RuleKind::TestingLibrary => "testing_library",
+        RuleKind::Security => "security",
     };
     let mod_def = format!("mod {mod_name}");
     let Some(mod_start) = rules.find(&mod_def) else {
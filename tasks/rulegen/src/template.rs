@@ -44,6 +44,10 @@ impl<'a> Template<'a> {
             RuleKind::Node => Path::new("crates/oxc_linter/src/rules/node"),
             RuleKind::Promise => Path::new("crates/oxc_linter/src/rules/promise"),
             RuleKind::Vitest => Path::new("crates/oxc_linter/src/rules/vitest"),
+            RuleKind::Regexp => Path::new("crates/oxc_linter/src/rules/regexp"),
+            RuleKind::Vue => Path::new("crates/oxc_linter/src/rules/vue"),
+            RuleKind::TestingLibrary => Path::new("crates/oxc_linter/src/rules/testing_library"),
+            RuleKind::Security => Path::new("crates/oxc_linter/src/rules/security"),
         };
 
         std::fs::create_dir_all(path)?;